@@ -135,6 +135,7 @@ pub enum ContactsServiceRequest {
     UpsertContact(Contact),
     RemoveContact(TariAddress),
     GetContacts,
+    SearchContacts(String),
     GetContactOnlineStatus(Contact),
     SendMessage(TariAddress, Message),
     GetMessages(TariAddress, i64, i64),
@@ -203,6 +204,17 @@ impl ContactsServiceHandle {
         }
     }
 
+    pub async fn search_contacts(&mut self, query: String) -> Result<Vec<Contact>, ContactsServiceError> {
+        match self
+            .request_response_service
+            .call(ContactsServiceRequest::SearchContacts(query))
+            .await??
+        {
+            ContactsServiceResponse::Contacts(c) => Ok(c),
+            _ => Err(ContactsServiceError::UnexpectedApiResponse),
+        }
+    }
+
     pub async fn upsert_contact(&mut self, contact: Contact) -> Result<(), ContactsServiceError> {
         match self
             .request_response_service
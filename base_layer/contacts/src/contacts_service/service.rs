@@ -289,6 +289,10 @@ where T: ContactsBackend + 'static
                 }
                 Ok(result.map(ContactsServiceResponse::Contacts)?)
             },
+            ContactsServiceRequest::SearchContacts(query) => {
+                let result = self.db.search_contacts(query);
+                Ok(result.map(ContactsServiceResponse::Contacts)?)
+            },
             ContactsServiceRequest::GetContactOnlineStatus(contact) => {
                 let result = self.get_online_status(&contact).await;
                 Ok(result.map(ContactsServiceResponse::OnlineStatus)?)
@@ -52,6 +52,7 @@ pub enum DbKey {
     Contact(TariAddress),
     ContactId(NodeId),
     Contacts,
+    ContactsByAlias(String),
     Message(Vec<u8>),
     Messages(TariAddress, i64, i64),
     Conversationalists,
@@ -124,6 +125,20 @@ where T: ContactsBackend + 'static
         }
     }
 
+    pub fn search_contacts(&self, query: String) -> Result<Vec<Contact>, ContactsServiceStorageError> {
+        let db_clone = self.db.clone();
+        let key = DbKey::ContactsByAlias(query);
+        match db_clone.fetch(&key) {
+            Ok(None) => log_error(
+                key,
+                ContactsServiceStorageError::UnexpectedResult("Could not retrieve contacts".to_string()),
+            ),
+            Ok(Some(DbValue::Contacts(c))) => Ok(c),
+            Ok(Some(other)) => unexpected_result(key, other),
+            Err(e) => log_error(key, e),
+        }
+    }
+
     pub fn upsert_contact(&self, contact: Contact) -> Result<(), ContactsServiceStorageError> {
         self.db.write(WriteOperation::Upsert(Box::new(DbKeyValuePair::Contact(
             contact.address.clone(),
@@ -262,6 +277,7 @@ impl Display for DbKey {
             DbKey::Contact(c) => f.write_str(&format!("Contact: {:?}", c)),
             DbKey::ContactId(id) => f.write_str(&format!("Contact: {:?}", id)),
             DbKey::Contacts => f.write_str("Contacts"),
+            DbKey::ContactsByAlias(q) => f.write_str(&format!("Contacts matching: {}", q)),
             DbKey::Messages(c, _l, _p) => f.write_str(&format!("Messages for id: {:?}", c)),
             DbKey::Message(m) => f.write_str(&format!("Message for id: {:?}", m)),
             DbKey::Conversationalists => f.write_str("Conversationalists"),
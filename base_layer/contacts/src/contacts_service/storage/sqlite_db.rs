@@ -111,6 +111,12 @@ where TContactServiceDbConnection: PooledDbConnection<Error = SqliteStorageError
                     .map(|c| Contact::try_from(c.clone()))
                     .collect::<Result<Vec<_>, _>>()?,
             )),
+            DbKey::ContactsByAlias(query) => Some(DbValue::Contacts(
+                ContactSql::search_by_alias(query, &mut conn)?
+                    .iter()
+                    .map(|c| Contact::try_from(c.clone()))
+                    .collect::<Result<Vec<_>, _>>()?,
+            )),
             DbKey::Messages(address, limit, page) => {
                 match MessagesSql::find_by_address(&address.to_vec(), *limit, *page, &mut conn) {
                     Ok(messages_sql) => {
@@ -211,6 +217,7 @@ where TContactServiceDbConnection: PooledDbConnection<Error = SqliteStorageError
                     Err(e) => return Err(e),
                 },
                 DbKey::Contacts => return Err(ContactsServiceStorageError::OperationNotSupported),
+                DbKey::ContactsByAlias(_) => return Err(ContactsServiceStorageError::OperationNotSupported),
                 DbKey::Messages(_pk, _l, _p) => return Err(ContactsServiceStorageError::OperationNotSupported),
                 DbKey::Message(_id) => return Err(ContactsServiceStorageError::OperationNotSupported),
                 DbKey::Conversationalists => return Err(ContactsServiceStorageError::OperationNotSupported),
@@ -266,6 +273,12 @@ mod test {
                 assert!(retrieved_contacts.iter().any(|v| v == &ContactSql::from(i.clone())));
             }
 
+            let matches = ContactSql::search_by_alias("aRo", &mut conn).unwrap();
+            assert_eq!(matches.len(), 1);
+            assert_eq!(matches[0].alias, "Carol".to_string());
+            assert_eq!(ContactSql::search_by_alias("", &mut conn).unwrap().len(), 3);
+            assert!(ContactSql::search_by_alias("%", &mut conn).unwrap().is_empty());
+
             assert_eq!(
                 contacts[1],
                 Contact::try_from(ContactSql::find_by_address(&contacts[1].address.to_vec(), &mut conn).unwrap())
@@ -60,6 +60,21 @@ impl ContactSql {
         Ok(contacts::table.load::<ContactSql>(conn)?)
     }
 
+    /// Return all contacts whose alias contains `query`. SQLite matches ASCII characters case-insensitively. An empty
+    /// query returns all contacts.
+    pub fn search_by_alias(
+        query: &str,
+        conn: &mut SqliteConnection,
+    ) -> Result<Vec<ContactSql>, ContactsServiceStorageError> {
+        if query.is_empty() {
+            return Self::index(conn);
+        }
+        let escaped = query.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_");
+        Ok(contacts::table
+            .filter(contacts::alias.like(format!("%{}%", escaped)).escape('\\'))
+            .load::<ContactSql>(conn)?)
+    }
+
     /// Find a particular Contact by their address, if it exists
     pub fn find_by_address(
         address: &[u8],
@@ -682,6 +682,17 @@ where TBackend: KeyManagerBackend<PublicKey> + 'static
         Err(KeyManagerServiceError::KeyNotFoundInKeyChain)
     }
 
+    /// Returns the current key index of the specified branch key manager.
+    pub async fn get_current_key_index(&self, branch: &str) -> Result<u64, KeyManagerServiceError> {
+        let km = self
+            .key_managers
+            .get(branch)
+            .ok_or_else(|| self.unknown_key_branch_error("get_current_key_index", branch))?
+            .read()
+            .await;
+        Ok(km.key_index())
+    }
+
     /// If the supplied index is higher than the current UTXO key chain indices then they will be updated.
     pub async fn update_current_key_index_if_higher(
         &self,
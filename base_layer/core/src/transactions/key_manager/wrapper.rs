@@ -158,6 +158,14 @@ where TBackend: KeyManagerBackend<PublicKey> + 'static
             .await
     }
 
+    async fn get_current_key_index<T: Into<String> + Send>(&self, branch: T) -> Result<u64, KeyManagerServiceError> {
+        self.transaction_key_manager_inner
+            .read()
+            .await
+            .get_current_key_index(&branch.into())
+            .await
+    }
+
     async fn update_current_key_index_if_higher<T: Into<String> + Send>(
         &self,
         branch: T,
@@ -243,6 +243,9 @@ where
     /// Searches the branch to find the index used to generated the key, O(N) where N = index used.
     async fn find_key_index<T: Into<String> + Send>(&self, branch: T, key: &PK) -> Result<u64, KeyManagerServiceError>;
 
+    /// Gets the current key index of the branch, i.e. the index of the last key derived from it
+    async fn get_current_key_index<T: Into<String> + Send>(&self, branch: T) -> Result<u64, KeyManagerServiceError>;
+
     /// Will update the index of the branch if the index given is higher than the current saved index
     async fn update_current_key_index_if_higher<T: Into<String> + Send>(
         &self,
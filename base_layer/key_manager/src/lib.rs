@@ -79,6 +79,11 @@ impl SeedWords {
         self.words.push(word);
     }
 
+    /// Removes all words, the hidden words are zeroized as they are dropped
+    pub fn clear(&mut self) {
+        self.words.clear();
+    }
+
     pub fn join(&self, sep: &str) -> Hidden<String> {
         Hidden::hide(
             self.words
@@ -119,6 +124,17 @@ mod tests {
         assert_eq!(seed_words.len(), 5_usize);
     }
 
+    #[test]
+    pub fn test_seed_words_clear() {
+        let mut seed_words = SeedWords::new(vec![Hidden::hide("hi".to_string()), Hidden::hide("niao".to_string())]);
+        seed_words.clear();
+        assert!(seed_words.is_empty());
+
+        seed_words.push("hola".to_string());
+        assert_eq!(seed_words.len(), 1_usize);
+        assert_eq!(seed_words.get_word(0).unwrap(), "hola");
+    }
+
     #[test]
     pub fn test_seed_words_get_word_at_index() {
         let seed_words = SeedWords::new(vec![
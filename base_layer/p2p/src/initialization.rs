@@ -245,11 +245,19 @@ pub async fn spawn_comms_using_transport<F: Fn(TorIdentity) + Send + Sync + Unpi
             );
             let mut transport = TcpWithTorTransport::new();
             if let Some(addr) = config.tor_socks_address {
-                transport.set_tor_socks_proxy(SocksConfig {
+                let socks_config = SocksConfig {
                     proxy_address: addr,
                     authentication: config.tor_socks_auth.into(),
                     proxy_bypass_predicate: Arc::new(FalsePredicate::new()),
-                });
+                };
+                if config.tor_socks_bypass_for_tcp {
+                    transport.set_tor_socks_proxy_for_onion_only(socks_config);
+                } else {
+                    transport.set_tor_socks_proxy(socks_config);
+                }
+            }
+            if let Some(dial_timeout) = config.dial_timeout {
+                transport.tcp_transport_mut().set_connect_timeout(dial_timeout);
             }
             comms
                 .with_listener_address(config.listener_address)
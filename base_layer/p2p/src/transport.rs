@@ -19,9 +19,10 @@
 //  SERVICES; LOSS OF USE, DATA, OR PROFITS; OR BUSINESS INTERRUPTION) HOWEVER CAUSED AND ON ANY THEORY OF LIABILITY,
 //  WHETHER IN CONTRACT, STRICT LIABILITY, OR TORT (INCLUDING NEGLIGENCE OR OTHERWISE) ARISING IN ANY WAY OUT OF THE
 //  USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
-use std::{num::NonZeroU16, sync::Arc};
+use std::{num::NonZeroU16, sync::Arc, time::Duration};
 
 use serde::{Deserialize, Serialize};
+use tari_common::configuration::serializers;
 use tari_comms::{
     multiaddr::Multiaddr,
     socks,
@@ -118,6 +119,12 @@ pub struct TcpTransportConfig {
     pub tor_socks_address: Option<Multiaddr>,
     /// Optional tor SOCKS proxy authentication
     pub tor_socks_auth: SocksAuthentication,
+    /// When set to true, DNS addresses are resolved directly instead of through the tor SOCKS proxy, which is then
+    /// only used to dial onion addresses.
+    pub tor_socks_bypass_for_tcp: bool,
+    /// The maximum time to wait for an outbound TCP connection to be established. If None, the OS default is used.
+    #[serde(with = "serializers::optional_seconds")]
+    pub dial_timeout: Option<Duration>,
 }
 
 impl Default for TcpTransportConfig {
@@ -126,6 +133,8 @@ impl Default for TcpTransportConfig {
             listener_address: "/ip4/0.0.0.0/tcp/18189".parse().unwrap(),
             tor_socks_address: None,
             tor_socks_auth: SocksAuthentication::None,
+            tor_socks_bypass_for_tcp: false,
+            dial_timeout: None,
         }
     }
 }
@@ -0,0 +1,9 @@
+-- The status of an imported transaction moves to 'OneSidedConfirmed' once it is mined, so whether it was received as
+-- a one-sided payment has to be recorded separately. Transactions stored before this column existed keep the
+-- classification their status gave them.
+ALTER TABLE completed_transactions
+    ADD one_sided_payment BOOLEAN NOT NULL DEFAULT 0;
+
+UPDATE completed_transactions
+    SET one_sided_payment = 1
+    WHERE status IN (8, 9);
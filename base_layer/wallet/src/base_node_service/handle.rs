@@ -36,12 +36,14 @@ pub type BaseNodeEventReceiver = broadcast::Receiver<Arc<BaseNodeEvent>>;
 pub enum BaseNodeServiceRequest {
     GetChainMetadata,
     GetBaseNodeLatency,
+    GetIsSynced,
 }
 /// API Response enum
 #[derive(Debug)]
 pub enum BaseNodeServiceResponse {
     ChainMetadata(Option<ChainMetadata>),
     Latency(Option<Duration>),
+    IsSynced(Option<bool>),
 }
 #[derive(Clone, Debug, Hash, PartialEq, Eq)]
 pub enum BaseNodeEvent {
@@ -98,4 +100,11 @@ impl BaseNodeServiceHandle {
             _ => Err(BaseNodeServiceError::UnexpectedApiResponse),
         }
     }
+
+    pub async fn get_is_synced(&mut self) -> Result<Option<bool>, BaseNodeServiceError> {
+        match self.handle.call(BaseNodeServiceRequest::GetIsSynced).await?? {
+            BaseNodeServiceResponse::IsSynced(is_synced) => Ok(is_synced),
+            _ => Err(BaseNodeServiceError::UnexpectedApiResponse),
+        }
+    }
 }
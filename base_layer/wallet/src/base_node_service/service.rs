@@ -162,6 +162,9 @@ where T: WalletBackend + 'static
             BaseNodeServiceRequest::GetBaseNodeLatency => {
                 Ok(BaseNodeServiceResponse::Latency(self.state.read().await.latency))
             },
+            BaseNodeServiceRequest::GetIsSynced => {
+                Ok(BaseNodeServiceResponse::IsSynced(self.state.read().await.is_synced))
+            },
         }
     }
 }
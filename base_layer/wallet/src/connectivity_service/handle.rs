@@ -37,6 +37,7 @@ use crate::{
 pub enum WalletConnectivityRequest {
     ObtainBaseNodeWalletRpcClient(oneshot::Sender<RpcClientLease<BaseNodeWalletRpcClient>>),
     ObtainBaseNodeSyncRpcClient(oneshot::Sender<RpcClientLease<BaseNodeSyncRpcClient>>),
+    ResetBaseNodeConnection(oneshot::Sender<bool>),
 }
 
 #[derive(Clone)]
@@ -118,6 +119,22 @@ impl WalletConnectivityInterface for WalletConnectivityHandle {
         reply_rx.await.ok()
     }
 
+    /// Drop the connection to the currently selected base node and re-establish it. Returns true if a reconnection
+    /// attempt was initiated, false if no base node has been set or the service is shutting down.
+    async fn reset_base_node_connection(&mut self) -> bool {
+        let (reply_tx, reply_rx) = oneshot::channel();
+        if self
+            .sender
+            .send(WalletConnectivityRequest::ResetBaseNodeConnection(reply_tx))
+            .await
+            .is_err()
+        {
+            return false;
+        }
+
+        reply_rx.await.unwrap_or(false)
+    }
+
     fn get_connectivity_status(&mut self) -> OnlineStatus {
         *self.online_status_rx.borrow()
     }
@@ -64,6 +64,10 @@ pub trait WalletConnectivityInterface: Clone + Send + Sync + 'static {
     /// BaseNodeSyncRpcClient RPC session.
     async fn obtain_base_node_sync_rpc_client(&mut self) -> Option<RpcClientLease<BaseNodeSyncRpcClient>>;
 
+    /// Drop the connection to the currently selected base node and re-establish it. Returns true if a reconnection
+    /// attempt was initiated.
+    async fn reset_base_node_connection(&mut self) -> bool;
+
     fn get_connectivity_status(&mut self) -> OnlineStatus;
 
     fn get_connectivity_status_watch(&self) -> watch::Receiver<OnlineStatus>;
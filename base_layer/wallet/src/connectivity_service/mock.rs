@@ -116,6 +116,10 @@ impl WalletConnectivityInterface for WalletConnectivityMock {
         borrow.as_ref().cloned()
     }
 
+    async fn reset_base_node_connection(&mut self) -> bool {
+        self.base_node_watch.borrow().is_some()
+    }
+
     fn get_connectivity_status(&mut self) -> OnlineStatus {
         *self.online_status_watch.borrow()
     }
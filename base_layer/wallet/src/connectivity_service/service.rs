@@ -161,7 +161,11 @@ impl WalletConnectivityService {
     }
 
     async fn handle_request(&mut self, request: WalletConnectivityRequest) {
-        use WalletConnectivityRequest::{ObtainBaseNodeSyncRpcClient, ObtainBaseNodeWalletRpcClient};
+        use WalletConnectivityRequest::{
+            ObtainBaseNodeSyncRpcClient,
+            ObtainBaseNodeWalletRpcClient,
+            ResetBaseNodeConnection,
+        };
         match request {
             ObtainBaseNodeWalletRpcClient(reply) => {
                 self.handle_pool_request(reply.into()).await;
@@ -169,6 +173,9 @@ impl WalletConnectivityService {
             ObtainBaseNodeSyncRpcClient(reply) => {
                 self.handle_pool_request(reply.into()).await;
             },
+            ResetBaseNodeConnection(reply) => {
+                self.handle_reset_base_node_connection(reply).await;
+            },
         }
     }
 
@@ -264,6 +271,22 @@ impl WalletConnectivityService {
         }
     }
 
+    async fn handle_reset_base_node_connection(&mut self, reply: oneshot::Sender<bool>) {
+        let node_id = match self.current_base_node() {
+            Some(node_id) => node_id,
+            None => {
+                debug!(target: LOG_TARGET, "Base node peer manger has not been set, nothing to reset");
+                let _result = reply.send(false);
+                return;
+            },
+        };
+        debug!(target: LOG_TARGET, "Resetting connection to base node '{}'", node_id);
+        self.disconnect_base_node(node_id.clone()).await;
+        self.pools.remove(&node_id);
+        let _result = reply.send(true);
+        self.check_connection().await;
+    }
+
     fn current_base_node(&self) -> Option<NodeId> {
         self.base_node_watch_receiver
             .borrow()
@@ -168,6 +168,8 @@ pub enum WalletStorageError {
     AeadError(String),
     #[error("Wallet db is already encrypted and cannot be encrypted until the previous encryption is removed")]
     AlreadyEncrypted,
+    #[error("Wallet db is not protected by a passphrase")]
+    NotEncrypted,
     #[error("Byte array error: `{0}`")]
     ByteArrayError(String),
     #[error("Cannot acquire exclusive file lock, another instance of the application is already running")]
@@ -25,13 +25,20 @@ use std::{collections::HashMap, fmt, fmt::Formatter, sync::Arc};
 use tari_common_types::{
     tari_address::TariAddress,
     transaction::TxId,
-    types::{Commitment, FixedHash, HashOutput, PublicKey},
+    types::{BlockHash, Commitment, FixedHash, HashOutput, PublicKey},
 };
 use tari_core::{
     covenants::Covenant,
     transactions::{
         tari_amount::MicroMinotari,
-        transaction_components::{OutputFeatures, Transaction, TransactionOutput, WalletOutput, WalletOutputBuilder},
+        transaction_components::{
+            encrypted_data::PaymentId,
+            OutputFeatures,
+            Transaction,
+            TransactionOutput,
+            WalletOutput,
+            WalletOutputBuilder,
+        },
         transaction_protocol::{sender::TransactionSenderMessage, TransactionMetadata},
         ReceiverTransactionProtocol,
         SenderTransactionProtocol,
@@ -106,6 +113,12 @@ pub enum OutputManagerRequest {
         fee_per_gram: MicroMinotari,
         selection_criteria: UtxoSelectionCriteria,
     },
+    CreateOneSidedTransactionToMany {
+        recipients: Vec<(TariAddress, MicroMinotari)>,
+        selection_criteria: UtxoSelectionCriteria,
+        fee_per_gram: MicroMinotari,
+        payment_id: PaymentId,
+    },
     CancelTransaction(TxId),
     GetSpentOutputs,
     GetUnspentOutputs,
@@ -116,6 +129,7 @@ pub enum OutputManagerRequest {
     CreateCoinSplitEven((Vec<Commitment>, usize, MicroMinotari)),
     PreviewCoinJoin((Vec<Commitment>, MicroMinotari)),
     PreviewCoinSplitEven((Vec<Commitment>, usize, MicroMinotari)),
+    PreviewJoinSplit((Vec<Commitment>, usize, MicroMinotari)),
     ScrapeWallet {
         tx_id: TxId,
         fee_per_gram: MicroMinotari,
@@ -131,6 +145,7 @@ pub enum OutputManagerRequest {
         num_kernels: usize,
         num_outputs: usize,
     },
+    GetMaxSpendableAmount(MicroMinotari),
 
     ScanForRecoverableOutputs(Vec<(TransactionOutput, Option<TxId>)>),
     ScanOutputs(Vec<(TransactionOutput, Option<TxId>)>),
@@ -141,6 +156,12 @@ pub enum OutputManagerRequest {
     },
 
     ReinstateCancelledInboundTx(TxId),
+    ConfirmImportedOutputs {
+        tx_id: TxId,
+        mined_height: u64,
+        mined_in_block: BlockHash,
+        mined_timestamp: u64,
+    },
     CreateClaimShaAtomicSwapTransaction(HashOutput, PublicKey, MicroMinotari),
     CreateHtlcRefundTransaction(HashOutput, MicroMinotari),
     GetOutputInfoByTxId(TxId),
@@ -222,6 +243,11 @@ impl fmt::Display for OutputManagerRequest {
                 "PreviewCoinSplitEven(commitments={:#?}, number_of_splits={}, fee_per_gram={})",
                 commitments, number_of_splits, fee_per_gram
             ),
+            PreviewJoinSplit((commitments, number_of_splits, fee_per_gram)) => write!(
+                f,
+                "PreviewJoinSplit(commitments={:#?}, number_of_splits={}, fee_per_gram={})",
+                commitments, number_of_splits, fee_per_gram
+            ),
             CreateCoinSplit(v) => write!(f, "CreateCoinSplit ({:?})", v.0),
             CreateCoinSplitEven(v) => write!(f, "CreateCoinSplitEven ({:?})", v.0),
             CreateCoinJoin {
@@ -243,6 +269,7 @@ impl fmt::Display for OutputManagerRequest {
                 "FeeEstimate(amount: {}, fee_per_gram: {}, num_kernels: {}, num_outputs: {}, selection_criteria: {:?})",
                 amount, fee_per_gram, num_kernels, num_outputs, selection_criteria
             ),
+            GetMaxSpendableAmount(fee_per_gram) => write!(f, "GetMaxSpendableAmount(fee_per_gram: {})", fee_per_gram),
             ScanForRecoverableOutputs(_) => write!(f, "ScanForRecoverableOutputs"),
             ScanOutputs(_) => write!(f, "ScanOutputs"),
             AddKnownOneSidedPaymentScript(_) => write!(f, "AddKnownOneSidedPaymentScript"),
@@ -250,7 +277,20 @@ impl fmt::Display for OutputManagerRequest {
                 write!(f, "CreateOutputWithFeatures({}, {})", value, features,)
             },
             CreatePayToSelfWithOutputs { .. } => write!(f, "CreatePayToSelfWithOutputs"),
+            CreateOneSidedTransactionToMany { recipients, .. } => {
+                write!(f, "CreateOneSidedTransactionToMany({} recipients)", recipients.len())
+            },
             ReinstateCancelledInboundTx(_) => write!(f, "ReinstateCancelledInboundTx"),
+            ConfirmImportedOutputs {
+                tx_id,
+                mined_height,
+                mined_in_block,
+                ..
+            } => write!(
+                f,
+                "ConfirmImportedOutputs (TxId: {}, mined height: {}, mined in block: {})",
+                tx_id, mined_height, mined_in_block
+            ),
             CreateClaimShaAtomicSwapTransaction(output, pre_image, fee_per_gram) => write!(
                 f,
                 "ClaimShaAtomicSwap(output hash: {}, pre_image: {}, fee_per_gram: {} )",
@@ -302,7 +342,11 @@ pub enum OutputManagerResponse {
     PublicRewindKeys(Box<PublicRewindKeys>),
     RecoveryByte(u8),
     FeeEstimate(MicroMinotari),
-    RewoundOutputs(Vec<RecoveredOutput>),
+    MaxSpendableAmount(MicroMinotari),
+    RewoundOutputs {
+        outputs: Vec<RecoveredOutput>,
+        num_unrecoverable: u64,
+    },
     ScanOutputs(Vec<RecoveredOutput>),
     AddKnownOneSidedPaymentScript,
     CreateOutputWithFeatures {
@@ -312,7 +356,12 @@ pub enum OutputManagerResponse {
         transaction: Box<Transaction>,
         tx_id: TxId,
     },
+    CreateOneSidedTransactionToMany {
+        transaction: Box<Transaction>,
+        tx_id: TxId,
+    },
     ReinstatedCancelledInboundTx,
+    ImportedOutputsConfirmed,
     ClaimHtlcTransaction((TxId, MicroMinotari, MicroMinotari, Transaction)),
     OutputInfoByTxId(OutputInfoByTxId),
     CoinPreview((Vec<MicroMinotari>, MicroMinotari)),
@@ -572,6 +621,22 @@ impl OutputManagerHandle {
         }
     }
 
+    /// Get the largest amount of MicroMinotari that can be sent in a single transaction at the specified fee per gram,
+    /// i.e. the value of the spendable outputs less the fee to spend all of them without a change output.
+    pub async fn get_max_spendable_amount(
+        &mut self,
+        fee_per_gram: MicroMinotari,
+    ) -> Result<MicroMinotari, OutputManagerError> {
+        match self
+            .handle
+            .call(OutputManagerRequest::GetMaxSpendableAmount(fee_per_gram))
+            .await??
+        {
+            OutputManagerResponse::MaxSpendableAmount(amount) => Ok(amount),
+            _ => Err(OutputManagerError::UnexpectedApiResponse),
+        }
+    }
+
     pub async fn confirm_pending_transaction(&mut self, tx_id: TxId) -> Result<(), OutputManagerError> {
         match self
             .handle
@@ -658,6 +723,26 @@ impl OutputManagerHandle {
         }
     }
 
+    pub async fn preview_join_split_with_commitments(
+        &mut self,
+        commitments: Vec<Commitment>,
+        split_count: usize,
+        fee_per_gram: MicroMinotari,
+    ) -> Result<(Vec<MicroMinotari>, MicroMinotari), OutputManagerError> {
+        match self
+            .handle
+            .call(OutputManagerRequest::PreviewJoinSplit((
+                commitments,
+                split_count,
+                fee_per_gram,
+            )))
+            .await??
+        {
+            OutputManagerResponse::CoinPreview((expected_outputs, fee)) => Ok((expected_outputs, fee)),
+            _ => Err(OutputManagerError::UnexpectedApiResponse),
+        }
+    }
+
     /// Create a coin split transaction.
     /// Returns (tx_id, tx, utxos_total_value).
     pub async fn create_coin_split(
@@ -759,12 +844,25 @@ impl OutputManagerHandle {
         &mut self,
         outputs: Vec<(TransactionOutput, Option<TxId>)>,
     ) -> Result<Vec<RecoveredOutput>, OutputManagerError> {
+        let (outputs, _) = self.scan_for_recoverable_outputs_with_failures(outputs).await?;
+        Ok(outputs)
+    }
+
+    /// Scans the outputs for any that belong to this wallet, as `scan_for_recoverable_outputs` does, and also returns
+    /// the number of outputs whose spending key could be recovered but that could not be imported
+    pub async fn scan_for_recoverable_outputs_with_failures(
+        &mut self,
+        outputs: Vec<(TransactionOutput, Option<TxId>)>,
+    ) -> Result<(Vec<RecoveredOutput>, u64), OutputManagerError> {
         match self
             .handle
             .call(OutputManagerRequest::ScanForRecoverableOutputs(outputs))
             .await??
         {
-            OutputManagerResponse::RewoundOutputs(outputs) => Ok(outputs),
+            OutputManagerResponse::RewoundOutputs {
+                outputs,
+                num_unrecoverable,
+            } => Ok((outputs, num_unrecoverable)),
             _ => Err(OutputManagerError::UnexpectedApiResponse),
         }
     }
@@ -810,6 +908,29 @@ impl OutputManagerHandle {
         }
     }
 
+    /// Create a single transaction that pays a one-sided output to each of the given recipients
+    pub async fn create_one_sided_transaction_to_many(
+        &mut self,
+        recipients: Vec<(TariAddress, MicroMinotari)>,
+        selection_criteria: UtxoSelectionCriteria,
+        fee_per_gram: MicroMinotari,
+        payment_id: PaymentId,
+    ) -> Result<(TxId, Transaction), OutputManagerError> {
+        match self
+            .handle
+            .call(OutputManagerRequest::CreateOneSidedTransactionToMany {
+                recipients,
+                selection_criteria,
+                fee_per_gram,
+                payment_id,
+            })
+            .await??
+        {
+            OutputManagerResponse::CreateOneSidedTransactionToMany { transaction, tx_id } => Ok((tx_id, *transaction)),
+            _ => Err(OutputManagerError::UnexpectedApiResponse),
+        }
+    }
+
     #[allow(clippy::mutable_key_type)]
     pub async fn encumber_aggregate_utxo(
         &mut self,
@@ -938,6 +1059,29 @@ impl OutputManagerHandle {
         }
     }
 
+    /// Marks the outputs received in an imported transaction as mined and confirmed in the given block
+    pub async fn confirm_imported_outputs(
+        &mut self,
+        tx_id: TxId,
+        mined_height: u64,
+        mined_in_block: BlockHash,
+        mined_timestamp: u64,
+    ) -> Result<(), OutputManagerError> {
+        match self
+            .handle
+            .call(OutputManagerRequest::ConfirmImportedOutputs {
+                tx_id,
+                mined_height,
+                mined_in_block,
+                mined_timestamp,
+            })
+            .await??
+        {
+            OutputManagerResponse::ImportedOutputsConfirmed => Ok(()),
+            _ => Err(OutputManagerError::UnexpectedApiResponse),
+        }
+    }
+
     pub async fn get_output_info_for_tx_id(&mut self, tx_id: TxId) -> Result<OutputInfoByTxId, OutputManagerError> {
         match self
             .handle
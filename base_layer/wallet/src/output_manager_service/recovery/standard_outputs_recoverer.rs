@@ -70,17 +70,19 @@ where
     }
 
     /// Attempt to rewind all of the given transaction outputs into key_manager outputs. If they can be rewound then add
-    /// them to the database and increment the key manager index
+    /// them to the database and increment the key manager index. Also returns the number of outputs whose spending key
+    /// could be recovered but that could not be imported because their script key could not be found.
     pub async fn scan_and_recover_outputs(
         &mut self,
         outputs: Vec<(TransactionOutput, Option<TxId>)>,
-    ) -> Result<Vec<RecoveredOutput>, OutputManagerError> {
+    ) -> Result<(Vec<RecoveredOutput>, u64), OutputManagerError> {
         let start = Instant::now();
         let outputs_length = outputs.len();
 
         let known_scripts = self.db.get_all_known_one_sided_payment_scripts()?;
 
         let mut rewound_outputs: Vec<(WalletOutput, bool, FixedHash, Option<TxId>)> = Vec::new();
+        let mut num_unrecoverable = 0u64;
         let push_pub_key_script = script!(PushPubKey(Box::default()))?;
         for (output, tx_id) in outputs {
             let known_script_index = known_scripts.iter().position(|s| s.script == output.script);
@@ -100,7 +102,10 @@ where
                 .await?
             {
                 Some((input_data, script_key)) => (input_data, script_key),
-                None => continue,
+                None => {
+                    num_unrecoverable += 1;
+                    continue;
+                },
             };
 
             let hash = output.hash();
@@ -172,7 +177,7 @@ where
             );
         }
 
-        Ok(rewound_outputs_with_tx_id)
+        Ok((rewound_outputs_with_tx_id, num_unrecoverable))
     }
 
     // Helper function to get the output source for a given output
@@ -102,6 +102,7 @@ use crate::{
         storage::{
             database::{OutputBackendQuery, OutputManagerBackend, OutputManagerDatabase},
             models::{DbWalletOutput, KnownOneSidedPaymentScript, SpendingPriority},
+            sqlite_db::ReceivedOutputInfoForBatch,
             OutputSource,
             OutputStatus,
         },
@@ -370,6 +371,10 @@ where
                 .fee_estimate(amount, selection_criteria, fee_per_gram, num_kernels, num_outputs)
                 .await
                 .map(OutputManagerResponse::FeeEstimate),
+            OutputManagerRequest::GetMaxSpendableAmount(fee_per_gram) => self
+                .get_max_spendable_amount(fee_per_gram)
+                .await
+                .map(OutputManagerResponse::MaxSpendableAmount),
             OutputManagerRequest::ConfirmPendingTransaction(tx_id) => self
                 .confirm_encumberance(tx_id)
                 .map(|_| OutputManagerResponse::PendingTransactionConfirmed),
@@ -411,6 +416,12 @@ where
                         .await?,
                 ))
             },
+            OutputManagerRequest::PreviewJoinSplit((commitments, number_of_splits, fee_per_gram)) => {
+                Ok(OutputManagerResponse::CoinPreview(
+                    self.preview_join_split_with_commitments(commitments, number_of_splits, fee_per_gram)
+                        .await?,
+                ))
+            },
             OutputManagerRequest::CreateCoinSplit((commitments, amount_per_split, split_count, fee_per_gram)) => {
                 if commitments.is_empty() {
                     self.create_coin_split_auto(Some(amount_per_split), split_count, fee_per_gram)
@@ -450,7 +461,10 @@ where
                 StandardUtxoRecoverer::new(self.resources.key_manager.clone(), self.resources.db.clone())
                     .scan_and_recover_outputs(outputs)
                     .await
-                    .map(OutputManagerResponse::RewoundOutputs)
+                    .map(|(outputs, num_unrecoverable)| OutputManagerResponse::RewoundOutputs {
+                        outputs,
+                        num_unrecoverable,
+                    })
             },
             OutputManagerRequest::ScanOutputs(outputs) => self
                 .scan_outputs_for_one_sided_payments(outputs)
@@ -462,6 +476,14 @@ where
             OutputManagerRequest::ReinstateCancelledInboundTx(tx_id) => self
                 .reinstate_cancelled_inbound_transaction_outputs(tx_id)
                 .map(|_| OutputManagerResponse::ReinstatedCancelledInboundTx),
+            OutputManagerRequest::ConfirmImportedOutputs {
+                tx_id,
+                mined_height,
+                mined_in_block,
+                mined_timestamp,
+            } => self
+                .confirm_imported_outputs(tx_id, mined_height, mined_in_block, mined_timestamp)
+                .map(|_| OutputManagerResponse::ImportedOutputsConfirmed),
             OutputManagerRequest::CreateOutputWithFeatures { value, features } => {
                 let wallet_output = self.create_output_with_features(value, *features).await?;
                 Ok(OutputManagerResponse::CreateOutputWithFeatures {
@@ -481,6 +503,20 @@ where
                     tx_id,
                 })
             },
+            OutputManagerRequest::CreateOneSidedTransactionToMany {
+                recipients,
+                selection_criteria,
+                fee_per_gram,
+                payment_id,
+            } => {
+                let (tx_id, transaction) = self
+                    .create_one_sided_transaction_to_many(recipients, selection_criteria, fee_per_gram, payment_id)
+                    .await?;
+                Ok(OutputManagerResponse::CreateOneSidedTransactionToMany {
+                    transaction: Box::new(transaction),
+                    tx_id,
+                })
+            },
             OutputManagerRequest::CreateClaimShaAtomicSwapTransaction(output_hash, pre_image, fee_per_gram) => {
                 self.claim_sha_atomic_swap_with_hash(output_hash, pre_image, fee_per_gram)
                     .await
@@ -653,6 +689,11 @@ where
     }
 
     fn revalidate_outputs(&mut self) -> Result<u64, OutputManagerError> {
+        // Resetting the validation state underneath a running validation would leave it half applied, and the new
+        // validation task would be ignored anyway
+        if self.validation_in_progress.try_lock().is_err() {
+            return Err(OutputManagerError::ValidationInProgress);
+        }
         self.resources.db.set_outputs_to_be_revalidated()?;
         self.validate_outputs()
     }
@@ -950,6 +991,48 @@ where
         Ok(fee)
     }
 
+    /// Get the largest amount that can be sent in a single transaction. The same outputs that would be selected to send
+    /// an unreachable amount (the largest spendable outputs, up to the transaction input limit) are summed and the
+    /// exact fee to spend all of them into a single output, without change, is deducted.
+    async fn get_max_spendable_amount(
+        &mut self,
+        fee_per_gram: MicroMinotari,
+    ) -> Result<MicroMinotari, OutputManagerError> {
+        let mut selection_criteria = UtxoSelectionCriteria::default();
+        if self.resources.config.autoignore_onesided_utxos {
+            selection_criteria.excluding_onesided = true;
+        }
+        let chain_metadata = self.base_node_service.get_chain_metadata().await?;
+        let tip_height = chain_metadata.as_ref().map(|m| m.best_block_height());
+        let src_outputs =
+            self.resources
+                .db
+                .fetch_unspent_outputs_for_spending(&selection_criteria, u64::MAX, tip_height)?;
+        if src_outputs.is_empty() {
+            return Ok(MicroMinotari::zero());
+        }
+
+        let accumulated_amount = src_outputs
+            .iter()
+            .fold(MicroMinotari::zero(), |acc, x| acc + x.wallet_output.value);
+        let fee = self.get_fee_calc().calculate(
+            fee_per_gram,
+            1,
+            src_outputs.len(),
+            1,
+            self.default_features_and_scripts_size()?,
+        );
+        debug!(
+            target: LOG_TARGET,
+            "Max spendable amount: {} from {} outputs, fee {}",
+            accumulated_amount.saturating_sub(fee),
+            src_outputs.len(),
+            fee
+        );
+
+        Ok(accumulated_amount.saturating_sub(fee))
+    }
+
     /// Prepare a Sender Transaction Protocol for the amount and fee_per_gram specified. If required a change output
     /// will be produced.
     #[allow(clippy::too_many_lines)]
@@ -1209,6 +1292,182 @@ where
         Ok((tx_id, stp.into_transaction()?))
     }
 
+    /// Create a single transaction paying a one-sided (stealth) output to each of the given recipients. Only the
+    /// change output, if any, is stored as an output of this wallet.
+    #[allow(clippy::too_many_lines)]
+    async fn create_one_sided_transaction_to_many(
+        &mut self,
+        recipients: Vec<(TariAddress, MicroMinotari)>,
+        selection_criteria: UtxoSelectionCriteria,
+        fee_per_gram: MicroMinotari,
+        payment_id: PaymentId,
+    ) -> Result<(TxId, Transaction), OutputManagerError> {
+        if recipients.is_empty() {
+            return Err(OutputManagerError::ServiceError(
+                "At least one recipient is required".to_string(),
+            ));
+        }
+        let total_value = recipients
+            .iter()
+            .try_fold(MicroMinotari::zero(), |total, (_, amount)| total.checked_add(*amount))
+            .ok_or_else(|| OutputManagerError::InvalidArgument("Total recipient amount overflows".to_string()))?;
+        let output_features = OutputFeatures::default();
+        let metadata_byte_size = self
+            .resources
+            .consensus_constants
+            .transaction_weight_params()
+            .round_up_features_and_scripts_size(
+                output_features.get_serialized_size()? +
+                    push_pubkey_script(&Default::default()).get_serialized_size()? +
+                    Covenant::default().get_serialized_size()?,
+            );
+
+        let input_selection = self
+            .select_utxos(
+                total_value,
+                selection_criteria,
+                fee_per_gram,
+                recipients.len(),
+                metadata_byte_size * recipients.len(),
+            )
+            .await?;
+
+        let mut builder = SenderTransactionProtocol::builder(
+            self.resources.consensus_constants.clone(),
+            self.resources.key_manager.clone(),
+        );
+        builder
+            .with_lock_height(0)
+            .with_fee_per_gram(fee_per_gram)
+            .with_prevent_fee_gt_amount(self.resources.config.prevent_fee_gt_amount)
+            .with_kernel_features(KernelFeatures::empty());
+
+        for uo in input_selection.iter() {
+            builder.with_input(uo.wallet_output.clone()).await?;
+        }
+
+        if input_selection.requires_change_output() {
+            let (change_commitment_mask_key, change_script_key) = self
+                .resources
+                .key_manager
+                .get_next_commitment_mask_and_script_key()
+                .await?;
+            builder.with_change_data(
+                script!(PushPubKey(Box::new(change_script_key.pub_key)))?,
+                ExecutionStack::default(),
+                change_script_key.key_id,
+                change_commitment_mask_key.key_id,
+                Covenant::default(),
+                self.resources.interactive_tari_address.clone(),
+            );
+        }
+
+        let payment_id = match payment_id {
+            PaymentId::Open(v) => PaymentId::AddressAndData(self.resources.interactive_tari_address.clone(), v),
+            PaymentId::Empty => PaymentId::Address(self.resources.interactive_tari_address.clone()),
+            _ => payment_id,
+        };
+
+        for (recipient_address, amount) in recipients {
+            let sender_offset_key_id = self
+                .resources
+                .key_manager
+                .get_next_key(TransactionKeyManagerBranch::OneSidedSenderOffset.get_branch_key())
+                .await?
+                .key_id;
+
+            // Diffie-Hellman shared secret `k_Ob * K_Sb = K_Ob * k_Sb` results in a public key, which is fed into
+            // KDFs to produce the spending and encryption keys.
+            let shared_secret = self
+                .resources
+                .key_manager
+                .get_diffie_hellman_shared_secret(
+                    &sender_offset_key_id,
+                    recipient_address
+                        .public_view_key()
+                        .ok_or(OutputManagerError::ServiceError(format!(
+                            "Missing public view key for recipient {}",
+                            recipient_address
+                        )))?,
+                )
+                .await?;
+
+            let commitment_mask_key = shared_secret_to_output_spending_key(&shared_secret)?;
+            let commitment_mask_key_id = self.resources.key_manager.import_key(commitment_mask_key).await?;
+
+            let encryption_private_key = shared_secret_to_output_encryption_key(&shared_secret)?;
+            let encryption_key_id = self.resources.key_manager.import_key(encryption_private_key).await?;
+
+            let sender_offset_public_key = self
+                .resources
+                .key_manager
+                .get_public_key_at_key_id(&sender_offset_key_id)
+                .await?;
+
+            let script_spending_key = self
+                .resources
+                .key_manager
+                .stealth_address_script_spending_key(&commitment_mask_key_id, recipient_address.public_spend_key())
+                .await?;
+
+            let output = WalletOutputBuilder::new(amount, commitment_mask_key_id)
+                .with_features(output_features.clone())
+                .with_script(push_pubkey_script(&script_spending_key))
+                .encrypt_data_for_recovery(&self.resources.key_manager, Some(&encryption_key_id), payment_id.clone())
+                .await?
+                .with_input_data(ExecutionStack::default()) // Just a placeholder in the wallet
+                .with_sender_offset_public_key(sender_offset_public_key)
+                .with_script_key(KeyId::Zero)
+                .with_minimum_value_promise(MicroMinotari::zero())
+                .sign_as_sender_and_receiver_verified(
+                    &self.resources.key_manager,
+                    &sender_offset_key_id,
+                    &recipient_address,
+                )
+                .await?
+                .try_build(&self.resources.key_manager)
+                .await?;
+
+            builder
+                .with_output(output, sender_offset_key_id)
+                .await
+                .map_err(|e| OutputManagerError::BuildError(e.to_string()))?;
+        }
+
+        let mut stp = builder
+            .build()
+            .await
+            .map_err(|e| OutputManagerError::BuildError(e.message))?;
+        let tx_id = stp.get_tx_id()?;
+        let mut db_outputs = vec![];
+        if let Some(wallet_output) = stp.get_change_output()? {
+            db_outputs.push(
+                DbWalletOutput::from_wallet_output(
+                    wallet_output,
+                    &self.resources.key_manager,
+                    None,
+                    OutputSource::default(),
+                    Some(tx_id),
+                    None,
+                )
+                .await?,
+            );
+        }
+
+        self.resources
+            .db
+            .encumber_outputs(tx_id, input_selection.into_selected(), db_outputs)?;
+        stp.finalize(&self.resources.key_manager)
+            .await
+            .map_err(|e| service_error_with_id(tx_id, e.to_string(), true))?;
+        info!(
+            target: LOG_TARGET,
+            "Finalized one-sided transaction to many recipients TxId: {}", tx_id
+        );
+
+        Ok((tx_id, stp.into_transaction()?))
+    }
+
     async fn pre_mine_script_key_from_payment_id(
         &self,
         payment_id: PaymentId,
@@ -2018,6 +2277,39 @@ where
         Ok(())
     }
 
+    /// Marks the outputs received in an imported transaction as mined and confirmed in the block the caller has
+    /// verified they are mined in
+    fn confirm_imported_outputs(
+        &mut self,
+        tx_id: TxId,
+        mined_height: u64,
+        mined_in_block: BlockHash,
+        mined_timestamp: u64,
+    ) -> Result<(), OutputManagerError> {
+        let updates = self
+            .resources
+            .db
+            .fetch_outputs_by_tx_id(tx_id)?
+            .into_iter()
+            .filter(|output| output.received_in_tx_id == Some(tx_id))
+            .map(|output| ReceivedOutputInfoForBatch {
+                commitment: output.commitment,
+                mined_height,
+                mined_in_block,
+                confirmed: true,
+                mined_timestamp,
+            })
+            .collect::<Vec<_>>();
+        if updates.is_empty() {
+            return Err(OutputManagerStorageError::ValuesNotFound.into());
+        }
+        self.resources
+            .db
+            .set_received_outputs_mined_height_and_statuses(updates)?;
+
+        Ok(())
+    }
+
     /// Select which unspent transaction outputs to use to send a transaction of the specified amount. Use the specified
     /// selection strategy to choose the outputs. It also determines if a change output is required.
     #[allow(clippy::too_many_lines)]
@@ -2277,6 +2569,56 @@ where
         Ok((expected_outputs, fee))
     }
 
+    /// Previews joining the given commitments into a single output and then splitting that output evenly into
+    /// `number_of_splits` outputs. The returned fee is the sum of the join and split fees.
+    pub async fn preview_join_split_with_commitments(
+        &mut self,
+        commitments: Vec<Commitment>,
+        number_of_splits: usize,
+        fee_per_gram: MicroMinotari,
+    ) -> Result<(Vec<MicroMinotari>, MicroMinotari), OutputManagerError> {
+        if commitments.is_empty() {
+            return Err(OutputManagerError::NoCommitmentsProvided);
+        }
+
+        if number_of_splits == 0 {
+            return Err(OutputManagerError::InvalidArgument(
+                "number_of_splits must be greater than 0".to_string(),
+            ));
+        }
+
+        let (joined, join_fee) = self
+            .preview_coin_join_with_commitments(commitments, fee_per_gram)
+            .await?;
+        let joined_amount: MicroMinotari = joined.into_iter().sum();
+
+        // The split spends the single output produced by the join
+        let split_fee = self.get_fee_calc().calculate(
+            fee_per_gram,
+            1,
+            1,
+            number_of_splits,
+            self.default_features_and_scripts_size()
+                .map_err(|e| OutputManagerError::ConversionError(e.to_string()))? *
+                number_of_splits,
+        );
+
+        let aftertax_amount = joined_amount.saturating_sub(split_fee);
+        let amount_per_split = MicroMinotari(aftertax_amount.as_u64() / number_of_splits as u64);
+        let unspent_remainder = MicroMinotari(aftertax_amount.as_u64() % number_of_splits as u64);
+        let mut expected_outputs = vec![];
+
+        for i in 1..=number_of_splits {
+            expected_outputs.push(if i == number_of_splits {
+                amount_per_split + unspent_remainder
+            } else {
+                amount_per_split
+            });
+        }
+
+        Ok((expected_outputs, join_fee + split_fee))
+    }
+
     async fn create_coin_split_with_commitments(
         &mut self,
         commitments: Vec<Commitment>,
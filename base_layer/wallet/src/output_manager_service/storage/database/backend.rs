@@ -12,9 +12,10 @@ use crate::output_manager_service::{
     input_selection::UtxoSelectionCriteria,
     service::Balance,
     storage::{
-        database::{DbKey, DbValue, OutputBackendQuery, WriteOperation},
+        database::{DbKey, DbValue, OutputBackendQuery, OutputsSummary, WriteOperation},
         models::DbWalletOutput,
         sqlite_db::{ReceivedOutputInfoForBatch, SpentOutputInfoForBatch},
+        OutputStatus,
     },
 };
 
@@ -94,4 +95,11 @@ pub trait OutputManagerBackend: Send + Sync + Clone {
     ) -> Result<Vec<DbWalletOutput>, OutputManagerStorageError>;
     fn fetch_outputs_by_tx_id(&self, tx_id: TxId) -> Result<Vec<DbWalletOutput>, OutputManagerStorageError>;
     fn fetch_outputs_by_query(&self, q: OutputBackendQuery) -> Result<Vec<DbWalletOutput>, OutputManagerStorageError>;
+    /// Aggregate the count and value of the outputs matching the query, ignoring pagination and sorting
+    fn fetch_outputs_summary_by_query(
+        &self,
+        q: OutputBackendQuery,
+    ) -> Result<OutputsSummary, OutputManagerStorageError>;
+    /// Count the outputs in each status. Statuses without outputs are not returned.
+    fn fetch_output_status_counts(&self) -> Result<Vec<(OutputStatus, u64)>, OutputManagerStorageError>;
 }
@@ -65,6 +65,8 @@ pub struct OutputBackendQuery {
     pub pagination: Option<(i64, i64)>,
     pub value_min: Option<(i64, bool)>,
     pub value_max: Option<(i64, bool)>,
+    pub maturity_min: Option<(i64, bool)>,
+    pub maturity_max: Option<(i64, bool)>,
     pub sorting: Vec<(&'static str, SortDirection)>,
 }
 
@@ -77,11 +79,22 @@ impl Default for OutputBackendQuery {
             pagination: None,
             value_min: None,
             value_max: None,
+            maturity_min: None,
+            maturity_max: None,
             sorting: vec![],
         }
     }
 }
 
+/// Aggregate totals of the outputs matching an `OutputBackendQuery`, pagination and sorting are ignored
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct OutputsSummary {
+    pub count: u64,
+    pub total_value: MicroMinotari,
+    pub min_value: MicroMinotari,
+    pub max_value: MicroMinotari,
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub enum DbKey {
     SpentOutput(String),
@@ -437,6 +450,17 @@ where T: OutputManagerBackend + 'static
     ) -> Result<Vec<DbWalletOutput>, OutputManagerStorageError> {
         self.db.fetch_outputs_by_query(q)
     }
+
+    pub fn fetch_outputs_summary_by_query(
+        &self,
+        q: OutputBackendQuery,
+    ) -> Result<OutputsSummary, OutputManagerStorageError> {
+        self.db.fetch_outputs_summary_by_query(q)
+    }
+
+    pub fn fetch_output_status_counts(&self) -> Result<Vec<(OutputStatus, u64)>, OutputManagerStorageError> {
+        self.db.fetch_output_status_counts()
+    }
 }
 
 fn unexpected_result<T>(req: DbKey, res: DbValue) -> Result<T, OutputManagerStorageError> {
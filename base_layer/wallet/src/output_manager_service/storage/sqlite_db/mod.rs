@@ -51,7 +51,15 @@ use crate::{
         error::OutputManagerStorageError,
         service::Balance,
         storage::{
-            database::{DbKey, DbKeyValuePair, DbValue, OutputBackendQuery, OutputManagerBackend, WriteOperation},
+            database::{
+                DbKey,
+                DbKeyValuePair,
+                DbValue,
+                OutputBackendQuery,
+                OutputManagerBackend,
+                OutputsSummary,
+                WriteOperation,
+            },
             models::{DbWalletOutput, KnownOneSidedPaymentScript},
             OutputStatus,
         },
@@ -1199,6 +1207,19 @@ impl OutputManagerBackend for OutputManagerSqliteDatabase {
             })
             .collect())
     }
+
+    fn fetch_outputs_summary_by_query(
+        &self,
+        q: OutputBackendQuery,
+    ) -> Result<OutputsSummary, OutputManagerStorageError> {
+        let mut conn = self.database_connection.get_pooled_connection()?;
+        OutputSql::fetch_outputs_summary_by_query(q, &mut conn)
+    }
+
+    fn fetch_output_status_counts(&self) -> Result<Vec<(OutputStatus, u64)>, OutputManagerStorageError> {
+        let mut conn = self.database_connection.get_pooled_connection()?;
+        OutputSql::count_by_status(&mut conn)
+    }
 }
 
 /// These are the fields to be set for the received outputs batch mode update
@@ -1434,7 +1455,8 @@ impl KnownOneSidedPaymentScriptSql {
 #[cfg(test)]
 mod test {
 
-    use diesel::{sql_query, Connection, RunQueryDsl, SqliteConnection};
+    use chrono::NaiveDateTime;
+    use diesel::{sql_query, Connection, ExpressionMethods, QueryDsl, RunQueryDsl, SqliteConnection};
     use diesel_migrations::{EmbeddedMigrations, MigrationHarness};
     use rand::{rngs::OsRng, RngCore};
     use tari_core::transactions::{
@@ -1447,10 +1469,14 @@ mod test {
     use tari_test_utils::random;
     use tempfile::tempdir;
 
-    use crate::output_manager_service::storage::{
-        models::DbWalletOutput,
-        sqlite_db::{new_output_sql::NewOutputSql, output_sql::OutputSql, OutputStatus, UpdateOutput},
-        OutputSource,
+    use crate::{
+        output_manager_service::storage::{
+            database::{OutputBackendQuery, SortDirection},
+            models::DbWalletOutput,
+            sqlite_db::{new_output_sql::NewOutputSql, output_sql::OutputSql, OutputStatus, UpdateOutput},
+            OutputSource,
+        },
+        schema::outputs,
     };
 
     pub async fn make_input(val: MicroMinotari, key_manager: &MemoryDbKeyManager) -> (TransactionInput, WalletOutput) {
@@ -1573,6 +1599,65 @@ mod test {
 
         assert!(OutputSql::find_status(&outputs[0].spending_key, OutputStatus::Spent, &mut conn).is_err());
 
+        // all of the outputs are mature from the genesis block
+        let query = OutputBackendQuery {
+            status: vec![],
+            maturity_max: Some((0, true)),
+            ..Default::default()
+        };
+        assert_eq!(
+            OutputSql::fetch_outputs_by_query(query.clone(), &mut conn)
+                .unwrap()
+                .len(),
+            5
+        );
+        assert_eq!(
+            OutputSql::fetch_outputs_summary_by_query(query, &mut conn)
+                .unwrap()
+                .count,
+            5
+        );
+        let query = OutputBackendQuery {
+            status: vec![],
+            maturity_min: Some((0, false)),
+            ..Default::default()
+        };
+        assert!(OutputSql::fetch_outputs_by_query(query.clone(), &mut conn)
+            .unwrap()
+            .is_empty());
+        assert_eq!(
+            OutputSql::fetch_outputs_summary_by_query(query, &mut conn)
+                .unwrap()
+                .count,
+            0
+        );
+
+        let mut status_counts = OutputSql::count_by_status(&mut conn).unwrap();
+        status_counts.sort_by_key(|(status, _)| *status as i32);
+        assert_eq!(status_counts, vec![
+            (OutputStatus::Unspent, 2),
+            (OutputStatus::Spent, 3)
+        ]);
+
+        for (i, timestamp) in [(1usize, 2000i64), (3, 1000)] {
+            diesel::update(outputs::table.filter(outputs::spending_key.eq(&outputs[i].spending_key)))
+                .set(outputs::mined_timestamp.eq(NaiveDateTime::from_timestamp_opt(timestamp, 0)))
+                .execute(&mut conn)
+                .unwrap();
+        }
+        for (direction, expected) in [(SortDirection::Asc, [3, 1]), (SortDirection::Desc, [1, 3])] {
+            let query = OutputBackendQuery {
+                status: vec![],
+                sorting: vec![("mined_timestamp", direction)],
+                ..Default::default()
+            };
+            let sorted = OutputSql::fetch_outputs_by_query(query, &mut conn).unwrap();
+            assert_eq!(sorted.len(), 5);
+            assert_eq!(sorted[0].spending_key, outputs[expected[0]].spending_key);
+            assert_eq!(sorted[1].spending_key, outputs[expected[1]].spending_key);
+            assert!(sorted[2..].iter().all(|o| o.mined_timestamp.is_none()));
+        }
+
         let _result = OutputSql::find(&outputs[4].spending_key, &mut conn)
             .unwrap()
             .delete(&mut conn);
@@ -57,7 +57,7 @@ use crate::{
         input_selection::{UtxoSelectionCriteria, UtxoSelectionMode},
         service::Balance,
         storage::{
-            database::{OutputBackendQuery, SortDirection},
+            database::{OutputBackendQuery, OutputsSummary, SortDirection},
             models::{DbWalletOutput, SpendingPriority},
             sqlite_db::{UpdateOutput, UpdateOutputSql},
             OutputSource,
@@ -179,10 +179,32 @@ impl OutputSql {
             };
         }
 
+        // if set, filtering by minimum maturity
+        if let Some((min, is_inclusive)) = q.maturity_min {
+            query = if is_inclusive {
+                query.filter(outputs::maturity.ge(min))
+            } else {
+                query.filter(outputs::maturity.gt(min))
+            };
+        }
+
+        // if set, filtering by max maturity
+        if let Some((max, is_inclusive)) = q.maturity_max {
+            query = if is_inclusive {
+                query.filter(outputs::maturity.le(max))
+            } else {
+                query.filter(outputs::maturity.lt(max))
+            };
+        }
+
         use SortDirection::{Asc, Desc};
         Ok(q.sorting
             .into_iter()
             .fold(query, |query, s| match s {
+                ("id", d) => match d {
+                    Asc => query.then_order_by(outputs::id.asc()),
+                    Desc => query.then_order_by(outputs::id.desc()),
+                },
                 ("value", d) => match d {
                     Asc => query.then_order_by(outputs::value.asc()),
                     Desc => query.then_order_by(outputs::value.desc()),
@@ -191,11 +213,129 @@ impl OutputSql {
                     Asc => query.then_order_by(outputs::mined_height.asc()),
                     Desc => query.then_order_by(outputs::mined_height.desc()),
                 },
+                // Unmined outputs have no timestamp and are always sorted last
+                ("mined_timestamp", d) => match d {
+                    Asc => query
+                        .then_order_by(outputs::mined_timestamp.is_null().asc())
+                        .then_order_by(outputs::mined_timestamp.asc()),
+                    Desc => query
+                        .then_order_by(outputs::mined_timestamp.is_null().asc())
+                        .then_order_by(outputs::mined_timestamp.desc()),
+                },
                 _ => query,
             })
             .load(conn)?)
     }
 
+    /// Aggregates the count and value of the UTXOs matching a set of given rules, pagination and sorting are ignored
+    #[allow(clippy::cast_sign_loss)]
+    pub fn fetch_outputs_summary_by_query(
+        q: OutputBackendQuery,
+        conn: &mut SqliteConnection,
+    ) -> Result<OutputsSummary, OutputManagerStorageError> {
+        #[derive(QueryableByName, Clone)]
+        struct SummaryQueryResult {
+            #[diesel(sql_type = diesel::sql_types::BigInt)]
+            count: i64,
+            #[diesel(sql_type = diesel::sql_types::BigInt)]
+            total_value: i64,
+            #[diesel(sql_type = diesel::sql_types::BigInt)]
+            min_value: i64,
+            #[diesel(sql_type = diesel::sql_types::BigInt)]
+            max_value: i64,
+        }
+
+        let mut sql = String::from(
+            "SELECT count(*) as count, coalesce(sum(value), 0) as total_value, coalesce(min(value), 0) as min_value, \
+             coalesce(max(value), 0) as max_value FROM outputs WHERE script_lock_height <= ? AND maturity <= ?",
+        );
+        if !q.status.is_empty() {
+            sql.push_str(&format!(" AND status IN ({})", vec!["?"; q.status.len()].join(", ")));
+        }
+        if !q.commitments.is_empty() {
+            sql.push_str(&format!(
+                " AND commitment IN ({})",
+                vec!["?"; q.commitments.len()].join(", ")
+            ));
+        }
+        if let Some((_, is_inclusive)) = q.value_min {
+            sql.push_str(if is_inclusive {
+                " AND value >= ?"
+            } else {
+                " AND value > ?"
+            });
+        }
+        if let Some((_, is_inclusive)) = q.value_max {
+            sql.push_str(if is_inclusive {
+                " AND value <= ?"
+            } else {
+                " AND value < ?"
+            });
+        }
+        if let Some((_, is_inclusive)) = q.maturity_min {
+            sql.push_str(if is_inclusive {
+                " AND maturity >= ?"
+            } else {
+                " AND maturity > ?"
+            });
+        }
+        if let Some((_, is_inclusive)) = q.maturity_max {
+            sql.push_str(if is_inclusive {
+                " AND maturity <= ?"
+            } else {
+                " AND maturity < ?"
+            });
+        }
+
+        let mut query = sql_query(sql)
+            .into_boxed::<diesel::sqlite::Sqlite>()
+            .bind::<diesel::sql_types::BigInt, _>(q.tip_height)
+            .bind::<diesel::sql_types::BigInt, _>(q.tip_height);
+        for status in q.status {
+            query = query.bind::<diesel::sql_types::Integer, _>(status as i32);
+        }
+        for commitment in q.commitments {
+            query = query.bind::<diesel::sql_types::Binary, _>(commitment.to_vec());
+        }
+        if let Some((min, _)) = q.value_min {
+            query = query.bind::<diesel::sql_types::BigInt, _>(min);
+        }
+        if let Some((max, _)) = q.value_max {
+            query = query.bind::<diesel::sql_types::BigInt, _>(max);
+        }
+        if let Some((min, _)) = q.maturity_min {
+            query = query.bind::<diesel::sql_types::BigInt, _>(min);
+        }
+        if let Some((max, _)) = q.maturity_max {
+            query = query.bind::<diesel::sql_types::BigInt, _>(max);
+        }
+
+        let result = query.get_result::<SummaryQueryResult>(conn)?;
+        Ok(OutputsSummary {
+            count: result.count as u64,
+            total_value: MicroMinotari::from(result.total_value as u64),
+            min_value: MicroMinotari::from(result.min_value as u64),
+            max_value: MicroMinotari::from(result.max_value as u64),
+        })
+    }
+
+    /// Counts the outputs in each status with a single grouped query. Statuses without outputs are not returned.
+    pub fn count_by_status(conn: &mut SqliteConnection) -> Result<Vec<(OutputStatus, u64)>, OutputManagerStorageError> {
+        #[derive(QueryableByName, Clone)]
+        struct StatusCountQueryResult {
+            #[diesel(sql_type = diesel::sql_types::Integer)]
+            status: i32,
+            #[diesel(sql_type = diesel::sql_types::BigInt)]
+            count: i64,
+        }
+
+        sql_query("SELECT status, count(*) as count FROM outputs GROUP BY status")
+            .load::<StatusCountQueryResult>(conn)?
+            .into_iter()
+            .map(|r| Ok((OutputStatus::try_from(r.status)?, r.count as u64)))
+            .collect()
+    }
+
     /// Retrieves UTXOs than can be spent, sorted by priority, then value from smallest to largest.
     #[allow(clippy::cast_sign_loss)]
     pub fn fetch_unspent_outputs_for_spending(
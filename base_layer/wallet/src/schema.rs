@@ -38,6 +38,7 @@ diesel::table! {
         transaction_signature_nonce -> Binary,
         transaction_signature_key -> Binary,
         payment_id -> Nullable<Binary>,
+        one_sided_payment -> Bool,
     }
 }
 
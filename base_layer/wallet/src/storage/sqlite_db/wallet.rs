@@ -864,6 +864,20 @@ fn get_db_cipher(
     Ok(XChaCha20Poly1305::new(Key::from_slice(main_key.reveal())))
 }
 
+/// Determine whether the database main key is protected by a passphrase. A database that has no key-related data yet,
+/// or whose main key is encrypted with the empty passphrase, is not protected.
+pub fn is_passphrase_protected(database_connection: &WalletDbConnection) -> Result<bool, WalletStorageError> {
+    // The pooled connection is released before the cipher is derived, which acquires its own
+    if DatabaseEncryptionFields::read(&mut database_connection.get_pooled_connection()?)?.is_none() {
+        return Ok(false);
+    }
+    match get_db_cipher(database_connection, &SafePassword::from("")) {
+        Ok(_) => Ok(false),
+        Err(WalletStorageError::InvalidPassphrase) => Ok(true),
+        Err(e) => Err(e),
+    }
+}
+
 /// A Sql version of the wallet setting key-value table
 #[derive(Clone, Debug, Queryable, Insertable, PartialEq)]
 #[diesel(table_name = wallet_settings)]
@@ -1092,6 +1106,8 @@ impl Encryptable<XChaCha20Poly1305> for BurntProofSql {
 
 #[cfg(test)]
 mod test {
+    use std::path::Path;
+
     use tari_common_sqlite::sqlite_connection_pool::PooledDbConnection;
     use tari_common_types::encryption::{decrypt_bytes_integral_nonce, Encryptable};
     use tari_key_manager::cipher_seed::CipherSeed;
@@ -1103,10 +1119,17 @@ mod test {
     };
     use tempfile::tempdir;
 
-    use crate::storage::{
-        database::{DbKey, DbValue, WalletBackend},
-        sqlite_db::wallet::{ClientKeyValueSql, WalletSettingSql, WalletSqliteDatabase},
-        sqlite_utilities::run_migration_and_create_sqlite_connection,
+    use crate::{
+        error::WalletStorageError,
+        storage::{
+            database::{DbKey, DbValue, WalletBackend},
+            sqlite_db::wallet::{ClientKeyValueSql, WalletSettingSql, WalletSqliteDatabase},
+            sqlite_utilities::{
+                apply_database_encryption,
+                remove_database_encryption,
+                run_migration_and_create_sqlite_connection,
+            },
+        },
     };
     #[test]
     fn test_passphrase() {
@@ -1152,6 +1175,57 @@ mod test {
         assert!(WalletSqliteDatabase::new(connection, "new passphrase".to_string().into()).is_ok());
     }
 
+    #[test]
+    fn test_apply_and_remove_encryption() {
+        let db_name = format!("{}.sqlite3", string(8).as_str());
+        let db_tempdir = tempdir().unwrap();
+        let db_folder = db_tempdir.path().to_str().unwrap().to_string();
+        let db_path = format!("{}/{}", db_folder, db_name);
+        let passphrase = SafePassword::from("passphrase");
+
+        // Only an existing database can be protected or have its protection removed, none is created
+        assert!(matches!(
+            apply_database_encryption(&db_path, &passphrase),
+            Err(WalletStorageError::DbPathDoesNotExist)
+        ));
+        assert!(matches!(
+            remove_database_encryption(&db_path, &passphrase),
+            Err(WalletStorageError::DbPathDoesNotExist)
+        ));
+        assert!(!Path::new(&db_path).exists());
+        {
+            let connection = run_migration_and_create_sqlite_connection(&db_path, 1).unwrap();
+            WalletSqliteDatabase::new(connection, SafePassword::from("")).unwrap();
+        }
+
+        // A database without a passphrase cannot have its encryption removed
+        assert!(matches!(
+            remove_database_encryption(&db_path, &passphrase),
+            Err(WalletStorageError::NotEncrypted)
+        ));
+
+        // Protect the database, which can then only be opened with the passphrase
+        apply_database_encryption(&db_path, &passphrase).unwrap();
+        assert!(matches!(
+            apply_database_encryption(&db_path, &passphrase),
+            Err(WalletStorageError::AlreadyEncrypted)
+        ));
+        {
+            let connection = run_migration_and_create_sqlite_connection(&db_path, 1).unwrap();
+            assert!(WalletSqliteDatabase::new(connection.clone(), SafePassword::from("")).is_err());
+            assert!(WalletSqliteDatabase::new(connection, passphrase.clone()).is_ok());
+        }
+
+        // Removing the encryption requires the correct passphrase
+        assert!(matches!(
+            remove_database_encryption(&db_path, &SafePassword::from("evil passphrase")),
+            Err(WalletStorageError::InvalidPassphrase)
+        ));
+        remove_database_encryption(&db_path, &passphrase).unwrap();
+        let connection = run_migration_and_create_sqlite_connection(&db_path, 1).unwrap();
+        assert!(WalletSqliteDatabase::new(connection, SafePassword::from("")).is_ok());
+    }
+
     #[test]
     #[allow(unused_must_use)]
     fn test_malleated_secondary_key_hash() {
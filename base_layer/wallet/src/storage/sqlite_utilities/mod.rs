@@ -35,8 +35,8 @@ use crate::{
     error::WalletStorageError,
     output_manager_service::storage::sqlite_db::OutputManagerSqliteDatabase,
     storage::{
-        database::DbKey,
-        sqlite_db::wallet::{WalletSettingSql, WalletSqliteDatabase},
+        database::{DbKey, WalletBackend},
+        sqlite_db::wallet::{is_passphrase_protected, WalletSettingSql, WalletSqliteDatabase},
     },
     transaction_service::storage::sqlite_db::TransactionServiceSqliteDatabase,
 };
@@ -164,6 +164,47 @@ pub fn initialize_sqlite_database_backends<P: AsRef<Path>>(
     ))
 }
 
+/// Open the existing wallet database at the given path, failing with `DbPathDoesNotExist` rather than creating an empty
+/// database if there is none.
+fn open_existing_database<P: AsRef<Path>>(db_path: P) -> Result<WalletDbConnection, WalletStorageError> {
+    if !db_path.as_ref().is_file() {
+        return Err(WalletStorageError::DbPathDoesNotExist);
+    }
+    run_migration_and_create_sqlite_connection(db_path, 1)
+}
+
+/// Protect the wallet database at the given path with a passphrase. All the sqlite backends share the main key, so
+/// only the encrypted main key is replaced. Fails with `AlreadyEncrypted` if the database already has a passphrase.
+pub fn apply_database_encryption<P: AsRef<Path>>(
+    db_path: P,
+    passphrase: &SafePassword,
+) -> Result<(), WalletStorageError> {
+    if passphrase.reveal().is_empty() {
+        return Err(WalletStorageError::NoPasswordError);
+    }
+    let connection = open_existing_database(db_path)?;
+    if is_passphrase_protected(&connection)? {
+        return Err(WalletStorageError::AlreadyEncrypted);
+    }
+    let empty = SafePassword::from("");
+    let wallet_backend = WalletSqliteDatabase::new(connection, empty.clone())?;
+    wallet_backend.change_passphrase(&empty, passphrase)
+}
+
+/// Remove the passphrase protecting the wallet database at the given path, after which it can be opened with the empty
+/// passphrase. Fails with `NotEncrypted` if the database has no passphrase.
+pub fn remove_database_encryption<P: AsRef<Path>>(
+    db_path: P,
+    passphrase: &SafePassword,
+) -> Result<(), WalletStorageError> {
+    let connection = open_existing_database(db_path)?;
+    if !is_passphrase_protected(&connection)? {
+        return Err(WalletStorageError::NotEncrypted);
+    }
+    let wallet_backend = WalletSqliteDatabase::new(connection, passphrase.clone())?;
+    wallet_backend.change_passphrase(passphrase, &SafePassword::from(""))
+}
+
 pub fn get_last_version<P: AsRef<Path>>(db_path: P) -> Result<Option<String>, WalletStorageError> {
     let path_str = db_path
         .as_ref()
@@ -0,0 +1,73 @@
+// Copyright 2024 The Tari Project
+// SPDX-License-Identifier: BSD-3-Clause
+
+#[cfg(feature = "test_clock")]
+use std::sync::atomic::{AtomicI64, Ordering};
+#[cfg(feature = "test_clock")]
+use std::time::Duration;
+
+use chrono::{NaiveDateTime, Utc};
+
+/// The source of the current time for the transaction service. Everything in the service that measures the age of a
+/// transaction (resend periods, reply cooldowns, pending transaction timeouts) reads the time from here.
+pub trait Clock: Send + Sync + 'static {
+    /// The current UTC time
+    fn now(&self) -> NaiveDateTime;
+}
+
+/// The clock used in production, it reports the system time.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> NaiveDateTime {
+        Utc::now().naive_utc()
+    }
+}
+
+/// A clock that reports the system time plus an offset that can only be moved forward. This lets tests exercise
+/// time-based behaviour deterministically without waiting for real time to pass.
+#[cfg(feature = "test_clock")]
+#[derive(Debug, Default)]
+pub struct AdjustableClock {
+    offset_ms: AtomicI64,
+}
+
+#[cfg(feature = "test_clock")]
+impl AdjustableClock {
+    /// Moves the clock forward by `duration`, saturating at the largest representable offset
+    pub fn advance(&self, duration: Duration) {
+        let ms = i64::try_from(duration.as_millis()).unwrap_or(i64::MAX);
+        let _result = self
+            .offset_ms
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |offset| {
+                Some(offset.saturating_add(ms))
+            });
+    }
+}
+
+#[cfg(feature = "test_clock")]
+impl Clock for AdjustableClock {
+    fn now(&self) -> NaiveDateTime {
+        let offset = chrono::Duration::milliseconds(self.offset_ms.load(Ordering::SeqCst));
+        Utc::now()
+            .naive_utc()
+            .checked_add_signed(offset)
+            .unwrap_or(NaiveDateTime::MAX)
+    }
+}
+
+#[cfg(all(test, feature = "test_clock"))]
+mod test {
+    use super::*;
+
+    #[test]
+    fn it_advances_the_clock() {
+        let clock = AdjustableClock::default();
+        let before = SystemClock.now();
+        clock.advance(Duration::from_secs(3600));
+        let elapsed = clock.now().signed_duration_since(before);
+        assert!(elapsed >= chrono::Duration::seconds(3600));
+        assert!(elapsed < chrono::Duration::seconds(3660));
+    }
+}
@@ -25,14 +25,15 @@ use std::{
     fmt,
     fmt::{Display, Formatter},
     sync::Arc,
+    time::Duration,
 };
 
 use chrono::NaiveDateTime;
 use tari_common_types::{
     burnt_proof::BurntProof,
     tari_address::TariAddress,
-    transaction::{ImportStatus, TxId},
-    types::{FixedHash, HashOutput, PrivateKey, PublicKey, Signature},
+    transaction::{ImportStatus, TransactionDirection, TransactionStatus, TxId},
+    types::{BlockHash, FixedHash, HashOutput, PrivateKey, PublicKey, Signature},
 };
 use tari_comms::types::CommsPublicKey;
 use tari_core::{
@@ -59,6 +60,8 @@ use tari_utilities::hex::Hex;
 use tokio::sync::broadcast;
 use tower::Service;
 
+#[cfg(feature = "test_clock")]
+use crate::transaction_service::clock::AdjustableClock;
 use crate::{
     output_manager_service::{service::UseOutput, UtxoSelectionCriteria},
     transaction_service::{
@@ -80,12 +83,18 @@ use crate::{
 pub enum TransactionServiceRequest {
     GetPendingInboundTransactions,
     GetPendingOutboundTransactions,
+    GetPendingInboundTotal,
+    GetPendingOutboundTotal,
     GetCompletedTransactions,
+    GetCompletedTransactionsSince(NaiveDateTime),
+    GetNonCoinbaseCompletedTransactions,
+    GetCompletedTransactionCount(Option<TransactionStatus>),
     GetCancelledPendingInboundTransactions,
     GetCancelledPendingOutboundTransactions,
     GetCancelledCompletedTransactions,
     GetCompletedTransaction(TxId),
     GetAnyTransaction(TxId),
+    GetTransactionDirection(TxId),
     ImportTransaction(WalletTransaction),
     SendTransaction {
         destination: TariAddress,
@@ -166,17 +175,30 @@ pub enum TransactionServiceRequest {
         message: String,
         payment_id: PaymentId,
     },
+    SendOneSidedTransactionToMany {
+        recipients: Vec<(TariAddress, MicroMinotari)>,
+        selection_criteria: UtxoSelectionCriteria,
+        fee_per_gram: MicroMinotari,
+        message: String,
+        payment_id: PaymentId,
+    },
     ScrapeWallet {
         destination: TariAddress,
         fee_per_gram: MicroMinotari,
     },
     SendShaAtomicSwapTransaction(TariAddress, MicroMinotari, UtxoSelectionCriteria, MicroMinotari, String),
     CancelTransaction(TxId),
+    CancelExpiredPendingTransactions(Duration),
+    #[cfg(feature = "test_harness")]
+    TestReceiveTransaction,
+    #[cfg(feature = "test_harness")]
+    TestCompleteSentTransaction(TxId),
     ImportUtxoWithStatus {
         amount: MicroMinotari,
         source_address: TariAddress,
         message: String,
         import_status: ImportStatus,
+        one_sided_payment: bool,
         tx_id: Option<TxId>,
         current_height: Option<u64>,
         mined_timestamp: Option<NaiveDateTime>,
@@ -190,6 +212,12 @@ pub enum TransactionServiceRequest {
     RestartBroadcastProtocols,
     GetNumConfirmationsRequired,
     SetNumConfirmationsRequired(u64),
+    MarkImportedTransactionConfirmed {
+        tx_id: TxId,
+        mined_height: u64,
+        mined_in_block: BlockHash,
+        mined_timestamp: u64,
+    },
     ValidateTransactions,
     ReValidateTransactions,
     /// Returns the fee per gram estimates for the next {count} blocks.
@@ -204,7 +232,12 @@ impl fmt::Display for TransactionServiceRequest {
         match self {
             Self::GetPendingInboundTransactions => write!(f, "GetPendingInboundTransactions"),
             Self::GetPendingOutboundTransactions => write!(f, "GetPendingOutboundTransactions"),
+            Self::GetPendingInboundTotal => write!(f, "GetPendingInboundTotal"),
+            Self::GetPendingOutboundTotal => write!(f, "GetPendingOutboundTotal"),
             Self::GetCompletedTransactions => write!(f, "GetCompletedTransactions"),
+            Self::GetCompletedTransactionsSince(t) => write!(f, "GetCompletedTransactionsSince({})", t),
+            Self::GetNonCoinbaseCompletedTransactions => write!(f, "GetNonCoinbaseCompletedTransactions"),
+            Self::GetCompletedTransactionCount(s) => write!(f, "GetCompletedTransactionCount({:?})", s),
             Self::ImportTransaction(tx) => write!(f, "ImportTransaction: {:?}", tx),
             Self::GetCancelledPendingInboundTransactions => write!(f, "GetCancelledPendingInboundTransactions"),
             Self::GetCancelledPendingOutboundTransactions => write!(f, "GetCancelledPendingOutboundTransactions"),
@@ -345,10 +378,25 @@ impl fmt::Display for TransactionServiceRequest {
                 "SendOneSidedToStealthAddressTransaction (to {}, {}, {})",
                 destination, amount, message
             ),
+            Self::SendOneSidedTransactionToMany {
+                recipients, message, ..
+            } => write!(
+                f,
+                "SendOneSidedTransactionToMany (to {} recipients, {})",
+                recipients.len(),
+                message
+            ),
             Self::SendShaAtomicSwapTransaction(k, _, v, _, msg) => {
                 write!(f, "SendShaAtomicSwapTransaction (to {}, {}, {})", k, v, msg)
             },
             Self::CancelTransaction(t) => write!(f, "CancelTransaction ({})", t),
+            #[cfg(feature = "test_harness")]
+            Self::TestReceiveTransaction => write!(f, "TestReceiveTransaction"),
+            #[cfg(feature = "test_harness")]
+            Self::TestCompleteSentTransaction(tx_id) => write!(f, "TestCompleteSentTransaction ({})", tx_id),
+            Self::CancelExpiredPendingTransactions(d) => {
+                write!(f, "CancelExpiredPendingTransactions (older than {:.0?})", d)
+            },
             Self::ImportUtxoWithStatus {
                 amount,
                 source_address,
@@ -371,7 +419,18 @@ impl fmt::Display for TransactionServiceRequest {
             Self::RestartBroadcastProtocols => write!(f, "RestartBroadcastProtocols"),
             Self::GetNumConfirmationsRequired => write!(f, "GetNumConfirmationsRequired"),
             Self::SetNumConfirmationsRequired(_) => write!(f, "SetNumConfirmationsRequired"),
+            Self::MarkImportedTransactionConfirmed {
+                tx_id,
+                mined_height,
+                mined_in_block,
+                ..
+            } => write!(
+                f,
+                "MarkImportedTransactionConfirmed (TxId: {}, mined height: {}, mined in block: {})",
+                tx_id, mined_height, mined_in_block
+            ),
             Self::GetAnyTransaction(t) => write!(f, "GetAnyTransaction({})", t),
+            Self::GetTransactionDirection(t) => write!(f, "GetTransactionDirection({})", t),
             Self::ValidateTransactions => write!(f, "ValidateTransactions"),
             Self::ReValidateTransactions => write!(f, "ReValidateTransactions"),
             Self::GetFeePerGramStatsPerBlock { count } => {
@@ -408,10 +467,17 @@ pub enum TransactionServiceResponse {
         template_registration: Box<CodeTemplateRegistration>,
     },
     TransactionCancelled,
+    TransactionsCancelled(Vec<TxId>),
+    #[cfg(feature = "test_harness")]
+    TestTransactionReceived(TxId),
+    #[cfg(feature = "test_harness")]
+    TestSentTransactionCompleted,
     PendingInboundTransactions(HashMap<TxId, InboundTransaction>),
     PendingOutboundTransactions(HashMap<TxId, OutboundTransaction>),
+    PendingTransactionsTotal(MicroMinotari),
     CompletedTransactions(HashMap<TxId, CompletedTransaction>),
     CompletedTransaction(Box<CompletedTransaction>),
+    CompletedTransactionCount(u64),
     BaseNodePublicKeySet,
     UtxoImported(TxId),
     TransactionSubmitted,
@@ -419,8 +485,10 @@ pub enum TransactionServiceResponse {
     NormalPowerModeSet,
     ProtocolsRestarted,
     AnyTransaction(Box<Option<WalletTransaction>>),
+    TransactionDirection(Option<TransactionDirection>),
     NumConfirmationsRequired(u64),
     NumConfirmationsSet,
+    ImportedTransactionConfirmed,
     ValidationStarted(OperationId),
     CompletedTransactionValidityChanged,
     ShaAtomicSwapTransactionSent(Box<(TxId, PublicKey, TransactionOutput)>),
@@ -584,6 +652,8 @@ impl From<proto::base_node::GetMempoolFeePerGramStatsResponse> for FeePerGramSta
 pub struct TransactionServiceHandle {
     handle: SenderService<TransactionServiceRequest, Result<TransactionServiceResponse, TransactionServiceError>>,
     event_stream_sender: TransactionEventSender,
+    #[cfg(feature = "test_clock")]
+    clock: Arc<AdjustableClock>,
 }
 
 impl TransactionServiceHandle {
@@ -594,9 +664,25 @@ impl TransactionServiceHandle {
         Self {
             handle,
             event_stream_sender,
+            #[cfg(feature = "test_clock")]
+            clock: Arc::new(AdjustableClock::default()),
         }
     }
 
+    /// The clock to share with the service this handle talks to, see `TransactionService::with_clock`
+    #[cfg(feature = "test_clock")]
+    pub fn adjustable_clock(&self) -> Arc<AdjustableClock> {
+        self.clock.clone()
+    }
+
+    /// Moves the clock the transaction service reads the time from forward by `duration`, so that time-based
+    /// behaviour such as pending transaction timeouts can be tested without waiting. The service observes the new time
+    /// the next time it measures the age of a transaction.
+    #[cfg(feature = "test_clock")]
+    pub fn advance_clock(&self, duration: Duration) {
+        self.clock.advance(duration);
+    }
+
     pub fn get_event_stream(&self) -> TransactionEventReceiver {
         self.event_stream_sender.subscribe()
     }
@@ -731,6 +817,31 @@ impl TransactionServiceHandle {
         }
     }
 
+    /// Sends a single transaction containing a one-sided payment to each of the given recipients
+    pub async fn send_one_sided_transaction_to_many(
+        &mut self,
+        recipients: Vec<(TariAddress, MicroMinotari)>,
+        selection_criteria: UtxoSelectionCriteria,
+        fee_per_gram: MicroMinotari,
+        message: String,
+        payment_id: PaymentId,
+    ) -> Result<TxId, TransactionServiceError> {
+        match self
+            .handle
+            .call(TransactionServiceRequest::SendOneSidedTransactionToMany {
+                recipients,
+                selection_criteria,
+                fee_per_gram,
+                message,
+                payment_id,
+            })
+            .await??
+        {
+            TransactionServiceResponse::TransactionSent(tx_id) => Ok(tx_id),
+            _ => Err(TransactionServiceError::UnexpectedApiResponse),
+        }
+    }
+
     /// Burns the given amount of Tari from the wallet
     pub async fn burn_tari(
         &mut self,
@@ -902,6 +1013,50 @@ impl TransactionServiceHandle {
         }
     }
 
+    /// Cancels every pending outbound transaction older than `older_than`, releasing its encumbered outputs, and
+    /// returns the ids of the cancelled transactions. A transaction that fails to cancel is left pending and does not
+    /// stop the others from being cancelled.
+    pub async fn cancel_expired_pending_transactions(
+        &mut self,
+        older_than: Duration,
+    ) -> Result<Vec<TxId>, TransactionServiceError> {
+        match self
+            .handle
+            .call(TransactionServiceRequest::CancelExpiredPendingTransactions(older_than))
+            .await??
+        {
+            TransactionServiceResponse::TransactionsCancelled(tx_ids) => Ok(tx_ids),
+            _ => Err(TransactionServiceError::UnexpectedApiResponse),
+        }
+    }
+
+    /// Simulates a counterparty sending this wallet a transaction. The transaction is accepted as if it arrived over
+    /// the network, so it is stored as a pending inbound transaction and its receive protocol is started.
+    #[cfg(feature = "test_harness")]
+    pub async fn test_receive_transaction(&mut self) -> Result<TxId, TransactionServiceError> {
+        match self
+            .handle
+            .call(TransactionServiceRequest::TestReceiveTransaction)
+            .await??
+        {
+            TransactionServiceResponse::TestTransactionReceived(tx_id) => Ok(tx_id),
+            _ => Err(TransactionServiceError::UnexpectedApiResponse),
+        }
+    }
+
+    /// Simulates the recipient of a pending outbound transaction replying, which completes the transaction
+    #[cfg(feature = "test_harness")]
+    pub async fn test_complete_sent_transaction(&mut self, tx_id: TxId) -> Result<(), TransactionServiceError> {
+        match self
+            .handle
+            .call(TransactionServiceRequest::TestCompleteSentTransaction(tx_id))
+            .await??
+        {
+            TransactionServiceResponse::TestSentTransactionCompleted => Ok(()),
+            _ => Err(TransactionServiceError::UnexpectedApiResponse),
+        }
+    }
+
     pub async fn get_pending_inbound_transactions(
         &mut self,
     ) -> Result<HashMap<TxId, InboundTransaction>, TransactionServiceError> {
@@ -915,6 +1070,28 @@ impl TransactionServiceHandle {
         }
     }
 
+    pub async fn get_pending_inbound_total(&mut self) -> Result<MicroMinotari, TransactionServiceError> {
+        match self
+            .handle
+            .call(TransactionServiceRequest::GetPendingInboundTotal)
+            .await??
+        {
+            TransactionServiceResponse::PendingTransactionsTotal(total) => Ok(total),
+            _ => Err(TransactionServiceError::UnexpectedApiResponse),
+        }
+    }
+
+    pub async fn get_pending_outbound_total(&mut self) -> Result<MicroMinotari, TransactionServiceError> {
+        match self
+            .handle
+            .call(TransactionServiceRequest::GetPendingOutboundTotal)
+            .await??
+        {
+            TransactionServiceResponse::PendingTransactionsTotal(total) => Ok(total),
+            _ => Err(TransactionServiceError::UnexpectedApiResponse),
+        }
+    }
+
     pub async fn get_cancelled_pending_inbound_transactions(
         &mut self,
     ) -> Result<HashMap<TxId, InboundTransaction>, TransactionServiceError> {
@@ -967,6 +1144,47 @@ impl TransactionServiceHandle {
         }
     }
 
+    pub async fn get_completed_transactions_since(
+        &mut self,
+        since: NaiveDateTime,
+    ) -> Result<HashMap<TxId, CompletedTransaction>, TransactionServiceError> {
+        match self
+            .handle
+            .call(TransactionServiceRequest::GetCompletedTransactionsSince(since))
+            .await??
+        {
+            TransactionServiceResponse::CompletedTransactions(c) => Ok(c),
+            _ => Err(TransactionServiceError::UnexpectedApiResponse),
+        }
+    }
+
+    pub async fn get_non_coinbase_completed_transactions(
+        &mut self,
+    ) -> Result<HashMap<TxId, CompletedTransaction>, TransactionServiceError> {
+        match self
+            .handle
+            .call(TransactionServiceRequest::GetNonCoinbaseCompletedTransactions)
+            .await??
+        {
+            TransactionServiceResponse::CompletedTransactions(c) => Ok(c),
+            _ => Err(TransactionServiceError::UnexpectedApiResponse),
+        }
+    }
+
+    pub async fn get_completed_transaction_count(
+        &mut self,
+        status: Option<TransactionStatus>,
+    ) -> Result<u64, TransactionServiceError> {
+        match self
+            .handle
+            .call(TransactionServiceRequest::GetCompletedTransactionCount(status))
+            .await??
+        {
+            TransactionServiceResponse::CompletedTransactionCount(c) => Ok(c),
+            _ => Err(TransactionServiceError::UnexpectedApiResponse),
+        }
+    }
+
     pub async fn get_cancelled_completed_transactions(
         &mut self,
     ) -> Result<HashMap<TxId, CompletedTransaction>, TransactionServiceError> {
@@ -1008,6 +1226,20 @@ impl TransactionServiceHandle {
         }
     }
 
+    pub async fn get_transaction_direction(
+        &mut self,
+        tx_id: TxId,
+    ) -> Result<Option<TransactionDirection>, TransactionServiceError> {
+        match self
+            .handle
+            .call(TransactionServiceRequest::GetTransactionDirection(tx_id))
+            .await??
+        {
+            TransactionServiceResponse::TransactionDirection(d) => Ok(d),
+            _ => Err(TransactionServiceError::UnexpectedApiResponse),
+        }
+    }
+
     pub async fn import_transaction(&mut self, tx: WalletTransaction) -> Result<TxId, TransactionServiceError> {
         match self
             .handle
@@ -1025,6 +1257,7 @@ impl TransactionServiceHandle {
         source_address: TariAddress,
         message: String,
         import_status: ImportStatus,
+        one_sided_payment: bool,
         tx_id: Option<TxId>,
         current_height: Option<u64>,
         mined_timestamp: Option<NaiveDateTime>,
@@ -1038,6 +1271,7 @@ impl TransactionServiceHandle {
                 source_address,
                 message,
                 import_status,
+                one_sided_payment,
                 tx_id,
                 current_height,
                 mined_timestamp,
@@ -1122,6 +1356,28 @@ impl TransactionServiceHandle {
         }
     }
 
+    pub async fn mark_imported_transaction_confirmed(
+        &mut self,
+        tx_id: TxId,
+        mined_height: u64,
+        mined_in_block: BlockHash,
+        mined_timestamp: u64,
+    ) -> Result<(), TransactionServiceError> {
+        match self
+            .handle
+            .call(TransactionServiceRequest::MarkImportedTransactionConfirmed {
+                tx_id,
+                mined_height,
+                mined_in_block,
+                mined_timestamp,
+            })
+            .await??
+        {
+            TransactionServiceResponse::ImportedTransactionConfirmed => Ok(()),
+            _ => Err(TransactionServiceError::UnexpectedApiResponse),
+        }
+    }
+
     pub async fn restart_transaction_protocols(&mut self) -> Result<(), TransactionServiceError> {
         match self
             .handle
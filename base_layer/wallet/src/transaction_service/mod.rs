@@ -58,6 +58,7 @@ use crate::{
     output_manager_service::handle::OutputManagerHandle,
     storage::database::{WalletBackend, WalletDatabase},
     transaction_service::{
+        clock::Clock,
         config::TransactionServiceConfig,
         handle::TransactionServiceHandle,
         service::TransactionService,
@@ -65,6 +66,7 @@ use crate::{
     },
 };
 
+pub mod clock;
 pub mod config;
 pub mod error;
 pub mod handle;
@@ -216,6 +218,10 @@ where
         let (publisher, _) = broadcast::channel(self.config.transaction_event_channel_size);
 
         let transaction_handle = TransactionServiceHandle::new(sender, publisher.clone());
+        #[cfg(feature = "test_clock")]
+        let clock: Arc<dyn Clock> = transaction_handle.adjustable_clock();
+        #[cfg(not(feature = "test_clock"))]
+        let clock: Arc<dyn Clock> = Arc::new(clock::SystemClock);
 
         // Register handle before waiting for handles to be ready
         context.register_handle(transaction_handle);
@@ -269,6 +275,7 @@ where
             )
             .await
             .expect("Could not initialize Transaction Manager Service")
+            .with_clock(clock)
             .start()
             .await;
 
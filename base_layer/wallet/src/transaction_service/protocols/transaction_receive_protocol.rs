@@ -22,7 +22,6 @@
 
 use std::sync::Arc;
 
-use chrono::Utc;
 use futures::future::FutureExt;
 use log::*;
 use tari_common_types::{
@@ -156,7 +155,7 @@ where
                 rtp,
                 TransactionStatus::Pending,
                 data.message.clone(),
-                Utc::now().naive_utc(),
+                self.resources.clock.now(),
             );
 
             // Verify that the negotiated transaction is not too large to be broadcast
@@ -181,7 +180,7 @@ where
 
             self.resources
                 .db
-                .increment_send_count(self.id)
+                .increment_send_count(self.id, self.resources.clock.now())
                 .map_err(|e| TransactionServiceProtocolError::new(self.id, TransactionServiceError::from(e)))?;
 
             if send_result {
@@ -255,7 +254,7 @@ where
         }
 
         // Determine the time remaining before this transaction times out
-        let elapsed_time = utc_duration_since(&inbound_tx.timestamp)
+        let elapsed_time = utc_duration_since(self.resources.clock.as_ref(), &inbound_tx.timestamp)
             .map_err(|e| TransactionServiceProtocolError::new(self.id, e.into()))?;
 
         let timeout_duration = match self
@@ -277,7 +276,7 @@ where
         let resend = match inbound_tx.last_send_timestamp {
             None => true,
             Some(timestamp) => {
-                let elapsed_time = utc_duration_since(&timestamp)
+                let elapsed_time = utc_duration_since(self.resources.clock.as_ref(), &timestamp)
                     .map_err(|e| TransactionServiceProtocolError::new(self.id, e.into()))?;
                 elapsed_time > self.resources.config.transaction_resend_period
             },
@@ -299,7 +298,7 @@ where
             }
             self.resources
                 .db
-                .increment_send_count(self.id)
+                .increment_send_count(self.id, self.resources.clock.now())
                 .map_err(|e| TransactionServiceProtocolError::new(self.id, TransactionServiceError::from(e)))?;
         }
 
@@ -342,7 +341,7 @@ where
                         .await {
                             Ok(_) => self.resources
                                         .db
-                                        .increment_send_count(self.id)
+                                        .increment_send_count(self.id, self.resources.clock.now())
                                         .map_err(|e| TransactionServiceProtocolError::new(self.id, TransactionServiceError::from(e)))?,
                             Err(e) => warn!(
                                             target: LOG_TARGET,
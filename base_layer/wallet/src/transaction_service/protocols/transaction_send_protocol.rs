@@ -22,7 +22,6 @@
 
 use std::{convert::TryInto, sync::Arc};
 
-use chrono::Utc;
 use futures::FutureExt;
 use log::*;
 use tari_common_types::{
@@ -290,7 +289,7 @@ where
             sender_protocol.clone(),
             TransactionStatus::Pending, // This does not matter for the check
             self.message.clone(),
-            Utc::now().naive_utc(),
+            self.resources.clock.now(),
             true, // This does not matter for the check
         );
 
@@ -344,7 +343,7 @@ where
                 sender_protocol.clone(),
                 initial_send.transaction_status.clone(),
                 self.message.clone(),
-                Utc::now().naive_utc(),
+                self.resources.clock.now(),
                 initial_send.direct_send_result,
             );
             self.resources
@@ -359,7 +358,7 @@ where
         if initial_send.transaction_status == TransactionStatus::Pending {
             self.resources
                 .db
-                .increment_send_count(self.id)
+                .increment_send_count(self.id, self.resources.clock.now())
                 .map_err(|e| TransactionServiceProtocolError::new(self.id, TransactionServiceError::from(e)))?;
         }
 
@@ -429,7 +428,7 @@ where
         }
 
         // Determine the time remaining before this transaction times out
-        let elapsed_time = utc_duration_since(&outbound_tx.timestamp)
+        let elapsed_time = utc_duration_since(self.resources.clock.as_ref(), &outbound_tx.timestamp)
             .map_err(|e| TransactionServiceProtocolError::new(self.id, e.into()))?;
 
         let timeout_duration = match self
@@ -451,7 +450,7 @@ where
         let resend = match outbound_tx.last_send_timestamp {
             None => true,
             Some(timestamp) => {
-                let elapsed_time = utc_duration_since(&timestamp)
+                let elapsed_time = utc_duration_since(self.resources.clock.as_ref(), &timestamp)
                     .map_err(|e| TransactionServiceProtocolError::new(self.id, e.into()))?;
                 elapsed_time > self.resources.config.transaction_resend_period
             },
@@ -472,7 +471,7 @@ where
                     if val.transaction_status == TransactionStatus::Pending {
                         self.resources
                             .db
-                            .increment_send_count(self.id)
+                            .increment_send_count(self.id, self.resources.clock.now())
                             .map_err(|e| TransactionServiceProtocolError::new(self.id, e.into()))?
                     }
                 },
@@ -518,7 +517,7 @@ where
                         });
                         self.resources
                             .db
-                            .increment_send_count(self.id)
+                            .increment_send_count(self.id, self.resources.clock.now())
                             .map_err(|e| TransactionServiceProtocolError::new(
                                 self.id, TransactionServiceError::from(e))
                             )?;
@@ -539,7 +538,7 @@ where
                         Ok(val) => if val.transaction_status == TransactionStatus::Pending {
                              self.resources
                                 .db
-                                .increment_send_count(self.id)
+                                .increment_send_count(self.id, self.resources.clock.now())
                                 .map_err(|e| TransactionServiceProtocolError::new(
                                     self.id, TransactionServiceError::from(e))
                                 )?
@@ -600,7 +599,7 @@ where
             tx.clone(),
             TransactionStatus::Completed,
             outbound_tx.message.clone(),
-            Utc::now().naive_utc(),
+            self.resources.clock.now(),
             TransactionDirection::Outbound,
             None,
             None,
@@ -630,7 +629,7 @@ where
 
         self.resources
             .db
-            .increment_send_count(tx_id)
+            .increment_send_count(tx_id, self.resources.clock.now())
             .map_err(|e| TransactionServiceProtocolError::new(self.id, TransactionServiceError::from(e)))?;
 
         let _size = self
@@ -951,7 +950,7 @@ where
         });
         self.resources
             .db
-            .increment_send_count(self.id)
+            .increment_send_count(self.id, self.resources.clock.now())
             .map_err(|e| TransactionServiceProtocolError::new(self.id, TransactionServiceError::from(e)))?;
 
         self.resources.db.cancel_pending_transaction(self.id).map_err(|e| {
@@ -27,7 +27,7 @@ use std::{
     time::{Duration, Instant},
 };
 
-use chrono::{NaiveDateTime, Utc};
+use chrono::NaiveDateTime;
 use digest::Digest;
 use futures::{pin_mut, stream::FuturesUnordered, Stream, StreamExt};
 use log::*;
@@ -39,7 +39,7 @@ use tari_common_types::{
     key_branches::TransactionKeyManagerBranch,
     tari_address::{TariAddress, TariAddressFeatures},
     transaction::{ImportStatus, TransactionDirection, TransactionStatus, TxId},
-    types::{CommitmentFactory, HashOutput, PrivateKey, PublicKey, Signature},
+    types::{BlockHash, CommitmentFactory, HashOutput, PrivateKey, PublicKey, Signature},
     wallet_types::WalletType,
 };
 use tari_comms::{types::CommsPublicKey, NodeIdentity};
@@ -98,6 +98,7 @@ use crate::{
     },
     storage::database::{WalletBackend, WalletDatabase},
     transaction_service::{
+        clock::{Clock, SystemClock},
         config::TransactionServiceConfig,
         error::{TransactionServiceError, TransactionServiceProtocolError},
         handle::{
@@ -137,6 +138,10 @@ use crate::{
 
 const LOG_TARGET: &str = "wallet::transaction_service::service";
 
+/// The lowest fee per gram the wallet will suggest. It is also used for the fee estimates when the mempool of the base
+/// node is empty. There is no consensus minimum fee per gram, but a zero fee per gram gives a transaction with no fee.
+pub const MINIMUM_FEE_PER_GRAM: MicroMinotari = MicroMinotari(1);
+
 /// TransactionService allows for the management of multiple inbound and outbound transaction protocols
 /// which are uniquely identified by a tx_id. The TransactionService generates and accepts the various protocol
 /// messages and applies them to the appropriate protocol instances based on the tx_id.
@@ -281,6 +286,7 @@ where
             shutdown_signal,
             consensus_manager: consensus_manager.clone(),
             wallet_type,
+            clock: Arc::new(SystemClock),
         };
         let power_mode = PowerMode::default();
         let timeout = match power_mode {
@@ -315,6 +321,12 @@ where
         })
     }
 
+    /// Replace the clock the service and its protocols read the current time from, the default is the system clock
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.resources.clock = clock;
+        self
+    }
+
     #[allow(clippy::too_many_lines)]
     pub async fn start(mut self) -> Result<(), TransactionServiceError> {
         let request_stream = self
@@ -681,6 +693,23 @@ where
                 )
                 .await
                 .map(TransactionServiceResponse::TransactionSent),
+            TransactionServiceRequest::SendOneSidedTransactionToMany {
+                recipients,
+                selection_criteria,
+                fee_per_gram,
+                message,
+                payment_id,
+            } => self
+                .send_one_sided_transaction_to_many(
+                    recipients,
+                    selection_criteria,
+                    fee_per_gram,
+                    message,
+                    payment_id,
+                    transaction_broadcast_join_handles,
+                )
+                .await
+                .map(TransactionServiceResponse::TransactionSent),
             TransactionServiceRequest::BurnTari {
                 amount,
                 selection_criteria,
@@ -850,16 +879,56 @@ where
                 .cancel_pending_transaction(tx_id)
                 .await
                 .map(|_| TransactionServiceResponse::TransactionCancelled),
+            TransactionServiceRequest::CancelExpiredPendingTransactions(older_than) => self
+                .cancel_expired_pending_transactions(older_than)
+                .await
+                .map(TransactionServiceResponse::TransactionsCancelled),
+            #[cfg(feature = "test_harness")]
+            TransactionServiceRequest::TestReceiveTransaction => self
+                .test_receive_transaction(receive_transaction_join_handles)
+                .map(TransactionServiceResponse::TestTransactionReceived),
+            #[cfg(feature = "test_harness")]
+            TransactionServiceRequest::TestCompleteSentTransaction(tx_id) => self
+                .test_complete_sent_transaction(tx_id)
+                .await
+                .map(|_| TransactionServiceResponse::TestSentTransactionCompleted),
             TransactionServiceRequest::GetPendingInboundTransactions => Ok(
                 TransactionServiceResponse::PendingInboundTransactions(self.db.get_pending_inbound_transactions()?),
             ),
             TransactionServiceRequest::GetPendingOutboundTransactions => Ok(
                 TransactionServiceResponse::PendingOutboundTransactions(self.db.get_pending_outbound_transactions()?),
             ),
+            TransactionServiceRequest::GetPendingInboundTotal => Ok(
+                TransactionServiceResponse::PendingTransactionsTotal(self.db.get_pending_inbound_total()?),
+            ),
+            TransactionServiceRequest::GetPendingOutboundTotal => Ok(
+                TransactionServiceResponse::PendingTransactionsTotal(self.db.get_pending_outbound_total()?),
+            ),
 
             TransactionServiceRequest::GetCompletedTransactions => Ok(
                 TransactionServiceResponse::CompletedTransactions(self.db.get_completed_transactions()?),
             ),
+            TransactionServiceRequest::GetCompletedTransactionsSince(since) => {
+                Ok(TransactionServiceResponse::CompletedTransactions(
+                    self.db
+                        .get_completed_transactions_since(since)?
+                        .into_iter()
+                        .map(|tx| (tx.tx_id, tx))
+                        .collect(),
+                ))
+            },
+            TransactionServiceRequest::GetNonCoinbaseCompletedTransactions => {
+                Ok(TransactionServiceResponse::CompletedTransactions(
+                    self.db
+                        .get_non_coinbase_completed_transactions()?
+                        .into_iter()
+                        .map(|tx| (tx.tx_id, tx))
+                        .collect(),
+                ))
+            },
+            TransactionServiceRequest::GetCompletedTransactionCount(status) => Ok(
+                TransactionServiceResponse::CompletedTransactionCount(self.db.count_completed_transactions(status)?),
+            ),
             TransactionServiceRequest::GetCancelledPendingInboundTransactions => {
                 Ok(TransactionServiceResponse::PendingInboundTransactions(
                     self.db.get_cancelled_pending_inbound_transactions()?,
@@ -879,6 +948,9 @@ where
             TransactionServiceRequest::GetAnyTransaction(tx_id) => Ok(TransactionServiceResponse::AnyTransaction(
                 Box::new(self.db.get_any_transaction(tx_id)?),
             )),
+            TransactionServiceRequest::GetTransactionDirection(tx_id) => Ok(
+                TransactionServiceResponse::TransactionDirection(self.db.get_transaction_direction(tx_id)?),
+            ),
             TransactionServiceRequest::ImportTransaction(tx) => {
                 let tx_id = match tx {
                     PendingInbound(inbound_tx) => {
@@ -907,6 +979,7 @@ where
                 source_address,
                 message,
                 import_status,
+                one_sided_payment,
                 tx_id,
                 current_height,
                 mined_timestamp,
@@ -918,6 +991,7 @@ where
                     source_address,
                     message,
                     import_status,
+                    one_sided_payment,
                     tx_id,
                     current_height,
                     mined_timestamp,
@@ -954,6 +1028,15 @@ where
                 self.resources.config.num_confirmations_required = number;
                 Ok(TransactionServiceResponse::NumConfirmationsSet)
             },
+            TransactionServiceRequest::MarkImportedTransactionConfirmed {
+                tx_id,
+                mined_height,
+                mined_in_block,
+                mined_timestamp,
+            } => self
+                .mark_imported_transaction_confirmed(tx_id, mined_height, mined_in_block, mined_timestamp)
+                .await
+                .map(|_| TransactionServiceResponse::ImportedTransactionConfirmed),
             TransactionServiceRequest::ValidateTransactions => self
                 .start_transaction_validation_protocol(transaction_validation_join_handles)
                 .await
@@ -1001,9 +1084,9 @@ where
             if resp.stats.is_empty() {
                 resp.stats = vec![FeePerGramStat {
                     order: 0,
-                    min_fee_per_gram: 1.into(),
-                    avg_fee_per_gram: 1.into(),
-                    max_fee_per_gram: 1.into(),
+                    min_fee_per_gram: MINIMUM_FEE_PER_GRAM,
+                    avg_fee_per_gram: MINIMUM_FEE_PER_GRAM,
+                    max_fee_per_gram: MINIMUM_FEE_PER_GRAM,
                 }]
             }
             Ok(TransactionServiceResponse::FeePerGramStatsPerBlock(resp))
@@ -1131,7 +1214,7 @@ where
                     transaction,
                     TransactionStatus::Completed,
                     message,
-                    Utc::now().naive_utc(),
+                    self.resources.clock.now(),
                     TransactionDirection::Inbound,
                     None,
                     None,
@@ -1256,7 +1339,7 @@ where
                     transaction.clone(),
                     TransactionStatus::Pending,
                     "claimed n-of-m utxo".to_string(),
-                    Utc::now().naive_utc(),
+                    self.resources.clock.now(),
                     TransactionDirection::Outbound,
                     None,
                     None,
@@ -1308,7 +1391,7 @@ where
                     transaction.clone(),
                     TransactionStatus::Pending,
                     "claimed n-of-m utxo".to_string(),
-                    Utc::now().naive_utc(),
+                    self.resources.clock.now(),
                     TransactionDirection::Outbound,
                     None,
                     None,
@@ -1639,7 +1722,7 @@ where
                 tx.clone(),
                 TransactionStatus::Completed,
                 message.clone(),
-                Utc::now().naive_utc(),
+                self.resources.clock.now(),
                 TransactionDirection::Outbound,
                 None,
                 None,
@@ -1876,7 +1959,7 @@ where
                 tx.clone(),
                 TransactionStatus::Completed,
                 message.clone(),
-                Utc::now().naive_utc(),
+                self.resources.clock.now(),
                 TransactionDirection::Outbound,
                 None,
                 None,
@@ -2089,7 +2172,7 @@ where
                 tx.clone(),
                 TransactionStatus::Completed,
                 "".to_string(),
-                Utc::now().naive_utc(),
+                self.resources.clock.now(),
                 TransactionDirection::Outbound,
                 None,
                 None,
@@ -2134,6 +2217,97 @@ where
         .await
     }
 
+    /// Sends a single transaction containing a one-sided payment to each of the given recipients. The completed
+    /// transaction is recorded against the first recipient with the total amount sent to all recipients.
+    /// # Arguments
+    /// 'recipients': The addresses of the recipients and the amount of Tari to send to each
+    /// 'fee_per_gram': The amount of fee per transaction gram to be included in transaction
+    pub async fn send_one_sided_transaction_to_many(
+        &mut self,
+        recipients: Vec<(TariAddress, MicroMinotari)>,
+        selection_criteria: UtxoSelectionCriteria,
+        fee_per_gram: MicroMinotari,
+        message: String,
+        payment_id: PaymentId,
+        transaction_broadcast_join_handles: &mut FuturesUnordered<
+            JoinHandle<Result<TxId, TransactionServiceProtocolError<TxId>>>,
+        >,
+    ) -> Result<TxId, TransactionServiceError> {
+        let destination = recipients
+            .first()
+            .map(|(address, _)| address.clone())
+            .ok_or_else(|| TransactionServiceError::InvalidAddress("No recipients provided".to_string()))?;
+        for (address, _) in &recipients {
+            self.verify_send(address, TariAddressFeatures::create_one_sided_only())?;
+        }
+        let amount = recipients
+            .iter()
+            .try_fold(MicroMinotari::zero(), |total, (_, amount)| total.checked_add(*amount))
+            .ok_or_else(|| {
+                TransactionServiceError::OneSidedTransactionError("Total recipient amount overflows".to_string())
+            })?;
+        let recipient_public_keys = recipients
+            .iter()
+            .map(|(address, _)| address.comms_public_key().clone())
+            .collect::<Vec<_>>();
+        let payment_id = match payment_id {
+            PaymentId::Open(v) => PaymentId::AddressAndData(self.resources.interactive_tari_address.clone(), v),
+            PaymentId::Empty => PaymentId::Address(self.resources.interactive_tari_address.clone()),
+            _ => payment_id,
+        };
+
+        let (tx_id, tx) = self
+            .resources
+            .output_manager_service
+            .create_one_sided_transaction_to_many(recipients, selection_criteria, fee_per_gram, payment_id.clone())
+            .await?;
+        let fee = tx.body.get_total_fee()?;
+
+        // This event being sent is important, but not critical to the protocol being successful. Send only fails if
+        // there are no subscribers.
+        let _result = self
+            .event_publisher
+            .send(Arc::new(TransactionEvent::TransactionCompletedImmediately(tx_id)));
+
+        self.resources
+            .output_manager_service
+            .confirm_pending_transaction(tx_id)
+            .await
+            .map_err(|e| TransactionServiceProtocolError::new(tx_id, e.into()))?;
+        self.submit_transaction(
+            transaction_broadcast_join_handles,
+            CompletedTransaction::new(
+                tx_id,
+                self.resources.one_sided_tari_address.clone(),
+                destination,
+                amount,
+                fee,
+                tx.clone(),
+                TransactionStatus::Completed,
+                message,
+                self.resources.clock.now(),
+                TransactionDirection::Outbound,
+                None,
+                None,
+                Some(payment_id),
+            )?,
+        )
+        .await?;
+
+        for public_key in recipient_public_keys {
+            tokio::spawn(send_finalized_transaction_message(
+                tx_id,
+                tx.clone(),
+                public_key,
+                self.resources.outbound_message_service.clone(),
+                self.resources.config.direct_send_timeout,
+                self.resources.config.transaction_routing_mechanism,
+            ));
+        }
+
+        Ok(tx_id)
+    }
+
     /// Creates a transaction to burn some Minotari. The optional _claim public key_ parameter is used in the challenge
     /// of the
     // corresponding optional _ownership proof_ return value. Burn commitments and ownership proofs will exclusively be
@@ -2349,7 +2523,7 @@ where
                 tx.clone(),
                 TransactionStatus::Completed,
                 message.clone(),
-                Utc::now().naive_utc(),
+                self.resources.clock.now(),
                 TransactionDirection::Outbound,
                 None,
                 None,
@@ -2496,7 +2670,7 @@ where
         let check_cooldown = |timestamp: Option<NaiveDateTime>| {
             if let Some(t) = timestamp {
                 // Check if the last reply is beyond the resend cooldown
-                if let Ok(elapsed_time) = Utc::now().naive_utc().signed_duration_since(t).to_std() {
+                if let Ok(elapsed_time) = self.resources.clock.now().signed_duration_since(t).to_std() {
                     if elapsed_time < self.resources.config.resend_response_cooldown {
                         trace!(
                             target: LOG_TARGET,
@@ -2549,7 +2723,11 @@ where
                 ));
             }
 
-            if let Err(e) = self.resources.db.increment_send_count(tx_id) {
+            if let Err(e) = self
+                .resources
+                .db
+                .increment_send_count(tx_id, self.resources.clock.now())
+            {
                 warn!(
                     target: LOG_TARGET,
                     "Could not increment send count for completed transaction TxId {}: {:?}", tx_id, e
@@ -2580,7 +2758,11 @@ where
                 self.resources.outbound_message_service.clone(),
             ));
 
-            if let Err(e) = self.resources.db.increment_send_count(tx_id) {
+            if let Err(e) = self
+                .resources
+                .db
+                .increment_send_count(tx_id, self.resources.clock.now())
+            {
                 warn!(
                     target: LOG_TARGET,
                     "Could not increment send count for completed transaction TxId {}: {:?}", tx_id, e
@@ -2662,6 +2844,289 @@ where
         }
     }
 
+    /// Promote an imported (faux) transaction and its outputs to a mined and confirmed state in the block the caller
+    /// has verified the output is mined in. TXO validation checks the block hash against the chain, so it must be the
+    /// hash of the real header at `mined_height`. The outputs are confirmed by the output manager first, so a
+    /// transaction without outputs is left as it was.
+    async fn mark_imported_transaction_confirmed(
+        &mut self,
+        tx_id: TxId,
+        mined_height: u64,
+        mined_in_block: BlockHash,
+        mined_timestamp: u64,
+    ) -> Result<(), TransactionServiceError> {
+        let completed_tx = self.db.get_completed_transaction(tx_id)?;
+        if completed_tx.status != TransactionStatus::Imported {
+            warn!(
+                target: LOG_TARGET,
+                "Transaction (TxId: {}) has status {} and cannot be marked as a confirmed import",
+                tx_id,
+                completed_tx.status
+            );
+            return Err(TransactionServiceError::InvalidStateError);
+        }
+        self.resources
+            .output_manager_service
+            .confirm_imported_outputs(tx_id, mined_height, mined_in_block, mined_timestamp)
+            .await?;
+        self.db.set_transaction_mined_height(
+            tx_id,
+            mined_height,
+            mined_in_block,
+            mined_timestamp,
+            self.resources.config.num_confirmations_required,
+            true,
+            &completed_tx.status,
+        )?;
+
+        let _size = self
+            .event_publisher
+            .send(Arc::new(TransactionEvent::DetectedTransactionConfirmed {
+                tx_id,
+                is_valid: true,
+            }))
+            .map_err(|e| {
+                trace!(
+                    target: LOG_TARGET,
+                    "Error sending event because there are no subscribers: {:?}",
+                    e
+                );
+                e
+            });
+
+        info!(
+            target: LOG_TARGET,
+            "Imported Transaction (TxId: {}) marked as confirmed at height {}", tx_id, mined_height
+        );
+
+        Ok(())
+    }
+
+    /// Cancels every pending outbound transaction older than `older_than`. Each one goes through
+    /// `cancel_pending_transaction` so its encumbered outputs are released and its protocol is stopped. A transaction
+    /// that fails to cancel is logged and left pending for a later call, and does not stop the others from being
+    /// cancelled. Only the ids of the transactions that were cancelled are returned.
+    async fn cancel_expired_pending_transactions(
+        &mut self,
+        older_than: Duration,
+    ) -> Result<Vec<TxId>, TransactionServiceError> {
+        let now = self.resources.clock.now();
+        let expired = self
+            .db
+            .get_pending_outbound_transactions()?
+            .into_values()
+            .filter(|tx| {
+                now.signed_duration_since(tx.timestamp)
+                    .to_std()
+                    .map_or(false, |age| age > older_than)
+            })
+            .map(|tx| tx.tx_id)
+            .collect::<Vec<_>>();
+
+        let mut cancelled = Vec::with_capacity(expired.len());
+        let mut failed = 0usize;
+        for tx_id in expired {
+            match self.cancel_pending_transaction(tx_id).await {
+                Ok(()) => cancelled.push(tx_id),
+                Err(e) => {
+                    failed += 1;
+                    warn!(
+                        target: LOG_TARGET,
+                        "Could not cancel expired pending outbound transaction (TxId: {}): {}", tx_id, e
+                    );
+                },
+            }
+        }
+        if !cancelled.is_empty() || failed > 0 {
+            info!(
+                target: LOG_TARGET,
+                "Cancelled {} expired pending outbound transaction(s), {} could not be cancelled",
+                cancelled.len(),
+                failed
+            );
+        }
+
+        Ok(cancelled)
+    }
+
+    /// Simulates a counterparty sending this wallet a transaction by building a sender message from random keys and
+    /// accepting it as if it had arrived over the network. The reply is sent to a peer that does not exist.
+    #[cfg(feature = "test_harness")]
+    fn test_receive_transaction(
+        &mut self,
+        join_handles: &mut FuturesUnordered<JoinHandle<Result<TxId, TransactionServiceProtocolError<TxId>>>>,
+    ) -> Result<TxId, TransactionServiceError> {
+        use rand::RngCore;
+        use tari_core::transactions::{
+            transaction_components::{TransactionKernelVersion, TransactionOutputVersion},
+            transaction_protocol::sender::SingleRoundSenderData,
+        };
+
+        let random_public_key = || PublicKey::from_secret_key(&PrivateKey::random(&mut OsRng));
+        let source_pubkey = random_public_key();
+        let tx_id = TxId::new_random();
+        let sender_message = TransactionSenderMessage::Single(Box::new(SingleRoundSenderData {
+            tx_id,
+            amount: MicroMinotari::from(10_000 + OsRng.next_u64() % 101_000),
+            public_excess: random_public_key(),
+            public_nonce: random_public_key(),
+            metadata: TransactionMetadata::default(),
+            message: "Test transaction".to_string(),
+            features: OutputFeatures::default(),
+            script: push_pubkey_script(&PublicKey::default()),
+            sender_offset_public_key: random_public_key(),
+            ephemeral_public_nonce: random_public_key(),
+            covenant: Covenant::default(),
+            minimum_value_promise: MicroMinotari::zero(),
+            output_version: TransactionOutputVersion::get_current_version(),
+            kernel_version: TransactionKernelVersion::get_current_version(),
+            sender_address: TariAddress::new_single_address(
+                source_pubkey.clone(),
+                self.resources.interactive_tari_address.network(),
+                TariAddressFeatures::INTERACTIVE,
+            ),
+        }));
+        let sender_message = proto::TransactionSenderMessage::try_from(sender_message)
+            .map_err(TransactionServiceError::InvalidMessageError)?;
+        self.accept_transaction(source_pubkey, Ok(sender_message), OsRng.next_u64(), join_handles)?;
+        Ok(tx_id)
+    }
+
+    /// Simulates the recipient of a pending outbound transaction replying. The reply is built with a recipient output
+    /// from this wallet's keys, which is not added to the output manager. If the send protocol is still waiting it
+    /// receives the reply and finalizes the transaction as it would for a real one, otherwise the transaction is
+    /// finalized here. Either way the finalized transaction is moved to the completed transactions and broadcast like
+    /// any other.
+    #[cfg(feature = "test_harness")]
+    async fn test_complete_sent_transaction(&mut self, tx_id: TxId) -> Result<(), TransactionServiceError> {
+        use tari_core::transactions::transaction_components::{TransactionOutputVersion, WalletOutput};
+
+        let mut outbound_tx = self.db.get_pending_outbound_transaction(tx_id)?;
+        let key_manager = &self.resources.transaction_key_manager_service;
+        let sender_data = if outbound_tx.sender_protocol.is_collecting_single_signature() {
+            outbound_tx
+                .sender_protocol
+                .get_single_round_message(key_manager)
+                .await?
+        } else {
+            outbound_tx
+                .sender_protocol
+                .build_single_round_message(key_manager)
+                .await?
+        };
+
+        let (commitment_mask, script_key) = key_manager.get_next_commitment_mask_and_script_key().await?;
+        let script = if sender_data.script == push_pubkey_script(&PublicKey::default()) {
+            push_pubkey_script(&script_key.pub_key)
+        } else {
+            sender_data.script.clone()
+        };
+        let payment_id = PaymentId::Address(sender_data.sender_address.clone());
+        let encrypted_data = key_manager
+            .encrypt_data_for_recovery(
+                &commitment_mask.key_id,
+                None,
+                sender_data.amount.as_u64(),
+                payment_id.clone(),
+            )
+            .await?;
+        let metadata_message = TransactionOutput::metadata_signature_message_from_parts(
+            &TransactionOutputVersion::get_current_version(),
+            &script,
+            &sender_data.features,
+            &sender_data.covenant,
+            &encrypted_data,
+            &sender_data.minimum_value_promise,
+        );
+        let metadata_signature = key_manager
+            .get_receiver_partial_metadata_signature(
+                &commitment_mask.key_id,
+                &sender_data.amount.into(),
+                &sender_data.sender_offset_public_key,
+                &sender_data.ephemeral_public_nonce,
+                &TransactionOutputVersion::get_current_version(),
+                &metadata_message,
+                sender_data.features.range_proof_type,
+            )
+            .await?;
+        let output = WalletOutput::new_current_version(
+            sender_data.amount,
+            commitment_mask.key_id,
+            sender_data.features.clone(),
+            script,
+            ExecutionStack::default(),
+            script_key.key_id,
+            sender_data.sender_offset_public_key.clone(),
+            metadata_signature,
+            0,
+            sender_data.covenant.clone(),
+            encrypted_data,
+            sender_data.minimum_value_promise,
+            payment_id,
+            key_manager,
+        )
+        .await?;
+        let consensus_constants = self
+            .consensus_manager
+            .consensus_constants(self.last_seen_tip_height.unwrap_or(0));
+        let rtp = ReceiverTransactionProtocol::new(
+            TransactionSenderMessage::Single(Box::new(sender_data)),
+            output,
+            key_manager,
+            consensus_constants,
+        )
+        .await;
+        let recipient_reply = rtp.get_signed_data()?.clone();
+
+        if let Some(sender) = self.pending_transaction_reply_senders.get_mut(&tx_id) {
+            let source_pubkey = outbound_tx.destination_address.comms_public_key().clone();
+            if sender.send((source_pubkey, recipient_reply.clone())).await.is_ok() {
+                return Ok(());
+            }
+        }
+
+        let key_manager = &self.resources.transaction_key_manager_service;
+        outbound_tx
+            .sender_protocol
+            .add_single_recipient_info(recipient_reply, key_manager)
+            .await?;
+        outbound_tx.sender_protocol.finalize(key_manager).await?;
+        let transaction = outbound_tx.sender_protocol.get_transaction()?.clone();
+
+        // A queued transaction never reached the recipient, so its outputs were not confirmed as pending when it was
+        // sent
+        if outbound_tx.status == TransactionStatus::Queued {
+            self.resources
+                .output_manager_service
+                .confirm_pending_transaction(tx_id)
+                .await?;
+        }
+        let completed_transaction = CompletedTransaction::new(
+            tx_id,
+            self.resources.interactive_tari_address.clone(),
+            outbound_tx.destination_address,
+            outbound_tx.amount,
+            outbound_tx.fee,
+            transaction,
+            TransactionStatus::Completed,
+            outbound_tx.message,
+            self.resources.clock.now(),
+            TransactionDirection::Outbound,
+            None,
+            None,
+            None,
+        )?;
+        self.db.complete_outbound_transaction(tx_id, completed_transaction)?;
+
+        // The send protocol for this transaction has already finished, so only its channels are left to clean up
+        let _sender = self.pending_transaction_reply_senders.remove(&tx_id);
+        let _sender = self.send_transaction_cancellation_senders.remove(&tx_id);
+        let _size = self
+            .event_publisher
+            .send(Arc::new(TransactionEvent::ReceivedTransactionReply(tx_id)));
+        Ok(())
+    }
+
     /// Cancel a pending transaction
     async fn cancel_pending_transaction(&mut self, tx_id: TxId) -> Result<(), TransactionServiceError> {
         self.db.cancel_pending_transaction(tx_id).map_err(|e| {
@@ -2869,7 +3334,7 @@ where
                 }
                 // Check if the last reply is beyond the resend cooldown
                 if let Some(timestamp) = inbound_tx.last_send_timestamp {
-                    let elapsed_time = utc_duration_since(&timestamp)?;
+                    let elapsed_time = utc_duration_since(self.resources.clock.as_ref(), &timestamp)?;
                     if elapsed_time < self.resources.config.resend_response_cooldown {
                         trace!(
                             target: LOG_TARGET,
@@ -2892,7 +3357,11 @@ where
                     self.resources.config.direct_send_timeout,
                     self.resources.config.transaction_routing_mechanism,
                 ));
-                if let Err(e) = self.resources.db.increment_send_count(tx_id) {
+                if let Err(e) = self
+                    .resources
+                    .db
+                    .increment_send_count(tx_id, self.resources.clock.now())
+                {
                     warn!(
                         target: LOG_TARGET,
                         "Could not increment send count for inbound transaction TxId {}: {:?}", tx_id, e
@@ -3064,7 +3533,7 @@ where
                             transaction.clone(),
                             TransactionStatus::Completed,
                             "".to_string(),
-                            Utc::now().naive_utc(),
+                            self.resources.clock.now(),
                             TransactionDirection::Inbound,
                             None,
                             None,
@@ -3501,12 +3970,15 @@ where
     }
 
     /// Add a completed transaction to the Transaction Manager to record directly importing a spendable UTXO.
+    /// `one_sided_payment` records that the UTXO was received as a one-sided payment, which its status alone cannot
+    /// tell once the transaction has been mined.
     pub async fn add_utxo_import_transaction_with_status(
         &mut self,
         value: MicroMinotari,
         source_address: TariAddress,
         message: String,
         import_status: ImportStatus,
+        one_sided_payment: bool,
         tx_id: Option<TxId>,
         current_height: Option<u64>,
         mined_timestamp: Option<NaiveDateTime>,
@@ -3521,7 +3993,9 @@ where
             self.resources.interactive_tari_address.clone(),
             message,
             import_status.clone(),
+            one_sided_payment,
             current_height,
+            mined_timestamp.unwrap_or_else(|| self.resources.clock.now()),
             mined_timestamp,
             scanned_output,
             payment_id,
@@ -3625,7 +4099,7 @@ where
                 tx,
                 TransactionStatus::Completed,
                 message,
-                Utc::now().naive_utc(),
+                self.resources.clock.now(),
                 TransactionDirection::Inbound,
                 None,
                 None,
@@ -3692,6 +4166,7 @@ pub struct TransactionServiceResources<TBackend, TWalletConnectivity, TKeyManage
     pub config: TransactionServiceConfig,
     pub shutdown_signal: ShutdownSignal,
     pub wallet_type: Arc<WalletType>,
+    pub clock: Arc<dyn Clock>,
 }
 
 #[derive(Default, Clone, Copy)]
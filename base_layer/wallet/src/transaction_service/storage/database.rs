@@ -28,7 +28,7 @@ use std::{
     sync::Arc,
 };
 
-use chrono::{NaiveDateTime, Utc};
+use chrono::NaiveDateTime;
 use log::*;
 use tari_common_types::{
     tari_address::TariAddress,
@@ -124,8 +124,8 @@ pub trait TransactionBackend: Send + Sync + Clone {
     ) -> Result<TariAddress, TransactionStorageError>;
     /// Mark a pending transaction direct send attempt as a success
     fn mark_direct_send_success(&self, tx_id: TxId) -> Result<(), TransactionStorageError>;
-    /// Increment the send counter and timestamp of a transaction
-    fn increment_send_count(&self, tx_id: TxId) -> Result<(), TransactionStorageError>;
+    /// Increment the send counter and set the last send timestamp of a transaction
+    fn increment_send_count(&self, tx_id: TxId, timestamp: NaiveDateTime) -> Result<(), TransactionStorageError>;
     /// Update a transactions mined height. A transaction can either be mined as valid or mined as invalid
     /// A normal transaction can only be mined with valid = true,
     /// The mined height and block are used to determine reorgs
@@ -147,6 +147,11 @@ pub trait TransactionBackend: Send + Sync + Clone {
     fn get_pending_inbound_transaction_sender_info(
         &self,
     ) -> Result<Vec<InboundTransactionSenderInfo>, TransactionStorageError>;
+    /// Light weight method to sum the amounts of all pending inbound transactions that have not been cancelled
+    fn fetch_pending_inbound_total(&self) -> Result<MicroMinotari, TransactionStorageError>;
+    /// Light weight method to sum the amounts (excluding fees) of all pending outbound transactions that have not been
+    /// cancelled
+    fn fetch_pending_outbound_total(&self) -> Result<MicroMinotari, TransactionStorageError>;
     fn fetch_imported_transactions(&self) -> Result<Vec<CompletedTransaction>, TransactionStorageError>;
     fn fetch_unconfirmed_detected_transactions(&self) -> Result<Vec<CompletedTransaction>, TransactionStorageError>;
     fn fetch_confirmed_detected_transactions_from_height(
@@ -157,6 +162,18 @@ pub trait TransactionBackend: Send + Sync + Clone {
         &self,
         height: u64,
     ) -> Result<Vec<CompletedTransaction>, TransactionStorageError>;
+    /// Fetch the non-cancelled completed transactions that were created or mined at or after `since`
+    fn fetch_completed_transactions_since(
+        &self,
+        since: NaiveDateTime,
+    ) -> Result<Vec<CompletedTransaction>, TransactionStorageError>;
+    /// Fetch the non-cancelled completed transactions, leaving out coinbase transactions
+    fn fetch_non_coinbase_completed_transactions(&self) -> Result<Vec<CompletedTransaction>, TransactionStorageError>;
+    /// Count the non-cancelled completed transactions, optionally only those with the given status
+    fn count_completed_transactions(&self, status: Option<TransactionStatus>) -> Result<u64, TransactionStorageError>;
+    /// Fetch only the direction of a pending or completed transaction, if it exists
+    fn fetch_transaction_direction(&self, tx_id: TxId)
+        -> Result<Option<TransactionDirection>, TransactionStorageError>;
 }
 
 #[derive(Clone, PartialEq)]
@@ -471,6 +488,35 @@ where T: TransactionBackend + 'static
         Ok(t)
     }
 
+    pub fn get_completed_transactions_since(
+        &self,
+        since: NaiveDateTime,
+    ) -> Result<Vec<CompletedTransaction>, TransactionStorageError> {
+        let t = self.db.fetch_completed_transactions_since(since)?;
+        Ok(t)
+    }
+
+    pub fn count_completed_transactions(
+        &self,
+        status: Option<TransactionStatus>,
+    ) -> Result<u64, TransactionStorageError> {
+        self.db.count_completed_transactions(status)
+    }
+
+    pub fn get_transaction_direction(
+        &self,
+        tx_id: TxId,
+    ) -> Result<Option<TransactionDirection>, TransactionStorageError> {
+        self.db.fetch_transaction_direction(tx_id)
+    }
+
+    pub fn get_non_coinbase_completed_transactions(
+        &self,
+    ) -> Result<Vec<CompletedTransaction>, TransactionStorageError> {
+        let t = self.db.fetch_non_coinbase_completed_transactions()?;
+        Ok(t)
+    }
+
     pub fn get_unmined_coinbase_transactions(
         &self,
         height: u64,
@@ -692,7 +738,9 @@ where T: TransactionBackend + 'static
         comms_address: TariAddress,
         message: String,
         import_status: ImportStatus,
+        one_sided_payment: bool,
         current_height: Option<u64>,
+        timestamp: NaiveDateTime,
         mined_timestamp: Option<NaiveDateTime>,
         scanned_output: TransactionOutput,
         payment_id: PaymentId,
@@ -701,7 +749,7 @@ where T: TransactionBackend + 'static
             PaymentId::Empty => None,
             v => Some(v),
         };
-        let transaction = CompletedTransaction::new(
+        let mut transaction = CompletedTransaction::new(
             tx_id,
             source_address,
             comms_address,
@@ -716,12 +764,13 @@ where T: TransactionBackend + 'static
             ),
             TransactionStatus::try_from(import_status)?,
             message,
-            mined_timestamp.unwrap_or_else(|| Utc::now().naive_utc()),
+            timestamp,
             TransactionDirection::Inbound,
             current_height,
             mined_timestamp,
             payment_id,
         )?;
+        transaction.one_sided_payment = one_sided_payment;
 
         self.db
             .write(WriteOperation::Insert(DbKeyValuePair::CompletedTransaction(
@@ -731,8 +780,8 @@ where T: TransactionBackend + 'static
         Ok(())
     }
 
-    pub fn increment_send_count(&self, tx_id: TxId) -> Result<(), TransactionStorageError> {
-        self.db.increment_send_count(tx_id)
+    pub fn increment_send_count(&self, tx_id: TxId, timestamp: NaiveDateTime) -> Result<(), TransactionStorageError> {
+        self.db.increment_send_count(tx_id, timestamp)
     }
 
     pub fn set_transaction_as_unmined(&self, tx_id: TxId) -> Result<(), TransactionStorageError> {
@@ -773,6 +822,20 @@ where T: TransactionBackend + 'static
         }?;
         Ok(t)
     }
+
+    pub fn get_pending_inbound_total(&self) -> Result<MicroMinotari, TransactionStorageError> {
+        match self.db.fetch_pending_inbound_total() {
+            Ok(v) => Ok(v),
+            Err(e) => log_error(DbKey::PendingInboundTransactions, e),
+        }
+    }
+
+    pub fn get_pending_outbound_total(&self) -> Result<MicroMinotari, TransactionStorageError> {
+        match self.db.fetch_pending_outbound_total() {
+            Ok(v) => Ok(v),
+            Err(e) => log_error(DbKey::PendingOutboundTransactions, e),
+        }
+    }
 }
 
 impl Display for DbKey {
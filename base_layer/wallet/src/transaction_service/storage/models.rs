@@ -148,6 +148,8 @@ pub struct CompletedTransaction {
     pub mined_in_block: Option<BlockHash>,
     pub mined_timestamp: Option<NaiveDateTime>,
     pub payment_id: Option<PaymentId>,
+    #[serde(default)]
+    pub one_sided_payment: bool,
 }
 
 impl CompletedTransaction {
@@ -194,6 +196,7 @@ impl CompletedTransaction {
             mined_in_block: None,
             mined_timestamp,
             payment_id,
+            one_sided_payment: false,
         })
     }
 }
@@ -274,6 +277,7 @@ impl From<OutboundTransaction> for CompletedTransaction {
             mined_in_block: None,
             mined_timestamp: None,
             payment_id: None,
+            one_sided_payment: false,
         }
     }
 }
@@ -304,6 +308,7 @@ impl From<InboundTransaction> for CompletedTransaction {
             mined_in_block: None,
             mined_timestamp: None,
             payment_id: None,
+            one_sided_payment: false,
         }
     }
 }
@@ -27,8 +27,8 @@ use std::{
 };
 
 use chacha20poly1305::XChaCha20Poly1305;
-use chrono::{NaiveDateTime, Utc};
-use diesel::{prelude::*, result::Error as DieselError};
+use chrono::NaiveDateTime;
+use diesel::{prelude::*, result::Error as DieselError, sql_query};
 use log::*;
 use tari_common_sqlite::{sqlite_connection_pool::PooledDbConnection, util::diesel_ext::ExpectedRowsExtension};
 use tari_common_types::{
@@ -781,14 +781,14 @@ impl TransactionBackend for TransactionServiceSqliteDatabase {
         Ok(())
     }
 
-    fn increment_send_count(&self, tx_id: TxId) -> Result<(), TransactionStorageError> {
+    fn increment_send_count(&self, tx_id: TxId, timestamp: NaiveDateTime) -> Result<(), TransactionStorageError> {
         let start = Instant::now();
         let mut conn = self.database_connection.get_pooled_connection()?;
         let acquire_lock = start.elapsed();
 
-        if CompletedTransactionSql::increment_send_count(tx_id, &mut conn).is_err() &&
-            OutboundTransactionSql::increment_send_count(tx_id, &mut conn).is_err() &&
-            InboundTransactionSql::increment_send_count(tx_id, &mut conn).is_err()
+        if CompletedTransactionSql::increment_send_count(tx_id, timestamp, &mut conn).is_err() &&
+            OutboundTransactionSql::increment_send_count(tx_id, timestamp, &mut conn).is_err() &&
+            InboundTransactionSql::increment_send_count(tx_id, timestamp, &mut conn).is_err()
         {
             return Err(TransactionStorageError::ValuesNotFound);
         }
@@ -1027,6 +1027,16 @@ impl TransactionBackend for TransactionServiceSqliteDatabase {
         Ok(sender_info)
     }
 
+    fn fetch_pending_inbound_total(&self) -> Result<MicroMinotari, TransactionStorageError> {
+        let mut conn = self.database_connection.get_pooled_connection()?;
+        InboundTransactionSql::sum_pending_amounts(&mut conn)
+    }
+
+    fn fetch_pending_outbound_total(&self) -> Result<MicroMinotari, TransactionStorageError> {
+        let mut conn = self.database_connection.get_pooled_connection()?;
+        OutboundTransactionSql::sum_pending_amounts(&mut conn)
+    }
+
     fn fetch_imported_transactions(&self) -> Result<Vec<CompletedTransaction>, TransactionStorageError> {
         let mut conn = self.database_connection.get_pooled_connection()?;
         let cipher = acquire_read_lock!(self.cipher);
@@ -1120,6 +1130,59 @@ impl TransactionBackend for TransactionServiceSqliteDatabase {
         coinbases.append(&mut one_sided);
         Ok(coinbases)
     }
+
+    fn fetch_completed_transactions_since(
+        &self,
+        since: NaiveDateTime,
+    ) -> Result<Vec<CompletedTransaction>, TransactionStorageError> {
+        let mut conn = self.database_connection.get_pooled_connection()?;
+        let cipher = acquire_read_lock!(self.cipher);
+
+        CompletedTransactionSql::index_by_cancelled_since_timestamp(false, since, &mut conn)?
+            .into_iter()
+            .map(|ct: CompletedTransactionSql| {
+                CompletedTransaction::try_from(ct, &cipher).map_err(TransactionStorageError::from)
+            })
+            .collect::<Result<Vec<CompletedTransaction>, TransactionStorageError>>()
+    }
+
+    fn fetch_non_coinbase_completed_transactions(&self) -> Result<Vec<CompletedTransaction>, TransactionStorageError> {
+        let mut conn = self.database_connection.get_pooled_connection()?;
+        let cipher = acquire_read_lock!(self.cipher);
+
+        CompletedTransactionSql::index_by_cancelled_excluding_coinbase(false, &mut conn)?
+            .into_iter()
+            .map(|ct: CompletedTransactionSql| {
+                CompletedTransaction::try_from(ct, &cipher).map_err(TransactionStorageError::from)
+            })
+            .collect::<Result<Vec<CompletedTransaction>, TransactionStorageError>>()
+    }
+
+    // A count can never be negative
+    #[allow(clippy::cast_sign_loss)]
+    fn count_completed_transactions(&self, status: Option<TransactionStatus>) -> Result<u64, TransactionStorageError> {
+        let mut conn = self.database_connection.get_pooled_connection()?;
+        Ok(CompletedTransactionSql::count_by_status(status, &mut conn)? as u64)
+    }
+
+    fn fetch_transaction_direction(
+        &self,
+        tx_id: TxId,
+    ) -> Result<Option<TransactionDirection>, TransactionStorageError> {
+        let mut conn = self.database_connection.get_pooled_connection()?;
+
+        // Pending transactions do not store a direction, it is implied by the table they are in
+        if OutboundTransactionSql::exists(tx_id, &mut conn)? {
+            return Ok(Some(TransactionDirection::Outbound));
+        }
+        if InboundTransactionSql::exists(tx_id, &mut conn)? {
+            return Ok(Some(TransactionDirection::Inbound));
+        }
+        match CompletedTransactionSql::find_direction(tx_id, &mut conn)? {
+            Some(direction) => Ok(Some(TransactionDirection::try_from(direction.unwrap_or(2i32))?)),
+            None => Ok(None),
+        }
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -1158,6 +1221,13 @@ impl InboundTransactionSenderInfoSql {
     }
 }
 
+/// The sum of the amounts of the pending transactions in one of the pending transaction tables
+#[derive(QueryableByName)]
+struct PendingAmountSql {
+    #[diesel(sql_type = diesel::sql_types::BigInt)]
+    amount: i64,
+}
+
 #[derive(Clone, Debug, Queryable, Insertable, PartialEq)]
 #[diesel(table_name = inbound_transactions)]
 struct InboundTransactionSql {
@@ -1186,6 +1256,14 @@ impl InboundTransactionSql {
         Ok(inbound_transactions::table.load::<InboundTransactionSql>(conn)?)
     }
 
+    pub fn sum_pending_amounts(conn: &mut SqliteConnection) -> Result<MicroMinotari, TransactionStorageError> {
+        let total =
+            sql_query("SELECT coalesce(sum(amount), 0) as amount FROM inbound_transactions WHERE cancelled = ?")
+                .bind::<diesel::sql_types::Integer, _>(i32::from(false))
+                .get_result::<PendingAmountSql>(conn)?;
+        Ok(MicroMinotari::from(total.amount as u64))
+    }
+
     pub fn index_by_cancelled(
         conn: &mut SqliteConnection,
         cancelled: bool,
@@ -1201,6 +1279,15 @@ impl InboundTransactionSql {
             .first::<InboundTransactionSql>(conn)?)
     }
 
+    pub fn exists(tx_id: TxId, conn: &mut SqliteConnection) -> Result<bool, TransactionStorageError> {
+        Ok(inbound_transactions::table
+            .select(inbound_transactions::tx_id)
+            .filter(inbound_transactions::tx_id.eq(tx_id.as_u64() as i64))
+            .first::<i64>(conn)
+            .optional()?
+            .is_some())
+    }
+
     pub fn find_by_cancelled(
         tx_id: TxId,
         cancelled: bool,
@@ -1246,7 +1333,11 @@ impl InboundTransactionSql {
         Ok(())
     }
 
-    pub fn increment_send_count(tx_id: TxId, conn: &mut SqliteConnection) -> Result<(), TransactionStorageError> {
+    pub fn increment_send_count(
+        tx_id: TxId,
+        timestamp: NaiveDateTime,
+        conn: &mut SqliteConnection,
+    ) -> Result<(), TransactionStorageError> {
         diesel::update(
             inbound_transactions::table
                 .filter(inbound_transactions::tx_id.eq(tx_id.as_u64() as i64))
@@ -1269,7 +1360,7 @@ impl InboundTransactionSql {
                     return Err(TransactionStorageError::DieselError(DieselError::NotFound));
                 },
             ),
-            last_send_timestamp: Some(Some(Utc::now().naive_utc())),
+            last_send_timestamp: Some(Some(timestamp)),
         })
         .execute(conn)
         .num_rows_affected_or_not_found(1)?;
@@ -1450,6 +1541,14 @@ impl OutboundTransactionSql {
         Ok(outbound_transactions::table.load::<OutboundTransactionSql>(conn)?)
     }
 
+    pub fn sum_pending_amounts(conn: &mut SqliteConnection) -> Result<MicroMinotari, TransactionStorageError> {
+        let total =
+            sql_query("SELECT coalesce(sum(amount), 0) as amount FROM outbound_transactions WHERE cancelled = ?")
+                .bind::<diesel::sql_types::Integer, _>(i32::from(false))
+                .get_result::<PendingAmountSql>(conn)?;
+        Ok(MicroMinotari::from(total.amount as u64))
+    }
+
     pub fn index_by_cancelled(
         conn: &mut SqliteConnection,
         cancelled: bool,
@@ -1465,6 +1564,15 @@ impl OutboundTransactionSql {
             .first::<OutboundTransactionSql>(conn)?)
     }
 
+    pub fn exists(tx_id: TxId, conn: &mut SqliteConnection) -> Result<bool, TransactionStorageError> {
+        Ok(outbound_transactions::table
+            .select(outbound_transactions::tx_id)
+            .filter(outbound_transactions::tx_id.eq(tx_id.as_u64() as i64))
+            .first::<i64>(conn)
+            .optional()?
+            .is_some())
+    }
+
     pub fn find_by_cancelled(
         tx_id: TxId,
         cancelled: bool,
@@ -1510,7 +1618,11 @@ impl OutboundTransactionSql {
         Ok(())
     }
 
-    pub fn increment_send_count(tx_id: TxId, conn: &mut SqliteConnection) -> Result<(), TransactionStorageError> {
+    pub fn increment_send_count(
+        tx_id: TxId,
+        timestamp: NaiveDateTime,
+        conn: &mut SqliteConnection,
+    ) -> Result<(), TransactionStorageError> {
         diesel::update(outbound_transactions::table.filter(outbound_transactions::tx_id.eq(tx_id.as_u64() as i64)))
             .set(UpdateOutboundTransactionSql {
                 cancelled: None,
@@ -1528,7 +1640,7 @@ impl OutboundTransactionSql {
                         return Err(TransactionStorageError::DieselError(DieselError::NotFound));
                     },
                 ),
-                last_send_timestamp: Some(Some(Utc::now().naive_utc())),
+                last_send_timestamp: Some(Some(timestamp)),
             })
             .execute(conn)
             .num_rows_affected_or_not_found(1)?;
@@ -1700,6 +1812,7 @@ pub struct CompletedTransactionSql {
     transaction_signature_nonce: Vec<u8>,
     transaction_signature_key: Vec<u8>,
     payment_id: Option<Vec<u8>>,
+    one_sided_payment: bool,
 }
 
 impl CompletedTransactionSql {
@@ -1765,12 +1878,85 @@ impl CompletedTransactionSql {
             .load::<CompletedTransactionSql>(conn)?)
     }
 
+    /// A transaction matches if it was created or mined at or after `since`, so that one created earlier but mined
+    /// since is not missed. Timestamps are compared inclusively so transactions landing in the same second as `since`
+    /// are not missed either.
+    pub fn index_by_cancelled_since_timestamp(
+        cancelled: bool,
+        since: NaiveDateTime,
+        conn: &mut SqliteConnection,
+    ) -> Result<Vec<CompletedTransactionSql>, TransactionStorageError> {
+        let mut query = completed_transactions::table.into_boxed();
+        query = if cancelled {
+            query.filter(completed_transactions::cancelled.is_not_null())
+        } else {
+            query.filter(completed_transactions::cancelled.is_null())
+        };
+
+        Ok(query
+            .filter(
+                completed_transactions::timestamp
+                    .ge(since)
+                    .or(completed_transactions::mined_timestamp.ge(since)),
+            )
+            .order_by(completed_transactions::timestamp.asc())
+            .load::<CompletedTransactionSql>(conn)?)
+    }
+
+    pub fn index_by_cancelled_excluding_coinbase(
+        cancelled: bool,
+        conn: &mut SqliteConnection,
+    ) -> Result<Vec<CompletedTransactionSql>, TransactionStorageError> {
+        let mut query = completed_transactions::table.into_boxed();
+        query = if cancelled {
+            query.filter(completed_transactions::cancelled.is_not_null())
+        } else {
+            query.filter(completed_transactions::cancelled.is_null())
+        };
+
+        Ok(query
+            .filter(completed_transactions::status.ne_all::<Vec<i32>>(vec![
+                TransactionStatus::Coinbase as i32,
+                TransactionStatus::CoinbaseUnconfirmed as i32,
+                TransactionStatus::CoinbaseConfirmed as i32,
+                TransactionStatus::CoinbaseNotInBlockChain as i32,
+            ]))
+            .load::<CompletedTransactionSql>(conn)?)
+    }
+
+    /// Counts the non-cancelled completed transactions, optionally only those with the given status
+    pub fn count_by_status(
+        status: Option<TransactionStatus>,
+        conn: &mut SqliteConnection,
+    ) -> Result<i64, TransactionStorageError> {
+        let mut query = completed_transactions::table
+            .filter(completed_transactions::cancelled.is_null())
+            .into_boxed();
+        if let Some(status) = status {
+            query = query.filter(completed_transactions::status.eq(status as i32));
+        }
+
+        Ok(query.count().get_result::<i64>(conn)?)
+    }
+
     pub fn find(tx_id: TxId, conn: &mut SqliteConnection) -> Result<CompletedTransactionSql, TransactionStorageError> {
         Ok(completed_transactions::table
             .filter(completed_transactions::tx_id.eq(tx_id.as_u64() as i64))
             .first::<CompletedTransactionSql>(conn)?)
     }
 
+    /// Selects only the direction column of a completed transaction, returning `None` if the transaction is not found
+    pub fn find_direction(
+        tx_id: TxId,
+        conn: &mut SqliteConnection,
+    ) -> Result<Option<Option<i32>>, TransactionStorageError> {
+        Ok(completed_transactions::table
+            .select(completed_transactions::direction)
+            .filter(completed_transactions::tx_id.eq(tx_id.as_u64() as i64))
+            .first::<Option<i32>>(conn)
+            .optional()?)
+    }
+
     pub fn find_by_cancelled(
         tx_id: TxId,
         cancelled: bool,
@@ -1810,7 +1996,11 @@ impl CompletedTransactionSql {
         Ok(())
     }
 
-    pub fn increment_send_count(tx_id: TxId, conn: &mut SqliteConnection) -> Result<(), TransactionStorageError> {
+    pub fn increment_send_count(
+        tx_id: TxId,
+        timestamp: NaiveDateTime,
+        conn: &mut SqliteConnection,
+    ) -> Result<(), TransactionStorageError> {
         // This query uses a sub-query to retrieve an existing value in the table
         diesel::update(completed_transactions::table.filter(completed_transactions::tx_id.eq(tx_id.as_u64() as i64)))
             .set(UpdateCompletedTransactionSql {
@@ -1826,7 +2016,7 @@ impl CompletedTransactionSql {
                         return Err(TransactionStorageError::DieselError(DieselError::NotFound));
                     },
                 ),
-                last_send_timestamp: Some(Some(Utc::now().naive_utc())),
+                last_send_timestamp: Some(Some(timestamp)),
                 ..Default::default()
             })
             .execute(conn)
@@ -1989,6 +2179,7 @@ impl CompletedTransactionSql {
             transaction_signature_nonce: c.transaction_signature.get_public_nonce().to_vec(),
             transaction_signature_key: c.transaction_signature.get_signature().to_vec(),
             payment_id,
+            one_sided_payment: c.one_sided_payment,
         };
 
         output.encrypt(cipher).map_err(TransactionStorageError::AeadError)
@@ -2099,6 +2290,7 @@ impl CompletedTransaction {
             mined_in_block,
             mined_timestamp: c.mined_timestamp,
             payment_id: Some(payment_id),
+            one_sided_payment: c.one_sided_payment,
         };
 
         // zeroize sensitive data
@@ -2371,6 +2563,10 @@ mod test {
 
         let outbound_txs = OutboundTransactionSql::index_by_cancelled(&mut conn, false).unwrap();
         assert_eq!(outbound_txs.len(), 2);
+        assert_eq!(
+            OutboundTransactionSql::sum_pending_amounts(&mut conn).unwrap(),
+            amount + amount
+        );
 
         let returned_outbound_tx = OutboundTransaction::try_from(
             OutboundTransactionSql::find_by_cancelled(1u64.into(), false, &mut conn).unwrap(),
@@ -2455,6 +2651,10 @@ mod test {
 
         let inbound_txs = InboundTransactionSql::index_by_cancelled(&mut conn, false).unwrap();
         assert_eq!(inbound_txs.len(), 2);
+        assert_eq!(
+            InboundTransactionSql::sum_pending_amounts(&mut conn).unwrap(),
+            amount + amount
+        );
 
         let returned_inbound_tx = InboundTransaction::try_from(
             InboundTransactionSql::find_by_cancelled(2u64.into(), false, &mut conn).unwrap(),
@@ -2509,6 +2709,7 @@ mod test {
             mined_in_block: None,
             mined_timestamp: None,
             payment_id: None,
+            one_sided_payment: false,
         };
         let source_address = TariAddress::new_dual_address_with_default_features(
             PublicKey::from_secret_key(&PrivateKey::random(&mut OsRng)),
@@ -2540,6 +2741,7 @@ mod test {
             mined_in_block: None,
             mined_timestamp: None,
             payment_id: None,
+            one_sided_payment: false,
         };
 
         CompletedTransactionSql::try_from(completed_tx1.clone(), &cipher)
@@ -2614,7 +2816,12 @@ mod test {
             .unwrap();
 
         assert!(InboundTransactionSql::find_by_cancelled(inbound_tx1.tx_id, true, &mut conn).is_err());
+        let inbound_total = InboundTransactionSql::sum_pending_amounts(&mut conn).unwrap();
         InboundTransactionSql::find_and_set_cancelled(inbound_tx1.tx_id, true, &mut conn).unwrap();
+        assert_eq!(
+            InboundTransactionSql::sum_pending_amounts(&mut conn).unwrap(),
+            inbound_total - amount
+        );
         assert!(InboundTransactionSql::find_by_cancelled(inbound_tx1.tx_id, false, &mut conn).is_err());
         assert!(InboundTransactionSql::find_by_cancelled(inbound_tx1.tx_id, true, &mut conn).is_ok());
         InboundTransactionSql::find_and_set_cancelled(inbound_tx1.tx_id, false, &mut conn).unwrap();
@@ -2626,7 +2833,12 @@ mod test {
             .unwrap();
 
         assert!(OutboundTransactionSql::find_by_cancelled(outbound_tx1.tx_id, true, &mut conn).is_err());
+        let outbound_total = OutboundTransactionSql::sum_pending_amounts(&mut conn).unwrap();
         OutboundTransactionSql::find_and_set_cancelled(outbound_tx1.tx_id, true, &mut conn).unwrap();
+        assert_eq!(
+            OutboundTransactionSql::sum_pending_amounts(&mut conn).unwrap(),
+            outbound_total - amount
+        );
         assert!(OutboundTransactionSql::find_by_cancelled(outbound_tx1.tx_id, false, &mut conn).is_err());
         assert!(OutboundTransactionSql::find_by_cancelled(outbound_tx1.tx_id, true, &mut conn).is_ok());
         OutboundTransactionSql::find_and_set_cancelled(outbound_tx1.tx_id, false, &mut conn).unwrap();
@@ -2780,6 +2992,7 @@ mod test {
             mined_in_block: None,
             mined_timestamp: None,
             payment_id: Some(PaymentId::Empty),
+            one_sided_payment: false,
         };
 
         let completed_tx_sql = CompletedTransactionSql::try_from(completed_tx.clone(), &cipher).unwrap();
@@ -2913,6 +3126,7 @@ mod test {
                 mined_in_block: None,
                 mined_timestamp: None,
                 payment_id: None,
+                one_sided_payment: false,
             };
             let completed_tx_sql = CompletedTransactionSql::try_from(completed_tx, &cipher).unwrap();
 
@@ -3056,6 +3270,7 @@ mod test {
                 mined_in_block: None,
                 mined_timestamp: None,
                 payment_id: None,
+                one_sided_payment: false,
             };
             let completed_tx_sql = CompletedTransactionSql::try_from(completed_tx.clone(), &cipher).unwrap();
 
@@ -3,8 +3,11 @@
 
 use std::{convert::TryFrom, time::Duration};
 
-use chrono::{NaiveDateTime, Utc};
+use chrono::NaiveDateTime;
 use thiserror::Error;
+
+use crate::transaction_service::clock::Clock;
+
 /// The error happens when a duration is negative.
 #[derive(Debug, Error)]
 #[error("Diration is negative: {ms} ms")]
@@ -18,8 +21,8 @@ pub struct NegativeDurationError {
 ///    the standard (ISO 8601).
 /// 3. Leap-second handled automatically by NTP and we could ignore it as soon as `chrono` doesn't handle them
 ///    accurately. No guarantees and only the one second handeled.
-pub fn utc_duration_since(since: &NaiveDateTime) -> Result<Duration, NegativeDurationError> {
-    let now_ms = Utc::now().naive_utc().timestamp_millis();
+pub fn utc_duration_since(clock: &dyn Clock, since: &NaiveDateTime) -> Result<Duration, NegativeDurationError> {
+    let now_ms = clock.now().timestamp_millis();
     let since_ms = since.timestamp_millis();
     let ms = now_ms - since_ms;
     if ms >= 0 {
@@ -59,11 +59,25 @@ pub enum UtxoScannerEvent {
     ScanningFailed,
 }
 
+/// Output counts from the most recent scan that checked outputs
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RecoveryStats {
+    /// The number of outputs received from the base node and checked for ownership
+    pub outputs_scanned: u64,
+    /// The number of outputs that were imported into the wallet
+    pub outputs_recovered: u64,
+    /// The number of outputs whose spending key could be recovered with this wallet's keys but that could not be
+    /// imported, because the key needed to spend their script could not be derived
+    pub outputs_failed_decode: u64,
+}
+
 #[derive(Clone)]
 pub struct UtxoScannerHandle {
     event_sender: broadcast::Sender<UtxoScannerEvent>,
     one_sided_message_watch: Watch<String>,
     recovery_message_watch: Watch<String>,
+    scan_start_height_watch: Watch<Option<u64>>,
+    recovery_stats_watch: Watch<RecoveryStats>,
 }
 
 impl UtxoScannerHandle {
@@ -71,11 +85,15 @@ impl UtxoScannerHandle {
         event_sender: broadcast::Sender<UtxoScannerEvent>,
         one_sided_message_watch: Watch<String>,
         recovery_message_watch: Watch<String>,
+        scan_start_height_watch: Watch<Option<u64>>,
+        recovery_stats_watch: Watch<RecoveryStats>,
     ) -> Self {
         UtxoScannerHandle {
             event_sender,
             one_sided_message_watch,
             recovery_message_watch,
+            scan_start_height_watch,
+            recovery_stats_watch,
         }
     }
 
@@ -91,6 +109,17 @@ impl UtxoScannerHandle {
         self.recovery_message_watch.send(note);
     }
 
+    /// Sets the height from which a scan starts when there are no previously scanned blocks to continue from, instead
+    /// of the height derived from the wallet birthday
+    pub fn set_scan_start_height(&mut self, height: u64) {
+        self.scan_start_height_watch.send(Some(height));
+    }
+
+    /// Returns the output counts from the most recent scan, whether it was a recovery or a background scan
+    pub fn get_recovery_stats(&self) -> RecoveryStats {
+        *self.recovery_stats_watch.borrow()
+    }
+
     pub(crate) fn get_one_sided_payment_message_watcher(&self) -> watch::Receiver<String> {
         self.one_sided_message_watch.get_receiver()
     }
@@ -98,4 +127,12 @@ impl UtxoScannerHandle {
     pub(crate) fn get_recovery_message_watcher(&self) -> watch::Receiver<String> {
         self.recovery_message_watch.get_receiver()
     }
+
+    pub(crate) fn get_scan_start_height_watcher(&self) -> watch::Receiver<Option<u64>> {
+        self.scan_start_height_watch.get_receiver()
+    }
+
+    pub(crate) fn get_recovery_stats_watch(&self) -> Watch<RecoveryStats> {
+        self.recovery_stats_watch.clone()
+    }
 }
@@ -39,7 +39,7 @@ use crate::{
     transaction_service::handle::TransactionServiceHandle,
     util::watch::Watch,
     utxo_scanner_service::{
-        handle::UtxoScannerHandle,
+        handle::{RecoveryStats, UtxoScannerHandle},
         service::UtxoScannerService,
         uxto_scanner_service_builder::UtxoScannerMode,
     },
@@ -80,13 +80,21 @@ where
 
         let recovery_message_watch = Watch::new("Output found on blockchain during Wallet Recovery".to_string());
         let one_sided_message_watch = Watch::new("Detected one-sided payment on blockchain".to_string());
+        let scan_start_height_watch = Watch::new(None);
+        let recovery_stats_watch = Watch::new(RecoveryStats::default());
 
         let recovery_message_watch_receiver = recovery_message_watch.get_receiver();
         let one_sided_message_watch_receiver = one_sided_message_watch.get_receiver();
+        let scan_start_height_watch_receiver = scan_start_height_watch.get_receiver();
 
         // Register handle before waiting for handles to be ready
-        let utxo_scanner_handle =
-            UtxoScannerHandle::new(event_sender.clone(), one_sided_message_watch, recovery_message_watch);
+        let utxo_scanner_handle = UtxoScannerHandle::new(
+            event_sender.clone(),
+            one_sided_message_watch,
+            recovery_message_watch,
+            scan_start_height_watch,
+            recovery_stats_watch.clone(),
+        );
         context.register_handle(utxo_scanner_handle);
 
         let backend = self
@@ -136,6 +144,8 @@ where
                     base_node_service_handle,
                     one_sided_message_watch_receiver,
                     recovery_message_watch_receiver,
+                    scan_start_height_watch_receiver,
+                    recovery_stats_watch,
                 )
                 .await
                 .run();
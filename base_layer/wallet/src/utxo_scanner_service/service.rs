@@ -39,8 +39,9 @@ use crate::{
     output_manager_service::handle::OutputManagerHandle,
     storage::database::{WalletBackend, WalletDatabase},
     transaction_service::handle::TransactionServiceHandle,
+    util::watch::Watch,
     utxo_scanner_service::{
-        handle::UtxoScannerEvent,
+        handle::{RecoveryStats, UtxoScannerEvent},
         utxo_scanner_task::UtxoScannerTask,
         uxto_scanner_service_builder::{UtxoScannerMode, UtxoScannerServiceBuilder},
     },
@@ -61,6 +62,7 @@ pub struct UtxoScannerService<TBackend, TWalletConnectivity> {
     pub(crate) base_node_service: BaseNodeServiceHandle,
     one_sided_message_watch: watch::Receiver<String>,
     recovery_message_watch: watch::Receiver<String>,
+    scan_start_height_watch: watch::Receiver<Option<u64>>,
 }
 
 impl<TBackend, TWalletConnectivity> UtxoScannerService<TBackend, TWalletConnectivity>
@@ -78,6 +80,7 @@ where
         base_node_service: BaseNodeServiceHandle,
         one_sided_message_watch: watch::Receiver<String>,
         recovery_message_watch: watch::Receiver<String>,
+        scan_start_height_watch: watch::Receiver<Option<u64>>,
     ) -> Self {
         Self {
             resources,
@@ -89,6 +92,7 @@ where
             base_node_service,
             one_sided_message_watch,
             recovery_message_watch,
+            scan_start_height_watch,
         }
     }
 
@@ -113,6 +117,12 @@ where
         self.event_sender.subscribe()
     }
 
+    /// The height a scan starts from when there are no scanned blocks to continue from. This is the scan start height
+    /// at the time the service was built, later changes to it are only seen by services built afterwards.
+    pub fn scan_start_height(&self) -> Option<u64> {
+        self.resources.scan_start_height
+    }
+
     pub async fn run(mut self) -> Result<(), WalletError> {
         info!(target: LOG_TARGET, "UTXO scanning service starting");
 
@@ -179,6 +189,9 @@ where
                     Ok(_) = self.recovery_message_watch.changed() => {
                             self.resources.recovery_message = (*self.recovery_message_watch.borrow()).clone();
                     },
+                    Ok(_) = self.scan_start_height_watch.changed() => {
+                            self.resources.scan_start_height = *self.scan_start_height_watch.borrow();
+                    },
                 }
             }
         }
@@ -197,6 +210,8 @@ pub struct UtxoScannerResources<TBackend, TWalletConnectivity> {
     pub factories: CryptoFactories,
     pub recovery_message: String,
     pub one_sided_payment_message: String,
+    pub scan_start_height: Option<u64>,
+    pub recovery_stats: Watch<RecoveryStats>,
 }
 
 #[derive(Debug, Clone)]
@@ -63,7 +63,7 @@ use crate::{
     transaction_service::error::{TransactionServiceError, TransactionStorageError},
     utxo_scanner_service::{
         error::UtxoScannerError,
-        handle::UtxoScannerEvent,
+        handle::{RecoveryStats, UtxoScannerEvent},
         service::{ScannedBlock, UtxoScannerResources, SCANNED_BLOCK_CACHE_SIZE},
         uxto_scanner_service_builder::UtxoScannerMode,
         RECOVERY_KEY,
@@ -228,6 +228,7 @@ where
         ));
 
         let timer = Instant::now();
+        let mut stats = RecoveryStats::default();
         loop {
             let tip_header = self.get_chain_tip_header(&mut client).await?;
             let tip_header_hash = tip_header.hash();
@@ -266,16 +267,32 @@ where
                 // The node does not know of any of our cached headers so we will start the scan anew from the
                 // wallet birthday
                 self.resources.db.clear_scanned_blocks()?;
-                let birthday_height_hash = match self.resources.db.get_wallet_type()? {
-                    Some(WalletType::ProvidedKeys(_)) => {
-                        let header_proto = client.get_header_by_height(0).await?;
-                        let header = BlockHeader::try_from(header_proto).map_err(UtxoScannerError::ConversionError)?;
-                        HeightHash {
-                            height: 0,
-                            header_hash: header.hash(),
-                        }
-                    },
-                    _ => self.get_birthday_header_height_hash(&mut client).await?,
+                let birthday_height_hash = if let Some(start_height) = self.resources.scan_start_height {
+                    // An explicitly requested start height takes precedence over the wallet birthday
+                    let height = start_height.min(tip_header.height);
+                    info!(
+                        target: LOG_TARGET,
+                        "Wallet scanning starting at requested Block {}", height
+                    );
+                    let header_proto = client.get_header_by_height(height).await?;
+                    let header = BlockHeader::try_from(header_proto).map_err(UtxoScannerError::ConversionError)?;
+                    HeightHash {
+                        height,
+                        header_hash: header.hash(),
+                    }
+                } else {
+                    match self.resources.db.get_wallet_type()? {
+                        Some(WalletType::ProvidedKeys(_)) => {
+                            let header_proto = client.get_header_by_height(0).await?;
+                            let header =
+                                BlockHeader::try_from(header_proto).map_err(UtxoScannerError::ConversionError)?;
+                            HeightHash {
+                                height: 0,
+                                header_hash: header.hash(),
+                            }
+                        },
+                        _ => self.get_birthday_header_height_hash(&mut client).await?,
+                    }
                 };
 
                 ScannedBlock {
@@ -304,7 +321,7 @@ where
                 next_block_to_scan.header_hash.to_hex(),
             );
 
-            let (num_recovered, num_scanned, amount) = self
+            let (num_recovered, amount, round_stats) = self
                 .scan_utxos(
                     &mut client,
                     next_block_to_scan.header_hash,
@@ -312,20 +329,26 @@ where
                     tip_header.height,
                 )
                 .await?;
-            if num_scanned == 0 {
+            if round_stats.outputs_scanned == 0 {
                 return Err(UtxoScannerError::UtxoScanningError(
                     "Peer returned 0 UTXOs to scan".to_string(),
                 ));
             }
             debug!(
                 target: LOG_TARGET,
-                "Scanning round completed up to height {} in {:.2?} ({} outputs scanned, {} recovered with value {})",
+                "Scanning round completed up to height {} in {:.2?} ({} outputs scanned, {} recovered with value {}, \
+                 {} could not be recovered)",
                 tip_header.height,
                 timer.elapsed(),
-                num_scanned,
+                round_stats.outputs_scanned,
                 num_recovered,
-                amount
+                amount,
+                round_stats.outputs_failed_decode,
             );
+            stats.outputs_scanned += round_stats.outputs_scanned;
+            stats.outputs_recovered += round_stats.outputs_recovered;
+            stats.outputs_failed_decode += round_stats.outputs_failed_decode;
+            self.resources.recovery_stats.send(stats);
         }
     }
 
@@ -455,13 +478,15 @@ where
         start_header_hash: HashOutput,
         end_header_hash: HashOutput,
         tip_height: u64,
-    ) -> Result<(u64, u64, MicroMinotari), UtxoScannerError> {
+    ) -> Result<(u64, MicroMinotari, RecoveryStats), UtxoScannerError> {
         // Setting how often the progress event and log should occur during scanning. Defined in blocks
         const PROGRESS_REPORT_INTERVAL: u64 = 100;
 
         let mut num_recovered = 0u64;
         let mut total_amount = MicroMinotari::from(0);
         let mut total_scanned = 0;
+        let mut total_imported = 0u64;
+        let mut total_unrecoverable = 0u64;
 
         let request = SyncUtxosByBlockRequest {
             start_header_hash: start_header_hash.to_vec(),
@@ -487,7 +512,12 @@ where
         } {
             if self.shutdown_signal.is_triggered() {
                 // if running is set to false, we know its been canceled upstream so lets exit the loop
-                return Ok((num_recovered, total_scanned as u64, total_amount));
+                let stats = RecoveryStats {
+                    outputs_scanned: total_scanned as u64,
+                    outputs_recovered: total_imported,
+                    outputs_failed_decode: total_unrecoverable,
+                };
+                return Ok((num_recovered, total_amount, stats));
             }
 
             let response = response.map_err(|e| UtxoScannerError::RpcStatus(e.to_string()))?;
@@ -503,12 +533,14 @@ where
             total_scanned += outputs.len();
 
             let start = Instant::now();
-            let found_outputs = self.scan_for_outputs(outputs, current_height).await?;
+            let (found_outputs, num_unrecoverable) = self.scan_for_outputs(outputs, current_height).await?;
             scan_for_outputs_profiling.push(start.elapsed());
+            total_unrecoverable += num_unrecoverable;
 
             let (mut count, mut amount) = self
                 .import_utxos_to_transaction_service(found_outputs, current_height, mined_timestamp)
                 .await?;
+            total_imported += count;
             let block_hash = current_header_hash.try_into()?;
             if let Some(scanned_block) = prev_scanned_block {
                 if block_hash == scanned_block.header_hash {
@@ -565,22 +597,30 @@ where
             scan_for_outputs_profiling.iter().fold(0, |acc, &x| acc + x.as_millis()),
         );
 
-        Ok((num_recovered, total_scanned as u64, total_amount))
+        let stats = RecoveryStats {
+            outputs_scanned: total_scanned as u64,
+            outputs_recovered: total_imported,
+            outputs_failed_decode: total_unrecoverable,
+        };
+        Ok((num_recovered, total_amount, stats))
     }
 
+    /// Returns the outputs that belong to this wallet along with the number of outputs that were recognised as this
+    /// wallet's but could not be recovered
     async fn scan_for_outputs(
         &mut self,
         outputs: Vec<TransactionOutput>,
         height: u64,
-    ) -> Result<Vec<(WalletOutput, String, ImportStatus, TxId, TransactionOutput)>, UtxoScannerError> {
+    ) -> Result<(Vec<(WalletOutput, String, ImportStatus, TxId, TransactionOutput)>, u64), UtxoScannerError> {
         let mut found_outputs: Vec<(WalletOutput, String, ImportStatus, TxId, TransactionOutput)> = Vec::new();
         let start = Instant::now();
+        let (recovered_outputs, num_unrecoverable) = self
+            .resources
+            .output_manager_service
+            .scan_for_recoverable_outputs_with_failures(outputs.clone().into_iter().map(|o| (o, None)).collect())
+            .await?;
         found_outputs.append(
-            &mut self
-                .resources
-                .output_manager_service
-                .scan_for_recoverable_outputs(outputs.clone().into_iter().map(|o| (o, None)).collect())
-                .await?
+            &mut recovered_outputs
                 .into_iter()
                 .map(|ro| -> Result<_, UtxoScannerError> {
                     let (message, status) = if ro.output.features.is_coinbase() {
@@ -634,7 +674,7 @@ where
             scanned_time.as_millis(),
             one_sided_time.as_millis(),
         );
-        Ok(found_outputs)
+        Ok((found_outputs, num_unrecoverable))
     }
 
     async fn import_utxos_to_transaction_service(
@@ -725,6 +765,8 @@ where
         mined_timestamp: NaiveDateTime,
         scanned_output: TransactionOutput,
     ) -> Result<TxId, WalletError> {
+        // Only outputs found by scanning for one-sided payments are given this status
+        let one_sided_payment = import_status == ImportStatus::OneSidedUnconfirmed;
         let tx_id = self
             .resources
             .transaction_service
@@ -733,6 +775,7 @@ where
                 source_address,
                 message,
                 import_status.clone(),
+                one_sided_payment,
                 Some(tx_id),
                 Some(current_height),
                 Some(mined_timestamp),
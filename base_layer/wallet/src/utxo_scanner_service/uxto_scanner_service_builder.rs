@@ -36,8 +36,9 @@ use crate::{
         sqlite_db::wallet::WalletSqliteDatabase,
     },
     transaction_service::handle::TransactionServiceHandle,
+    util::watch::Watch,
     utxo_scanner_service::{
-        handle::UtxoScannerEvent,
+        handle::{RecoveryStats, UtxoScannerEvent},
         service::{UtxoScannerResources, UtxoScannerService},
     },
     WalletSqlite,
@@ -105,6 +106,7 @@ impl UtxoScannerServiceBuilder {
         shutdown_signal: ShutdownSignal,
     ) -> Result<UtxoScannerService<WalletSqliteDatabase, WalletConnectivityHandle>, KeyManagerServiceError> {
         let one_sided_tari_address = wallet.get_wallet_one_sided_address().await?;
+        let scan_start_height_watch = wallet.utxo_scanner_service.get_scan_start_height_watcher();
         let resources = UtxoScannerResources {
             db: wallet.db.clone(),
             comms_connectivity: wallet.comms.connectivity(),
@@ -116,6 +118,8 @@ impl UtxoScannerServiceBuilder {
             factories: wallet.factories.clone(),
             recovery_message: self.recovery_message.clone(),
             one_sided_payment_message: self.one_sided_message.clone(),
+            scan_start_height: *scan_start_height_watch.borrow(),
+            recovery_stats: wallet.utxo_scanner_service.get_recovery_stats_watch(),
         };
 
         let (event_sender, _) = broadcast::channel(200);
@@ -130,6 +134,7 @@ impl UtxoScannerServiceBuilder {
             wallet.base_node_service.clone(),
             wallet.utxo_scanner_service.get_one_sided_payment_message_watcher(),
             wallet.utxo_scanner_service.get_recovery_message_watcher(),
+            scan_start_height_watch,
         ))
     }
 
@@ -151,6 +156,8 @@ impl UtxoScannerServiceBuilder {
         base_node_service: BaseNodeServiceHandle,
         one_sided_message_watch: watch::Receiver<String>,
         recovery_message_watch: watch::Receiver<String>,
+        scan_start_height_watch: watch::Receiver<Option<u64>>,
+        recovery_stats: Watch<RecoveryStats>,
     ) -> UtxoScannerService<TBackend, TWalletConnectivity> {
         let resources = UtxoScannerResources {
             db,
@@ -163,6 +170,8 @@ impl UtxoScannerServiceBuilder {
             factories,
             recovery_message: self.recovery_message.clone(),
             one_sided_payment_message: self.one_sided_message.clone(),
+            scan_start_height: *scan_start_height_watch.borrow(),
+            recovery_stats,
         };
 
         UtxoScannerService::new(
@@ -175,6 +184,7 @@ impl UtxoScannerServiceBuilder {
             base_node_service,
             one_sided_message_watch,
             recovery_message_watch,
+            scan_start_height_watch,
         )
     }
 }
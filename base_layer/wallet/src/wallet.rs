@@ -27,11 +27,13 @@ use digest::consts::U32;
 use futures::executor::block_on;
 use log::*;
 use rand::rngs::OsRng;
+use strum::IntoEnumIterator;
 use tari_common::configuration::bootstrap::ApplicationType;
 use tari_common_types::{
+    key_branches::TransactionKeyManagerBranch,
     tari_address::{TariAddress, TariAddressFeatures},
     transaction::{ImportStatus, TxId},
-    types::{ComAndPubSignature, Commitment, PrivateKey, PublicKey, RangeProof, SignatureWithDomain},
+    types::{BlockHash, ComAndPubSignature, Commitment, PrivateKey, PublicKey, RangeProof, SignatureWithDomain},
     wallet_types::WalletType,
 };
 use tari_comms::{
@@ -64,7 +66,13 @@ use tari_crypto::{hash_domain, signatures::SchnorrSignatureError};
 use tari_key_manager::{
     cipher_seed::CipherSeed,
     key_manager::KeyManager,
-    key_manager_service::{storage::database::KeyManagerBackend, KeyDigest, KeyManagerBranch, KeyManagerServiceError},
+    key_manager_service::{
+        storage::database::KeyManagerBackend,
+        KeyDigest,
+        KeyManagerBranch,
+        KeyManagerInterface,
+        KeyManagerServiceError,
+    },
     mnemonic::{Mnemonic, MnemonicLanguage},
     SeedWords,
 };
@@ -101,6 +109,7 @@ use crate::{
             models::KnownOneSidedPaymentScript,
         },
         OutputManagerServiceInitializer,
+        UtxoSelectionCriteria,
     },
     storage::database::{WalletBackend, WalletDatabase},
     transaction_service::{
@@ -494,6 +503,15 @@ where
         }
     }
 
+    pub fn get_wallet_type(&self) -> &WalletType {
+        &self.wallet_type
+    }
+
+    /// Returns true if this wallet was created from a view key and public spend key only, so it cannot spend
+    pub fn is_watch_only(&self) -> bool {
+        self.wallet_type.is_watch_only()
+    }
+
     pub async fn get_wallet_interactive_address(&self) -> Result<TariAddress, KeyManagerServiceError> {
         let view_key = self.key_manager_service.get_view_key().await?;
         let comms_key = self.key_manager_service.get_comms_key().await?;
@@ -520,6 +538,24 @@ where
         ))
     }
 
+    /// Returns the name and current derivation index of each of the standard key manager branches
+    pub async fn get_key_manager_branch_indices(&self) -> Result<Vec<(String, u64)>, KeyManagerServiceError> {
+        let mut indices = Vec::new();
+        for branch in TransactionKeyManagerBranch::iter() {
+            let branch = branch.get_branch_key();
+            let index = self.key_manager_service.get_current_key_index(branch.clone()).await?;
+            indices.push((branch, index));
+        }
+        Ok(indices)
+    }
+
+    /// Derives the private key at the given index of a key manager branch from the wallet seed
+    pub async fn derive_key(&self, branch: String, index: u64) -> Result<PrivateKey, KeyManagerServiceError> {
+        self.key_manager_service
+            .get_private_key(&TariKeyId::Managed { branch, index })
+            .await
+    }
+
     pub async fn get_wallet_id(&self) -> Result<WalletIdentity, WalletError> {
         let address_interactive = self.get_wallet_interactive_address().await?;
         let address_one_sided = self.get_wallet_one_sided_address().await?;
@@ -583,9 +619,41 @@ where
         source_address: TariAddress,
         message: String,
     ) -> Result<TxId, WalletError> {
+        self.import_unblinded_output_as_non_rewindable_with_metadata(
+            unblinded_output,
+            source_address,
+            message,
+            ImportStatus::Imported,
+            PaymentId::Empty,
+        )
+        .await
+    }
+
+    /// Import an external spendable UTXO into the wallet as a non-rewindable/non-recoverable UTXO, as
+    /// `import_unblinded_output_as_non_rewindable` does, but with the import status and payment id of the faux incoming
+    /// transaction supplied by the caller so that it can be matched to an external record. Only `Imported` and
+    /// `OneSidedUnconfirmed` are accepted, a confirmed status requires the block the output was mined in.
+    pub async fn import_unblinded_output_as_non_rewindable_with_metadata(
+        &mut self,
+        unblinded_output: UnblindedOutput,
+        source_address: TariAddress,
+        message: String,
+        import_status: ImportStatus,
+        payment_id: PaymentId,
+    ) -> Result<TxId, WalletError> {
+        if !matches!(
+            import_status,
+            ImportStatus::Imported | ImportStatus::OneSidedUnconfirmed
+        ) {
+            return Err(WalletError::ArgumentError {
+                argument: "import_status".to_string(),
+                value: import_status.to_string(),
+                message: "Only unconfirmed import statuses can be used without the mined block".to_string(),
+            });
+        }
         let value = unblinded_output.value;
         let wallet_output = unblinded_output
-            .to_wallet_output(&self.key_manager_service, PaymentId::Empty)
+            .to_wallet_output(&self.key_manager_service, payment_id.clone())
             .await?;
         let tx_id = self
             .transaction_service
@@ -593,12 +661,13 @@ where
                 value,
                 source_address,
                 message,
-                ImportStatus::Imported,
+                import_status.clone(),
+                false,
                 None,
                 None,
                 None,
                 wallet_output.to_transaction_output(&self.key_manager_service).await?,
-                PaymentId::Empty,
+                payment_id,
             )
             .await?;
         // As non-rewindable
@@ -607,15 +676,87 @@ where
             .await?;
         info!(
             target: LOG_TARGET,
-            "UTXO (Commitment: {}, value: {}, txID: {}) imported into wallet as 'ImportStatus::Imported' and is non-rewindable",
+            "UTXO (Commitment: {}, value: {}, txID: {}) imported into wallet as 'ImportStatus::{}' and is non-rewindable",
             wallet_output.commitment(&self.key_manager_service).await?.to_hex(),
             wallet_output.value,
             tx_id,
+            import_status,
         );
 
         Ok(tx_id)
     }
 
+    /// Import an external spendable UTXO into the wallet as a rewindable/recoverable UTXO. Unlike
+    /// `import_unblinded_output_as_non_rewindable`, the output is passed through the same recovery path used when
+    /// scanning the chain, so it is only accepted if its encrypted data can be decrypted with this wallet's keys. An
+    /// output imported this way will be found again when the wallet is recovered from its seed words. A faux incoming
+    /// transaction will be created to provide a record of the event. The TxId of the generated transaction is
+    /// returned.
+    pub async fn import_unblinded_output_as_rewindable(
+        &mut self,
+        unblinded_output: UnblindedOutput,
+        source_address: TariAddress,
+        message: String,
+    ) -> Result<TxId, WalletError> {
+        let value = unblinded_output.value;
+        let transaction_output = unblinded_output
+            .to_wallet_output(&self.key_manager_service, PaymentId::Empty)
+            .await?
+            .to_transaction_output(&self.key_manager_service)
+            .await?;
+        let tx_id = TxId::new_random();
+        let recovered_output = self
+            .output_manager_service
+            .scan_for_recoverable_outputs(vec![(transaction_output.clone(), Some(tx_id))])
+            .await?
+            .pop()
+            .ok_or_else(|| WalletError::ArgumentError {
+                argument: "import_unblinded_output_as_rewindable, unblinded_output".to_string(),
+                value: transaction_output.commitment.to_hex(),
+                message: "Output cannot be rewound with this wallet's keys or has already been imported".to_string(),
+            })?;
+        let tx_id = self
+            .transaction_service
+            .import_utxo_with_status(
+                value,
+                source_address,
+                message,
+                ImportStatus::Imported,
+                false,
+                Some(recovered_output.tx_id),
+                None,
+                None,
+                transaction_output,
+                recovered_output.output.payment_id.clone(),
+            )
+            .await?;
+        info!(
+            target: LOG_TARGET,
+            "UTXO (Commitment: {}, value: {}, txID: {}) imported into wallet as 'ImportStatus::Imported' and is rewindable",
+            recovered_output.output.commitment(&self.key_manager_service).await?.to_hex(),
+            recovered_output.output.value,
+            tx_id,
+        );
+
+        Ok(tx_id)
+    }
+
+    /// Promote the faux transaction of a non-rewindable import to a mined and confirmed state once the caller has
+    /// verified that the output is mined in the block with the given hash, height and timestamp, making the imported
+    /// output spendable.
+    pub async fn mark_imported_transaction_confirmed(
+        &mut self,
+        tx_id: TxId,
+        mined_height: u64,
+        mined_in_block: BlockHash,
+        mined_timestamp: u64,
+    ) -> Result<(), WalletError> {
+        self.transaction_service
+            .mark_imported_transaction_confirmed(tx_id, mined_height, mined_in_block, mined_timestamp)
+            .await?;
+        Ok(())
+    }
+
     pub fn sign_message(
         &mut self,
         secret: &PrivateKey,
@@ -658,6 +799,19 @@ where
             .map_err(WalletError::OutputManagerError)
     }
 
+    /// Appraise the expected outputs and the combined fee of a coin join followed by an even coin split
+    pub async fn preview_join_split_with_commitments(
+        &mut self,
+        commitments: Vec<Commitment>,
+        split_count: usize,
+        fee_per_gram: MicroMinotari,
+    ) -> Result<(Vec<MicroMinotari>, MicroMinotari), WalletError> {
+        self.output_manager_service
+            .preview_join_split_with_commitments(commitments, split_count, fee_per_gram)
+            .await
+            .map_err(WalletError::OutputManagerError)
+    }
+
     /// Do a coin split
     pub async fn coin_split(
         &mut self,
@@ -770,6 +924,47 @@ where
         }
     }
 
+    /// Do a coin join of the smallest spendable outputs, adding outputs in ascending order of value until the joined
+    /// output is worth at least `target_amount` after the fee has been deducted. Outputs that are not yet mature or
+    /// are still time-locked at the current tip are not used.
+    pub async fn coin_join_to_amount(
+        &mut self,
+        target_amount: MicroMinotari,
+        fee_per_gram: MicroMinotari,
+        msg: Option<String>,
+    ) -> Result<TxId, WalletError> {
+        let tip_height = self
+            .base_node_service
+            .get_chain_metadata()
+            .await?
+            .as_ref()
+            .map(|m| m.best_block_height());
+        let mut unspent_outputs = self
+            .output_db
+            .fetch_unspent_outputs_for_spending(&UtxoSelectionCriteria::smallest_first(0), target_amount, tip_height)
+            .map_err(OutputManagerError::from)?;
+        unspent_outputs.sort_by_key(|o| o.wallet_output.value);
+
+        let mut commitments = Vec::new();
+        let mut accumulated_amount = MicroMinotari::zero();
+        for output in unspent_outputs {
+            accumulated_amount = accumulated_amount + output.wallet_output.value;
+            commitments.push(output.commitment);
+            // the fee only reduces the joined value, so there is no need to preview until the raw total is enough
+            if accumulated_amount < target_amount {
+                continue;
+            }
+            let (expected_outputs, _fee) = self
+                .preview_coin_join_with_commitments(commitments.clone(), fee_per_gram)
+                .await?;
+            if expected_outputs.first().is_some_and(|v| *v >= target_amount) {
+                return self.coin_join(commitments, fee_per_gram, msg).await;
+            }
+        }
+
+        Err(WalletError::OutputManagerError(OutputManagerError::NotEnoughFunds))
+    }
+
     /// Utility function to find out if there is data in the database indicating that there is an incomplete recovery
     /// process in progress
     pub fn is_recovery_in_progress(&self) -> Result<bool, WalletError> {
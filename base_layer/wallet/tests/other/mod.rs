@@ -679,6 +679,8 @@ async fn test_import_utxo() {
             listener_address: "/ip4/127.0.0.1/tcp/0".parse().unwrap(),
             tor_socks_address: None,
             tor_socks_auth: Default::default(),
+            tor_socks_bypass_for_tcp: false,
+            dial_timeout: None,
         }),
         datastore_path: temp_dir.path().to_path_buf(),
         peer_database_name: random::string(8),
@@ -2123,6 +2123,40 @@ async fn test_txo_revalidation() {
     assert_eq!(unspent_txos.len(), 0);
 }
 
+#[tokio::test]
+async fn test_revalidate_while_validation_in_progress() {
+    let (connection, _tempdir) = get_temp_sqlite_database_connection();
+    let backend = OutputManagerSqliteDatabase::new(connection);
+    // Without a base node RPC client the validation protocol waits for one, so the validation stays in progress
+    let mut oms = setup_output_manager_service(backend, false).await;
+    let mut event_stream = oms.output_manager_handle.get_event_stream();
+
+    oms.output_manager_handle.validate_txos().await.unwrap();
+    oms.output_manager_handle.validate_txos().await.unwrap();
+
+    // Whichever protocol is second reports that the other one is busy, which shows the validation lock is held
+    let delay = sleep(Duration::from_secs(30));
+    tokio::pin!(delay);
+    let mut validation_busy = false;
+    loop {
+        tokio::select! {
+            event = event_stream.recv() => {
+                if let OutputManagerEvent::TxoValidationAlreadyBusy(_) = &*event.unwrap() {
+                    validation_busy = true;
+                    break;
+                }
+            },
+            () = &mut delay => {
+                break;
+            },
+        }
+    }
+    assert!(validation_busy, "A validation protocol should be in progress");
+
+    let err = oms.output_manager_handle.revalidate_all_outputs().await.unwrap_err();
+    assert!(matches!(err, OutputManagerError::ValidationInProgress));
+}
+
 #[tokio::test]
 async fn test_get_status_by_tx_id() {
     let (connection, _tempdir) = get_temp_sqlite_database_connection();
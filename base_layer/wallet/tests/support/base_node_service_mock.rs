@@ -115,6 +115,7 @@ impl MockBaseNodeService {
                 self.state.chain_metadata.clone(),
             )),
             BaseNodeServiceRequest::GetBaseNodeLatency => Ok(BaseNodeServiceResponse::Latency(None)),
+            BaseNodeServiceRequest::GetIsSynced => Ok(BaseNodeServiceResponse::IsSynced(self.state.is_synced)),
         }
     }
 }
@@ -116,7 +116,10 @@ impl OutputManagerServiceMock {
                     .collect();
 
                 let _result = reply_tx
-                    .send(Ok(OutputManagerResponse::RewoundOutputs(outputs)))
+                    .send(Ok(OutputManagerResponse::RewoundOutputs {
+                        outputs,
+                        num_unrecoverable: 0,
+                    }))
                     .inspect_err(|_| {
                         warn!(target: LOG_TARGET, "Failed to send reply");
                     });
@@ -21,6 +21,7 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use std::{
+    collections::HashMap,
     convert::{TryFrom, TryInto},
     mem::size_of,
     path::Path,
@@ -55,6 +56,7 @@ use minotari_wallet::{
             database::{OutputManagerBackend, OutputManagerDatabase},
             models::KnownOneSidedPaymentScript,
             sqlite_db::{OutputManagerSqliteDatabase, ReceivedOutputInfoForBatch},
+            OutputStatus,
         },
         OutputManagerServiceInitializer,
         UtxoSelectionCriteria,
@@ -115,6 +117,7 @@ use tari_core::{
         proto::wallet_rpc::{TxLocation, TxQueryResponse, TxSubmissionRejectionReason, TxSubmissionResponse},
         rpc::BaseNodeWalletRpcServer,
     },
+    blocks::BlockHeader,
     consensus::{ConsensusConstantsBuilder, ConsensusManager},
     covenants::Covenant,
     one_sided::shared_secret_to_output_encryption_key,
@@ -445,6 +448,8 @@ async fn setup_transaction_service_no_comms(
     )
     .await
     .unwrap();
+    #[cfg(feature = "test_clock")]
+    let ts_service = ts_service.with_clock(transaction_service_handle.adjustable_clock());
     task::spawn(async move { output_manager_service.start().await.unwrap() });
     task::spawn(async move { ts_service.start().await.unwrap() });
     TransactionServiceNoCommsInterface {
@@ -517,6 +522,66 @@ fn try_decode_transaction_cancelled_message(bytes: Vec<u8>) -> Option<proto::Tra
         .unwrap_or_default()
 }
 
+/// Funds the wallet with a single unspent output and sends part of it to a random peer, returning once the
+/// transaction is waiting in the pending outbound set.
+async fn fund_and_send_pending_transaction(
+    ts_interface: &mut TransactionServiceNoCommsInterface,
+    total_available: MicroMinotari,
+) -> TxId {
+    let uo = make_input(
+        &mut OsRng,
+        total_available,
+        &OutputFeatures::default(),
+        &ts_interface.key_manager_handle,
+    )
+    .await;
+    ts_interface
+        .output_manager_service_handle
+        .add_output(uo.clone(), None)
+        .await
+        .unwrap();
+    ts_interface
+        .oms_db
+        .mark_outputs_as_unspent(vec![(uo.hash(&ts_interface.key_manager_handle).await.unwrap(), true)])
+        .unwrap();
+
+    let bob_node_identity =
+        NodeIdentity::random(&mut OsRng, get_next_memory_address(), PeerFeatures::COMMUNICATION_NODE);
+    let bob_address = TariAddress::new_single_address_with_interactive_only(
+        bob_node_identity.public_key().clone(),
+        Network::LocalNet,
+    );
+    let tx_id = ts_interface
+        .transaction_service_handle
+        .send_transaction(
+            bob_address,
+            100000 * uT,
+            UtxoSelectionCriteria::default(),
+            OutputFeatures::default(),
+            100 * uT,
+            "Testing Message".to_string(),
+        )
+        .await
+        .unwrap();
+
+    for i in 0..=12 {
+        if ts_interface
+            .transaction_service_handle
+            .get_pending_outbound_transactions()
+            .await
+            .unwrap()
+            .contains_key(&tx_id)
+        {
+            break;
+        }
+        sleep(Duration::from_secs(5)).await;
+        if i >= 12 {
+            panic!("Pending outbound transaction should have been added by now");
+        }
+    }
+    tx_id
+}
+
 #[tokio::test(flavor = "multi_thread", worker_threads = 1)]
 async fn manage_single_transaction() {
     let network = Network::LocalNet;
@@ -1673,6 +1738,99 @@ async fn send_one_sided_transaction_to_other() {
     assert!(found, "'TransactionCompletedImmediately(_)' event not found");
 }
 
+#[tokio::test]
+async fn send_one_sided_transaction_to_many_recipients() {
+    let network = Network::LocalNet;
+    let consensus_manager = ConsensusManager::builder(network).build().unwrap();
+    let factories = CryptoFactories::default();
+    let alice_node_identity = Arc::new(NodeIdentity::random(
+        &mut OsRng,
+        get_next_memory_address(),
+        PeerFeatures::COMMUNICATION_NODE,
+    ));
+
+    let temp_dir = tempdir().unwrap();
+    let database_path = temp_dir.path().to_str().unwrap().to_string();
+    let db_connection = make_wallet_database_memory_connection();
+
+    let shutdown = Shutdown::new();
+    let (mut alice_ts, mut alice_oms, _alice_comms, _alice_connectivity, key_manager_handle, alice_db) =
+        setup_transaction_service(
+            alice_node_identity,
+            vec![],
+            consensus_manager,
+            factories.clone(),
+            db_connection,
+            database_path,
+            Duration::from_secs(0),
+            shutdown.to_signal(),
+        )
+        .await;
+
+    let initial_wallet_value = 50000.into();
+    let uo1 = make_input(
+        &mut OsRng,
+        initial_wallet_value,
+        &OutputFeatures::default(),
+        &key_manager_handle,
+    )
+    .await;
+    alice_oms.add_output(uo1.clone(), None).await.unwrap();
+    alice_db
+        .mark_outputs_as_unspent(vec![(uo1.hash(&key_manager_handle).await.unwrap(), true)])
+        .unwrap();
+
+    let mut recipients = Vec::new();
+    for value in [10000u64, 7000u64] {
+        let bob_view_key = PublicKey::from_secret_key(&PrivateKey::random(&mut OsRng));
+        let bob_spend_key = PublicKey::from_secret_key(&PrivateKey::random(&mut OsRng));
+        let bob_address = TariAddress::new_dual_address_with_default_features(bob_view_key, bob_spend_key, network);
+        recipients.push((bob_address, MicroMinotari::from(value)));
+    }
+    let total_value = MicroMinotari::from(17000);
+
+    // the total sent to all recipients may not overflow
+    let overflowing = vec![
+        (recipients[0].0.clone(), MicroMinotari::from(u64::MAX)),
+        (recipients[1].0.clone(), MicroMinotari::from(1)),
+    ];
+    assert!(alice_ts
+        .send_one_sided_transaction_to_many(
+            overflowing,
+            UtxoSelectionCriteria::default(),
+            20.into(),
+            "".to_string(),
+            PaymentId::Empty,
+        )
+        .await
+        .is_err());
+
+    let tx_id = alice_ts
+        .send_one_sided_transaction_to_many(
+            recipients.clone(),
+            UtxoSelectionCriteria::default(),
+            20.into(),
+            "one transaction, two recipients".to_string(),
+            PaymentId::Empty,
+        )
+        .await
+        .expect("Alice sending one-sided tx to many recipients");
+
+    let completed_tx = alice_ts
+        .get_completed_transaction(tx_id)
+        .await
+        .expect("Could not find completed one-sided tx");
+    assert_eq!(completed_tx.amount, total_value);
+    assert_eq!(completed_tx.destination_address, recipients[0].0);
+    assert_eq!(completed_tx.transaction.body.inputs().len(), 1);
+    // one output per recipient plus alice's change
+    assert_eq!(completed_tx.transaction.body.outputs().len(), recipients.len() + 1);
+    assert_eq!(
+        alice_oms.get_balance().await.unwrap().pending_incoming_balance,
+        initial_wallet_value - total_value - completed_tx.fee
+    );
+}
+
 #[tokio::test]
 async fn recover_one_sided_transaction() {
     let network = Network::LocalNet;
@@ -3024,6 +3182,7 @@ async fn test_power_mode_updates() {
         mined_in_block: None,
         mined_timestamp: None,
         payment_id: None,
+        one_sided_payment: false,
     };
 
     let source_address = TariAddress::new_dual_address_with_default_features(
@@ -3056,6 +3215,7 @@ async fn test_power_mode_updates() {
         mined_in_block: None,
         mined_timestamp: None,
         payment_id: None,
+        one_sided_payment: false,
     };
 
     tx_backend
@@ -3530,6 +3690,182 @@ async fn test_transaction_cancellation() {
         .remove(&tx_id3)
         .is_none());
 }
+
+#[tokio::test]
+async fn test_cancel_expired_pending_transactions() {
+    let factories = CryptoFactories::default();
+    let connection = make_wallet_database_memory_connection();
+
+    let mut alice_ts_interface = setup_transaction_service_no_comms(factories.clone(), connection, None).await;
+
+    let alice_total_available = 2500000 * uT;
+    let tx_id = fund_and_send_pending_transaction(&mut alice_ts_interface, alice_total_available).await;
+    assert_eq!(
+        alice_ts_interface
+            .output_manager_service_handle
+            .get_balance()
+            .await
+            .unwrap()
+            .available_balance,
+        MicroMinotari::from(0)
+    );
+
+    // The transaction is not old enough to be cancelled yet
+    let cancelled = alice_ts_interface
+        .transaction_service_handle
+        .cancel_expired_pending_transactions(Duration::from_secs(3600))
+        .await
+        .unwrap();
+    assert!(cancelled.is_empty());
+
+    sleep(Duration::from_millis(10)).await;
+    let cancelled = alice_ts_interface
+        .transaction_service_handle
+        .cancel_expired_pending_transactions(Duration::ZERO)
+        .await
+        .unwrap();
+    assert_eq!(cancelled, vec![tx_id]);
+    assert!(alice_ts_interface
+        .transaction_service_handle
+        .get_pending_outbound_transactions()
+        .await
+        .unwrap()
+        .is_empty());
+    assert_eq!(
+        alice_ts_interface
+            .output_manager_service_handle
+            .get_balance()
+            .await
+            .unwrap()
+            .available_balance,
+        alice_total_available
+    );
+}
+
+#[cfg(feature = "test_clock")]
+#[tokio::test]
+async fn test_advancing_clock_expires_pending_transactions() {
+    let factories = CryptoFactories::default();
+    let connection = make_wallet_database_memory_connection();
+
+    let mut alice_ts_interface = setup_transaction_service_no_comms(factories.clone(), connection, None).await;
+
+    let tx_id = fund_and_send_pending_transaction(&mut alice_ts_interface, 2500000 * uT).await;
+    let cancelled = alice_ts_interface
+        .transaction_service_handle
+        .cancel_expired_pending_transactions(Duration::from_secs(1800))
+        .await
+        .unwrap();
+    assert!(cancelled.is_empty());
+
+    // Moving the service clock forward ages the pending transaction without waiting in real time
+    alice_ts_interface
+        .transaction_service_handle
+        .advance_clock(Duration::from_secs(3600));
+    let cancelled = alice_ts_interface
+        .transaction_service_handle
+        .cancel_expired_pending_transactions(Duration::from_secs(1800))
+        .await
+        .unwrap();
+    assert_eq!(cancelled, vec![tx_id]);
+}
+
+#[cfg(feature = "test_clock")]
+#[tokio::test]
+async fn test_transactions_sent_after_advancing_clock_are_stamped_with_service_time() {
+    let factories = CryptoFactories::default();
+    let connection = make_wallet_database_memory_connection();
+
+    let mut alice_ts_interface = setup_transaction_service_no_comms(factories.clone(), connection, None).await;
+
+    // Move the service clock well past both the resend period and the cancellation age used below
+    alice_ts_interface
+        .transaction_service_handle
+        .advance_clock(Duration::from_secs(3600));
+    let service_now = Utc::now().naive_utc() + ChronoDuration::seconds(3600);
+
+    let tx_id = fund_and_send_pending_transaction(&mut alice_ts_interface, 2500000 * uT).await;
+    // Give the send protocol time to reach its resend check
+    sleep(Duration::from_millis(500)).await;
+
+    let outbound_tx = alice_ts_interface
+        .transaction_service_handle
+        .get_pending_outbound_transactions()
+        .await
+        .unwrap()
+        .remove(&tx_id)
+        .unwrap();
+    assert!(outbound_tx.timestamp >= service_now);
+    assert!(outbound_tx.last_send_timestamp.unwrap() >= service_now);
+    assert_eq!(outbound_tx.send_count, 1);
+
+    let cancelled = alice_ts_interface
+        .transaction_service_handle
+        .cancel_expired_pending_transactions(Duration::from_secs(1800))
+        .await
+        .unwrap();
+    assert!(cancelled.is_empty());
+}
+
+#[cfg(feature = "test_harness")]
+#[tokio::test]
+async fn test_harness_receives_and_completes_transactions() {
+    let factories = CryptoFactories::default();
+    let connection = make_wallet_database_memory_connection();
+
+    let mut alice_ts_interface = setup_transaction_service_no_comms(factories.clone(), connection, None).await;
+
+    let received_tx_id = alice_ts_interface
+        .transaction_service_handle
+        .test_receive_transaction()
+        .await
+        .unwrap();
+    let pending_inbound = alice_ts_interface
+        .transaction_service_handle
+        .get_pending_inbound_transactions()
+        .await
+        .unwrap();
+    assert!(pending_inbound.contains_key(&received_tx_id));
+
+    let sent_tx_id = fund_and_send_pending_transaction(&mut alice_ts_interface, 2500000 * uT).await;
+
+    alice_ts_interface
+        .transaction_service_handle
+        .test_complete_sent_transaction(sent_tx_id)
+        .await
+        .unwrap();
+    // The waiting send protocol finalizes the transaction in the background
+    let mut completed_tx = None;
+    for _ in 0..20 {
+        if let Ok(tx) = alice_ts_interface
+            .transaction_service_handle
+            .get_completed_transaction(sent_tx_id)
+            .await
+        {
+            completed_tx = Some(tx);
+            break;
+        }
+        sleep(Duration::from_millis(250)).await;
+    }
+    let completed_tx = completed_tx.expect("The sent transaction should have been completed by now");
+    assert!(!alice_ts_interface
+        .transaction_service_handle
+        .get_pending_outbound_transactions()
+        .await
+        .unwrap()
+        .contains_key(&sent_tx_id));
+    assert_eq!(completed_tx.status, TransactionStatus::Completed);
+    assert_eq!(completed_tx.amount, 100000 * uT);
+    assert_eq!(completed_tx.transaction.body.kernels().len(), 1);
+
+    // The finalized transaction is broadcast like any other completed transaction
+    alice_ts_interface
+        .transaction_service_handle
+        .restart_broadcast_protocols()
+        .await
+        .unwrap();
+}
+
 #[tokio::test]
 async fn test_direct_vs_saf_send_of_tx_reply_and_finalize() {
     let factories = CryptoFactories::default();
@@ -5749,6 +6085,7 @@ async fn broadcast_all_completed_transactions_on_startup() {
         mined_in_block: None,
         mined_timestamp: None,
         payment_id: None,
+        one_sided_payment: false,
     };
 
     let completed_tx2 = CompletedTransaction {
@@ -5881,6 +6218,7 @@ async fn test_update_faux_tx_on_oms_validation() {
             alice_address.clone(),
             "blah".to_string(),
             ImportStatus::Imported,
+            false,
             None,
             None,
             None,
@@ -5898,6 +6236,7 @@ async fn test_update_faux_tx_on_oms_validation() {
             alice_address.clone(),
             "one-sided 1".to_string(),
             ImportStatus::OneSidedUnconfirmed,
+            true,
             None,
             None,
             None,
@@ -5915,6 +6254,7 @@ async fn test_update_faux_tx_on_oms_validation() {
             alice_address,
             "one-sided 2".to_string(),
             ImportStatus::OneSidedConfirmed,
+            true,
             None,
             None,
             None,
@@ -5997,6 +6337,7 @@ async fn test_update_faux_tx_on_oms_validation() {
                 .unwrap()
                 .unwrap();
             if let WalletTransaction::Completed(tx) = transaction {
+                assert_eq!(tx.one_sided_payment, tx_id != tx_id_1);
                 if tx_id == tx_id_1 && tx.status == TransactionStatus::OneSidedUnconfirmed && !found_imported {
                     found_imported = true;
                 }
@@ -6018,6 +6359,121 @@ async fn test_update_faux_tx_on_oms_validation() {
     );
 }
 
+#[tokio::test]
+async fn test_confirmed_import_survives_txo_validation() {
+    let factories = CryptoFactories::default();
+    let connection = make_wallet_database_memory_connection();
+    let mut alice_ts_interface = setup_transaction_service_no_comms(factories.clone(), connection, None).await;
+    let alice_address = TariAddress::new_single_address_with_interactive_only(
+        alice_ts_interface.base_node_identity.public_key().clone(),
+        Network::LocalNet,
+    );
+
+    let uo = make_input(
+        &mut OsRng.clone(),
+        MicroMinotari::from(10000),
+        &OutputFeatures::default(),
+        &alice_ts_interface.key_manager_handle,
+    )
+    .await;
+    let tx_id = alice_ts_interface
+        .transaction_service_handle
+        .import_utxo_with_status(
+            MicroMinotari::from(10000),
+            alice_address,
+            "imported".to_string(),
+            ImportStatus::Imported,
+            false,
+            None,
+            None,
+            None,
+            uo.to_transaction_output(&alice_ts_interface.key_manager_handle)
+                .await
+                .unwrap(),
+            PaymentId::Empty,
+        )
+        .await
+        .unwrap();
+    alice_ts_interface
+        .output_manager_service_handle
+        .add_unvalidated_output(tx_id, uo.clone(), None)
+        .await
+        .unwrap();
+
+    let mut block1_header = BlockHeader::new(1);
+    block1_header.height = 1;
+    let mut block4_header = BlockHeader::new(1);
+    block4_header.height = 4;
+    let mut block_headers = HashMap::new();
+    block_headers.insert(1, block1_header.clone());
+    block_headers.insert(4, block4_header.clone());
+    alice_ts_interface.base_node_rpc_mock_state.set_blocks(block_headers);
+
+    alice_ts_interface
+        .transaction_service_handle
+        .mark_imported_transaction_confirmed(tx_id, 1, block1_header.hash(), 1_700_000_000)
+        .await
+        .unwrap();
+    // a transaction can only be confirmed once
+    assert!(alice_ts_interface
+        .transaction_service_handle
+        .mark_imported_transaction_confirmed(tx_id, 1, block1_header.hash(), 1_700_000_000)
+        .await
+        .is_err());
+
+    alice_ts_interface
+        .base_node_rpc_mock_state
+        .set_query_deleted_response(base_node_proto::QueryDeletedResponse {
+            best_block_hash: block4_header.hash().to_vec(),
+            best_block_height: 4,
+            data: vec![base_node_proto::QueryDeletedData {
+                mined_at_height: 1,
+                block_mined_in: block1_header.hash().to_vec(),
+                height_deleted_at: 0,
+                block_deleted_in: Vec::new(),
+            }],
+        });
+    let mut oms_event_stream = alice_ts_interface.output_manager_service_handle.get_event_stream();
+    alice_ts_interface
+        .output_manager_service_handle
+        .validate_txos()
+        .await
+        .unwrap();
+    let delay = sleep(Duration::from_secs(30));
+    tokio::pin!(delay);
+    let mut validation_succeeded = false;
+    loop {
+        tokio::select! {
+            event = oms_event_stream.recv() => {
+                if let OutputManagerEvent::TxoValidationSuccess(_) = &*event.unwrap() {
+                    validation_succeeded = true;
+                    break;
+                }
+            },
+            () = &mut delay => {
+                break;
+            },
+        }
+    }
+    assert!(validation_succeeded, "TXO validation should have succeeded");
+
+    // the output is still mined in the block it was confirmed in, so it was not reverted by the validation
+    let outputs = alice_ts_interface.oms_db.fetch_outputs_by_tx_id(tx_id).unwrap();
+    assert_eq!(outputs.len(), 1);
+    assert_eq!(outputs[0].status, OutputStatus::Unspent);
+    assert_eq!(outputs[0].mined_height, Some(1));
+    assert_eq!(outputs[0].mined_in_block, Some(block1_header.hash()));
+    let tx = alice_ts_interface
+        .transaction_service_handle
+        .get_completed_transaction(tx_id)
+        .await
+        .unwrap();
+    assert_eq!(tx.status, TransactionStatus::OneSidedConfirmed);
+    assert_eq!(tx.mined_in_block, Some(block1_header.hash()));
+    // being mined gives the import a one-sided status, but it was not received as a one-sided payment
+    assert!(!tx.one_sided_payment);
+}
+
 #[tokio::test]
 async fn test_update_coinbase_tx_on_oms_validation() {
     let factories = CryptoFactories::default();
@@ -6059,6 +6515,7 @@ async fn test_update_coinbase_tx_on_oms_validation() {
             alice_address.clone(),
             "coinbase_confirmed".to_string(),
             ImportStatus::CoinbaseConfirmed,
+            false,
             None,
             None,
             None,
@@ -6076,6 +6533,7 @@ async fn test_update_coinbase_tx_on_oms_validation() {
             alice_address.clone(),
             "one-coinbase_unconfirmed 1".to_string(),
             ImportStatus::CoinbaseUnconfirmed,
+            false,
             None,
             None,
             None,
@@ -6093,6 +6551,7 @@ async fn test_update_coinbase_tx_on_oms_validation() {
             alice_address,
             "Coinbase_not_mined".to_string(),
             ImportStatus::CoinbaseUnconfirmed,
+            false,
             None,
             None,
             None,
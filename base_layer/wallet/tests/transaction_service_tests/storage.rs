@@ -166,7 +166,8 @@ pub async fn test_db_backend<T: TransactionBackend + 'static>(backend: T) {
         assert_eq!(&retrieved_outbound_txs.get(&i.tx_id).unwrap(), &i);
     }
 
-    db.increment_send_count(outbound_txs[0].tx_id).unwrap();
+    db.increment_send_count(outbound_txs[0].tx_id, Utc::now().naive_utc())
+        .unwrap();
     let retrieved_outbound_tx = db.get_pending_outbound_transaction(outbound_txs[0].tx_id).unwrap();
     assert_eq!(retrieved_outbound_tx.send_count, 1);
     assert!(retrieved_outbound_tx.last_send_timestamp.is_some());
@@ -177,6 +178,10 @@ pub async fn test_db_backend<T: TransactionBackend + 'static>(backend: T) {
     } else {
         panic!("Should have found outbound tx");
     }
+    assert_eq!(
+        db.get_transaction_direction(outbound_txs[0].tx_id).unwrap(),
+        Some(TransactionDirection::Outbound)
+    );
     let sender = stp.clone().build_single_round_message(&key_manager).await.unwrap();
     let commitment_mask_key = key_manager
         .get_next_key(TransactionKeyManagerBranch::CommitmentMask.get_branch_key())
@@ -275,7 +280,8 @@ pub async fn test_db_backend<T: TransactionBackend + 'static>(backend: T) {
         assert!(retrieved_tx.last_send_timestamp.is_none());
     }
 
-    db.increment_send_count(inbound_txs[0].tx_id).unwrap();
+    db.increment_send_count(inbound_txs[0].tx_id, Utc::now().naive_utc())
+        .unwrap();
     let retrieved_inbound_tx = db.get_pending_inbound_transaction(inbound_txs[0].tx_id).unwrap();
     assert_eq!(retrieved_inbound_tx.send_count, 1);
     assert!(retrieved_inbound_tx.last_send_timestamp.is_some());
@@ -286,6 +292,10 @@ pub async fn test_db_backend<T: TransactionBackend + 'static>(backend: T) {
     } else {
         panic!("Should have found inbound tx");
     }
+    assert_eq!(
+        db.get_transaction_direction(inbound_txs[0].tx_id).unwrap(),
+        Some(TransactionDirection::Inbound)
+    );
 
     let inbound_address = db
         .get_pending_transaction_counterparty_address_by_tx_id(inbound_txs[0].tx_id)
@@ -346,6 +356,7 @@ pub async fn test_db_backend<T: TransactionBackend + 'static>(backend: T) {
             mined_in_block: None,
             mined_timestamp: None,
             payment_id: Some(PaymentId::Empty),
+            one_sided_payment: false,
         });
         db.complete_outbound_transaction(outbound_txs[i].tx_id, completed_txs[i].clone())
             .unwrap();
@@ -373,8 +384,10 @@ pub async fn test_db_backend<T: TransactionBackend + 'static>(backend: T) {
         );
     }
 
-    db.increment_send_count(completed_txs[0].tx_id).unwrap();
-    db.increment_send_count(completed_txs[0].tx_id).unwrap();
+    db.increment_send_count(completed_txs[0].tx_id, Utc::now().naive_utc())
+        .unwrap();
+    db.increment_send_count(completed_txs[0].tx_id, Utc::now().naive_utc())
+        .unwrap();
     let retrieved_completed_tx = db.get_completed_transaction(completed_txs[0].tx_id).unwrap();
     assert_eq!(retrieved_completed_tx.send_count, 2);
     assert!(retrieved_completed_tx.last_send_timestamp.is_some());
@@ -407,6 +420,11 @@ pub async fn test_db_backend<T: TransactionBackend + 'static>(backend: T) {
     } else {
         panic!("Should have found completed tx");
     }
+    assert_eq!(
+        db.get_transaction_direction(completed_txs[0].tx_id).unwrap(),
+        Some(retrieved_completed_tx.direction)
+    );
+    assert_eq!(db.get_transaction_direction(TxId::from(12345u64)).unwrap(), None);
 
     let completed_txs_map = db.get_completed_transactions().unwrap();
     let num_completed_txs = completed_txs_map.len();
@@ -684,3 +702,209 @@ async fn import_tx_and_read_it_from_db() {
     assert_eq!(db_tx.first().unwrap().tx_id, TxId::from(3u64));
     assert_eq!(db_tx.first().unwrap().mined_height, Some(7));
 }
+
+#[tokio::test]
+async fn fetch_completed_transactions_since_timestamp() {
+    let db_name = format!("{}.sqlite3", random::string(8));
+    let db_tempdir = tempdir().unwrap();
+    let db_folder = db_tempdir.path().to_str().unwrap().to_string();
+    let db_path = format!("{}/{}", db_folder, db_name);
+    let connection = run_migration_and_create_sqlite_connection(db_path, 16).unwrap();
+
+    let mut key = [0u8; size_of::<Key>()];
+    OsRng.fill_bytes(&mut key);
+    let key_ga = Key::from_slice(&key);
+    let cipher = XChaCha20Poly1305::new(key_ga);
+    let sqlite_db = TransactionServiceSqliteDatabase::new(connection, cipher);
+
+    for (tx_id, timestamp) in [(1u64, 1_000i64), (2u64, 2_000i64), (3u64, 2_000i64)] {
+        let transaction = CompletedTransaction::new(
+            TxId::from(tx_id),
+            TariAddress::default(),
+            TariAddress::default(),
+            MicroMinotari::from(100000),
+            MicroMinotari::from(0),
+            Transaction::new(
+                Vec::new(),
+                Vec::new(),
+                Vec::new(),
+                PrivateKey::random(&mut OsRng),
+                PrivateKey::random(&mut OsRng),
+            ),
+            TransactionStatus::MinedConfirmed,
+            "message".to_string(),
+            NaiveDateTime::from_timestamp_opt(timestamp, 0).unwrap(),
+            TransactionDirection::Inbound,
+            Some(5),
+            Some(NaiveDateTime::from_timestamp_opt(0, 0).unwrap()),
+            None,
+        )
+        .unwrap();
+
+        sqlite_db
+            .write(WriteOperation::Insert(DbKeyValuePair::CompletedTransaction(
+                TxId::from(tx_id),
+                Box::new(transaction),
+            )))
+            .unwrap();
+    }
+
+    let db_tx = sqlite_db
+        .fetch_completed_transactions_since(NaiveDateTime::from_timestamp_opt(0, 0).unwrap())
+        .unwrap();
+    assert_eq!(db_tx.len(), 3);
+
+    // Transactions in the same second as the checkpoint are included
+    let db_tx = sqlite_db
+        .fetch_completed_transactions_since(NaiveDateTime::from_timestamp_opt(2_000, 0).unwrap())
+        .unwrap();
+    assert_eq!(db_tx.len(), 2);
+    assert!(db_tx.iter().all(|tx| tx.tx_id != TxId::from(1u64)));
+
+    let db_tx = sqlite_db
+        .fetch_completed_transactions_since(NaiveDateTime::from_timestamp_opt(2_001, 0).unwrap())
+        .unwrap();
+    assert!(db_tx.is_empty());
+    assert_eq!(sqlite_db.count_completed_transactions(None).unwrap(), 3);
+    assert_eq!(
+        sqlite_db
+            .count_completed_transactions(Some(TransactionStatus::MinedConfirmed))
+            .unwrap(),
+        3
+    );
+    assert_eq!(
+        sqlite_db
+            .count_completed_transactions(Some(TransactionStatus::Broadcast))
+            .unwrap(),
+        0
+    );
+
+    sqlite_db
+        .reject_completed_transaction(TxId::from(3u64), TxCancellationReason::Unknown)
+        .unwrap();
+    let db_tx = sqlite_db
+        .fetch_completed_transactions_since(NaiveDateTime::from_timestamp_opt(2_000, 0).unwrap())
+        .unwrap();
+    assert_eq!(db_tx.len(), 1);
+    assert_eq!(db_tx.first().unwrap().tx_id, TxId::from(2u64));
+    assert_eq!(sqlite_db.count_completed_transactions(None).unwrap(), 2);
+
+    // A transaction created before the checkpoint but mined after it is included
+    let transaction = CompletedTransaction::new(
+        TxId::from(4u64),
+        TariAddress::default(),
+        TariAddress::default(),
+        MicroMinotari::from(100000),
+        MicroMinotari::from(0),
+        Transaction::new(
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            PrivateKey::random(&mut OsRng),
+            PrivateKey::random(&mut OsRng),
+        ),
+        TransactionStatus::Broadcast,
+        "message".to_string(),
+        NaiveDateTime::from_timestamp_opt(1_500, 0).unwrap(),
+        TransactionDirection::Outbound,
+        None,
+        None,
+        None,
+    )
+    .unwrap();
+    sqlite_db
+        .write(WriteOperation::Insert(DbKeyValuePair::CompletedTransaction(
+            TxId::from(4u64),
+            Box::new(transaction),
+        )))
+        .unwrap();
+    let db_tx = sqlite_db
+        .fetch_completed_transactions_since(NaiveDateTime::from_timestamp_opt(2_001, 0).unwrap())
+        .unwrap();
+    assert!(db_tx.is_empty());
+    sqlite_db
+        .update_mined_height(
+            TxId::from(4u64),
+            6,
+            FixedHash::zero(),
+            3_000,
+            1,
+            true,
+            &TransactionStatus::Broadcast,
+        )
+        .unwrap();
+    let db_tx = sqlite_db
+        .fetch_completed_transactions_since(NaiveDateTime::from_timestamp_opt(2_001, 0).unwrap())
+        .unwrap();
+    assert_eq!(db_tx.len(), 1);
+    assert_eq!(db_tx.first().unwrap().tx_id, TxId::from(4u64));
+}
+
+#[tokio::test]
+async fn fetch_non_coinbase_completed_transactions() {
+    let db_name = format!("{}.sqlite3", random::string(8));
+    let db_tempdir = tempdir().unwrap();
+    let db_folder = db_tempdir.path().to_str().unwrap().to_string();
+    let db_path = format!("{}/{}", db_folder, db_name);
+    let connection = run_migration_and_create_sqlite_connection(db_path, 16).unwrap();
+
+    let mut key = [0u8; size_of::<Key>()];
+    OsRng.fill_bytes(&mut key);
+    let key_ga = Key::from_slice(&key);
+    let cipher = XChaCha20Poly1305::new(key_ga);
+    let sqlite_db = TransactionServiceSqliteDatabase::new(connection, cipher);
+
+    for (tx_id, status) in [
+        (1u64, TransactionStatus::MinedConfirmed),
+        (2u64, TransactionStatus::CoinbaseConfirmed),
+        (3u64, TransactionStatus::CoinbaseUnconfirmed),
+        (4u64, TransactionStatus::Broadcast),
+        (5u64, TransactionStatus::MinedUnconfirmed),
+    ] {
+        let transaction = CompletedTransaction::new(
+            TxId::from(tx_id),
+            TariAddress::default(),
+            TariAddress::default(),
+            MicroMinotari::from(100000),
+            MicroMinotari::from(0),
+            Transaction::new(
+                Vec::new(),
+                Vec::new(),
+                Vec::new(),
+                PrivateKey::random(&mut OsRng),
+                PrivateKey::random(&mut OsRng),
+            ),
+            status,
+            "message".to_string(),
+            Utc::now().naive_utc(),
+            TransactionDirection::Inbound,
+            Some(5),
+            Some(NaiveDateTime::from_timestamp_opt(0, 0).unwrap()),
+            None,
+        )
+        .unwrap();
+
+        sqlite_db
+            .write(WriteOperation::Insert(DbKeyValuePair::CompletedTransaction(
+                TxId::from(tx_id),
+                Box::new(transaction),
+            )))
+            .unwrap();
+    }
+
+    let mut tx_ids = sqlite_db
+        .fetch_non_coinbase_completed_transactions()
+        .unwrap()
+        .into_iter()
+        .map(|tx| tx.tx_id.as_u64())
+        .collect::<Vec<_>>();
+    tx_ids.sort_unstable();
+    assert_eq!(tx_ids, vec![1, 4, 5]);
+
+    sqlite_db
+        .reject_completed_transaction(TxId::from(5u64), TxCancellationReason::Unknown)
+        .unwrap();
+    let db_tx = sqlite_db.fetch_non_coinbase_completed_transactions().unwrap();
+    assert_eq!(db_tx.len(), 2);
+    assert!(db_tx.iter().all(|tx| tx.tx_id != TxId::from(5u64)));
+}
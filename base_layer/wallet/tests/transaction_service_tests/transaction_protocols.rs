@@ -33,6 +33,7 @@ use minotari_wallet::{
     },
     storage::sqlite_utilities::run_migration_and_create_sqlite_connection,
     transaction_service::{
+        clock::SystemClock,
         config::TransactionServiceConfig,
         error::TransactionServiceError,
         handle::{TransactionEvent, TransactionEventReceiver, TransactionEventSender},
@@ -194,6 +195,7 @@ pub async fn setup() -> (
         },
         shutdown_signal: shutdown.to_signal(),
         wallet_type,
+        clock: Arc::new(SystemClock),
     };
 
     (
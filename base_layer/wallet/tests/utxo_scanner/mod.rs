@@ -40,7 +40,7 @@ use minotari_wallet::{
     transaction_service::handle::TransactionServiceRequest,
     util::watch::Watch,
     utxo_scanner_service::{
-        handle::{UtxoScannerEvent, UtxoScannerHandle},
+        handle::{RecoveryStats, UtxoScannerEvent, UtxoScannerHandle},
         service::{ScannedBlock, UtxoScannerService},
         uxto_scanner_service_builder::UtxoScannerMode,
     },
@@ -172,11 +172,20 @@ async fn setup(
 
     let recovery_message_watch = Watch::new("unset".to_string());
     let one_sided_message_watch = Watch::new("unset".to_string());
+    let scan_start_height_watch = Watch::new(None);
+    let recovery_stats_watch = Watch::new(RecoveryStats::default());
 
     let recovery_message_watch_receiver = recovery_message_watch.get_receiver();
     let one_sided_message_watch_receiver = one_sided_message_watch.get_receiver();
-
-    let scanner_handle = UtxoScannerHandle::new(event_sender.clone(), one_sided_message_watch, recovery_message_watch);
+    let scan_start_height_watch_receiver = scan_start_height_watch.get_receiver();
+
+    let scanner_handle = UtxoScannerHandle::new(
+        event_sender.clone(),
+        one_sided_message_watch,
+        recovery_message_watch,
+        scan_start_height_watch,
+        recovery_stats_watch.clone(),
+    );
 
     let mut scanner_service_builder = UtxoScannerService::<WalletSqliteDatabase, WalletConnectivityMock>::builder();
 
@@ -213,6 +222,8 @@ async fn setup(
             base_node_service_handle,
             one_sided_message_watch_receiver,
             recovery_message_watch_receiver,
+            scan_start_height_watch_receiver,
+            recovery_stats_watch,
         )
         .await;
 
@@ -380,6 +391,10 @@ async fn test_utxo_scanner_recovery() {
                     assert_eq!(final_height, NUM_BLOCKS - 1);
                     assert_eq!(num_recovered, total_outputs_to_recover);
                     assert_eq!(value_recovered, total_amount_to_recover);
+                    let stats = test_interface.scanner_handle.get_recovery_stats();
+                    assert_eq!(stats.outputs_recovered, total_outputs_to_recover);
+                    assert_eq!(stats.outputs_failed_decode, 0);
+                    assert!(stats.outputs_scanned >= stats.outputs_recovered);
                     break;
                 }
             }
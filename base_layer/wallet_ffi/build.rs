@@ -40,6 +40,7 @@ fn main() {
             ..Default::default()
         },
         line_endings: LineEndingStyle::Native,
+        defines: [("feature = ffi-test".to_string(), "TARI_FFI_TEST".to_string())].into(),
         ..Default::default()
     };
 
@@ -35,7 +35,11 @@
 //! request_key is used to identify which request this callback references and a result of true means it was successful
 //! and false that the process timed out and new one will be started
 
-use std::{ffi::c_void, ops::Deref, sync::Arc};
+use std::{
+    ffi::c_void,
+    ops::Deref,
+    sync::{Arc, PoisonError, RwLock},
+};
 
 use log::*;
 use minotari_wallet::{
@@ -72,6 +76,61 @@ unsafe impl Send for Context {}
 
 const LOG_TARGET: &str = "wallet::transaction_service::callback_handler";
 
+type BalanceUpdatedCallback = unsafe extern "C" fn(context: *mut c_void, *mut Balance);
+type ConnectivityStatusCallback = unsafe extern "C" fn(context: *mut c_void, u64);
+type BaseNodeStateCallback = unsafe extern "C" fn(context: *mut c_void, *mut TariBaseNodeState);
+
+/// The callbacks that can be replaced after the callback handler has been started. Each function pointer is held
+/// behind a lock shared between the running callback handler and the wallet, so a new listener takes effect from the
+/// next event onwards.
+#[derive(Clone)]
+pub struct UpdatableCallbacks {
+    balance_updated: Arc<RwLock<BalanceUpdatedCallback>>,
+    connectivity_status: Arc<RwLock<ConnectivityStatusCallback>>,
+    base_node_state: Arc<RwLock<BaseNodeStateCallback>>,
+}
+
+impl UpdatableCallbacks {
+    fn new(
+        balance_updated: BalanceUpdatedCallback,
+        connectivity_status: ConnectivityStatusCallback,
+        base_node_state: BaseNodeStateCallback,
+    ) -> Self {
+        Self {
+            balance_updated: Arc::new(RwLock::new(balance_updated)),
+            connectivity_status: Arc::new(RwLock::new(connectivity_status)),
+            base_node_state: Arc::new(RwLock::new(base_node_state)),
+        }
+    }
+
+    pub fn set_balance_updated(&self, callback: BalanceUpdatedCallback) {
+        info!(target: LOG_TARGET, "BalanceUpdatedCallback -> Replacing Fn:  {:?}", callback);
+        *self.balance_updated.write().unwrap_or_else(PoisonError::into_inner) = callback;
+    }
+
+    pub fn set_connectivity_status(&self, callback: ConnectivityStatusCallback) {
+        info!(target: LOG_TARGET, "ConnectivityStatusCallback -> Replacing Fn:  {:?}", callback);
+        *self.connectivity_status.write().unwrap_or_else(PoisonError::into_inner) = callback;
+    }
+
+    pub fn set_base_node_state(&self, callback: BaseNodeStateCallback) {
+        info!(target: LOG_TARGET, "BaseNodeStateCallback -> Replacing Fn:  {:?}", callback);
+        *self.base_node_state.write().unwrap_or_else(PoisonError::into_inner) = callback;
+    }
+
+    fn balance_updated(&self) -> BalanceUpdatedCallback {
+        *self.balance_updated.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn connectivity_status(&self) -> ConnectivityStatusCallback {
+        *self.connectivity_status.read().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    fn base_node_state(&self) -> BaseNodeStateCallback {
+        *self.base_node_state.read().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
 pub struct CallbackHandler<TBackend>
 where TBackend: TransactionBackend + 'static
 {
@@ -88,12 +147,10 @@ where TBackend: TransactionBackend + 'static
     callback_transaction_cancellation: unsafe extern "C" fn(context: *mut c_void, *mut CompletedTransaction, u64),
     callback_txo_validation_complete: unsafe extern "C" fn(context: *mut c_void, u64, u64),
     callback_contacts_liveness_data_updated: unsafe extern "C" fn(context: *mut c_void, *mut ContactsLivenessData),
-    callback_balance_updated: unsafe extern "C" fn(context: *mut c_void, *mut Balance),
     callback_transaction_validation_complete: unsafe extern "C" fn(context: *mut c_void, u64, u64),
     callback_saf_messages_received: unsafe extern "C" fn(context: *mut c_void),
-    callback_connectivity_status: unsafe extern "C" fn(context: *mut c_void, u64),
     callback_wallet_scanned_height: unsafe extern "C" fn(context: *mut c_void, u64),
-    callback_base_node_state: unsafe extern "C" fn(context: *mut c_void, *mut TariBaseNodeState),
+    updatable_callbacks: UpdatableCallbacks,
     db: TransactionDatabase<TBackend>,
     base_node_service_event_stream: BaseNodeEventReceiver,
     transaction_service_event_stream: TransactionEventReceiver,
@@ -236,12 +293,14 @@ where TBackend: TransactionBackend + 'static
             callback_transaction_cancellation,
             callback_txo_validation_complete,
             callback_contacts_liveness_data_updated,
-            callback_balance_updated,
             callback_transaction_validation_complete,
             callback_saf_messages_received,
-            callback_connectivity_status,
             callback_wallet_scanned_height,
-            callback_base_node_state,
+            updatable_callbacks: UpdatableCallbacks::new(
+                callback_balance_updated,
+                callback_connectivity_status,
+                callback_base_node_state,
+            ),
             db,
             base_node_service_event_stream,
             transaction_service_event_stream,
@@ -257,6 +316,11 @@ where TBackend: TransactionBackend + 'static
         }
     }
 
+    /// The callbacks that can still be replaced once this handler has been started
+    pub fn updatable_callbacks(&self) -> UpdatableCallbacks {
+        self.updatable_callbacks.clone()
+    }
+
     #[allow(clippy::too_many_lines)]
     pub async fn start(mut self) {
         let mut shutdown_signal = self
@@ -515,7 +579,7 @@ where TBackend: TransactionBackend + 'static
                     );
                     let boxing = Box::into_raw(Box::new(balance));
                     unsafe {
-                        (self.callback_balance_updated)(self.context.0, boxing);
+                        (self.updatable_callbacks.balance_updated())(self.context.0, boxing);
                     }
                 }
             },
@@ -698,7 +762,7 @@ where TBackend: TransactionBackend + 'static
             "Calling Connectivity Status changed callback function"
         );
         unsafe {
-            (self.callback_connectivity_status)(self.context.0, status as u64);
+            (self.updatable_callbacks.connectivity_status())(self.context.0, status as u64);
         }
     }
 
@@ -744,7 +808,7 @@ where TBackend: TransactionBackend + 'static
         };
 
         unsafe {
-            (self.callback_base_node_state)(self.context.0, Box::into_raw(Box::new(state)));
+            (self.updatable_callbacks.base_node_state())(self.context.0, Box::into_raw(Box::new(state)));
         }
     }
 }
@@ -96,6 +96,7 @@ mod test {
         pub connectivity_status_callback_called: u64,
         pub wallet_scanner_height_callback_called: u64,
         pub base_node_state_changed_callback_invoked: bool,
+        pub replaced_connectivity_status_callback_called: u64,
     }
 
     impl CallbackState {
@@ -126,6 +127,7 @@ mod test {
                 connectivity_status_callback_called: 0,
                 wallet_scanner_height_callback_called: 0,
                 base_node_state_changed_callback_invoked: false,
+                replaced_connectivity_status_callback_called: 0,
             }
         }
     }
@@ -278,6 +280,12 @@ mod test {
         drop(lock);
     }
 
+    unsafe extern "C" fn replaced_connectivity_status_callback(_context: *mut c_void, status: u64) {
+        let mut lock = CALLBACK_STATE.lock().unwrap();
+        lock.replaced_connectivity_status_callback_called += status + 1;
+        drop(lock);
+    }
+
     unsafe extern "C" fn wallet_scanner_height_callback(_context: *mut c_void, height: u64) {
         let mut lock = CALLBACK_STATE.lock().unwrap();
         lock.wallet_scanner_height_callback_called += height;
@@ -536,6 +544,7 @@ mod test {
             base_node_state_changed_callback,
         );
 
+        let updatable_callbacks = callback_handler.updatable_callbacks();
         runtime.spawn(callback_handler.start());
 
         let ts_now = NaiveDateTime::from_timestamp_millis(
@@ -920,6 +929,19 @@ mod test {
         assert_eq!(lock.callback_transaction_validation_complete, 13);
         assert_eq!(lock.connectivity_status_callback_called, 7);
         assert_eq!(lock.wallet_scanner_height_callback_called, 1100);
+        assert_eq!(lock.replaced_connectivity_status_callback_called, 0);
+        drop(lock);
+
+        // after swapping the connectivity callback only the new listener is notified
+        updatable_callbacks.set_connectivity_status(replaced_connectivity_status_callback);
+        connectivity_tx.send(OnlineStatus::Online).unwrap();
+        thread::sleep(Duration::from_secs(2));
+        let lock = CALLBACK_STATE.lock().unwrap();
+        assert_eq!(lock.connectivity_status_callback_called, 7);
+        assert_eq!(
+            lock.replaced_connectivity_status_callback_called,
+            OnlineStatus::Online as u64 + 1
+        );
 
         drop(lock);
     }
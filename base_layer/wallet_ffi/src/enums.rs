@@ -29,3 +29,10 @@ pub enum SeedWordPushResult {
     InvalidObject,
     NoLanguageMatch,
 }
+
+/// Enum to indicate the result of verifying a message signature
+pub enum SignatureVerificationResult {
+    Valid,
+    Invalid,
+    MalformedInput,
+}
@@ -61,6 +61,20 @@ pub enum InterfaceError {
     InternalError(String),
     #[error("Balance Unavailable")]
     BalanceError,
+    #[error("The message is {length} bytes long, the maximum is {max} bytes")]
+    MessageTooLong { length: usize, max: usize },
+    #[error("The async runtime failed: `{0}`")]
+    RuntimeError(String),
+    #[error("The wallet has been stopped")]
+    WalletStopped,
+    #[error("A recovery or rescan is already in progress")]
+    ScanInProgress,
+    #[error("The wallet is watch-only and cannot spend")]
+    WatchOnlyWallet,
+    #[error("Decryption failed, the key or commitment does not match the encrypted data")]
+    DecryptionFailed,
+    #[error("The peer could not be dialed: `{0}`")]
+    DialFailed(String),
 }
 
 /// This struct is meant to hold an error for use by FFI client applications. The error has an integer code and string
@@ -112,6 +126,34 @@ impl From<InterfaceError> for LibWalletError {
                 code: 10,
                 message: format!("{:?}", v),
             },
+            InterfaceError::MessageTooLong { .. } => Self {
+                code: 11,
+                message: v.to_string(),
+            },
+            InterfaceError::RuntimeError(_) => Self {
+                code: 12,
+                message: format!("{:?}", v),
+            },
+            InterfaceError::WalletStopped => Self {
+                code: 13,
+                message: v.to_string(),
+            },
+            InterfaceError::ScanInProgress => Self {
+                code: 14,
+                message: v.to_string(),
+            },
+            InterfaceError::WatchOnlyWallet => Self {
+                code: 15,
+                message: v.to_string(),
+            },
+            InterfaceError::DecryptionFailed => Self {
+                code: 16,
+                message: v.to_string(),
+            },
+            InterfaceError::DialFailed(_) => Self {
+                code: 17,
+                message: v.to_string(),
+            },
         }
     }
 }
@@ -132,6 +174,10 @@ impl From<WalletError> for LibWalletError {
                 code: 115,
                 message: format!("{:?}", w),
             },
+            WalletError::OutputManagerError(OutputManagerError::ValidationInProgress) => Self {
+                code: 116,
+                message: format!("{:?}", w),
+            },
             WalletError::OutputManagerError(OutputManagerError::IncompleteTransaction(_)) => Self {
                 code: 102,
                 message: format!("{:?}", w),
@@ -255,6 +301,10 @@ impl From<WalletError> for LibWalletError {
                 code: 302,
                 message: format!("{:?}", w),
             },
+            WalletError::BaseNodeServiceError(_) => Self {
+                code: 303,
+                message: format!("{:?}", w),
+            },
             WalletError::ContactsServiceError(ContactsServiceError::ContactNotFound) => Self {
                 code: 401,
                 message: format!("{:?}", w),
@@ -332,6 +382,14 @@ impl From<WalletError> for LibWalletError {
                 code: 434,
                 message: format!("{:?}", w),
             },
+            WalletError::WalletStorageError(WalletStorageError::NotEncrypted) => Self {
+                code: 435,
+                message: format!("{:?}", w),
+            },
+            WalletError::WalletStorageError(WalletStorageError::DbPathDoesNotExist) => Self {
+                code: 436,
+                message: format!("{:?}", w),
+            },
             // these are general catch errors to try and reduce 999 when we get it with zero additional logging
             WalletError::SetLoggerError(_) => Self {
                 code: 994,
@@ -544,6 +602,32 @@ impl From<MnemonicError> for LibWalletError {
     }
 }
 
+#[derive(Debug, Error, PartialEq)]
+pub enum SeedPhraseError {
+    #[error("The seed phrase has `{0}` words, a seed phrase has 24 words")]
+    InvalidWordCount(usize),
+    #[error("The seed phrase has a word that is not in any mnemonic word list: `{0}`")]
+    WordNotFound(String),
+}
+
+/// This implementation maps the errors found when parsing a complete seed phrase to a set of LibWalletErrors.
+/// The mapping is explicitly managed here.
+impl From<SeedPhraseError> for LibWalletError {
+    fn from(v: SeedPhraseError) -> Self {
+        error!(target: LOG_TARGET, "{}", v);
+        match v {
+            SeedPhraseError::WordNotFound(_) => Self {
+                code: 911,
+                message: v.to_string(),
+            },
+            SeedPhraseError::InvalidWordCount(_) => Self {
+                code: 912,
+                message: v.to_string(),
+            },
+        }
+    }
+}
+
 impl From<KeyManagerServiceError> for LibWalletError {
     fn from(err: KeyManagerServiceError) -> Self {
         error!(target: LOG_TARGET, "{}", format!("{:?}", err));
@@ -22,13 +22,13 @@
 // USE OF THIS SOFTWARE, EVEN IF ADVISED OF THE POSSIBILITY OF SUCH DAMAGE.
 
 use std::{
-    ffi::{c_int, c_ulonglong},
+    ffi::{c_char, c_int, c_ulonglong, CString},
     ptr,
 };
 
 use tari_common_types::types::BlockHash;
 use tari_comms::peer_manager::NodeId;
-use tari_utilities::ByteArray;
+use tari_utilities::{hex::Hex, ByteArray};
 
 use crate::{
     error::{InterfaceError, LibWalletError},
@@ -157,6 +157,38 @@ pub unsafe extern "C" fn basenode_state_get_best_block(
     Box::into_raw(Box::new(ByteVector((*ptr).best_block_hash.to_vec())))
 }
 
+/// Extracts the best block hash as a hex string
+///
+/// ## Arguments
+/// `ptr` - The pointer to a `TariBaseNodeState`
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `*mut c_char` - The hex encoded block hash of the current tip of the longest valid chain. Note that it returns an
+/// empty string if there was an error
+///
+/// # Safety
+/// The ```string_destroy``` method must be called when finished with a string from rust to prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn basenode_state_get_best_block_hash(
+    ptr: *mut TariBaseNodeState,
+    error_out: *mut c_int,
+) -> *mut c_char {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+
+    if ptr.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("ptr".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return CString::new("").expect("Blank CString will not fail.").into_raw();
+    }
+
+    CString::new((*ptr).best_block_hash.to_hex())
+        .expect("Hex string will not fail")
+        .into_raw()
+}
+
 /// Extracts a timestamp of the best block
 ///
 /// ## Arguments
@@ -330,6 +362,23 @@ pub unsafe extern "C" fn basenode_state_get_latency(ptr: *mut TariBaseNodeState,
     (*ptr).latency
 }
 
+/// Frees memory for a `TariBaseNodeState` received through the base node state callback
+///
+/// ## Arguments
+/// `ptr` - The pointer to a `TariBaseNodeState`
+///
+/// ## Returns
+/// `()` - Does not return a value, equivalent to void in C
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn basenode_state_destroy(ptr: *mut TariBaseNodeState) {
+    if !ptr.is_null() {
+        drop(Box::from_raw(ptr))
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use tari_common_types::types::FixedHash;
@@ -382,6 +431,13 @@ mod tests {
             assert_eq!(best_block, original_best_block);
             assert_eq!(error_code, 0);
 
+            let best_block_hash = basenode_state_get_best_block_hash(boxed_state, &mut error_code);
+            assert_eq!(error_code, 0);
+            assert_eq!(
+                CString::from_raw(best_block_hash).to_str().unwrap(),
+                original_best_block.to_hex()
+            );
+
             // ----------------------------------------------------------------------------
             // other scalars
 
@@ -411,6 +467,8 @@ mod tests {
 
             assert_eq!(basenode_state_get_latency(boxed_state, &mut error_code), 115);
             assert_eq!(error_code, 0);
+
+            basenode_state_destroy(boxed_state);
         }
     }
 }
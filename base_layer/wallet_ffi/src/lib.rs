@@ -49,23 +49,26 @@
 
 use core::ptr;
 use std::{
+    collections::HashMap,
     convert::{TryFrom, TryInto},
     ffi::{CStr, CString},
     fmt::{Display, Formatter},
+    future::Future,
     mem::ManuallyDrop,
     num::NonZeroU16,
+    panic::{self, AssertUnwindSafe},
     path::PathBuf,
     slice,
     str::FromStr,
-    sync::Arc,
+    sync::{Arc, OnceLock},
     time::Duration,
 };
 
-use chrono::{DateTime, Local};
+use chrono::{DateTime, Local, NaiveDateTime, Utc};
 use error::LibWalletError;
 use ffi_basenode_state::TariBaseNodeState;
 use itertools::Itertools;
-use libc::{c_char, c_int, c_uchar, c_uint, c_ulonglong, c_ushort, c_void};
+use libc::{c_char, c_int, c_longlong, c_uchar, c_uint, c_ulonglong, c_ushort, c_void};
 use log::*;
 use log4rs::{
     append::{
@@ -80,11 +83,11 @@ use log4rs::{
     encode::pattern::PatternEncoder,
 };
 use minotari_wallet::{
-    base_node_service::config::BaseNodeServiceConfig,
+    base_node_service::{config::BaseNodeServiceConfig, error::BaseNodeServiceError, handle::BaseNodeEvent},
     connectivity_service::{WalletConnectivityHandle, WalletConnectivityInterface},
     error::{WalletError, WalletStorageError},
     output_manager_service::{
-        error::OutputManagerError,
+        error::{OutputManagerError, OutputManagerStorageError},
         storage::{
             database::{OutputBackendQuery, OutputManagerDatabase, SortDirection},
             models::DbWalletOutput,
@@ -95,14 +98,21 @@ use minotari_wallet::{
     storage::{
         database::WalletDatabase,
         sqlite_db::wallet::WalletSqliteDatabase,
-        sqlite_utilities::{get_last_network, get_last_version, initialize_sqlite_database_backends},
+        sqlite_utilities::{
+            apply_database_encryption,
+            get_last_network,
+            get_last_version,
+            initialize_sqlite_database_backends,
+            remove_database_encryption,
+        },
     },
     transaction_service::{
         config::TransactionServiceConfig,
         error::TransactionServiceError,
+        service::MINIMUM_FEE_PER_GRAM,
         storage::{
             database::TransactionDatabase,
-            models::{CompletedTransaction, InboundTransaction, OutboundTransaction},
+            models::{CompletedTransaction, InboundTransaction, OutboundTransaction, WalletTransaction},
         },
     },
     utxo_scanner_service::{service::UtxoScannerService, RECOVERY_KEY},
@@ -119,16 +129,17 @@ use tari_common::{
 };
 use tari_common_types::{
     emoji::{emoji_set, EMOJI},
-    tari_address::{TariAddress, TariAddressError},
-    transaction::{TransactionDirection, TransactionStatus, TxId},
-    types::{ComAndPubSignature, Commitment, PublicKey, RangeProof, SignatureWithDomain},
-    wallet_types::WalletType,
+    tari_address::{TariAddress, TariAddressError, TariAddressFeatures, TARI_ADDRESS_INTERNAL_DUAL_SIZE},
+    transaction::{ImportStatus, TransactionDirection, TransactionStatus, TxId},
+    types::{BlockHash, ComAndPubSignature, Commitment, PublicKey, RangeProof, SignatureWithDomain},
+    wallet_types::{ProvidedKeysWallet, WalletType},
 };
 use tari_comms::{
     multiaddr::Multiaddr,
     net_address::{MultiaddrRange, MultiaddrRangeList, IP4_TCP_TEST_ADDR_RANGE},
     peer_manager::{NodeIdentity, PeerQuery},
-    transports::MemoryTransport,
+    tor::TorIdentity,
+    transports::{predicate::is_onion_address, MemoryTransport},
     types::CommsPublicKey,
 };
 use tari_comms_dht::{
@@ -140,12 +151,13 @@ use tari_comms_dht::{
 };
 use tari_contacts::contacts_service::{handle::ContactsServiceHandle, types::Contact};
 use tari_core::{
-    borsh::FromBytes,
+    borsh::{FromBytes, SerializedSize},
     consensus::ConsensusManager,
     transactions::{
+        fee::Fee,
         tari_amount::MicroMinotari,
         transaction_components::{
-            encrypted_data::PaymentId,
+            encrypted_data::{EncryptedDataError, PaymentId},
             CoinBaseExtra,
             OutputFeatures,
             OutputFeaturesVersion,
@@ -153,15 +165,18 @@ use tari_core::{
             RangeProofType,
             UnblindedOutput,
         },
+        weight::TransactionWeight,
         CryptoFactories,
     },
 };
 use tari_crypto::{
+    commitment::HomomorphicCommitmentFactory,
     keys::{PublicKey as PublicKeyTrait, SecretKey},
     tari_utilities::{ByteArray, Hidden},
 };
 use tari_key_manager::{
     cipher_seed::CipherSeed,
+    error::MnemonicError,
     mnemonic::{Mnemonic, MnemonicLanguage},
     SeedWords,
 };
@@ -170,6 +185,7 @@ use tari_p2p::{
     transport::MemoryTransportConfig,
     Network,
     PeerSeedsConfig,
+    Socks5TransportConfig,
     SocksAuthentication,
     TcpTransportConfig,
     TorControlAuthentication,
@@ -185,13 +201,13 @@ use tari_utilities::{
     hex::{Hex, HexError},
     SafePassword,
 };
-use tokio::runtime::Runtime;
+use tokio::{runtime::Runtime, sync::broadcast};
 use zeroize::Zeroize;
 
 use crate::{
-    callback_handler::{CallbackHandler, Context},
-    enums::SeedWordPushResult,
-    error::{InterfaceError, TransactionError},
+    callback_handler::{CallbackHandler, Context, UpdatableCallbacks},
+    enums::{SeedWordPushResult, SignatureVerificationResult},
+    error::{InterfaceError, SeedPhraseError, TransactionError},
     tasks::recovery_event_monitoring,
 };
 
@@ -211,6 +227,50 @@ mod consts {
 }
 
 const LOG_TARGET: &str = "wallet_ffi";
+/// The maximum length in bytes of a message attached to a transaction. The message is only kept in the wallet
+/// databases and the messages exchanged by the wallets, it is not part of the transaction, so this is a limit of this
+/// library that keeps those records small.
+const MAX_TRANSACTION_MESSAGE_LENGTH: usize = 256;
+/// The maximum length in bytes of the payment id of a one-sided transaction. The payment id is stored in the encrypted
+/// data of the recipient's output, which holds at most 256 bytes of it, and the wallet prepends its own address.
+const MAX_PAYMENT_ID_LENGTH: usize = 256 - TARI_ADDRESS_INTERNAL_DUAL_SIZE;
+/// The client key value store key of the message used when a transaction is sent without one. It is reserved, so
+/// `wallet_set_key_value`, `wallet_get_value` and `wallet_clear_value` reject it and the message can only be changed
+/// through `wallet_set_default_message`, which enforces `MAX_TRANSACTION_MESSAGE_LENGTH`.
+const DEFAULT_MESSAGE_KEY: &str = "wallet_ffi.default_transaction_message";
+
+/// The Pedersen and range proof generators are expensive to set up, so a single set is shared by every FFI call
+static CRYPTO_FACTORIES: OnceLock<CryptoFactories> = OnceLock::new();
+
+fn crypto_factories() -> &'static CryptoFactories {
+    CRYPTO_FACTORIES.get_or_init(CryptoFactories::default)
+}
+
+/// Building a consensus manager sets up the constants of every consensus period, so the manager of the current network
+/// is built once and shared by every FFI call that weighs a transaction
+static CONSENSUS_MANAGER: OnceLock<ConsensusManager> = OnceLock::new();
+
+/// The consensus transaction weighting of the current network at `height`, the latest parameters are used when no
+/// height is given
+fn transaction_weighting(height: Option<u64>) -> Result<TransactionWeight, LibWalletError> {
+    let network = Network::get_current_or_user_setting_or_default();
+    let consensus_manager = match CONSENSUS_MANAGER.get() {
+        Some(consensus_manager) if consensus_manager.network().as_network() == network => consensus_manager.clone(),
+        _ => {
+            let consensus_manager = ConsensusManager::builder(network)
+                .build()
+                .map_err(|e| LibWalletError::from(InterfaceError::InternalError(e.to_string())))?;
+            // Until a wallet sets the network it can still change, so only a manager for the set network is kept
+            if Network::is_set() {
+                let _result = CONSENSUS_MANAGER.set(consensus_manager.clone());
+            }
+            consensus_manager
+        },
+    };
+    Ok(*consensus_manager
+        .consensus_constants(height.unwrap_or(u64::MAX))
+        .transaction_weight_params())
+}
 
 pub type TariTransportConfig = TransportConfig;
 pub type TariPublicKey = PublicKey;
@@ -264,6 +324,52 @@ pub struct TariWallet {
     runtime: Runtime,
     shutdown: Shutdown,
     context: Context,
+    callbacks: UpdatableCallbacks,
+}
+
+/// Drives a future to completion on the wallet runtime. A panic while doing so is returned as an
+/// `InterfaceError::RuntimeError`, so that a runtime failure is reported distinctly from the errors of the future
+/// itself.
+fn block_on_runtime<F: Future>(runtime: &Runtime, future: F) -> Result<F::Output, InterfaceError> {
+    panic::catch_unwind(AssertUnwindSafe(|| runtime.block_on(future))).map_err(|e| {
+        let msg = e
+            .downcast_ref::<&str>()
+            .map(|s| s.to_string())
+            .or_else(|| e.downcast_ref::<String>().cloned())
+            .unwrap_or_else(|| "Unknown panic".to_string());
+        InterfaceError::RuntimeError(msg)
+    })
+}
+
+/// Fails with `InterfaceError::WalletStopped` once `wallet_stop` has been called, so that calls which need the wallet
+/// services return straight away instead of waiting on services that are no longer running.
+fn ensure_wallet_running(wallet: &TariWallet) -> Result<(), InterfaceError> {
+    if wallet.shutdown.is_triggered() {
+        return Err(InterfaceError::WalletStopped);
+    }
+    Ok(())
+}
+
+/// Fails with `InterfaceError::WatchOnlyWallet` if the wallet was created with `wallet_create_watch_only`, as it has
+/// no private spend key to sign with.
+fn ensure_wallet_can_spend(wallet: &TariWallet) -> Result<(), InterfaceError> {
+    if wallet.wallet.is_watch_only() {
+        return Err(InterfaceError::WatchOnlyWallet);
+    }
+    Ok(())
+}
+
+/// Replaces an empty transaction message with the message set with `wallet_set_default_message`, so that every send
+/// function attaches the same default.
+fn message_or_default(wallet: &TariWallet, message: String) -> Result<String, WalletStorageError> {
+    if !message.is_empty() {
+        return Ok(message);
+    }
+    Ok(wallet
+        .wallet
+        .db
+        .get_client_key_value(DEFAULT_MESSAGE_KEY.to_owned())?
+        .unwrap_or_default())
 }
 
 #[derive(Debug)]
@@ -280,6 +386,8 @@ pub enum TariUtxoSort {
     ValueDesc = 1,
     MinedHeightAsc = 2,
     MinedHeightDesc = 3,
+    MinedTimestampAsc = 4,
+    MinedTimestampDesc = 5,
 }
 
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
@@ -290,6 +398,7 @@ pub enum TariTypeTag {
     Commitment = 2,
     U64 = 3,
     I64 = 4,
+    Kernel = 5,
 }
 
 impl Display for TariTypeTag {
@@ -300,6 +409,7 @@ impl Display for TariTypeTag {
             TariTypeTag::Commitment => write!(f, "Commitment"),
             TariTypeTag::U64 => write!(f, "U64"),
             TariTypeTag::I64 => write!(f, "I64"),
+            TariTypeTag::Kernel => write!(f, "Kernel"),
         }
     }
 }
@@ -315,6 +425,7 @@ pub struct TariUtxo {
     pub status: u8,
     pub coinbase_extra: *const c_char,
     pub payment_id: *const c_char,
+    pub is_coinbase: bool,
 }
 
 impl From<DbWalletOutput> for TariUtxo {
@@ -351,6 +462,7 @@ impl From<DbWalletOutput> for TariUtxo {
             )
             .expect("failed to obtain string from a payment id")
             .into_raw(),
+            is_coinbase: x.wallet_output.features.is_coinbase(),
         }
     }
 }
@@ -426,6 +538,23 @@ impl From<Vec<Commitment>> for TariVector {
     }
 }
 
+impl From<Vec<TariTransactionKernel>> for TariVector {
+    fn from(v: Vec<TariTransactionKernel>) -> Self {
+        let mut v = ManuallyDrop::new(
+            v.into_iter()
+                .map(|x| Box::into_raw(Box::new(x)))
+                .collect::<Vec<*mut TariTransactionKernel>>(),
+        );
+
+        Self {
+            tag: TariTypeTag::Kernel,
+            len: v.len(),
+            cap: v.capacity(),
+            ptr: v.as_mut_ptr() as *mut c_void,
+        }
+    }
+}
+
 impl From<Vec<DbWalletOutput>> for TariVector {
     fn from(v: Vec<DbWalletOutput>) -> TariVector {
         let mut v = ManuallyDrop::new(v.into_iter().map(TariUtxo::from).collect_vec());
@@ -508,6 +637,70 @@ impl TariVector {
 
         Ok(unsafe { Vec::from_raw_parts(self.ptr as *mut TariUtxo, self.len, self.cap) })
     }
+
+    fn to_u64_vec(&self) -> Result<Vec<u64>, InterfaceError> {
+        if self.tag != TariTypeTag::U64 {
+            return Err(InterfaceError::InvalidArgument(format!(
+                "expecting U64, got {}",
+                self.tag
+            )));
+        }
+
+        if self.ptr.is_null() {
+            return Err(InterfaceError::NullError(String::from(
+                "tari vector of u64 has null pointer",
+            )));
+        }
+
+        // copying rather than taking ownership, the buffer may have been allocated by `create_tari_vector` with a
+        // different layout and it still belongs to the caller
+        if self.len == 0 {
+            return Ok(Vec::new());
+        }
+        Ok(unsafe { slice::from_raw_parts(self.ptr as *const u64, self.len) }.to_vec())
+    }
+
+    fn get_u64_at(&self, position: usize) -> Result<u64, InterfaceError> {
+        if self.tag != TariTypeTag::U64 {
+            return Err(InterfaceError::InvalidArgument(format!(
+                "expecting U64, got {}",
+                self.tag
+            )));
+        }
+
+        if self.ptr.is_null() {
+            return Err(InterfaceError::NullError(String::from(
+                "tari vector of u64 has null pointer",
+            )));
+        }
+
+        if position >= self.len {
+            return Err(InterfaceError::PositionInvalidError);
+        }
+
+        Ok(unsafe { *(self.ptr as *const u64).add(position) })
+    }
+
+    fn get_i64_at(&self, position: usize) -> Result<i64, InterfaceError> {
+        if self.tag != TariTypeTag::I64 {
+            return Err(InterfaceError::InvalidArgument(format!(
+                "expecting I64, got {}",
+                self.tag
+            )));
+        }
+
+        if self.ptr.is_null() {
+            return Err(InterfaceError::NullError(String::from(
+                "tari vector of i64 has null pointer",
+            )));
+        }
+
+        if position >= self.len {
+            return Err(InterfaceError::PositionInvalidError);
+        }
+
+        Ok(unsafe { *(self.ptr as *const i64).add(position) })
+    }
 }
 
 /// Initialize a new `TariVector`
@@ -531,6 +724,61 @@ pub unsafe extern "C" fn create_tari_vector(tag: TariTypeTag) -> *mut TariVector
     }))
 }
 
+/// Gets the number of elements in a `TariVector`.
+///
+/// ## Arguments
+/// `tv` - The pointer to `TariVector`
+/// `error_ptr` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `usize` - Returns the number of elements, or 0 if the vector is null.
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn tari_vector_get_length(tv: *const TariVector, error_ptr: *mut i32) -> usize {
+    if tv.is_null() {
+        error!(target: LOG_TARGET, "tari vector pointer is null");
+        ptr::replace(
+            error_ptr,
+            LibWalletError::from(InterfaceError::NullError("vector".to_string())).code,
+        );
+        return 0;
+    }
+
+    ptr::replace(error_ptr, 0);
+    (*tv).len
+}
+
+/// Gets the type-tag of the payload of a `TariVector`.
+///
+/// ## Arguments
+/// `tv` - The pointer to `TariVector`
+/// `error_ptr` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `TariTypeTag` - Returns the type-tag of the vector. Note that it returns `Text` if the vector is null, the
+/// error_ptr must be checked to tell the two apart.
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn tari_vector_get_tag(tv: *const TariVector, error_ptr: *mut i32) -> TariTypeTag {
+    if tv.is_null() {
+        error!(target: LOG_TARGET, "tari vector pointer is null");
+        ptr::replace(
+            error_ptr,
+            LibWalletError::from(InterfaceError::NullError("vector".to_string())).code,
+        );
+        return TariTypeTag::Text;
+    }
+
+    ptr::replace(error_ptr, 0);
+    (*tv).tag
+}
+
 /// Appending a given value to the back of the vector.
 ///
 /// ## Arguments
@@ -590,6 +838,134 @@ pub unsafe extern "C" fn tari_vector_push_string(tv: *mut TariVector, s: *const
     ptr::replace(error_ptr, 0);
 }
 
+/// Appending a given u64 value to the back of a `TariVector` tagged as `U64`.
+///
+/// ## Arguments
+/// `tv` - The pointer to `TariVector`
+/// `value` - An item to push.
+/// `error_ptr` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `()` - Does not return a value, equivalent to void in C
+///
+/// # Safety
+/// `destroy_tari_vector()` must be called to free the allocated memory.
+#[no_mangle]
+pub unsafe extern "C" fn tari_vector_push_u64(tv: *mut TariVector, value: c_ulonglong, error_ptr: *mut i32) {
+    if tv.is_null() {
+        error!(target: LOG_TARGET, "tari vector pointer is null");
+        ptr::replace(
+            error_ptr,
+            LibWalletError::from(InterfaceError::NullError("vector".to_string())).code,
+        );
+        return;
+    }
+
+    // unpacking into native vector
+    let v = match (*tv).to_u64_vec() {
+        Ok(v) => v,
+        Err(e) => {
+            error!(target: LOG_TARGET, "{:#?}", e);
+            ptr::replace(error_ptr, LibWalletError::from(e).code);
+            return;
+        },
+    };
+
+    // appending new value to the fresh copy, which is handed over to the `TariVector`
+    let mut v = ManuallyDrop::new(v);
+    v.push(value);
+
+    (*tv).len = v.len();
+    (*tv).cap = v.capacity();
+    (*tv).ptr = v.as_mut_ptr() as *mut c_void;
+    ptr::replace(error_ptr, 0);
+}
+
+/// Gets the U64 element at the given position of a `TariVector` tagged as `U64`.
+///
+/// ## Arguments
+/// `tv` - The pointer to `TariVector`
+/// `position` - The zero based index of the element
+/// `error_ptr` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `c_ulonglong` - Returns the element, or 0 if the vector is null, is not tagged as `U64` or the position is out of
+/// range.
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn tari_vector_get_u64_at(
+    tv: *mut TariVector,
+    position: usize,
+    error_ptr: *mut i32,
+) -> c_ulonglong {
+    if tv.is_null() {
+        error!(target: LOG_TARGET, "tari vector pointer is null");
+        ptr::replace(
+            error_ptr,
+            LibWalletError::from(InterfaceError::NullError("vector".to_string())).code,
+        );
+        return 0;
+    }
+
+    match (*tv).get_u64_at(position) {
+        Ok(v) => {
+            ptr::replace(error_ptr, 0);
+            v as c_ulonglong
+        },
+        Err(e) => {
+            error!(target: LOG_TARGET, "{:#?}", e);
+            ptr::replace(error_ptr, LibWalletError::from(e).code);
+            0
+        },
+    }
+}
+
+/// Gets the I64 element at the given position of a `TariVector` tagged as `I64`.
+///
+/// ## Arguments
+/// `tv` - The pointer to `TariVector`
+/// `position` - The zero based index of the element
+/// `error_ptr` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `c_longlong` - Returns the element, or 0 if the vector is null, is not tagged as `I64` or the position is out of
+/// range.
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn tari_vector_get_i64_at(
+    tv: *mut TariVector,
+    position: usize,
+    error_ptr: *mut i32,
+) -> c_longlong {
+    if tv.is_null() {
+        error!(target: LOG_TARGET, "tari vector pointer is null");
+        ptr::replace(
+            error_ptr,
+            LibWalletError::from(InterfaceError::NullError("vector".to_string())).code,
+        );
+        return 0;
+    }
+
+    match (*tv).get_i64_at(position) {
+        Ok(v) => {
+            ptr::replace(error_ptr, 0);
+            v as c_longlong
+        },
+        Err(e) => {
+            error!(target: LOG_TARGET, "{:#?}", e);
+            ptr::replace(error_ptr, LibWalletError::from(e).code);
+            0
+        },
+    }
+}
+
 /// Frees memory allocated for `TariVector`.
 ///
 /// ## Arguments
@@ -626,6 +1002,51 @@ pub unsafe extern "C" fn destroy_tari_coin_preview(p: *mut TariCoinPreview) {
     }
 }
 
+/// Frees memory allocated for `TariUtxo`.
+///
+/// ## Arguments
+/// `utxo` - The pointer to `TariUtxo`
+///
+/// ## Returns
+/// `()` - Does not return a value, equivalent to void in C
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn destroy_tari_utxo(utxo: *mut TariUtxo) {
+    if !utxo.is_null() {
+        let x = Box::from_raw(utxo);
+        drop(CString::from_raw(x.commitment as *mut c_char));
+        drop(CString::from_raw(x.coinbase_extra as *mut c_char));
+        drop(CString::from_raw(x.payment_id as *mut c_char));
+    }
+}
+
+/// Gets the number of blocks until a `TariUtxo` matures and can be spent.
+///
+/// ## Arguments
+/// `utxo` - The pointer to `TariUtxo`
+/// `current_height` - The current tip height of the chain
+///
+/// ## Returns
+/// `c_ulonglong` - Returns the number of blocks until the output's `lock_height` is reached, 0 if it is already
+/// mature or if `utxo` is null.
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn tari_utxo_blocks_until_mature(
+    utxo: *const TariUtxo,
+    current_height: c_ulonglong,
+) -> c_ulonglong {
+    if utxo.is_null() {
+        error!(target: LOG_TARGET, "tari utxo pointer is null");
+        return 0;
+    }
+
+    (*utxo).lock_height.saturating_sub(current_height)
+}
+
 /// -------------------------------- Strings ------------------------------------------------ ///
 
 /// Frees memory for a char array
@@ -891,36 +1312,115 @@ pub unsafe extern "C" fn byte_vector_get_length(vec: *const ByteVector, error_ou
     (*vec).0.len() as c_uint
 }
 
-/// -------------------------------------------------------------------------------------------- ///
-
-/// -------------------------------- Public Key ------------------------------------------------ ///
-
-/// Creates a TariPublicKey from a ByteVector
+/// Creates a new ByteVector containing the bytes of `a` followed by the bytes of `b`
 ///
 /// ## Arguments
-/// `bytes` - The pointer to a ByteVector
+/// `a` - The pointer to the first ByteVector
+/// `b` - The pointer to the second ByteVector
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
 /// ## Returns
-/// `TariPublicKey` - Returns a public key. Note that it will be ptr::null_mut() if bytes is null or
-/// if there was an error with the contents of bytes
+/// `*mut ByteVector` - Pointer to the created ByteVector. Note that it will be ptr::null_mut() if either a or b is null
 ///
 /// # Safety
-/// The ```public_key_destroy``` function must be called when finished with a TariPublicKey to prevent a memory leak
+/// The ```byte_vector_destroy``` function must be called when finished with a ByteVector to prevent a memory leak
 #[no_mangle]
-pub unsafe extern "C" fn public_key_create(bytes: *mut ByteVector, error_out: *mut c_int) -> *mut TariPublicKey {
+pub unsafe extern "C" fn byte_vector_concat(
+    a: *const ByteVector,
+    b: *const ByteVector,
+    error_out: *mut c_int,
+) -> *mut ByteVector {
     let mut error = 0;
     ptr::swap(error_out, &mut error as *mut c_int);
-    if bytes.is_null() {
-        error = LibWalletError::from(InterfaceError::NullError("bytes".to_string())).code;
+    if a.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("a".to_string())).code;
         ptr::swap(error_out, &mut error as *mut c_int);
         return ptr::null_mut();
     }
-    let v = (*bytes).0.clone();
-    let pk = TariPublicKey::from_canonical_bytes(&v);
-    match pk {
-        Ok(pk) => Box::into_raw(Box::new(pk)),
+    if b.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("b".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+
+    let mut bytes = Vec::with_capacity((*a).0.len() + (*b).0.len());
+    bytes.extend_from_slice(&(*a).0);
+    bytes.extend_from_slice(&(*b).0);
+    Box::into_raw(Box::new(ByteVector(bytes)))
+}
+
+/// Creates a new ByteVector containing `len` bytes of a ByteVector starting at `start`
+///
+/// ## Arguments
+/// `vec` - The pointer to a ByteVector
+/// `start` - The position of the first byte of the slice
+/// `len` - The number of bytes in the slice
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `*mut ByteVector` - Pointer to the created ByteVector. Note that it will be ptr::null_mut() if vec is null or if
+/// the slice does not lie within the ByteVector
+///
+/// # Safety
+/// The ```byte_vector_destroy``` function must be called when finished with a ByteVector to prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn byte_vector_slice(
+    vec: *const ByteVector,
+    start: c_uint,
+    len: c_uint,
+    error_out: *mut c_int,
+) -> *mut ByteVector {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if vec.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("vec".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+
+    let start = start as usize;
+    match start.checked_add(len as usize).and_then(|end| (*vec).0.get(start..end)) {
+        Some(bytes) => Box::into_raw(Box::new(ByteVector(bytes.to_vec()))),
+        None => {
+            error = LibWalletError::from(InterfaceError::PositionInvalidError).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            ptr::null_mut()
+        },
+    }
+}
+
+/// -------------------------------------------------------------------------------------------- ///
+
+/// -------------------------------- Public Key ------------------------------------------------ ///
+
+/// Creates a TariPublicKey from a ByteVector
+///
+/// ## Arguments
+/// `bytes` - The pointer to a ByteVector
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `TariPublicKey` - Returns a public key. Note that it will be ptr::null_mut() if bytes is null or
+/// if there was an error with the contents of bytes
+///
+/// # Safety
+/// The ```public_key_destroy``` function must be called when finished with a TariPublicKey to prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn public_key_create(bytes: *mut ByteVector, error_out: *mut c_int) -> *mut TariPublicKey {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if bytes.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("bytes".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+    let v = (*bytes).0.clone();
+    let pk = TariPublicKey::from_canonical_bytes(&v);
+    match pk {
+        Ok(pk) => Box::into_raw(Box::new(pk)),
         Err(e) => {
             error = LibWalletError::from(e).code;
             ptr::swap(error_out, &mut error as *mut c_int);
@@ -1094,6 +1594,66 @@ pub unsafe extern "C" fn public_key_from_hex(key: *const c_char, error_out: *mut
     }
 }
 
+/// Converts a TariPublicKey to its hex encoded representation
+///
+/// ## Arguments
+/// `pk` - The pointer to a TariPublicKey
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `*mut c_char` - Returns a pointer to a char array containing the hex encoded public key. Note that it returns
+/// ptr::null_mut() if pk is null
+///
+/// # Safety
+/// The ```string_destroy``` method must be called when finished with a string from rust to prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn public_key_to_hex(pk: *mut TariPublicKey, error_out: *mut c_int) -> *mut c_char {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if pk.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("public key".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+    CString::new((*pk).to_hex()).expect("Hex will not fail.").into_raw()
+}
+
+/// Compares two TariPublicKeys for equality
+///
+/// ## Arguments
+/// `a` - The pointer to the first TariPublicKey
+/// `b` - The pointer to the second TariPublicKey
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `bool` - Returns true if the public keys are equal, false otherwise. Note that it returns false if either
+/// pointer is null
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn public_key_equals(
+    a: *mut TariPublicKey,
+    b: *mut TariPublicKey,
+    error_out: *mut c_int,
+) -> bool {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if a.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("a".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+    if b.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("b".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+    *a == *b
+}
+
 /// -------------------------------------------------------------------------------------------- ///
 
 /// -------------------------------- Tari Address ---------------------------------------------- ///
@@ -1132,6 +1692,68 @@ pub unsafe extern "C" fn tari_address_create(bytes: *mut ByteVector, error_out:
     }
 }
 
+/// Creates a dual TariWalletAddress from a spend key and a view key
+///
+/// ## Arguments
+/// `spend_key` - The pointer to the TariPublicKey used as the spend key
+/// `view_key` - The pointer to the TariPublicKey used as the view key
+/// `network` - The network byte of the address, which must be a known network
+/// `features` - The feature flags of the address, where bit 0 is one-sided and bit 1 is interactive. At least one
+/// must be set
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `*mut TariWalletAddress` - Returns the address. Note that it will be ptr::null_mut() if either key is null, or if
+/// the network or features are invalid
+///
+/// # Safety
+/// The ```tari_address_destroy``` function must be called when finished with a TariWalletAddress to prevent a memory
+/// leak
+#[no_mangle]
+pub unsafe extern "C" fn tari_address_create_dual(
+    spend_key: *mut TariPublicKey,
+    view_key: *mut TariPublicKey,
+    network: c_uint,
+    features: c_int,
+    error_out: *mut c_int,
+) -> *mut TariWalletAddress {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if spend_key.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("spend_key".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+    if view_key.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("view_key".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+    let network = match u8::try_from(network).ok().and_then(|n| Network::try_from(n).ok()) {
+        Some(network) => network,
+        None => {
+            error = LibWalletError::from(TariAddressError::InvalidNetwork).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return ptr::null_mut();
+        },
+    };
+    let features = match u8::try_from(features)
+        .ok()
+        .and_then(TariAddressFeatures::from_bits)
+        .filter(|f| !f.is_empty())
+    {
+        Some(features) => features,
+        None => {
+            error = LibWalletError::from(TariAddressError::InvalidFeatures).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return ptr::null_mut();
+        },
+    };
+    let address = TariWalletAddress::new_dual_address((*view_key).clone(), (*spend_key).clone(), network, features);
+    Box::into_raw(Box::new(address))
+}
+
 /// Frees memory for a TariWalletAddress
 ///
 /// ## Arguments
@@ -1320,6 +1942,43 @@ pub unsafe extern "C" fn tari_address_network_u8(address: *mut TariWalletAddress
         .as_byte()
 }
 
+/// Checks whether a TariWalletAddress belongs to the given network
+///
+/// ## Arguments
+/// `address` - The pointer to a TariWalletAddress
+/// `network` - The network id, as returned by `tari_address_network_u8`
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `bool` - Returns true if the address network matches. Returns false if it does not match, if address is null or if
+/// network is not a valid network id
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn tari_address_matches_network(
+    address: *mut TariWalletAddress,
+    network: c_uint,
+    error_out: *mut c_int,
+) -> bool {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if address.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("address".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+    match u8::try_from(network).ok().and_then(|n| Network::try_from(n).ok()) {
+        Some(network) => (*address).network() == network,
+        None => {
+            error = LibWalletError::from(TariAddressError::InvalidNetwork).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            false
+        },
+    }
+}
+
 /// Returns the u8 representation of a TariWalletAddress's checksum
 ///
 /// ## Arguments
@@ -1407,6 +2066,33 @@ pub unsafe extern "C" fn tari_address_features_u8(address: *mut TariWalletAddres
         .as_u8()
 }
 
+/// Checks whether a TariWalletAddress can receive one-sided payments
+///
+/// ## Arguments
+/// `address` - The pointer to a TariWalletAddress
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `bool` - Returns true if the address has the one-sided feature set, false if not or on failure
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn tari_address_supports_one_sided(
+    address: *mut TariWalletAddress,
+    error_out: *mut c_int,
+) -> bool {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if address.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("address".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+    (*address).features().contains(TariAddressFeatures::ONE_SIDED)
+}
+
 /// Creates a public key from a TariWalletAddress's view key
 ///
 /// ## Arguments
@@ -1415,10 +2101,11 @@ pub unsafe extern "C" fn tari_address_features_u8(address: *mut TariWalletAddres
 /// as an out parameter.
 ///
 /// ## Returns
-/// `*mut TariPublicKey` - Returns a pointer to a TariPublicKey. Note that it returns null if there is no key present
+/// `*mut TariPublicKey` - Returns a pointer to a TariPublicKey. Note that it returns null and sets an InvalidArgument
+/// error if the address is a single address, which has no view key
 ///
 /// # Safety
-/// The ```string_destroy``` method must be called when finished with a string from rust to prevent a memory leak
+/// The ```public_key_destroy``` method must be called when finished with a TariPublicKey to prevent a memory leak
 #[no_mangle]
 pub unsafe extern "C" fn tari_address_view_key(
     address: *mut TariWalletAddress,
@@ -1436,6 +2123,11 @@ pub unsafe extern "C" fn tari_address_view_key(
         Some(key) => Box::into_raw(Box::new(key.clone())),
         None => {
             debug!(target: LOG_TARGET, "No view key present on Tari Address");
+            error = LibWalletError::from(InterfaceError::InvalidArgument(
+                "single address has no view key".to_string(),
+            ))
+            .code;
+            ptr::swap(error_out, &mut error as *mut c_int);
             ptr::null_mut()
         },
     }
@@ -1452,7 +2144,7 @@ pub unsafe extern "C" fn tari_address_view_key(
 /// `*mut TariPublicKey` - Returns a pointer to a TariPublicKey. Note that it returns null
 ///
 /// # Safety
-/// The ```string_destroy``` method must be called when finished with a string from rust to prevent a memory leak
+/// The ```public_key_destroy``` method must be called when finished with a TariPublicKey to prevent a memory leak
 #[no_mangle]
 pub unsafe extern "C" fn tari_address_spend_key(
     address: *mut TariWalletAddress,
@@ -1472,6 +2164,42 @@ pub unsafe extern "C" fn tari_address_spend_key(
     Box::into_raw(Box::new(spend_key.clone()))
 }
 
+/// Compares two TariWalletAddresses for equality. The full address is compared, so addresses with the same keys on
+/// different networks, or with different features, are not equal.
+///
+/// ## Arguments
+/// `a` - The pointer to the first TariWalletAddress
+/// `b` - The pointer to the second TariWalletAddress
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `bool` - Returns true if the addresses are equal, false otherwise. Note that it returns false if either
+/// pointer is null
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn tari_address_equals(
+    a: *mut TariWalletAddress,
+    b: *mut TariWalletAddress,
+    error_out: *mut c_int,
+) -> bool {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if a.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("a".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+    if b.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("b".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+    *a == *b
+}
+
 /// Creates a TariWalletAddress from a char array in emoji format
 ///
 /// ## Arguments
@@ -1480,7 +2208,10 @@ pub unsafe extern "C" fn tari_address_spend_key(
 /// as an out parameter.
 ///
 /// ## Returns
-/// `*mut c_char` - Returns a pointer to a TariWalletAddress. Note that it returns null on error.
+/// `*mut c_char` - Returns a pointer to a TariWalletAddress. Note that it returns null on error, with `error_out` set
+/// to 701 if the emoji id is well formed but belongs to a different network than the one currently in use, 704 if it
+/// contains characters outside of the emoji set, 706 if its checksum does not match, or another `TariAddressError`
+/// code if it could not be decoded.
 ///
 /// # Safety
 /// The ```public_key_destroy``` method must be called when finished with a TariWalletAddress to prevent a memory leak
@@ -1501,10 +2232,16 @@ pub unsafe extern "C" fn emoji_id_to_tari_address(
         .to_str()
         .map_err(|_| TariAddressError::InvalidEmoji)
         .and_then(TariAddress::from_emoji_string)
-    {
+        .and_then(|address| {
+            if address.network() == Network::get_current_or_user_setting_or_default() {
+                Ok(address)
+            } else {
+                Err(TariAddressError::InvalidNetwork)
+            }
+        }) {
         Ok(address) => Box::into_raw(Box::new(address)),
-        Err(_) => {
-            error = LibWalletError::from(InterfaceError::InvalidEmojiId).code;
+        Err(e) => {
+            error = LibWalletError::from(e).code;
             ptr::swap(error_out, &mut error as *mut c_int);
             ptr::null_mut()
         },
@@ -1900,43 +2637,85 @@ pub unsafe extern "C" fn tari_unblinded_output_to_json(
     CString::into_raw(hex_bytes)
 }
 
-/// Creates a TariUnblindedOutput from a char array
+/// returns the TariUnblindedOutput as compact CBOR encoded bytes, e.g. to fit an output into a single QR code
 ///
 /// ## Arguments
-/// `output_json` - The pointer to a char array which is json of the TariUnblindedOutput
+/// `output` - The pointer to a TariUnblindedOutput
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
 /// ## Returns
-/// `*mut TariUnblindedOutput` - Returns a pointer to a TariUnblindedOutput. Note that it returns ptr::null_mut()
-/// if key is null or if there was an error creating the TariUnblindedOutput from key
+/// `*mut ByteVector` - Returns a ByteVector with the CBOR encoding. Note that it returns ptr::null_mut() if
+/// TariUnblindedOutput is null or the output could not be encoded, in which case `error_out` is set to 7
 ///
 /// # Safety
-/// The ```tari_unblinded_output_destroy``` function must be called when finished with a TariUnblindedOutput to
-// /// prevent a memory leak
+/// The ```byte_vector_destroy``` function must be called when finished with the ByteVector to prevent a memory leak
 #[no_mangle]
-pub unsafe extern "C" fn create_tari_unblinded_output_from_json(
-    output_json: *const c_char,
+pub unsafe extern "C" fn tari_unblinded_output_to_cbor(
+    output: *mut TariUnblindedOutput,
     error_out: *mut c_int,
-) -> *mut TariUnblindedOutput {
+) -> *mut ByteVector {
     let mut error = 0;
     ptr::swap(error_out, &mut error as *mut c_int);
-    let output_json_str;
-    if output_json.is_null() {
-        error = LibWalletError::from(InterfaceError::NullError("output_json".to_string())).code;
+    if output.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("output".to_string())).code;
         ptr::swap(error_out, &mut error as *mut c_int);
         return ptr::null_mut();
-    } else {
-        match CStr::from_ptr(output_json).to_str() {
-            Ok(v) => {
-                output_json_str = v.to_owned();
-            },
-            _ => {
-                error = LibWalletError::from(InterfaceError::PointerError("output_json".to_string())).code;
-                ptr::swap(error_out, &mut error as *mut c_int);
-                return ptr::null_mut();
-            },
-        };
+    }
+    let mut bytes = Vec::new();
+    match ciborium::ser::into_writer(&*output, &mut bytes) {
+        Ok(()) => Box::into_raw(Box::new(ByteVector(bytes))),
+        Err(e) => {
+            error!(target: LOG_TARGET, "Error encoding output as cbor: {:?}", e);
+
+            error = LibWalletError::from(InterfaceError::InvalidArgument(format!(
+                "output could not be encoded as CBOR: {}",
+                e
+            )))
+            .code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            ptr::null_mut()
+        },
+    }
+}
+
+/// Creates a TariUnblindedOutput from a char array
+///
+/// ## Arguments
+/// `output_json` - The pointer to a char array which is json of the TariUnblindedOutput
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `*mut TariUnblindedOutput` - Returns a pointer to a TariUnblindedOutput. Note that it returns ptr::null_mut()
+/// if key is null or if there was an error creating the TariUnblindedOutput from key
+///
+/// # Safety
+/// The ```tari_unblinded_output_destroy``` function must be called when finished with a TariUnblindedOutput to
+// /// prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn create_tari_unblinded_output_from_json(
+    output_json: *const c_char,
+    error_out: *mut c_int,
+) -> *mut TariUnblindedOutput {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    let output_json_str;
+    if output_json.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("output_json".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    } else {
+        match CStr::from_ptr(output_json).to_str() {
+            Ok(v) => {
+                output_json_str = v.to_owned();
+            },
+            _ => {
+                error = LibWalletError::from(InterfaceError::PointerError("output_json".to_string())).code;
+                ptr::swap(error_out, &mut error as *mut c_int);
+                return ptr::null_mut();
+            },
+        };
     }
     let output: Result<TariUnblindedOutput, _> = serde_json::from_str(&output_json_str);
 
@@ -1952,6 +2731,50 @@ pub unsafe extern "C" fn create_tari_unblinded_output_from_json(
     }
 }
 
+/// Creates a TariUnblindedOutput from CBOR encoded bytes as produced by `tari_unblinded_output_to_cbor`
+///
+/// ## Arguments
+/// `bytes` - The pointer to a ByteVector holding the CBOR encoding of the TariUnblindedOutput
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `*mut TariUnblindedOutput` - Returns a pointer to a TariUnblindedOutput. Note that it returns ptr::null_mut()
+/// if bytes is null or if the bytes could not be decoded, in which case `error_out` is set to 7
+///
+/// # Safety
+/// The ```tari_unblinded_output_destroy``` function must be called when finished with a TariUnblindedOutput to
+/// prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn create_tari_unblinded_output_from_cbor(
+    bytes: *const ByteVector,
+    error_out: *mut c_int,
+) -> *mut TariUnblindedOutput {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if bytes.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("bytes".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+    let output: Result<TariUnblindedOutput, _> = ciborium::de::from_reader((*bytes).0.as_slice());
+
+    match output {
+        Ok(output) => Box::into_raw(Box::new(output)),
+        Err(e) => {
+            error!(target: LOG_TARGET, "Error creating a output from cbor: {:?}", e);
+
+            error = LibWalletError::from(InterfaceError::InvalidArgument(format!(
+                "bytes are not a CBOR encoded output: {}",
+                e
+            )))
+            .code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            ptr::null_mut()
+        },
+    }
+}
+
 /// -------------------------------------------------------------------------------------------- ///
 
 /// ----------------------------------- TariUnblindedOutputs ------------------------------------///
@@ -2101,6 +2924,84 @@ pub unsafe extern "C" fn wallet_get_unspent_outputs(
     }
 }
 
+/// Get a page of the TariUnblindedOutputs from a TariWallet. Unlike `wallet_get_unspent_outputs`, only the outputs in
+/// the requested page are converted, which avoids deriving the keys of every unspent output on a large wallet. The
+/// outputs are ordered by the order in which they were added to the wallet, so consecutive pages do not overlap.
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer
+/// `offset` - The number of unspent outputs to skip
+/// `limit` - The maximum number of unspent outputs to return
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `*mut TariUnblindedOutputs` - returns at most `limit` unspent unblinded outputs, note that it returns
+/// ptr::null_mut() if wallet is null
+///
+/// # Safety
+/// The ```unblinded_outputs_destroy``` method must be called when finished with a TariUnblindedOutput to prevent a
+/// memory leak
+#[no_mangle]
+pub unsafe extern "C" fn wallet_get_unspent_outputs_paged(
+    wallet: *mut TariWallet,
+    offset: usize,
+    limit: usize,
+    error_out: *mut c_int,
+) -> *mut TariUnblindedOutputs {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+
+    let q = OutputBackendQuery {
+        tip_height: i64::MAX,
+        status: vec![OutputStatus::Unspent, OutputStatus::UnspentMinedUnconfirmed],
+        commitments: vec![],
+        pagination: Some((
+            i64::from_usize(offset).unwrap_or(i64::MAX),
+            i64::from_usize(limit).unwrap_or(i64::MAX),
+        )),
+        value_min: None,
+        value_max: None,
+        maturity_min: None,
+        maturity_max: None,
+        sorting: vec![("id", SortDirection::Asc)],
+    };
+
+    match (*wallet).wallet.output_db.fetch_outputs_by_query(q) {
+        Ok(db_outputs) => {
+            let mut outputs = Vec::with_capacity(db_outputs.len());
+            for output in db_outputs {
+                let unblinded = (*wallet).runtime.block_on(UnblindedOutput::from_wallet_output(
+                    output.wallet_output,
+                    &(*wallet).wallet.key_manager_service,
+                ));
+                match unblinded {
+                    Ok(uo) => outputs.push(uo),
+                    Err(e) => {
+                        error = LibWalletError::from(WalletError::TransactionError(e)).code;
+                        ptr::swap(error_out, &mut error as *mut c_int);
+                        return ptr::null_mut();
+                    },
+                }
+            }
+            Box::into_raw(Box::new(TariUnblindedOutputs(outputs)))
+        },
+        Err(e) => {
+            error = LibWalletError::from(WalletError::OutputManagerError(
+                OutputManagerError::OutputManagerStorageError(e),
+            ))
+            .code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            ptr::null_mut()
+        },
+    }
+}
+
 /// Import an external UTXO into the wallet as a non-rewindable (i.e. non-recoverable) output. This will add a spendable
 /// UTXO (as EncumberedToBeReceived) and create a faux completed transaction to record the event.
 ///
@@ -2185,95 +3086,350 @@ pub unsafe extern "C" fn wallet_import_external_utxo_as_non_rewindable(
         },
     }
 }
-/// -------------------------------------------------------------------------------------------- ///
-/// -------------------------------- Private Key ----------------------------------------------- ///
 
-/// Creates a TariPrivateKey from a ByteVector
+/// Import an external UTXO into the wallet as a non-rewindable (i.e. non-recoverable) output, as
+/// `wallet_import_external_utxo_as_non_rewindable` does, but with caller supplied metadata on the faux completed
+/// transaction so that it can be tied back to an external ledger entry.
 ///
 /// ## Arguments
-/// `bytes` - The pointer to a ByteVector
+/// `wallet` - The TariWallet pointer
+/// `output` - The pointer to a TariUnblindedOutput
+/// `source_address` - The tari address of the source of the transaction
+/// `message` - The message that the transaction will have, "Imported UTXO" is used if it is null
+/// `payment_id` - The pointer to a ByteVector containing the payment id to attach to the transaction and output, can be
+/// null if no payment id should be attached
+/// `import_status` - The status of the faux transaction, either 3 (Imported) or 8 (OneSidedUnconfirmed). A negative
+/// value uses the default of 3 (Imported). Any other value, including 9 (OneSidedConfirmed) which needs the block the
+/// output was mined in, fails with an `InvalidArgument` error (code 7).
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
 /// ## Returns
-/// `*mut TariPrivateKey` - Returns a pointer to a TariPrivateKey. Note that it returns ptr::null_mut()
-/// if bytes is null or if there was an error creating the TariPrivateKey from bytes
+/// `c_ulonglong` -  Returns the TransactionID of the generated transaction, note that it will be zero if the import
+/// failed
 ///
 /// # Safety
-/// The ```private_key_destroy``` method must be called when finished with a TariPrivateKey to prevent a memory leak
+/// None
 #[no_mangle]
-pub unsafe extern "C" fn private_key_create(bytes: *mut ByteVector, error_out: *mut c_int) -> *mut TariPrivateKey {
+pub unsafe extern "C" fn wallet_import_external_utxo_as_non_rewindable_with_metadata(
+    wallet: *mut TariWallet,
+    output: *mut TariUnblindedOutput,
+    source_address: *mut TariWalletAddress,
+    message: *const c_char,
+    payment_id: *const ByteVector,
+    import_status: c_int,
+    error_out: *mut c_int,
+) -> c_ulonglong {
     let mut error = 0;
     ptr::swap(error_out, &mut error as *mut c_int);
-    if bytes.is_null() {
-        error = LibWalletError::from(InterfaceError::NullError("bytes".to_string())).code;
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
         ptr::swap(error_out, &mut error as *mut c_int);
-        return ptr::null_mut();
+        return 0;
     }
-    let v = (*bytes).0.clone();
-    let pk = TariPrivateKey::from_canonical_bytes(&v);
-    match pk {
-        Ok(pk) => Box::into_raw(Box::new(pk)),
+    if output.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("output".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    };
+    let import_status = if import_status < 0 {
+        ImportStatus::Imported
+    } else {
+        match TransactionStatus::try_from(import_status) {
+            Ok(TransactionStatus::Imported) => ImportStatus::Imported,
+            Ok(TransactionStatus::OneSidedUnconfirmed) => ImportStatus::OneSidedUnconfirmed,
+            _ => {
+                error = LibWalletError::from(InterfaceError::InvalidArgument(format!(
+                    "import_status {} is not an unconfirmed import status",
+                    import_status
+                )))
+                .code;
+                ptr::swap(error_out, &mut error as *mut c_int);
+                return 0;
+            },
+        }
+    };
+    let source_address = if source_address.is_null() {
+        TariWalletAddress::default()
+    } else {
+        (*source_address).clone()
+    };
+    let message_string = if message.is_null() {
+        "Imported UTXO".to_string()
+    } else {
+        match CStr::from_ptr(message).to_str() {
+            Ok(v) => v.to_owned(),
+            _ => {
+                error = LibWalletError::from(InterfaceError::PointerError("message".to_string())).code;
+                ptr::swap(error_out, &mut error as *mut c_int);
+                return 0;
+            },
+        }
+    };
+    let payment_id = match payment_id.as_ref() {
+        None => PaymentId::Empty,
+        Some(bytes) => PaymentId::Open(bytes.0.clone()),
+    };
+    match (*wallet).runtime.block_on(
+        (*wallet)
+            .wallet
+            .import_unblinded_output_as_non_rewindable_with_metadata(
+                (*output).clone(),
+                source_address,
+                message_string,
+                import_status,
+                payment_id,
+            ),
+    ) {
+        Ok(tx_id) => tx_id.as_u64(),
         Err(e) => {
             error = LibWalletError::from(e).code;
             ptr::swap(error_out, &mut error as *mut c_int);
-            ptr::null_mut()
+            0
         },
     }
 }
 
-/// Frees memory for a TariPrivateKey
-///
-/// ## Arguments
-/// `pk` - The pointer to a TariPrivateKey
-///
-/// ## Returns
-/// `()` - Does not return a value, equivalent to void in C
-///
-/// # Safety
-/// None
-#[no_mangle]
-pub unsafe extern "C" fn private_key_destroy(pk: *mut TariPrivateKey) {
-    if !pk.is_null() {
-        drop(Box::from_raw(pk))
-    }
-}
-
-/// Gets a ByteVector from a TariPrivateKey
+/// Import an external UTXO into the wallet as a rewindable (i.e. recoverable) output. In contrast to
+/// `wallet_import_external_utxo_as_non_rewindable`, the output is run through the wallet's recovery path and is only
+/// accepted if its encrypted data can be decrypted with this wallet's keys. Such an output is tied to the wallet's key
+/// chain and will be found again when the wallet is recovered from its seed words, whereas a non-rewindable import is
+/// lost on recovery. A faux completed transaction is created to record the event.
 ///
 /// ## Arguments
-/// `pk` - The pointer to a TariPrivateKey
+/// `wallet` - The TariWallet pointer
+/// `output` - The pointer to a TariUnblindedOutput
+/// `source_address` - The tari address of the source of the transaction
+/// `message` - The message that the transaction will have
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
 /// ## Returns
-/// `*mut ByteVectror` - Returns a pointer to a ByteVector. Note that it returns ptr::null_mut()
-/// if pk is null
+/// `c_ulonglong` -  Returns the TransactionID of the generated transaction, note that it will be zero if the output
+/// could not be rewound with this wallet's keys or was already imported
 ///
 /// # Safety
-/// The ```byte_vector_destroy``` must be called when finished with a ByteVector to prevent a memory leak
+/// None
 #[no_mangle]
-pub unsafe extern "C" fn private_key_get_bytes(pk: *mut TariPrivateKey, error_out: *mut c_int) -> *mut ByteVector {
+pub unsafe extern "C" fn wallet_import_unblinded_output_as_rewindable(
+    wallet: *mut TariWallet,
+    output: *mut TariUnblindedOutput,
+    source_address: *mut TariWalletAddress,
+    message: *const c_char,
+    error_out: *mut c_int,
+) -> c_ulonglong {
     let mut error = 0;
     ptr::swap(error_out, &mut error as *mut c_int);
-    let mut bytes = ByteVector(Vec::new());
-    if pk.is_null() {
-        error = LibWalletError::from(InterfaceError::NullError("pk".to_string())).code;
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
         ptr::swap(error_out, &mut error as *mut c_int);
-        return ptr::null_mut();
-    } else {
-        bytes.0 = (*pk).to_vec();
+        return 0;
     }
-    Box::into_raw(Box::new(bytes))
-}
-
-/// Generates a TariPrivateKey
-///
-/// ## Arguments
-/// `()` - Does  not take any arguments
-///
-/// ## Returns
-/// `*mut TariPrivateKey` - Returns a pointer to a TariPrivateKey
+    if output.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("output".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    };
+    let source_address = if source_address.is_null() {
+        TariWalletAddress::default()
+    } else {
+        (*source_address).clone()
+    };
+    let message_string = if message.is_null() {
+        "Imported UTXO".to_string()
+    } else {
+        match CStr::from_ptr(message).to_str() {
+            Ok(v) => v.to_owned(),
+            _ => {
+                error = LibWalletError::from(InterfaceError::PointerError("message".to_string())).code;
+                ptr::swap(error_out, &mut error as *mut c_int);
+                return 0;
+            },
+        }
+    };
+    match (*wallet)
+        .runtime
+        .block_on((*wallet).wallet.import_unblinded_output_as_rewindable(
+            (*output).clone(),
+            source_address,
+            message_string,
+        )) {
+        Ok(tx_id) => tx_id.as_u64(),
+        Err(e) => {
+            error = LibWalletError::from(e).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            0
+        },
+    }
+}
+
+/// Mark the faux transaction created by `wallet_import_external_utxo_as_non_rewindable` as mined and confirmed once
+/// the caller has independently verified that the imported output is mined. The imported output becomes spendable.
+/// The block hash must be that of the real header at `mined_height`, as TXO validation checks it against the chain
+/// and reverts the output if it does not match.
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer
+/// `transaction_id` - The TransactionId of the imported transaction
+/// `mined_height` - The height of the block in which the imported output was mined
+/// `block_hash` - The pointer to a char array containing the hex encoded hash of the block in which the imported
+/// output was mined
+/// `mined_timestamp` - The timestamp, in seconds since the Unix epoch, of the block in which the imported output was
+/// mined
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `bool` - Returns true if the transaction was marked as confirmed, false if the wallet or block hash is null, the
+/// block hash is invalid, the transaction is not found or the transaction is not in the `Imported` status
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn wallet_mark_imported_transaction_confirmed(
+    wallet: *mut TariWallet,
+    transaction_id: c_ulonglong,
+    mined_height: c_ulonglong,
+    block_hash: *const c_char,
+    mined_timestamp: c_ulonglong,
+    error_out: *mut c_int,
+) -> bool {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+    if block_hash.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("block_hash".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+
+    let mined_in_block = match CStr::from_ptr(block_hash).to_str() {
+        Ok(hex) => match BlockHash::from_hex(hex) {
+            Ok(hash) => hash,
+            Err(e) => {
+                error!(target: LOG_TARGET, "failed to parse block hash: {:#?}", e);
+                error = LibWalletError::from(InterfaceError::InvalidArgument("block_hash".to_string())).code;
+                ptr::swap(error_out, &mut error as *mut c_int);
+                return false;
+            },
+        },
+        Err(_) => {
+            error = LibWalletError::from(InterfaceError::PointerError("block_hash".to_string())).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return false;
+        },
+    };
+
+    match (*wallet)
+        .runtime
+        .block_on((*wallet).wallet.mark_imported_transaction_confirmed(
+            TxId::from(transaction_id),
+            mined_height,
+            mined_in_block,
+            mined_timestamp,
+        )) {
+        Ok(_) => true,
+        Err(e) => {
+            error = LibWalletError::from(e).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            false
+        },
+    }
+}
+
+/// -------------------------------------------------------------------------------------------- ///
+/// -------------------------------- Private Key ----------------------------------------------- ///
+
+/// Creates a TariPrivateKey from a ByteVector
+///
+/// ## Arguments
+/// `bytes` - The pointer to a ByteVector
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `*mut TariPrivateKey` - Returns a pointer to a TariPrivateKey. Note that it returns ptr::null_mut()
+/// if bytes is null or if there was an error creating the TariPrivateKey from bytes
+///
+/// # Safety
+/// The ```private_key_destroy``` method must be called when finished with a TariPrivateKey to prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn private_key_create(bytes: *mut ByteVector, error_out: *mut c_int) -> *mut TariPrivateKey {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if bytes.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("bytes".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+    let v = (*bytes).0.clone();
+    let pk = TariPrivateKey::from_canonical_bytes(&v);
+    match pk {
+        Ok(pk) => Box::into_raw(Box::new(pk)),
+        Err(e) => {
+            error = LibWalletError::from(e).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            ptr::null_mut()
+        },
+    }
+}
+
+/// Frees memory for a TariPrivateKey
+///
+/// ## Arguments
+/// `pk` - The pointer to a TariPrivateKey
+///
+/// ## Returns
+/// `()` - Does not return a value, equivalent to void in C
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn private_key_destroy(pk: *mut TariPrivateKey) {
+    if !pk.is_null() {
+        drop(Box::from_raw(pk))
+    }
+}
+
+/// Gets a ByteVector from a TariPrivateKey
+///
+/// ## Arguments
+/// `pk` - The pointer to a TariPrivateKey
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `*mut ByteVectror` - Returns a pointer to a ByteVector. Note that it returns ptr::null_mut()
+/// if pk is null
+///
+/// # Safety
+/// The ```byte_vector_destroy``` must be called when finished with a ByteVector to prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn private_key_get_bytes(pk: *mut TariPrivateKey, error_out: *mut c_int) -> *mut ByteVector {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    let mut bytes = ByteVector(Vec::new());
+    if pk.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("pk".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    } else {
+        bytes.0 = (*pk).to_vec();
+    }
+    Box::into_raw(Box::new(bytes))
+}
+
+/// Generates a TariPrivateKey
+///
+/// ## Arguments
+/// `()` - Does  not take any arguments
+///
+/// ## Returns
+/// `*mut TariPrivateKey` - Returns a pointer to a TariPrivateKey
 ///
 /// # Safety
 /// The ```private_key_destroy``` method must be called when finished with a TariPrivateKey to prevent a memory leak.
@@ -2332,6 +3488,100 @@ pub unsafe extern "C" fn private_key_from_hex(key: *const c_char, error_out: *mu
     }
 }
 
+/// -------------------------------------------------------------------------------------------- ///
+/// -------------------------------- Commitment ------------------------------------------------ ///
+
+/// Creates the Pedersen commitment `C = v·H + k·G` to a value and blinding factor
+///
+/// ## Arguments
+/// `value` - The value, in MicroMinotari, being committed to
+/// `blinding_key` - The pointer to a TariPrivateKey used as the blinding factor
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `*mut c_char` - Returns a pointer to a char array containing the hex encoded commitment. Note that it returns
+/// ptr::null_mut() if blinding_key is null
+///
+/// # Safety
+/// The ```string_destroy``` method must be called when finished with a string from rust to prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn commitment_create(
+    value: c_ulonglong,
+    blinding_key: *mut TariPrivateKey,
+    error_out: *mut c_int,
+) -> *mut c_char {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if blinding_key.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("blinding_key".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+
+    let commitment = crypto_factories().commitment.commit_value(&*blinding_key, value);
+    CString::new(commitment.to_hex())
+        .expect("Hex will not fail.")
+        .into_raw()
+}
+
+/// Checks that a hex encoded Pedersen commitment opens to the given value and blinding factor
+///
+/// ## Arguments
+/// `commitment_hex` - The pointer to a char array containing the hex encoded commitment
+/// `value` - The value, in MicroMinotari, the commitment is expected to commit to
+/// `blinding_key` - The pointer to a TariPrivateKey expected to be the blinding factor
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `bool` - Returns true if the commitment opens to the value and blinding factor, false otherwise. Note that it
+/// returns false if either pointer is null or commitment_hex is not a valid commitment
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn commitment_verify(
+    commitment_hex: *const c_char,
+    value: c_ulonglong,
+    blinding_key: *mut TariPrivateKey,
+    error_out: *mut c_int,
+) -> bool {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if commitment_hex.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("commitment_hex".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+    if blinding_key.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("blinding_key".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+
+    let commitment = match CStr::from_ptr(commitment_hex).to_str() {
+        Ok(hex) => match Commitment::from_hex(hex) {
+            Ok(commitment) => commitment,
+            Err(e) => {
+                error!(target: LOG_TARGET, "failed to parse commitment: {:#?}", e);
+                error = LibWalletError::from(InterfaceError::InvalidArgument("commitment_hex".to_string())).code;
+                ptr::swap(error_out, &mut error as *mut c_int);
+                return false;
+            },
+        },
+        Err(_) => {
+            error = LibWalletError::from(InterfaceError::PointerError("commitment_hex".to_string())).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return false;
+        },
+    };
+
+    crypto_factories()
+        .commitment
+        .open_value(&*blinding_key, value, &commitment)
+}
+
 /// -------------------------------------------------------------------------------------------- ///
 /// -------------------------------- Range Proof ----------------------------------------------- ///
 
@@ -2643,23 +3893,103 @@ pub unsafe extern "C" fn encrypted_data_as_bytes(
     Box::into_raw(Box::new(encrypted_byte_vector))
 }
 
-/// Frees memory for a TariEncryptedOpenings
+/// Decrypts a TariEncryptedOpenings to recover the value and mask of the output it belongs to
 ///
 /// ## Arguments
 /// `encrypted_data` - The pointer to a TariEncryptedOpenings
+/// `encryption_key` - The pointer to a TariPrivateKey the data was encrypted with
+/// `commitment` - The pointer to a char array containing the hex encoded commitment of the output
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
 ///
 /// ## Returns
-/// `()` - Does not return a value, equivalent to void in C
+/// `*mut TariVector` - Returns a `U64` vector whose first element is the value and whose remaining 32 elements are the
+/// bytes of the mask, one byte per element. Note that it returns ptr::null_mut() if any argument is null or invalid,
+/// and fails with a `DecryptionFailed` error (code 16) if the key or commitment does not match the encrypted data
 ///
 /// # Safety
-/// None
+/// `destroy_tari_vector()` must be called after use.
 #[no_mangle]
-pub unsafe extern "C" fn encrypted_data_destroy(encrypted_data: *mut TariEncryptedOpenings) {
-    if !encrypted_data.is_null() {
-        // zeroize the data content of encrypted_data, as to prevent memory leaks
-        (*encrypted_data).zeroize();
+pub unsafe extern "C" fn encrypted_data_decrypt(
+    encrypted_data: *const TariEncryptedOpenings,
+    encryption_key: *mut TariPrivateKey,
+    commitment: *const c_char,
+    error_out: *mut c_int,
+) -> *mut TariVector {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+
+    if encrypted_data.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("encrypted_data".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+    if encryption_key.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("encryption_key".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+    if commitment.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("commitment".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
     }
-}
+
+    let commitment = match CStr::from_ptr(commitment).to_str() {
+        Ok(hex) => match Commitment::from_hex(hex) {
+            Ok(commitment) => commitment,
+            Err(e) => {
+                error!(target: LOG_TARGET, "failed to parse commitment: {:#?}", e);
+                error = LibWalletError::from(InterfaceError::InvalidArgument("commitment".to_string())).code;
+                ptr::swap(error_out, &mut error as *mut c_int);
+                return ptr::null_mut();
+            },
+        },
+        Err(_) => {
+            error = LibWalletError::from(InterfaceError::PointerError("commitment".to_string())).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return ptr::null_mut();
+        },
+    };
+
+    match TariEncryptedOpenings::decrypt_data(&*encryption_key, &commitment, &*encrypted_data) {
+        Ok((value, mask, _)) => {
+            let mut opening = Vec::with_capacity(1 + mask.as_bytes().len());
+            opening.push(value.as_u64());
+            opening.extend(mask.as_bytes().iter().map(|b| u64::from(*b)));
+            Box::into_raw(Box::new(TariVector::from(opening)))
+        },
+        Err(EncryptedDataError::EncryptionFailed(_)) => {
+            error = LibWalletError::from(InterfaceError::DecryptionFailed).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            ptr::null_mut()
+        },
+        Err(e) => {
+            error!(target: LOG_TARGET, "Error decrypting encrypted_data: {:?}", e);
+            error = LibWalletError::from(InterfaceError::InvalidArgument("encrypted_data".to_string())).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            ptr::null_mut()
+        },
+    }
+}
+
+/// Frees memory for a TariEncryptedOpenings
+///
+/// ## Arguments
+/// `encrypted_data` - The pointer to a TariEncryptedOpenings
+///
+/// ## Returns
+/// `()` - Does not return a value, equivalent to void in C
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn encrypted_data_destroy(encrypted_data: *mut TariEncryptedOpenings) {
+    if !encrypted_data.is_null() {
+        // zeroize the data content of encrypted_data, as to prevent memory leaks
+        (*encrypted_data).zeroize();
+    }
+}
 
 /// -------------------------------------------------------------------------------------------- ///
 /// ---------------------------------- Output Features ------------------------------------------///
@@ -2753,6 +4083,49 @@ pub unsafe extern "C" fn output_features_create_from_bytes(
     Box::into_raw(Box::new(output_features))
 }
 
+/// Creates the TariOutputFeatures of a coinbase output, with the coinbase output type and a bullet proof plus range
+/// proof
+///
+/// ## Arguments
+/// `maturity` - The block height from which the output can be spent
+/// `extra` - The coinbase extra bytes as a ByteVector. It can be null to model an empty coinbase extra.
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `TariOutputFeatures` - Returns an output features object. Note that it will be ptr::null_mut() if the coinbase
+/// extra is too long
+///
+/// # Safety
+/// The ```output_features_destroy``` function must be called when finished with a TariOutputFeatures to
+/// prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn output_features_create_coinbase(
+    maturity: c_ulonglong,
+    extra: *const ByteVector,
+    error_out: *mut c_int,
+) -> *mut TariOutputFeatures {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+
+    let decoded_extra = if extra.is_null() {
+        None
+    } else {
+        match CoinBaseExtra::try_from((*extra).0.clone()) {
+            Ok(v) => Some(v),
+            Err(e) => {
+                error!(target: LOG_TARGET, "Error creating a coinbase extra: {:?}", e);
+                error = LibWalletError::from(InterfaceError::InvalidArgument("extra".to_string())).code;
+                ptr::swap(error_out, &mut error as *mut c_int);
+                return ptr::null_mut();
+            },
+        }
+    };
+
+    let output_features = TariOutputFeatures::create_coinbase(maturity, decoded_extra, RangeProofType::BulletProofPlus);
+    Box::into_raw(Box::new(output_features))
+}
+
 /// Frees memory for a TariOutputFeatures
 ///
 /// ## Arguments
@@ -2869,6 +4242,84 @@ pub unsafe extern "C" fn seed_words_create_from_cipher(
     Box::into_raw(Box::new(TariSeedWords(seed_words)))
 }
 
+/// Create an instance of TariSeedWords from a complete, whitespace separated seed phrase
+///
+/// ## Arguments
+/// `phrase` - The seed phrase as a string, with the words separated by whitespace
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `TariSeedWords` - Returns the populated TariSeedWords instance, or null if the phrase is not a valid seed phrase.
+/// The error code distinguishes between an incorrect number of words (912), a word that is not in any mnemonic word
+/// list (911), words that do not share a language (910) and a phrase that fails the checksum (432).
+///
+/// # Safety
+/// The `seed_words_destroy` method must be called when finished with a TariSeedWords instance from rust to prevent a
+/// memory leak
+#[no_mangle]
+pub unsafe extern "C" fn seed_words_create_from_string(
+    phrase: *const c_char,
+    error_out: *mut c_int,
+) -> *mut TariSeedWords {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+
+    if phrase.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("phrase".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+    let phrase = match CStr::from_ptr(phrase).to_str() {
+        Ok(v) => Hidden::hide(v.to_owned()),
+        _ => {
+            error = LibWalletError::from(InterfaceError::PointerError("phrase".to_string())).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return ptr::null_mut();
+        },
+    };
+
+    let seed_words = SeedWords::new(
+        phrase
+            .reveal()
+            .split_whitespace()
+            .map(|w| Hidden::hide(w.to_string()))
+            .collect(),
+    );
+    if seed_words.len() != 24 {
+        error = LibWalletError::from(SeedPhraseError::InvalidWordCount(seed_words.len())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+    for i in 0..seed_words.len() {
+        let word = match seed_words.get_word(i) {
+            Ok(w) => w,
+            Err(e) => {
+                error = LibWalletError::from(e).code;
+                ptr::swap(error_out, &mut error as *mut c_int);
+                return ptr::null_mut();
+            },
+        };
+        if MnemonicLanguage::from(word).is_err() {
+            error = LibWalletError::from(SeedPhraseError::WordNotFound(format!("word at position {}", i + 1))).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return ptr::null_mut();
+        }
+    }
+    if let Err(e) = MnemonicLanguage::detect_language(&seed_words) {
+        error = LibWalletError::from(e).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+    if let Err(e) = CipherSeed::from_mnemonic(&seed_words, None) {
+        error = LibWalletError::from(WalletError::KeyManagerError(e)).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+
+    Box::into_raw(Box::new(TariSeedWords(seed_words)))
+}
+
 /// Create a TariSeedWords instance containing the entire mnemonic wordlist for the requested language
 ///
 /// ## Arguments
@@ -3164,6 +4615,84 @@ pub unsafe extern "C" fn seed_words_push_word(
     }
 }
 
+/// Validates a complete TariSeedWords phrase against an optional passphrase
+///
+/// ## Arguments
+/// `seed_words` - The pointer to a TariSeedWords
+/// `passphrase` - Optional passphrase that was used when the seed phrase was created, a null pointer validates the
+/// phrase as one that was created without a passphrase
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `bool` - Returns true if the seed words can be deciphered with the given passphrase, false otherwise. A mismatched
+/// passphrase fails the MAC check of the cipher seed and `error_out` is set to the decryption failure code (431), the
+/// same phrase with the correct passphrase is required to restore the wallet via `wallet_create`.
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn seed_words_validate_with_passphrase(
+    seed_words: *mut TariSeedWords,
+    passphrase: *const c_char,
+    error_out: *mut c_int,
+) -> bool {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if seed_words.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("seed_words".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+
+    let passphrase = if passphrase.is_null() {
+        None
+    } else {
+        match CStr::from_ptr(passphrase).to_str() {
+            Ok(v) => Some(SafePassword::from(v.to_owned())),
+            _ => {
+                error = LibWalletError::from(InterfaceError::PointerError("passphrase".to_string())).code;
+                ptr::swap(error_out, &mut error as *mut c_int);
+                return false;
+            },
+        }
+    };
+
+    match CipherSeed::from_mnemonic(&(*seed_words).0, passphrase) {
+        Ok(_) => true,
+        Err(e) => {
+            error!(target: LOG_TARGET, "Seed words could not be validated with passphrase: {:?}", e);
+            error = LibWalletError::from(WalletError::KeyManagerError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            false
+        },
+    }
+}
+
+/// Removes all words from a TariSeedWords so the same instance can be reused, the cleared words are zeroized
+///
+/// ## Arguments
+/// `seed_words` - The pointer to a TariSeedWords
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `()` - Does not return a value, equivalent to void in C
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn seed_words_clear(seed_words: *mut TariSeedWords, error_out: *mut c_int) {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if seed_words.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("seed_words".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return;
+    }
+    (*seed_words).0.clear();
+}
+
 /// Frees memory for a TariSeedWords
 ///
 /// ## Arguments
@@ -3325,6 +4854,83 @@ pub unsafe extern "C" fn contact_get_tari_address(
     Box::into_raw(Box::new((*contact).address.clone()))
 }
 
+/// Sets the alias of the TariContact in place, so a fetched contact can be modified and passed back to
+/// `wallet_upsert_contact`
+///
+/// ## Arguments
+/// `contact` - The pointer to a TariContact
+/// `alias` - The pointer to a char array containing the new alias
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `bool` - Returns true if the alias was updated, false if contact or alias is null or alias is not valid UTF-8
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn contact_set_alias(
+    contact: *mut TariContact,
+    alias: *const c_char,
+    error_out: *mut c_int,
+) -> bool {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if contact.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("contact".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+    if alias.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("alias".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+
+    match CStr::from_ptr(alias).to_str() {
+        Ok(v) => {
+            (*contact).alias = v.to_owned();
+            true
+        },
+        _ => {
+            error = LibWalletError::from(InterfaceError::PointerError("alias".to_string())).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            false
+        },
+    }
+}
+
+/// Sets the favourite flag of the TariContact in place, so a fetched contact can be modified and passed back to
+/// `wallet_upsert_contact`
+///
+/// ## Arguments
+/// `contact` - The pointer to a TariContact
+/// `favourite` - The new favourite flag
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `bool` - Returns true if the flag was updated, false if contact is null
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn contact_set_favourite(
+    contact: *mut TariContact,
+    favourite: bool,
+    error_out: *mut c_int,
+) -> bool {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if contact.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("contact".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+    (*contact).favourite = favourite;
+    true
+}
+
 /// Frees memory for a TariContact
 ///
 /// ## Arguments
@@ -4029,7 +5635,8 @@ pub unsafe extern "C" fn completed_transaction_get_transaction_kernel(
     Box::into_raw(Box::new(x))
 }
 
-/// Gets the source TariWalletAddress of a TariCompletedTransaction
+/// Gets all of the TariTransactionKernels of a TariCompletedTransaction, including transactions with more than one
+/// kernel
 ///
 /// ## Arguments
 /// `transaction` - The pointer to a TariCompletedTransaction
@@ -4037,16 +5644,17 @@ pub unsafe extern "C" fn completed_transaction_get_transaction_kernel(
 /// as an out parameter.
 ///
 /// ## Returns
-/// `*mut TariWalletAddress` - Returns the source TariWalletAddress, note that it will be
-/// ptr::null_mut() if transaction is null
+/// `*mut TariVector` - Returns a `Kernel` vector holding a `TariTransactionKernel` pointer per kernel, note that it
+/// will be ptr::null_mut() if transaction is null or if the transaction status is Pending
 ///
 /// # Safety
-/// The ```tari_address_destroy``` method must be called when finished with a TariWalletAddress to prevent a memory leak
+/// The ```transaction_kernel_destroy``` method must be called on each TariTransactionKernel and
+/// ```destroy_tari_vector``` on the vector when finished with them to prevent a memory leak
 #[no_mangle]
-pub unsafe extern "C" fn completed_transaction_get_source_tari_address(
+pub unsafe extern "C" fn completed_transaction_get_kernels(
     transaction: *mut TariCompletedTransaction,
     error_out: *mut c_int,
-) -> *mut TariWalletAddress {
+) -> *mut TariVector {
     let mut error = 0;
     ptr::swap(error_out, &mut error as *mut c_int);
     if transaction.is_null() {
@@ -4054,12 +5662,154 @@ pub unsafe extern "C" fn completed_transaction_get_source_tari_address(
         ptr::swap(error_out, &mut error as *mut c_int);
         return ptr::null_mut();
     }
-    let m = (*transaction).source_address.clone();
-    Box::into_raw(Box::new(m))
-}
 
-/// Gets the status of a TariCompletedTransaction
-///
+    // check the tx is not in pending state
+    if matches!(
+        (*transaction).status,
+        TransactionStatus::Pending | TransactionStatus::Imported
+    ) {
+        let msg = format!("Incorrect transaction status: {}", (*transaction).status);
+        error = LibWalletError::from(TransactionError::StatusError(msg)).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+
+    let kernels = (*transaction).transaction.body().kernels().clone();
+    Box::into_raw(Box::new(TariVector::from(kernels)))
+}
+
+/// Gets the input commitments of the body of a TariCompletedTransaction
+///
+/// ## Arguments
+/// `transaction` - The pointer to a TariCompletedTransaction
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `*mut TariVector` - Returns a `Commitment` vector of hex strings, note that it will be ptr::null_mut() if
+/// transaction is null, if the transaction status is Pending or Imported, or if an input commitment is not available
+///
+/// # Safety
+/// The ```destroy_tari_vector``` method must be called when finished with the TariVector to prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn completed_transaction_get_input_commitments(
+    transaction: *mut TariCompletedTransaction,
+    error_out: *mut c_int,
+) -> *mut TariVector {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if transaction.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("transaction".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+
+    // check the tx is not in pending state
+    if matches!(
+        (*transaction).status,
+        TransactionStatus::Pending | TransactionStatus::Imported
+    ) {
+        let msg = format!("Incorrect transaction status: {}", (*transaction).status);
+        error = LibWalletError::from(TransactionError::StatusError(msg)).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+
+    match (*transaction)
+        .transaction
+        .body()
+        .inputs()
+        .iter()
+        .map(|input| input.commitment().cloned())
+        .collect::<Result<Vec<Commitment>, _>>()
+    {
+        Ok(commitments) => Box::into_raw(Box::new(TariVector::from(commitments))),
+        Err(e) => {
+            error = LibWalletError::from(WalletError::TransactionError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            ptr::null_mut()
+        },
+    }
+}
+
+/// Gets the output commitments of the body of a TariCompletedTransaction
+///
+/// ## Arguments
+/// `transaction` - The pointer to a TariCompletedTransaction
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `*mut TariVector` - Returns a `Commitment` vector of hex strings, note that it will be ptr::null_mut() if
+/// transaction is null or if the transaction status is Pending or Imported
+///
+/// # Safety
+/// The ```destroy_tari_vector``` method must be called when finished with the TariVector to prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn completed_transaction_get_output_commitments(
+    transaction: *mut TariCompletedTransaction,
+    error_out: *mut c_int,
+) -> *mut TariVector {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if transaction.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("transaction".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+
+    // check the tx is not in pending state
+    if matches!(
+        (*transaction).status,
+        TransactionStatus::Pending | TransactionStatus::Imported
+    ) {
+        let msg = format!("Incorrect transaction status: {}", (*transaction).status);
+        error = LibWalletError::from(TransactionError::StatusError(msg)).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+
+    let commitments = (*transaction)
+        .transaction
+        .body()
+        .outputs()
+        .iter()
+        .map(|output| output.commitment().clone())
+        .collect::<Vec<Commitment>>();
+    Box::into_raw(Box::new(TariVector::from(commitments)))
+}
+
+/// Gets the source TariWalletAddress of a TariCompletedTransaction
+///
+/// ## Arguments
+/// `transaction` - The pointer to a TariCompletedTransaction
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `*mut TariWalletAddress` - Returns the source TariWalletAddress, note that it will be
+/// ptr::null_mut() if transaction is null
+///
+/// # Safety
+/// The ```tari_address_destroy``` method must be called when finished with a TariWalletAddress to prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn completed_transaction_get_source_tari_address(
+    transaction: *mut TariCompletedTransaction,
+    error_out: *mut c_int,
+) -> *mut TariWalletAddress {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if transaction.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("transaction".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+    let m = (*transaction).source_address.clone();
+    Box::into_raw(Box::new(m))
+}
+
+/// Gets the status of a TariCompletedTransaction
+///
 /// ## Arguments
 /// `transaction` - The pointer to a TariCompletedTransaction
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
@@ -4096,6 +5846,35 @@ pub unsafe extern "C" fn completed_transaction_get_status(
     status as c_int
 }
 
+/// Converts a transaction status code, as returned by `completed_transaction_get_status`, to its display string
+///
+/// ## Arguments
+/// `status` - The transaction status code
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `*mut c_char` - Returns a pointer to a char array. Note that it returns ptr::null_mut() if status is not a valid
+/// transaction status code
+///
+/// # Safety
+/// The ```string_destroy``` method must be called when finished with a string from rust to prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn transaction_status_to_string(status: c_int, error_out: *mut c_int) -> *mut c_char {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    match TransactionStatus::try_from(status) {
+        Ok(status) => CString::new(status.to_string())
+            .expect("status string will not fail")
+            .into_raw(),
+        Err(e) => {
+            error = LibWalletError::from(InterfaceError::InvalidArgument(e.to_string())).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            ptr::null_mut()
+        },
+    }
+}
+
 /// Gets the amount of a TariCompletedTransaction
 ///
 /// ## Arguments
@@ -4150,7 +5929,8 @@ pub unsafe extern "C" fn completed_transaction_get_fee(
     c_ulonglong::from((*transaction).fee)
 }
 
-/// Gets the timestamp of a TariCompletedTransaction
+/// Gets the net change a TariCompletedTransaction makes to the wallet's balance. Inbound transactions add their amount,
+/// outbound transactions remove their amount plus the fee the wallet paid.
 ///
 /// ## Arguments
 /// `transaction` - The pointer to a TariCompletedTransaction
@@ -4158,15 +5938,16 @@ pub unsafe extern "C" fn completed_transaction_get_fee(
 /// as an out parameter.
 ///
 /// ## Returns
-/// `c_ulonglong` - Returns the timestamp, note that it will be zero if transaction is null
+/// `c_longlong` - Returns the signed net amount, positive for received and negative for sent. Note that it will be
+/// zero if transaction is null or its direction is unknown
 ///
 /// # Safety
 /// None
 #[no_mangle]
-pub unsafe extern "C" fn completed_transaction_get_timestamp(
+pub unsafe extern "C" fn completed_transaction_get_net_amount(
     transaction: *mut TariCompletedTransaction,
     error_out: *mut c_int,
-) -> c_ulonglong {
+) -> c_longlong {
     let mut error = 0;
     ptr::swap(error_out, &mut error as *mut c_int);
     if transaction.is_null() {
@@ -4174,10 +5955,37 @@ pub unsafe extern "C" fn completed_transaction_get_timestamp(
         ptr::swap(error_out, &mut error as *mut c_int);
         return 0;
     }
-    (*transaction).timestamp.timestamp() as c_ulonglong
+
+    let net_amount = match (*transaction).direction {
+        TransactionDirection::Inbound => i64::try_from((*transaction).amount.as_u64()).ok(),
+        TransactionDirection::Outbound => (*transaction)
+            .amount
+            .as_u64()
+            .checked_add((*transaction).fee.as_u64())
+            .and_then(|v| i64::try_from(v).ok())
+            .map(|v| -v),
+        TransactionDirection::Unknown => {
+            error = LibWalletError::from(InterfaceError::InvalidArgument(
+                "transaction direction is unknown".to_string(),
+            ))
+            .code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return 0;
+        },
+    };
+
+    match net_amount {
+        Some(v) => v,
+        None => {
+            error = LibWalletError::from(InterfaceError::InvalidArgument("amount out of range".to_string())).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            0
+        },
+    }
 }
 
-/// Gets the message of a TariCompletedTransaction
+/// Gets the effective fee per gram of a TariCompletedTransaction, i.e. the kernel fee divided by the weight of the
+/// transaction body, weighed as `completed_transaction_get_weight` does
 ///
 /// ## Arguments
 /// `transaction` - The pointer to a TariCompletedTransaction
@@ -4185,38 +5993,60 @@ pub unsafe extern "C" fn completed_transaction_get_timestamp(
 /// as an out parameter.
 ///
 /// ## Returns
-/// `*const c_char` - Returns the pointer to the char array, note that it will return a pointer
-/// to an empty char array if transaction is null
+/// `c_ulonglong` - Returns the fee per gram rounded down, note that it will be zero if transaction is null or if the
+/// transaction status is Pending or Imported, as those transactions do not have a body
 ///
 /// # Safety
-/// The ```string_destroy``` method must be called when finished with string coming from rust to prevent a memory leak
+/// None
 #[no_mangle]
-pub unsafe extern "C" fn completed_transaction_get_message(
+pub unsafe extern "C" fn completed_transaction_get_fee_per_gram(
     transaction: *mut TariCompletedTransaction,
     error_out: *mut c_int,
-) -> *const c_char {
+) -> c_ulonglong {
     let mut error = 0;
     ptr::swap(error_out, &mut error as *mut c_int);
-    let message = (*transaction).message.clone();
-    let mut result = CString::new("").expect("Blank CString will not fail.");
     if transaction.is_null() {
         error = LibWalletError::from(InterfaceError::NullError("transaction".to_string())).code;
         ptr::swap(error_out, &mut error as *mut c_int);
-        return result.into_raw();
+        return 0;
     }
 
-    match CString::new(message) {
-        Ok(v) => result = v,
-        _ => {
-            error = LibWalletError::from(InterfaceError::PointerError("message".to_string())).code;
+    // check the tx is not in pending state
+    if matches!(
+        (*transaction).status,
+        TransactionStatus::Pending | TransactionStatus::Imported
+    ) {
+        let msg = format!("Incorrect transaction status: {}", (*transaction).status);
+        error = LibWalletError::from(TransactionError::StatusError(msg)).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
+
+    let weighting = match transaction_weighting((*transaction).mined_height) {
+        Ok(weighting) => weighting,
+        Err(e) => {
+            error = e.code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return 0;
+        },
+    };
+    let body = (*transaction).transaction.body();
+    let fee_and_weight = body
+        .get_total_fee()
+        .and_then(|fee| Ok((fee, body.calculate_weight(&weighting)?)));
+    match fee_and_weight {
+        Ok((_, 0)) => 0,
+        Ok((fee, weight)) => fee.as_u64() / weight,
+        Err(e) => {
+            error = LibWalletError::from(WalletError::TransactionError(e)).code;
             ptr::swap(error_out, &mut error as *mut c_int);
+            0
         },
     }
-
-    result.into_raw()
 }
 
-/// Gets the payment id of a TariCompletedTransaction
+/// Gets the weight, in grams, of the body of a TariCompletedTransaction, using the consensus weight parameters of the
+/// current network that apply at the height the transaction was mined at, or the latest ones if it is not yet mined
 ///
 /// ## Arguments
 /// `transaction` - The pointer to a TariCompletedTransaction
@@ -4224,73 +6054,229 @@ pub unsafe extern "C" fn completed_transaction_get_message(
 /// as an out parameter.
 ///
 /// ## Returns
-/// `*const c_char` - Returns the pointer to the char array, note that it will return a pointer
-/// to an empty char array if transaction is null
+/// `c_ulonglong` - Returns the weight of the transaction body, note that it will be zero if transaction is null or if
+/// the transaction does not have a body, i.e. it has no kernels, as is the case when its status is Pending or Imported
 ///
 /// # Safety
-/// The ```string_destroy``` method must be called when finished with string coming from rust to prevent a memory leak
+/// None
 #[no_mangle]
-pub unsafe extern "C" fn completed_transaction_get_payment_id(
+pub unsafe extern "C" fn completed_transaction_get_weight(
     transaction: *mut TariCompletedTransaction,
     error_out: *mut c_int,
-) -> *const c_char {
+) -> c_ulonglong {
     let mut error = 0;
     ptr::swap(error_out, &mut error as *mut c_int);
-    let payment_id = (*transaction).payment_id.clone();
-    let mut result = CString::new("").expect("Blank CString will not fail.");
     if transaction.is_null() {
         error = LibWalletError::from(InterfaceError::NullError("transaction".to_string())).code;
         ptr::swap(error_out, &mut error as *mut c_int);
-        return result.into_raw();
+        return 0;
     }
-    let payment_id_str = match payment_id {
-        None => "".to_string(),
-        Some(v) => {
-            let bytes = v.get_data();
-            if bytes.is_empty() {
-                format!("#{}", v)
-            } else {
-                String::from_utf8(bytes)
-                    .unwrap_or_else(|_| "Invalid string".to_string())
-                    .to_string()
-            }
+
+    // check the tx is not in pending state and has a body to weigh
+    if matches!(
+        (*transaction).status,
+        TransactionStatus::Pending | TransactionStatus::Imported
+    ) || (*transaction).transaction.body().kernels().is_empty()
+    {
+        let msg = format!(
+            "Incorrect transaction status: {}, the transaction has no body",
+            (*transaction).status
+        );
+        error = LibWalletError::from(TransactionError::StatusError(msg)).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
+
+    let weighting = match transaction_weighting((*transaction).mined_height) {
+        Ok(weighting) => weighting,
+        Err(e) => {
+            error = e.code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return 0;
         },
     };
-    match CString::new(payment_id_str) {
-        Ok(v) => result = v,
-        _ => {
-            error = LibWalletError::from(InterfaceError::PointerError("payment id".to_string())).code;
+    match (*transaction).transaction.body().calculate_weight(&weighting) {
+        Ok(weight) => weight,
+        Err(e) => {
+            error = LibWalletError::from(WalletError::TransactionError(e)).code;
             ptr::swap(error_out, &mut error as *mut c_int);
+            0
         },
     }
-
-    result.into_raw()
 }
 
-/// This function checks to determine if a TariCompletedTransaction was originally a TariPendingOutboundTransaction
+/// Gets the timestamp of a TariCompletedTransaction
 ///
 /// ## Arguments
-/// `tx` - The TariCompletedTransaction
+/// `transaction` - The pointer to a TariCompletedTransaction
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
 /// ## Returns
-/// `bool` - Returns if the transaction was originally sent from the wallet
+/// `c_ulonglong` - Returns the timestamp, note that it will be zero if transaction is null
 ///
 /// # Safety
 /// None
 #[no_mangle]
-pub unsafe extern "C" fn completed_transaction_is_outbound(
-    tx: *mut TariCompletedTransaction,
+pub unsafe extern "C" fn completed_transaction_get_timestamp(
+    transaction: *mut TariCompletedTransaction,
     error_out: *mut c_int,
-) -> bool {
+) -> c_ulonglong {
     let mut error = 0;
     ptr::swap(error_out, &mut error as *mut c_int);
-
-    if tx.is_null() {
-        error = LibWalletError::from(InterfaceError::NullError("tx".to_string())).code;
+    if transaction.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("transaction".to_string())).code;
         ptr::swap(error_out, &mut error as *mut c_int);
-        return false;
+        return 0;
+    }
+    (*transaction).timestamp.timestamp() as c_ulonglong
+}
+
+/// Gets the timestamp of the block in which a TariCompletedTransaction was mined
+///
+/// ## Arguments
+/// `transaction` - The pointer to a TariCompletedTransaction
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `c_ulonglong` - Returns the mined timestamp in milliseconds since the unix epoch, note that it will be zero if the
+/// transaction has not been mined yet or if transaction is null
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn completed_transaction_get_mined_timestamp(
+    transaction: *mut TariCompletedTransaction,
+    error_out: *mut c_int,
+) -> c_ulonglong {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if transaction.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("transaction".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
+    (*transaction)
+        .mined_timestamp
+        .map(|ts| ts.timestamp_millis() as c_ulonglong)
+        .unwrap_or(0)
+}
+
+/// Gets the message of a TariCompletedTransaction
+///
+/// ## Arguments
+/// `transaction` - The pointer to a TariCompletedTransaction
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `*const c_char` - Returns the pointer to the char array, note that it will return a pointer
+/// to an empty char array if transaction is null
+///
+/// # Safety
+/// The ```string_destroy``` method must be called when finished with string coming from rust to prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn completed_transaction_get_message(
+    transaction: *mut TariCompletedTransaction,
+    error_out: *mut c_int,
+) -> *const c_char {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    let message = (*transaction).message.clone();
+    let mut result = CString::new("").expect("Blank CString will not fail.");
+    if transaction.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("transaction".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return result.into_raw();
+    }
+
+    match CString::new(message) {
+        Ok(v) => result = v,
+        _ => {
+            error = LibWalletError::from(InterfaceError::PointerError("message".to_string())).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+        },
+    }
+
+    result.into_raw()
+}
+
+/// Gets the payment id of a TariCompletedTransaction
+///
+/// ## Arguments
+/// `transaction` - The pointer to a TariCompletedTransaction
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `*const c_char` - Returns the pointer to the char array, note that it will return a pointer
+/// to an empty char array if transaction is null
+///
+/// # Safety
+/// The ```string_destroy``` method must be called when finished with string coming from rust to prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn completed_transaction_get_payment_id(
+    transaction: *mut TariCompletedTransaction,
+    error_out: *mut c_int,
+) -> *const c_char {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    let payment_id = (*transaction).payment_id.clone();
+    let mut result = CString::new("").expect("Blank CString will not fail.");
+    if transaction.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("transaction".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return result.into_raw();
+    }
+    let payment_id_str = match payment_id {
+        None => "".to_string(),
+        Some(v) => {
+            let bytes = v.get_data();
+            if bytes.is_empty() {
+                format!("#{}", v)
+            } else {
+                String::from_utf8(bytes)
+                    .unwrap_or_else(|_| "Invalid string".to_string())
+                    .to_string()
+            }
+        },
+    };
+    match CString::new(payment_id_str) {
+        Ok(v) => result = v,
+        _ => {
+            error = LibWalletError::from(InterfaceError::PointerError("payment id".to_string())).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+        },
+    }
+
+    result.into_raw()
+}
+
+/// This function checks to determine if a TariCompletedTransaction was originally a TariPendingOutboundTransaction
+///
+/// ## Arguments
+/// `tx` - The TariCompletedTransaction
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `bool` - Returns if the transaction was originally sent from the wallet
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn completed_transaction_is_outbound(
+    tx: *mut TariCompletedTransaction,
+    error_out: *mut c_int,
+) -> bool {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+
+    if tx.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("tx".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
     }
 
     if (*tx).direction == TransactionDirection::Outbound {
@@ -4300,6 +6286,37 @@ pub unsafe extern "C" fn completed_transaction_is_outbound(
     false
 }
 
+/// This function checks to determine if a TariCompletedTransaction was received as a one-sided payment, i.e. it was
+/// found by scanning the blockchain rather than negotiated with the sender, so no reply was sent for it. This is
+/// recorded when the payment is found and is kept once it is mined; imported outputs are never one-sided payments
+///
+/// ## Arguments
+/// `tx` - The TariCompletedTransaction
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `bool` - Returns if the transaction was received as a one-sided payment, note that it returns false if tx is null
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn completed_transaction_is_one_sided(
+    tx: *mut TariCompletedTransaction,
+    error_out: *mut c_int,
+) -> bool {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+
+    if tx.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("tx".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+
+    (*tx).one_sided_payment
+}
+
 /// Gets the number of confirmations of a TariCompletedTransaction
 ///
 /// ## Arguments
@@ -5029,6 +7046,9 @@ pub unsafe extern "C" fn transport_memory_create() -> *mut TariTransportConfig {
 ///
 /// ## Arguments
 /// `listener_address` - The pointer to a char array
+/// `dial_timeout_secs` - The maximum time in seconds to wait for an outbound connection to be established, 0 to use
+/// the OS default
+/// `bypass_proxy` - When true, DNS addresses are resolved directly instead of through a configured SOCKS proxy
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
@@ -5041,6 +7061,8 @@ pub unsafe extern "C" fn transport_memory_create() -> *mut TariTransportConfig {
 #[no_mangle]
 pub unsafe extern "C" fn transport_tcp_create(
     listener_address: *const c_char,
+    dial_timeout_secs: c_ulonglong,
+    bypass_proxy: bool,
     error_out: *mut c_int,
 ) -> *mut TariTransportConfig {
     let mut error = 0;
@@ -5070,6 +7092,8 @@ pub unsafe extern "C" fn transport_tcp_create(
                 transport_type: TransportType::Tcp,
                 tcp: TcpTransportConfig {
                     listener_address: v,
+                    tor_socks_bypass_for_tcp: bypass_proxy,
+                    dial_timeout: (dial_timeout_secs > 0).then(|| Duration::from_secs(dial_timeout_secs)),
                     ..Default::default()
                 },
                 ..Default::default()
@@ -5084,30 +7108,26 @@ pub unsafe extern "C" fn transport_tcp_create(
     }
 }
 
-/// Creates a tor transport type
+/// Creates a tcp transport type that dials all peers through a SOCKS5 proxy
 ///
 /// ## Arguments
-/// `control_server_address` - The pointer to a char array
-/// `tor_cookie` - The pointer to a ByteVector containing the contents of the tor cookie file, can be null
-/// `tor_port` - The tor port
-/// `tor_proxy_bypass_for_outbound` - Whether tor will use a direct tcp connection for a given bypass address instead of
-/// the tor proxy if tcp is available, if not it has no effect
+/// `listener_address` - The pointer to a char array
+/// `socks_proxy_address` - The pointer to a char array containing the multiaddr of the SOCKS5 proxy
+/// `socks_username` - The pointer to a char array containing the socks username, can be null
 /// `socks_password` - The pointer to a char array containing the socks password, can be null
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
 /// ## Returns
-/// `*mut TariTransportConfig` - Returns a pointer to a tor TariTransportConfig, null on error.
+/// `*mut TariTransportConfig` - Returns a pointer to a SOCKS5 TariTransportConfig, null on error.
 ///
 /// # Safety
 /// The ```transport_config_destroy``` method must be called when finished with a TariTransportConfig to prevent a
 /// memory leak
 #[no_mangle]
-pub unsafe extern "C" fn transport_tor_create(
-    control_server_address: *const c_char,
-    tor_cookie: *const ByteVector,
-    tor_port: c_ushort,
-    tor_proxy_bypass_for_outbound: bool,
+pub unsafe extern "C" fn transport_tcp_create_with_proxy(
+    listener_address: *const c_char,
+    socks_proxy_address: *const c_char,
     socks_username: *const c_char,
     socks_password: *const c_char,
     error_out: *mut c_int,
@@ -5115,18 +7135,36 @@ pub unsafe extern "C" fn transport_tor_create(
     let mut error = 0;
     ptr::swap(error_out, &mut error as *mut c_int);
 
-    let control_address_str;
-    if control_server_address.is_null() {
-        error = LibWalletError::from(InterfaceError::NullError("control_server_address".to_string())).code;
+    let listener_address_str;
+    if listener_address.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("listener_address".to_string())).code;
         ptr::swap(error_out, &mut error as *mut c_int);
         return ptr::null_mut();
     } else {
-        match CStr::from_ptr(control_server_address).to_str() {
+        match CStr::from_ptr(listener_address).to_str() {
             Ok(v) => {
-                control_address_str = v.to_owned();
+                listener_address_str = v.to_owned();
             },
             _ => {
-                error = LibWalletError::from(InterfaceError::PointerError("control_server_address".to_string())).code;
+                error = LibWalletError::from(InterfaceError::PointerError("listener_address".to_string())).code;
+                ptr::swap(error_out, &mut error as *mut c_int);
+                return ptr::null_mut();
+            },
+        }
+    }
+
+    let proxy_address_str;
+    if socks_proxy_address.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("socks_proxy_address".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    } else {
+        match CStr::from_ptr(socks_proxy_address).to_str() {
+            Ok(v) => {
+                proxy_address_str = v.to_owned();
+            },
+            _ => {
+                error = LibWalletError::from(InterfaceError::PointerError("socks_proxy_address".to_string())).code;
                 ptr::swap(error_out, &mut error as *mut c_int);
                 return ptr::null_mut();
             },
@@ -5164,67 +7202,249 @@ pub unsafe extern "C" fn transport_tor_create(
         SocksAuthentication::None
     };
 
-    let tor_authentication = if tor_cookie.is_null() {
-        TorControlAuthentication::None
-    } else {
-        let cookie_hex = hex::to_hex((*tor_cookie).0.as_slice());
-        TorControlAuthentication::hex(cookie_hex)
-    };
-
-    let onion_port = match NonZeroU16::new(tor_port) {
-        Some(p) => p,
-        None => {
-            error = LibWalletError::from(InterfaceError::InvalidArgument(
-                "onion_port must be greater than 0".to_string(),
-            ))
-            .code;
+    let listener_address = match listener_address_str.parse::<Multiaddr>() {
+        Ok(v) => v,
+        Err(_) => {
+            error = LibWalletError::from(InterfaceError::InvalidArgument("listener_address".to_string())).code;
             ptr::swap(error_out, &mut error as *mut c_int);
             return ptr::null_mut();
         },
     };
 
-    match control_address_str.parse() {
-        Ok(v) => {
-            let transport = TariTransportConfig {
-                transport_type: TransportType::Tor,
-                tor: TorTransportConfig {
-                    control_address: v,
-                    control_auth: tor_authentication,
-                    // The wallet will populate this from the db
-                    identity: None,
-                    onion_port,
-                    socks_auth: socks_authentication,
-                    proxy_bypass_for_outbound_tcp: tor_proxy_bypass_for_outbound,
-                    ..Default::default()
-                },
-                ..Default::default()
-            };
-
+    match proxy_address_str.parse::<Multiaddr>() {
+        Ok(proxy_address) => {
+            let transport = TariTransportConfig::new_socks5(listener_address, Socks5TransportConfig {
+                proxy_address,
+                auth: socks_authentication,
+            });
             Box::into_raw(Box::new(transport))
         },
         Err(_) => {
-            error = LibWalletError::from(InterfaceError::InvalidArgument("control_address".to_string())).code;
+            error = LibWalletError::from(InterfaceError::InvalidArgument("socks_proxy_address".to_string())).code;
             ptr::swap(error_out, &mut error as *mut c_int);
             ptr::null_mut()
         },
     }
 }
 
-/// Gets the address for a memory transport type
+/// Creates a tor transport type
 ///
 /// ## Arguments
-/// `transport` - Pointer to a TariTransportConfig
+/// `control_server_address` - The pointer to a char array
+/// `tor_cookie` - The pointer to a ByteVector containing the contents of the tor cookie file, can be null
+/// `tor_port` - The tor port
+/// `tor_proxy_bypass_for_outbound` - Whether tor will use a direct tcp connection for a given bypass address instead of
+/// the tor proxy if tcp is available, if not it has no effect
+/// `socks_password` - The pointer to a char array containing the socks password, can be null
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
 /// ## Returns
-/// `*mut c_char` - Returns the address as a pointer to a char array, array will be empty on error
+/// `*mut TariTransportConfig` - Returns a pointer to a tor TariTransportConfig, null on error.
 ///
 /// # Safety
-/// Can only be used with a memory transport type, will crash otherwise
+/// The ```transport_config_destroy``` method must be called when finished with a TariTransportConfig to prevent a
+/// memory leak
 #[no_mangle]
-pub unsafe extern "C" fn transport_memory_get_address(
-    transport: *const TariTransportConfig,
+pub unsafe extern "C" fn transport_tor_create(
+    control_server_address: *const c_char,
+    tor_cookie: *const ByteVector,
+    tor_port: c_ushort,
+    tor_proxy_bypass_for_outbound: bool,
+    socks_username: *const c_char,
+    socks_password: *const c_char,
+    error_out: *mut c_int,
+) -> *mut TariTransportConfig {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+
+    let control_address_str;
+    if control_server_address.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("control_server_address".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    } else {
+        match CStr::from_ptr(control_server_address).to_str() {
+            Ok(v) => {
+                control_address_str = v.to_owned();
+            },
+            _ => {
+                error = LibWalletError::from(InterfaceError::PointerError("control_server_address".to_string())).code;
+                ptr::swap(error_out, &mut error as *mut c_int);
+                return ptr::null_mut();
+            },
+        }
+    }
+
+    let username_str;
+    let password_str;
+    let socks_authentication = if !socks_username.is_null() && !socks_password.is_null() {
+        match CStr::from_ptr(socks_username).to_str() {
+            Ok(v) => {
+                username_str = v.to_owned();
+            },
+            _ => {
+                error = LibWalletError::from(InterfaceError::PointerError("socks_username".to_string())).code;
+                ptr::swap(error_out, &mut error as *mut c_int);
+                return ptr::null_mut();
+            },
+        }
+        match CStr::from_ptr(socks_password).to_str() {
+            Ok(v) => {
+                password_str = v.to_owned();
+            },
+            _ => {
+                error = LibWalletError::from(InterfaceError::PointerError("socks_password".to_string())).code;
+                ptr::swap(error_out, &mut error as *mut c_int);
+                return ptr::null_mut();
+            },
+        };
+        SocksAuthentication::UsernamePassword {
+            username: username_str,
+            password: password_str,
+        }
+    } else {
+        SocksAuthentication::None
+    };
+
+    let tor_authentication = if tor_cookie.is_null() {
+        TorControlAuthentication::None
+    } else {
+        let cookie_hex = hex::to_hex((*tor_cookie).0.as_slice());
+        TorControlAuthentication::hex(cookie_hex)
+    };
+
+    let onion_port = match NonZeroU16::new(tor_port) {
+        Some(p) => p,
+        None => {
+            error = LibWalletError::from(InterfaceError::InvalidArgument(
+                "onion_port must be greater than 0".to_string(),
+            ))
+            .code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return ptr::null_mut();
+        },
+    };
+
+    match control_address_str.parse() {
+        Ok(v) => {
+            let transport = TariTransportConfig {
+                transport_type: TransportType::Tor,
+                tor: TorTransportConfig {
+                    control_address: v,
+                    control_auth: tor_authentication,
+                    // The wallet will populate this from the db
+                    identity: None,
+                    onion_port,
+                    socks_auth: socks_authentication,
+                    proxy_bypass_for_outbound_tcp: tor_proxy_bypass_for_outbound,
+                    ..Default::default()
+                },
+                ..Default::default()
+            };
+
+            Box::into_raw(Box::new(transport))
+        },
+        Err(_) => {
+            error = LibWalletError::from(InterfaceError::InvalidArgument("control_address".to_string())).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            ptr::null_mut()
+        },
+    }
+}
+
+/// Creates a tor transport type that reuses a previously exported tor identity, so that the onion address of the
+/// wallet is stable across restarts
+///
+/// ## Arguments
+/// `control_server_address` - The pointer to a char array
+/// `tor_cookie` - The pointer to a ByteVector containing the contents of the tor cookie file, can be null
+/// `tor_port` - The tor port
+/// `tor_proxy_bypass_for_outbound` - Whether tor will use a direct tcp connection for a given bypass address instead of
+/// the tor proxy if tcp is available, if not it has no effect
+/// `socks_password` - The pointer to a char array containing the socks password, can be null
+/// `tor_identity_json` - The pointer to a char array containing the tor identity JSON obtained from
+/// `wallet_export_tor_identity`
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `*mut TariTransportConfig` - Returns a pointer to a tor TariTransportConfig, null on error.
+///
+/// # Safety
+/// The ```transport_config_destroy``` method must be called when finished with a TariTransportConfig to prevent a
+/// memory leak
+#[no_mangle]
+pub unsafe extern "C" fn transport_tor_create_with_identity(
+    control_server_address: *const c_char,
+    tor_cookie: *const ByteVector,
+    tor_port: c_ushort,
+    tor_proxy_bypass_for_outbound: bool,
+    socks_username: *const c_char,
+    socks_password: *const c_char,
+    tor_identity_json: *const c_char,
+    error_out: *mut c_int,
+) -> *mut TariTransportConfig {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+
+    let identity_str;
+    if tor_identity_json.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("tor_identity_json".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    } else {
+        match CStr::from_ptr(tor_identity_json).to_str() {
+            Ok(v) => {
+                identity_str = v.to_owned();
+            },
+            _ => {
+                error = LibWalletError::from(InterfaceError::PointerError("tor_identity_json".to_string())).code;
+                ptr::swap(error_out, &mut error as *mut c_int);
+                return ptr::null_mut();
+            },
+        }
+    }
+
+    let identity = match serde_json::from_str::<TorIdentity>(&identity_str) {
+        Ok(identity) => identity,
+        Err(_) => {
+            error = LibWalletError::from(InterfaceError::InvalidArgument("tor_identity_json".to_string())).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return ptr::null_mut();
+        },
+    };
+
+    let transport = transport_tor_create(
+        control_server_address,
+        tor_cookie,
+        tor_port,
+        tor_proxy_bypass_for_outbound,
+        socks_username,
+        socks_password,
+        error_out,
+    );
+    if !transport.is_null() {
+        (*transport).tor.identity = Some(identity);
+    }
+    transport
+}
+
+/// Gets the address for a memory transport type
+///
+/// ## Arguments
+/// `transport` - Pointer to a TariTransportConfig
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `*mut c_char` - Returns the address as a pointer to a char array, array will be empty on error
+///
+/// # Safety
+/// Can only be used with a memory transport type, will crash otherwise
+#[no_mangle]
+pub unsafe extern "C" fn transport_memory_get_address(
+    transport: *const TariTransportConfig,
     error_out: *mut c_int,
 ) -> *mut c_char {
     let mut error = 0;
@@ -5287,6 +7507,15 @@ pub unsafe extern "C" fn transport_config_destroy(transport: *mut TariTransportC
 
 /// ----------------------------------- CommsConfig ---------------------------------------------///
 
+/// Returns `value` as a `usize`, or `default` when `value` is zero
+fn non_zero_or(value: c_uint, default: usize) -> usize {
+    if value == 0 {
+        default
+    } else {
+        value as usize
+    }
+}
+
 /// Creates a TariCommsConfig. The result from this function is required when initializing a TariWallet.
 ///
 /// ## Arguments
@@ -5301,6 +7530,13 @@ pub unsafe extern "C" fn transport_config_destroy(transport: *mut TariTransportC
 /// database files will be created and the application has write access to
 /// `discovery_timeout_in_secs`: specify how long the Discovery Timeout for the wallet is.
 /// `exclude_dial_test_addresses`: exclude dialing of test addresses; this should be 'true' for production wallets
+/// `saf_auto_request`: automatically request store-and-forward messages from peers; 'true' unless traffic needs to be
+/// kept to a minimum, e.g. on metered connections
+/// `dht_auto_join`: automatically send a join message to the network on startup; 'true' unless traffic needs to be
+/// kept to a minimum
+/// `num_neighbouring_nodes`: the number of neighbouring nodes the DHT maintains connections to, 0 for the default of 5
+/// `num_random_nodes`: the number of random nodes the DHT maintains connections to, 0 for the default of 1
+/// `min_desired_peers`: the minimum number of peers network discovery aims for, 0 for the default of 16
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
@@ -5320,6 +7556,11 @@ pub unsafe extern "C" fn comms_config_create(
     discovery_timeout_in_secs: c_ulonglong,
     saf_message_duration_in_secs: c_ulonglong,
     exclude_dial_test_addresses: bool,
+    saf_auto_request: bool,
+    dht_auto_join: bool,
+    num_neighbouring_nodes: c_uint,
+    num_random_nodes: c_uint,
+    min_desired_peers: c_uint,
     error_out: *mut c_int,
 ) -> *mut TariCommsConfig {
     let mut error = 0;
@@ -5421,20 +7662,19 @@ pub unsafe extern "C" fn comms_config_create(
                 max_concurrent_inbound_tasks: 25,
                 max_concurrent_outbound_tasks: 50,
                 dht: DhtConfig {
-                    num_neighbouring_nodes: 5,
-                    num_random_nodes: 1,
+                    num_neighbouring_nodes: non_zero_or(num_neighbouring_nodes, 5),
+                    num_random_nodes: non_zero_or(num_random_nodes, 1),
                     minimize_connections: true,
                     discovery_request_timeout: Duration::from_secs(discovery_timeout_in_secs),
                     database_url: DbConnectionUrl::File(dht_database_path),
-                    auto_join: true,
+                    auto_join: dht_auto_join,
                     saf: SafConfig {
                         msg_validity: Duration::from_secs(saf_message_duration_in_secs),
-                        // Ensure that SAF messages are requested automatically
-                        auto_request: true,
+                        auto_request: saf_auto_request,
                         ..Default::default()
                     },
                     network_discovery: NetworkDiscoveryConfig {
-                        min_desired_peers: 16,
+                        min_desired_peers: non_zero_or(min_desired_peers, 16),
                         initial_peer_sync_delay: Some(Duration::from_secs(25)),
                         ..Default::default()
                     },
@@ -5505,6 +7745,11 @@ pub unsafe extern "C" fn comms_list_connected_public_keys(
         ptr::swap(error_out, &mut error as *mut c_int);
         return ptr::null_mut();
     }
+    if let Err(e) = ensure_wallet_running(&*wallet) {
+        error = LibWalletError::from(e).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
 
     let mut connectivity = (*wallet).wallet.comms.connectivity();
     let peer_manager = (*wallet).wallet.comms.peer_manager();
@@ -5529,70 +7774,186 @@ pub unsafe extern "C" fn comms_list_connected_public_keys(
     }
 }
 
-/// Gets the length of the public keys vector
+/// This function lists all connected peers with their public key, node id and the address they are connected on
 ///
 /// ## Arguments
-/// `public_keys` - Pointer to TariPublicKeys
+/// `wallet` - The TariWallet pointer
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
 ///
 /// ## Returns
-/// `c_uint` - Length of the TariPublicKeys vector, 0 if is null
+/// `*mut TariVector` - Returns a `Text` vector with one JSON object per connected peer, in the form
+/// `{"public_key": "<hex>", "node_id": "<hex>", "address": "<multiaddr>"}`. Note the result will be null if there was
+/// an error
 ///
 /// # Safety
-/// None
-// casting here is okay as we wont have more than u32 public keys
-#[allow(clippy::cast_possible_truncation)]
+/// The caller is responsible for null checking and deallocating the returned object using `destroy_tari_vector()`.
 #[no_mangle]
-pub unsafe extern "C" fn public_keys_get_length(public_keys: *const TariPublicKeys, error_out: *mut c_int) -> c_uint {
+pub unsafe extern "C" fn comms_list_connected_peers(wallet: *mut TariWallet, error_out: *mut c_int) -> *mut TariVector {
     let mut error = 0;
     ptr::swap(error_out, &mut error as *mut c_int);
-    if public_keys.is_null() {
-        error = LibWalletError::from(InterfaceError::NullError("public_keys".to_string())).code;
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
         ptr::swap(error_out, &mut error as *mut c_int);
-        return 0;
+        return ptr::null_mut();
+    }
+    if let Err(e) = ensure_wallet_running(&*wallet) {
+        error = LibWalletError::from(e).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+
+    let mut connectivity = (*wallet).wallet.comms.connectivity();
+    let peer_manager = (*wallet).wallet.comms.peer_manager();
+
+    #[allow(clippy::blocks_in_conditions)]
+    match (*wallet).runtime.block_on(async move {
+        let connections = connectivity.get_active_connections().await?;
+        let mut peers = Vec::with_capacity(connections.len());
+        for conn in connections {
+            if let Some(peer) = peer_manager.find_by_node_id(conn.peer_node_id()).await? {
+                peers.push(
+                    serde_json::json!({
+                        "public_key": peer.public_key.to_hex(),
+                        "node_id": peer.node_id.to_hex(),
+                        "address": conn.address().to_string(),
+                    })
+                    .to_string(),
+                );
+            }
+        }
+        Result::<_, WalletError>::Ok(peers)
+    }) {
+        Ok(peers) => Box::into_raw(Box::new(TariVector::from(peers))),
+        Err(e) => {
+            error = LibWalletError::from(e).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            ptr::null_mut()
+        },
     }
-    (*public_keys).0.len() as c_uint
 }
 
-/// Gets a ByteVector at position in a EmojiSet
+/// This function attempts to connect to the peer with the given public key, waiting at most 30 seconds for the
+/// connection to be established
 ///
 /// ## Arguments
-/// `public_keys` - The pointer to a TariPublicKeys
-/// `position` - The integer position
+/// `wallet` - The TariWallet pointer
+/// `public_key` - The TariPublicKey pointer of the peer to dial
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
 /// ## Returns
-/// `ByteVector` - Returns a ByteVector. Note that the ByteVector will be null if ptr
-/// is null or if the position is invalid
+/// `bool` - Returns true if the peer is connected, false otherwise. If the peer could not be dialed or the dial timed
+/// out the error code 17 is set.
 ///
 /// # Safety
-/// The ```byte_vector_destroy``` function must be called when finished with the ByteVector to prevent a memory leak.
+/// This function blocks the calling thread until the dial completes or times out
 #[no_mangle]
-pub unsafe extern "C" fn public_keys_get_at(
-    public_keys: *const TariPublicKeys,
-    position: c_uint,
+pub unsafe extern "C" fn wallet_dial_peer(
+    wallet: *mut TariWallet,
+    public_key: *mut TariPublicKey,
     error_out: *mut c_int,
-) -> *mut TariPublicKey {
+) -> bool {
     let mut error = 0;
     ptr::swap(error_out, &mut error as *mut c_int);
-    if public_keys.is_null() {
-        error = LibWalletError::from(InterfaceError::NullError("public_keys".to_string())).code;
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
         ptr::swap(error_out, &mut error as *mut c_int);
-        return ptr::null_mut();
+        return false;
     }
-    let last_index = public_keys_get_length(public_keys, error_out) - 1;
-    if position > last_index {
-        error = LibWalletError::from(InterfaceError::PositionInvalidError).code;
+    if let Err(e) = ensure_wallet_running(&*wallet) {
+        error = LibWalletError::from(e).code;
         ptr::swap(error_out, &mut error as *mut c_int);
-        return ptr::null_mut();
+        return false;
     }
-    let result = (*public_keys).0[position as usize].clone();
-    Box::into_raw(Box::new(result))
-}
-
-/// ---------------------------------------------------------------------------------------------- ///
-
-/// ------------------------------------- Wallet -------------------------------------------------///
+    if public_key.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("public_key".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+
+    let connectivity = (*wallet).wallet.comms.connectivity();
+    let node_id = TariNodeId::from_public_key(&*public_key);
+
+    match (*wallet).runtime.block_on(async move {
+        match tokio::time::timeout(Duration::from_secs(30), connectivity.dial_peer(node_id)).await {
+            Ok(result) => result.map(|_| ()).map_err(|e| e.to_string()),
+            Err(_) => Err("Timed out dialing peer".to_string()),
+        }
+    }) {
+        Ok(()) => true,
+        Err(e) => {
+            error = LibWalletError::from(InterfaceError::DialFailed(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            false
+        },
+    }
+}
+
+/// Gets the length of the public keys vector
+///
+/// ## Arguments
+/// `public_keys` - Pointer to TariPublicKeys
+///
+/// ## Returns
+/// `c_uint` - Length of the TariPublicKeys vector, 0 if is null
+///
+/// # Safety
+/// None
+// casting here is okay as we wont have more than u32 public keys
+#[allow(clippy::cast_possible_truncation)]
+#[no_mangle]
+pub unsafe extern "C" fn public_keys_get_length(public_keys: *const TariPublicKeys, error_out: *mut c_int) -> c_uint {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if public_keys.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("public_keys".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
+    (*public_keys).0.len() as c_uint
+}
+
+/// Gets a ByteVector at position in a EmojiSet
+///
+/// ## Arguments
+/// `public_keys` - The pointer to a TariPublicKeys
+/// `position` - The integer position
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `ByteVector` - Returns a ByteVector. Note that the ByteVector will be null if ptr
+/// is null or if the position is invalid
+///
+/// # Safety
+/// The ```byte_vector_destroy``` function must be called when finished with the ByteVector to prevent a memory leak.
+#[no_mangle]
+pub unsafe extern "C" fn public_keys_get_at(
+    public_keys: *const TariPublicKeys,
+    position: c_uint,
+    error_out: *mut c_int,
+) -> *mut TariPublicKey {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if public_keys.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("public_keys".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+    let last_index = public_keys_get_length(public_keys, error_out) - 1;
+    if position > last_index {
+        error = LibWalletError::from(InterfaceError::PositionInvalidError).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+    let result = (*public_keys).0[position as usize].clone();
+    Box::into_raw(Box::new(result))
+}
+
+/// ---------------------------------------------------------------------------------------------- ///
+
+/// ------------------------------------- Wallet -------------------------------------------------///
 
 /// Inits logging, this function is deliberately not exposed externally in the header
 ///
@@ -5740,113 +8101,11 @@ unsafe fn init_logging(
     }
 }
 
-/// Creates a TariWallet
-///
-/// ## Arguments
-/// Context - a pointer to some context used by all the callbacks
-/// `config` - The TariCommsConfig pointer
-/// `log_path` - An optional file path to the file where the logs will be written. If no log is required pass *null*
-/// pointer.
-/// `log_verbosity` - how verbose should logging be as a c_int 0-5, or 11
-///        0 => Off
-///        1 => Error
-///        2 => Warn
-///        3 => Info
-///        4 => Debug
-///        5 | 11 => Trace // Cranked up to 11
-/// `num_rolling_log_files` - Specifies how many rolling log files to produce, if no rolling files are wanted then set
-/// this to 0
-/// `size_per_log_file_bytes` - Specifies the size, in bytes, at which the logs files will roll over, if no
-/// rolling files are wanted then set this to 0
-/// `passphrase` - An optional string that represents the passphrase used to
-/// encrypt/decrypt the databases for this wallet. If it is left Null no encryption is used. If the databases have been
-/// encrypted then the correct passphrase is required or this function will fail.
-/// `seed_passphrase` - an optional string, if present this will derypt the seed words
-/// `seed_words` - An optional instance of TariSeedWords, used to create a wallet for recovery purposes.
-/// If this is null, then a new master key is created for the wallet.
-/// `dns_seed_name_servers_str` - An optional list of DNS servers to query to get hold of the seed peer list.
-/// `use_dns_sec` - Use DNSSEC when querying the DNS servers.
-/// `callback_received_transaction` - The callback function pointer matching the function signature. This will be
-/// called when an inbound transaction is received.
-/// `callback_received_transaction_reply` - The callback function
-/// pointer matching the function signature. This will be called when a reply is received for a pending outbound
-/// transaction
-/// `callback_received_finalized_transaction` - The callback function pointer matching the function
-/// signature. This will be called when a Finalized version on an Inbound transaction is received
-/// `callback_transaction_broadcast` - The callback function pointer matching the function signature. This will be
-/// called when a Finalized transaction is detected a Broadcast to a base node mempool.
-/// `callback_transaction_mined` - The callback function pointer matching the function signature. This will be called
-/// when a Broadcast transaction is detected as mined AND confirmed.
-/// `callback_transaction_mined_unconfirmed` - The callback function pointer matching the function signature. This will
-/// be called when a Broadcast transaction is detected as mined but not yet confirmed.
-/// `callback_faux_transaction_confirmed` - The callback function pointer matching the function signature. This will be
-/// called when a one-sided transaction is detected as mined AND confirmed.
-/// `callback_faux_transaction_unconfirmed` - The callback function pointer matching the function signature. This
-/// will be called when a one-sided transaction is detected as mined but not yet confirmed.
-/// `callback_transaction_send_result` - The callback function pointer matching the function signature. This is called
-/// when a transaction send is completed. The first parameter is the transaction id and the second contains the
-/// transaction send status, weather it was send direct and/or send via saf on the one hand or queued for further retry
-/// sending on the other hand.
-///     !direct_send & !saf_send &  queued   = 0
-///      direct_send &  saf_send & !queued   = 1
-///      direct_send & !saf_send & !queued   = 2
-///     !direct_send &  saf_send & !queued   = 3
-///     any other combination (is not valid) = 4
-/// `callback_transaction_cancellation` - The callback function pointer matching
-/// the function signature. This is called when a transaction is cancelled. The first parameter is a pointer to the
-/// cancelled transaction, the second is a reason as to why said transaction failed that is mapped to the
-/// `TxCancellationReason` enum: pub enum TxCancellationReason {
-///     Unknown,                // 0
-///     UserCancelled,          // 1
-///     Timeout,                // 2
-///     DoubleSpend,            // 3
-///     Orphan,                 // 4
-///     TimeLocked,             // 5
-///     InvalidTransaction,     // 6
-/// }
-/// `callback_txo_validation_complete` - The callback function pointer matching the function signature. This is called
-/// when a TXO validation process is completed. The request_key is used to identify which request this
-/// callback references and the second parameter the second contains, weather it was successful, already busy, failed
-/// due to an internal failure or failed due to a communication failure.
-///     TxoValidationSuccess,               // 0
-///     TxoValidationAlreadyBusy            // 1
-///     TxoValidationInternalFailure        // 2
-///     TxoValidationCommunicationFailure   // 3
-/// `callback_contacts_liveness_data_updated` - The callback function pointer matching the function signature. This is
-/// called when a contact's liveness status changed. The data represents the contact's updated status information.
-/// `callback_balance_updated` - The callback function pointer matching the function signature. This is called whenever
-/// the balance changes.
-/// `callback_transaction_validation_complete` - The callback function pointer matching the function signature. This is
-/// called when a Transaction validation process is completed. The request_key is used to identify which request this
-/// callback references and the second parameter is a u64 that returns if the validation was successful or not.
-///         ValidationSuccess,               // 0
-///         ValidationAlreadyBusy            // 1
-///         ValidationInternalFailure        // 2
-///         ValidationCommunicationFailure   // 3
-/// `callback_saf_message_received` - The callback function pointer that will be called when the Dht has determined that
-/// is has connected to enough of its neighbours to be confident that it has received any SAF messages that were waiting
-/// for it.
-/// `callback_connectivity_status` -  This callback is called when the status of connection to the set base node
-/// changes. it will return an enum encoded as an integer as follows:
-/// pub enum OnlineStatus {
-///     Connecting,     // 0
-///     Online,         // 1
-///     Offline,        // 2
-/// }
-/// `recovery_in_progress` - Pointer to an bool which will be modified to indicate if there is an outstanding recovery
-/// that should be completed or not to an error code should one occur, may not be null. Functions as an out parameter.
-/// `error_out` - Pointer to an int which will be modified
-/// to an error code should one occur, may not be null. Functions as an out parameter.
-/// ## Returns
-/// `*mut TariWallet` - Returns a pointer to a TariWallet, note that it returns ptr::null_mut()
-/// if config is null, a wallet error was encountered or if the runtime could not be created
-///
-/// # Safety
-/// The ```wallet_destroy``` method must be called when finished with a TariWallet to prevent a memory leak
-#[no_mangle]
+/// Starts a TariWallet of the given type, see `wallet_create` for the arguments
 #[allow(clippy::cognitive_complexity)]
+#[allow(clippy::too_many_arguments)]
 #[allow(clippy::too_many_lines)]
-pub unsafe extern "C" fn wallet_create(
+unsafe fn start_wallet(
     context: *mut c_void,
     config: *mut TariCommsConfig,
     log_path: *const c_char,
@@ -5891,6 +8150,7 @@ pub unsafe extern "C" fn wallet_create(
     callback_connectivity_status: unsafe extern "C" fn(context: *mut c_void, u64),
     callback_wallet_scanned_height: unsafe extern "C" fn(context: *mut c_void, u64),
     callback_base_node_state: unsafe extern "C" fn(context: *mut c_void, *mut TariBaseNodeState),
+    wallet_type: WalletType,
     recovery_in_progress: *mut bool,
     error_out: *mut c_int,
 ) -> *mut TariWallet {
@@ -6022,7 +8282,7 @@ pub unsafe extern "C" fn wallet_create(
             return ptr::null_mut();
         },
     };
-    let factories = CryptoFactories::default();
+    let factories = crypto_factories().clone();
 
     let sql_database_path = (*config)
         .datastore_path
@@ -6044,12 +8304,28 @@ pub unsafe extern "C" fn wallet_create(
     let wallet_database = WalletDatabase::new(wallet_backend);
     let output_manager_database = OutputManagerDatabase::new(output_manager_backend.clone());
 
+    // The stored wallet type wins when the wallet is started, so a database that already belongs to another wallet
+    // cannot be opened as watch-only
+    if wallet_type.is_watch_only() &&
+        matches!(wallet_database.get_wallet_type(), Ok(Some(ref existing)) if *existing != wallet_type)
+    {
+        error = LibWalletError::from(InterfaceError::InvalidArgument(
+            "the wallet database belongs to a different wallet".to_string(),
+        ))
+        .code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+
     debug!(target: LOG_TARGET, "Databases Initialized");
 
-    // If the transport type is Tor then check if there is a stored TorID, if there is update the Transport Type
+    // If the transport type is Tor and no identity was provided then check if there is a stored TorID, if there is
+    // update the Transport Type
     let mut comms_config = (*config).clone();
     if let TransportType::Tor = comms_config.transport.transport_type {
-        comms_config.transport.tor.identity = wallet_database.get_tor_id().ok().flatten();
+        if comms_config.transport.tor.identity.is_none() {
+            comms_config.transport.tor.identity = wallet_database.get_tor_id().ok().flatten();
+        }
     }
 
     let result = runtime.block_on(async {
@@ -6162,7 +8438,7 @@ pub unsafe extern "C" fn wallet_create(
         key_manager_backend,
         shutdown.to_signal(),
         master_seed,
-        Some(WalletType::default()),
+        Some(wallet_type),
         user_agent,
     ));
 
@@ -6238,6 +8514,7 @@ pub unsafe extern "C" fn wallet_create(
                 callback_base_node_state,
             );
 
+            let callbacks = callback_handler.updatable_callbacks();
             runtime.spawn(callback_handler.start());
 
             let tari_wallet = TariWallet {
@@ -6245,6 +8522,7 @@ pub unsafe extern "C" fn wallet_create(
                 runtime,
                 shutdown,
                 context,
+                callbacks,
             };
 
             Box::into_raw(Box::new(tari_wallet))
@@ -6257,825 +8535,3958 @@ pub unsafe extern "C" fn wallet_create(
     }
 }
 
-/// Retrieves the version of an app that last accessed the wallet database
-///
-/// ## Arguments
-/// `config` - The TariCommsConfig pointer
-/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
-/// as an out parameter.
-/// ## Returns
-/// `*mut c_char` - Returns the pointer to the hexadecimal representation of the signature and
-///
-/// # Safety
-/// The ```string_destroy``` method must be called when finished with a string coming from rust to prevent a memory leak
-#[no_mangle]
-pub unsafe extern "C" fn wallet_get_last_version(config: *mut TariCommsConfig, error_out: *mut c_int) -> *mut c_char {
-    let mut error = 0;
-    ptr::swap(error_out, &mut error as *mut c_int);
-    if config.is_null() {
-        error = LibWalletError::from(InterfaceError::NullError("config".to_string())).code;
-        ptr::swap(error_out, &mut error as *mut c_int);
-        return ptr::null_mut();
-    }
-
-    let sql_database_path = (*config)
-        .datastore_path
-        .join((*config).peer_database_name.clone())
-        .with_extension("sqlite3");
-    match get_last_version(sql_database_path) {
-        Ok(None) => ptr::null_mut(),
-        Ok(Some(version)) => {
-            let version = CString::new(version).expect("failed to initialize CString");
-            version.into_raw()
-        },
-        Err(e) => {
-            error = LibWalletError::from(WalletError::WalletStorageError(e)).code;
-            ptr::swap(error_out, &mut error as *mut c_int);
-            ptr::null_mut()
-        },
-    }
-}
-
-/// Retrieves the network of an app that last accessed the wallet database
+/// Creates a TariWallet
 ///
 /// ## Arguments
+/// Context - a pointer to some context used by all the callbacks
 /// `config` - The TariCommsConfig pointer
-/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
-/// as an out parameter.
-/// ## Returns
-/// `*mut c_char` - Returns the pointer to the hexadecimal representation of the signature and
-///
-/// # Safety
-/// The ```string_destroy``` method must be called when finished with a string coming from rust to prevent a memory leak
-#[no_mangle]
-pub unsafe extern "C" fn wallet_get_last_network(config: *mut TariCommsConfig, error_out: *mut c_int) -> *mut c_char {
-    let mut error = 0;
-    ptr::swap(error_out, &mut error as *mut c_int);
-    if config.is_null() {
-        error = LibWalletError::from(InterfaceError::NullError("config".to_string())).code;
-        ptr::swap(error_out, &mut error as *mut c_int);
-        return ptr::null_mut();
-    }
-
-    let sql_database_path = (*config)
-        .datastore_path
-        .join((*config).peer_database_name.clone())
+/// `log_path` - An optional file path to the file where the logs will be written. If no log is required pass *null*
+/// pointer.
+/// `log_verbosity` - how verbose should logging be as a c_int 0-5, or 11
+///        0 => Off
+///        1 => Error
+///        2 => Warn
+///        3 => Info
+///        4 => Debug
+///        5 | 11 => Trace // Cranked up to 11
+/// `num_rolling_log_files` - Specifies how many rolling log files to produce, if no rolling files are wanted then set
+/// this to 0
+/// `size_per_log_file_bytes` - Specifies the size, in bytes, at which the logs files will roll over, if no
+/// rolling files are wanted then set this to 0
+/// `passphrase` - An optional string that represents the passphrase used to
+/// encrypt/decrypt the databases for this wallet. If it is left Null no encryption is used. If the databases have been
+/// encrypted then the correct passphrase is required or this function will fail.
+/// `seed_passphrase` - an optional string, if present this will derypt the seed words. It must match the passphrase
+/// the seed words were created with, a mismatched passphrase fails to decipher the seed words and this function will
+/// fail. Use `seed_words_validate_with_passphrase` to check a phrase and passphrase before creating the wallet.
+/// `seed_words` - An optional instance of TariSeedWords, used to create a wallet for recovery purposes.
+/// If this is null, then a new master key is created for the wallet.
+/// `dns_seed_name_servers_str` - An optional list of DNS servers to query to get hold of the seed peer list.
+/// `use_dns_sec` - Use DNSSEC when querying the DNS servers.
+/// `callback_received_transaction` - The callback function pointer matching the function signature. This will be
+/// called when an inbound transaction is received.
+/// `callback_received_transaction_reply` - The callback function
+/// pointer matching the function signature. This will be called when a reply is received for a pending outbound
+/// transaction
+/// `callback_received_finalized_transaction` - The callback function pointer matching the function
+/// signature. This will be called when a Finalized version on an Inbound transaction is received
+/// `callback_transaction_broadcast` - The callback function pointer matching the function signature. This will be
+/// called when a Finalized transaction is detected a Broadcast to a base node mempool.
+/// `callback_transaction_mined` - The callback function pointer matching the function signature. This will be called
+/// when a Broadcast transaction is detected as mined AND confirmed.
+/// `callback_transaction_mined_unconfirmed` - The callback function pointer matching the function signature. This will
+/// be called when a Broadcast transaction is detected as mined but not yet confirmed.
+/// `callback_faux_transaction_confirmed` - The callback function pointer matching the function signature. This will be
+/// called when a one-sided transaction is detected as mined AND confirmed.
+/// `callback_faux_transaction_unconfirmed` - The callback function pointer matching the function signature. This
+/// will be called when a one-sided transaction is detected as mined but not yet confirmed.
+/// `callback_transaction_send_result` - The callback function pointer matching the function signature. This is called
+/// when a transaction send is completed. The first parameter is the transaction id and the second contains the
+/// transaction send status, weather it was send direct and/or send via saf on the one hand or queued for further retry
+/// sending on the other hand.
+///     !direct_send & !saf_send &  queued   = 0
+///      direct_send &  saf_send & !queued   = 1
+///      direct_send & !saf_send & !queued   = 2
+///     !direct_send &  saf_send & !queued   = 3
+///     any other combination (is not valid) = 4
+/// `callback_transaction_cancellation` - The callback function pointer matching
+/// the function signature. This is called when a transaction is cancelled. The first parameter is a pointer to the
+/// cancelled transaction, the second is a reason as to why said transaction failed that is mapped to the
+/// `TxCancellationReason` enum: pub enum TxCancellationReason {
+///     Unknown,                // 0
+///     UserCancelled,          // 1
+///     Timeout,                // 2
+///     DoubleSpend,            // 3
+///     Orphan,                 // 4
+///     TimeLocked,             // 5
+///     InvalidTransaction,     // 6
+/// }
+/// `callback_txo_validation_complete` - The callback function pointer matching the function signature. This is called
+/// when a TXO validation process is completed. The request_key is used to identify which request this
+/// callback references and the second parameter the second contains, weather it was successful, already busy, failed
+/// due to an internal failure or failed due to a communication failure.
+///     TxoValidationSuccess,               // 0
+///     TxoValidationAlreadyBusy            // 1
+///     TxoValidationInternalFailure        // 2
+///     TxoValidationCommunicationFailure   // 3
+/// `callback_contacts_liveness_data_updated` - The callback function pointer matching the function signature. This is
+/// called when a contact's liveness status changed. The data represents the contact's updated status information.
+/// `callback_balance_updated` - The callback function pointer matching the function signature. This is called whenever
+/// the balance changes.
+/// `callback_transaction_validation_complete` - The callback function pointer matching the function signature. This is
+/// called when a Transaction validation process is completed. The request_key is used to identify which request this
+/// callback references and the second parameter is a u64 that returns if the validation was successful or not.
+///         ValidationSuccess,               // 0
+///         ValidationAlreadyBusy            // 1
+///         ValidationInternalFailure        // 2
+///         ValidationCommunicationFailure   // 3
+/// `callback_saf_message_received` - The callback function pointer that will be called when the Dht has determined that
+/// is has connected to enough of its neighbours to be confident that it has received any SAF messages that were waiting
+/// for it.
+/// `callback_connectivity_status` -  This callback is called when the status of connection to the set base node
+/// changes. it will return an enum encoded as an integer as follows:
+/// pub enum OnlineStatus {
+///     Connecting,     // 0
+///     Online,         // 1
+///     Offline,        // 2
+/// }
+/// `callback_base_node_state` - This callback is called when the state of the connected base node changes. The
+/// `TariBaseNodeState` can be read with the `basenode_state_get_*` functions and must be freed with
+/// `basenode_state_destroy`.
+/// `recovery_in_progress` - Pointer to an bool which will be modified to indicate if there is an outstanding recovery
+/// that should be completed or not to an error code should one occur, may not be null. Functions as an out parameter.
+/// `error_out` - Pointer to an int which will be modified
+/// to an error code should one occur, may not be null. Functions as an out parameter.
+/// ## Returns
+/// `*mut TariWallet` - Returns a pointer to a TariWallet, note that it returns ptr::null_mut()
+/// if config is null, a wallet error was encountered or if the runtime could not be created
+///
+/// # Safety
+/// The ```wallet_destroy``` method must be called when finished with a TariWallet to prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn wallet_create(
+    context: *mut c_void,
+    config: *mut TariCommsConfig,
+    log_path: *const c_char,
+    log_verbosity: c_int,
+    num_rolling_log_files: c_uint,
+    size_per_log_file_bytes: c_uint,
+    passphrase: *const c_char,
+    seed_passphrase: *const c_char,
+    seed_words: *const TariSeedWords,
+    network_str: *const c_char,
+    dns_seeds_str: *const c_char,
+    dns_seed_name_servers_str: *const c_char,
+    use_dns_sec: bool,
+
+    callback_received_transaction: unsafe extern "C" fn(context: *mut c_void, *mut TariPendingInboundTransaction),
+    callback_received_transaction_reply: unsafe extern "C" fn(context: *mut c_void, *mut TariCompletedTransaction),
+    callback_received_finalized_transaction: unsafe extern "C" fn(context: *mut c_void, *mut TariCompletedTransaction),
+    callback_transaction_broadcast: unsafe extern "C" fn(context: *mut c_void, *mut TariCompletedTransaction),
+    callback_transaction_mined: unsafe extern "C" fn(context: *mut c_void, *mut TariCompletedTransaction),
+    callback_transaction_mined_unconfirmed: unsafe extern "C" fn(
+        context: *mut c_void,
+        *mut TariCompletedTransaction,
+        u64,
+    ),
+    callback_faux_transaction_confirmed: unsafe extern "C" fn(context: *mut c_void, *mut TariCompletedTransaction),
+    callback_faux_transaction_unconfirmed: unsafe extern "C" fn(
+        context: *mut c_void,
+        *mut TariCompletedTransaction,
+        u64,
+    ),
+    callback_transaction_send_result: unsafe extern "C" fn(
+        context: *mut c_void,
+        c_ulonglong,
+        *mut TariTransactionSendStatus,
+    ),
+    callback_transaction_cancellation: unsafe extern "C" fn(context: *mut c_void, *mut TariCompletedTransaction, u64),
+    callback_txo_validation_complete: unsafe extern "C" fn(context: *mut c_void, u64, u64),
+    callback_contacts_liveness_data_updated: unsafe extern "C" fn(context: *mut c_void, *mut TariContactsLivenessData),
+    callback_balance_updated: unsafe extern "C" fn(context: *mut c_void, *mut TariBalance),
+    callback_transaction_validation_complete: unsafe extern "C" fn(context: *mut c_void, u64, u64),
+    callback_saf_messages_received: unsafe extern "C" fn(context: *mut c_void),
+    callback_connectivity_status: unsafe extern "C" fn(context: *mut c_void, u64),
+    callback_wallet_scanned_height: unsafe extern "C" fn(context: *mut c_void, u64),
+    callback_base_node_state: unsafe extern "C" fn(context: *mut c_void, *mut TariBaseNodeState),
+    recovery_in_progress: *mut bool,
+    error_out: *mut c_int,
+) -> *mut TariWallet {
+    start_wallet(
+        context,
+        config,
+        log_path,
+        log_verbosity,
+        num_rolling_log_files,
+        size_per_log_file_bytes,
+        passphrase,
+        seed_passphrase,
+        seed_words,
+        network_str,
+        dns_seeds_str,
+        dns_seed_name_servers_str,
+        use_dns_sec,
+        callback_received_transaction,
+        callback_received_transaction_reply,
+        callback_received_finalized_transaction,
+        callback_transaction_broadcast,
+        callback_transaction_mined,
+        callback_transaction_mined_unconfirmed,
+        callback_faux_transaction_confirmed,
+        callback_faux_transaction_unconfirmed,
+        callback_transaction_send_result,
+        callback_transaction_cancellation,
+        callback_txo_validation_complete,
+        callback_contacts_liveness_data_updated,
+        callback_balance_updated,
+        callback_transaction_validation_complete,
+        callback_saf_messages_received,
+        callback_connectivity_status,
+        callback_wallet_scanned_height,
+        callback_base_node_state,
+        WalletType::default(),
+        recovery_in_progress,
+        error_out,
+    )
+}
+
+/// Creates a watch-only TariWallet from a private view key and a public spend key. The wallet scans for and reports
+/// funds sent to the address made from these keys, but cannot spend them: sending, coin splits and coin joins fail
+/// with the watch-only error code (15).
+///
+/// ## Arguments
+/// `view_key` - The TariPrivateKey pointer of the private view key, may not be null
+/// `spend_public_key` - The TariPublicKey pointer of the public spend key, may not be null
+/// All other arguments are as for `wallet_create`. A wallet database that was created by another kind of wallet cannot
+/// be opened as watch-only.
+///
+/// ## Returns
+/// `*mut TariWallet` - Returns a pointer to a TariWallet, note that it returns ptr::null_mut()
+/// if config, view_key or spend_public_key is null, a wallet error was encountered or if the runtime could not be
+/// created
+///
+/// # Safety
+/// The ```wallet_destroy``` method must be called when finished with a TariWallet to prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn wallet_create_watch_only(
+    context: *mut c_void,
+    config: *mut TariCommsConfig,
+    log_path: *const c_char,
+    log_verbosity: c_int,
+    num_rolling_log_files: c_uint,
+    size_per_log_file_bytes: c_uint,
+    passphrase: *const c_char,
+    view_key: *mut TariPrivateKey,
+    spend_public_key: *mut TariPublicKey,
+    network_str: *const c_char,
+    dns_seeds_str: *const c_char,
+    dns_seed_name_servers_str: *const c_char,
+    use_dns_sec: bool,
+
+    callback_received_transaction: unsafe extern "C" fn(context: *mut c_void, *mut TariPendingInboundTransaction),
+    callback_received_transaction_reply: unsafe extern "C" fn(context: *mut c_void, *mut TariCompletedTransaction),
+    callback_received_finalized_transaction: unsafe extern "C" fn(context: *mut c_void, *mut TariCompletedTransaction),
+    callback_transaction_broadcast: unsafe extern "C" fn(context: *mut c_void, *mut TariCompletedTransaction),
+    callback_transaction_mined: unsafe extern "C" fn(context: *mut c_void, *mut TariCompletedTransaction),
+    callback_transaction_mined_unconfirmed: unsafe extern "C" fn(
+        context: *mut c_void,
+        *mut TariCompletedTransaction,
+        u64,
+    ),
+    callback_faux_transaction_confirmed: unsafe extern "C" fn(context: *mut c_void, *mut TariCompletedTransaction),
+    callback_faux_transaction_unconfirmed: unsafe extern "C" fn(
+        context: *mut c_void,
+        *mut TariCompletedTransaction,
+        u64,
+    ),
+    callback_transaction_send_result: unsafe extern "C" fn(
+        context: *mut c_void,
+        c_ulonglong,
+        *mut TariTransactionSendStatus,
+    ),
+    callback_transaction_cancellation: unsafe extern "C" fn(context: *mut c_void, *mut TariCompletedTransaction, u64),
+    callback_txo_validation_complete: unsafe extern "C" fn(context: *mut c_void, u64, u64),
+    callback_contacts_liveness_data_updated: unsafe extern "C" fn(context: *mut c_void, *mut TariContactsLivenessData),
+    callback_balance_updated: unsafe extern "C" fn(context: *mut c_void, *mut TariBalance),
+    callback_transaction_validation_complete: unsafe extern "C" fn(context: *mut c_void, u64, u64),
+    callback_saf_messages_received: unsafe extern "C" fn(context: *mut c_void),
+    callback_connectivity_status: unsafe extern "C" fn(context: *mut c_void, u64),
+    callback_wallet_scanned_height: unsafe extern "C" fn(context: *mut c_void, u64),
+    callback_base_node_state: unsafe extern "C" fn(context: *mut c_void, *mut TariBaseNodeState),
+    recovery_in_progress: *mut bool,
+    error_out: *mut c_int,
+) -> *mut TariWallet {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if view_key.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("view_key".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+    if spend_public_key.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("spend_public_key".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+
+    let wallet_type = WalletType::ProvidedKeys(ProvidedKeysWallet {
+        public_spend_key: (*spend_public_key).clone(),
+        private_spend_key: None,
+        private_comms_key: None,
+        view_key: (*view_key).clone(),
+    });
+
+    start_wallet(
+        context,
+        config,
+        log_path,
+        log_verbosity,
+        num_rolling_log_files,
+        size_per_log_file_bytes,
+        passphrase,
+        ptr::null(),
+        ptr::null(),
+        network_str,
+        dns_seeds_str,
+        dns_seed_name_servers_str,
+        use_dns_sec,
+        callback_received_transaction,
+        callback_received_transaction_reply,
+        callback_received_finalized_transaction,
+        callback_transaction_broadcast,
+        callback_transaction_mined,
+        callback_transaction_mined_unconfirmed,
+        callback_faux_transaction_confirmed,
+        callback_faux_transaction_unconfirmed,
+        callback_transaction_send_result,
+        callback_transaction_cancellation,
+        callback_txo_validation_complete,
+        callback_contacts_liveness_data_updated,
+        callback_balance_updated,
+        callback_transaction_validation_complete,
+        callback_saf_messages_received,
+        callback_connectivity_status,
+        callback_wallet_scanned_height,
+        callback_base_node_state,
+        wallet_type,
+        recovery_in_progress,
+        error_out,
+    )
+}
+
+/// Gets the type of a TariWallet
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `c_int` - Returns the wallet type, or -1 if wallet is null:
+///     DerivedKeys,    // 0 - keys are derived from the wallet seed
+///     Ledger,         // 1 - spending is signed on a Ledger device
+///     ProvidedKeys,   // 2 - keys were provided when the wallet was created
+///     WatchOnly,      // 3 - provided keys without a private spend key, see `wallet_create_watch_only`
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn wallet_get_wallet_type(wallet: *mut TariWallet, error_out: *mut c_int) -> c_int {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return -1;
+    }
+
+    match (*wallet).wallet.get_wallet_type() {
+        WalletType::DerivedKeys => 0,
+        WalletType::Ledger(_) => 1,
+        wallet_type if wallet_type.is_watch_only() => 3,
+        WalletType::ProvidedKeys(_) => 2,
+    }
+}
+
+/// Replaces the balance updated callback of a running wallet. The new callback is used from the next event onwards and
+/// is called with the same context that was passed to `wallet_create`.
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer
+/// `callback` - The callback function pointer matching the function signature. This is called when the balance of the
+/// wallet changes, with the same semantics as the `callback_balance_updated` argument of `wallet_create`
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `bool` - Returns true if the callback was replaced, false if the wallet is null
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn wallet_set_balance_updated_callback(
+    wallet: *mut TariWallet,
+    callback: unsafe extern "C" fn(context: *mut c_void, *mut TariBalance),
+    error_out: *mut c_int,
+) -> bool {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+
+    (*wallet).callbacks.set_balance_updated(callback);
+    true
+}
+
+/// Replaces the connectivity status callback of a running wallet. The new callback is used from the next event onwards
+/// and is called with the same context that was passed to `wallet_create`.
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer
+/// `callback` - The callback function pointer matching the function signature. This is called when the connectivity
+/// status to the base node changes, with the same semantics as the `callback_connectivity_status` argument of
+/// `wallet_create`
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `bool` - Returns true if the callback was replaced, false if the wallet is null
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn wallet_set_connectivity_status_callback(
+    wallet: *mut TariWallet,
+    callback: unsafe extern "C" fn(context: *mut c_void, u64),
+    error_out: *mut c_int,
+) -> bool {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+
+    (*wallet).callbacks.set_connectivity_status(callback);
+    true
+}
+
+/// Replaces the base node state callback of a running wallet. The new callback is used from the next event onwards and
+/// is called with the same context that was passed to `wallet_create`.
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer
+/// `callback` - The callback function pointer matching the function signature. This is called when the state of the
+/// base node changes, with the same semantics as the `callback_base_node_state` argument of `wallet_create`
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `bool` - Returns true if the callback was replaced, false if the wallet is null
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn wallet_set_base_node_state_callback(
+    wallet: *mut TariWallet,
+    callback: unsafe extern "C" fn(context: *mut c_void, *mut TariBaseNodeState),
+    error_out: *mut c_int,
+) -> bool {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+
+    (*wallet).callbacks.set_base_node_state(callback);
+    true
+}
+
+/// Retrieves the version of this library build, including the git commit and build profile
+///
+/// ## Arguments
+/// None
+///
+/// ## Returns
+/// `*mut c_char` - Returns the pointer to the version string, e.g. `1.0.0-fc435c-release`
+///
+/// # Safety
+/// The ```string_destroy``` method must be called when finished with a string coming from rust to prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn wallet_get_version() -> *mut c_char {
+    CString::new(consts::APP_VERSION)
+        .expect("The compiled-in version should not contain a nul byte")
+        .into_raw()
+}
+
+/// Retrieves the git commit hash this library was built from
+///
+/// ## Arguments
+/// None
+///
+/// ## Returns
+/// `*mut c_char` - Returns the pointer to the commit hash string, or `NoGitRepository` if the build had no git
+/// information available
+///
+/// # Safety
+/// The ```string_destroy``` method must be called when finished with a string coming from rust to prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn wallet_get_commit_hash() -> *mut c_char {
+    CString::new(consts::APP_COMMIT)
+        .expect("The compiled-in commit hash should not contain a nul byte")
+        .into_raw()
+}
+
+/// Retrieves the version of an app that last accessed the wallet database
+///
+/// ## Arguments
+/// `config` - The TariCommsConfig pointer
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+/// ## Returns
+/// `*mut c_char` - Returns the pointer to the hexadecimal representation of the signature and
+///
+/// # Safety
+/// The ```string_destroy``` method must be called when finished with a string coming from rust to prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn wallet_get_last_version(config: *mut TariCommsConfig, error_out: *mut c_int) -> *mut c_char {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if config.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("config".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+
+    let sql_database_path = (*config)
+        .datastore_path
+        .join((*config).peer_database_name.clone())
+        .with_extension("sqlite3");
+    match get_last_version(sql_database_path) {
+        Ok(None) => ptr::null_mut(),
+        Ok(Some(version)) => {
+            let version = CString::new(version).expect("failed to initialize CString");
+            version.into_raw()
+        },
+        Err(e) => {
+            error = LibWalletError::from(WalletError::WalletStorageError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            ptr::null_mut()
+        },
+    }
+}
+
+/// Retrieves the network of an app that last accessed the wallet database
+///
+/// ## Arguments
+/// `config` - The TariCommsConfig pointer
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+/// ## Returns
+/// `*mut c_char` - Returns the pointer to the hexadecimal representation of the signature and
+///
+/// # Safety
+/// The ```string_destroy``` method must be called when finished with a string coming from rust to prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn wallet_get_last_network(config: *mut TariCommsConfig, error_out: *mut c_int) -> *mut c_char {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if config.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("config".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+
+    let sql_database_path = (*config)
+        .datastore_path
+        .join((*config).peer_database_name.clone())
+        .with_extension("sqlite3");
+    match get_last_network(sql_database_path) {
+        Ok(None) => ptr::null_mut(),
+        Ok(Some(network)) => {
+            let network = CString::new(network).expect("failed to initialize CString");
+            network.into_raw()
+        },
+        Err(e) => {
+            error = LibWalletError::from(WalletError::WalletStorageError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            ptr::null_mut()
+        },
+    }
+}
+
+/// Retrieves the version of an app that last accessed the wallet database at the given path. Unlike
+/// `wallet_get_last_version` this does not require a TariCommsConfig, so it can be used to inspect a wallet file before
+/// deciding whether to open it.
+///
+/// ## Arguments
+/// `db_path` - The full path to the wallet sqlite database file, e.g. `<datastore_path>/<database_name>.sqlite3`
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+/// ## Returns
+/// `*mut c_char` - Returns the pointer to the version string, or null if no version has been recorded
+///
+/// # Safety
+/// The ```string_destroy``` method must be called when finished with a string coming from rust to prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn wallet_db_get_last_version(db_path: *const c_char, error_out: *mut c_int) -> *mut c_char {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if db_path.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("db_path".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+    let sql_database_path = match CStr::from_ptr(db_path).to_str() {
+        Ok(v) => PathBuf::from(v),
+        _ => {
+            error = LibWalletError::from(InterfaceError::PointerError("db_path".to_string())).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return ptr::null_mut();
+        },
+    };
+
+    match get_last_version(sql_database_path) {
+        Ok(None) => ptr::null_mut(),
+        Ok(Some(version)) => {
+            let version = CString::new(version).expect("failed to initialize CString");
+            version.into_raw()
+        },
+        Err(e) => {
+            error = LibWalletError::from(WalletError::WalletStorageError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            ptr::null_mut()
+        },
+    }
+}
+
+/// Retrieves the network of an app that last accessed the wallet database at the given path. Unlike
+/// `wallet_get_last_network` this does not require a TariCommsConfig, so it can be used to inspect a wallet file before
+/// deciding whether to open it.
+///
+/// ## Arguments
+/// `db_path` - The full path to the wallet sqlite database file, e.g. `<datastore_path>/<database_name>.sqlite3`
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+/// ## Returns
+/// `*mut c_char` - Returns the pointer to the network string, or null if no network has been recorded
+///
+/// # Safety
+/// The ```string_destroy``` method must be called when finished with a string coming from rust to prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn wallet_db_get_last_network(db_path: *const c_char, error_out: *mut c_int) -> *mut c_char {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if db_path.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("db_path".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+    let sql_database_path = match CStr::from_ptr(db_path).to_str() {
+        Ok(v) => PathBuf::from(v),
+        _ => {
+            error = LibWalletError::from(InterfaceError::PointerError("db_path".to_string())).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return ptr::null_mut();
+        },
+    };
+
+    match get_last_network(sql_database_path) {
+        Ok(None) => ptr::null_mut(),
+        Ok(Some(network)) => {
+            let network = CString::new(network).expect("failed to initialize CString");
+            network.into_raw()
+        },
+        Err(e) => {
+            error = LibWalletError::from(WalletError::WalletStorageError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            ptr::null_mut()
+        },
+    }
+}
+
+/// Protects a wallet database that has no passphrase with the given passphrase. This is a one-shot database
+/// operation that is used before `wallet_create`, which must then be given the same passphrase.
+///
+/// ## Arguments
+/// `config` - The TariCommsConfig pointer, used to derive the path of the wallet database
+/// `passphrase` - The passphrase to encrypt the wallet databases with, may not be null or empty
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `bool` - Returns true if the passphrase was applied, false otherwise. If the database is already protected by a
+/// passphrase the error code 422 is set, an empty passphrase sets the error code 426, and if there is no wallet
+/// database at the path none is created and the error code 436 is set.
+///
+/// # Safety
+/// This must not be called while a TariWallet is open on the same database. All the sqlite backends share a main key
+/// that is encrypted with a key derived from the passphrase, so only the encrypted main key is re-keyed.
+#[no_mangle]
+pub unsafe extern "C" fn wallet_apply_encryption(
+    config: *mut TariCommsConfig,
+    passphrase: *const c_char,
+    error_out: *mut c_int,
+) -> bool {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if config.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("config".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+    if passphrase.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("passphrase".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+    let passphrase = match CStr::from_ptr(passphrase).to_str() {
+        Ok(v) => SafePassword::from(v.to_owned()),
+        _ => {
+            error = LibWalletError::from(InterfaceError::PointerError("passphrase".to_string())).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return false;
+        },
+    };
+    let sql_database_path = (*config)
+        .datastore_path
+        .join((*config).peer_database_name.clone())
+        .with_extension("sqlite3");
+
+    match apply_database_encryption(sql_database_path, &passphrase) {
+        Ok(()) => true,
+        Err(e) => {
+            error = LibWalletError::from(WalletError::WalletStorageError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            false
+        },
+    }
+}
+
+/// Removes the passphrase protecting a wallet database, after which `wallet_create` must be given an empty
+/// passphrase to open it. This is a one-shot database operation that is used before `wallet_create`.
+///
+/// ## Arguments
+/// `config` - The TariCommsConfig pointer, used to derive the path of the wallet database
+/// `passphrase` - The passphrase currently used to encrypt the wallet databases, may not be null
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `bool` - Returns true if the passphrase was removed, false otherwise. If the database is not protected by a
+/// passphrase the error code 435 is set, if `passphrase` does not match the error code for an invalid passphrase
+/// (428) is set, and if there is no wallet database at the path none is created and the error code 436 is set.
+///
+/// # Safety
+/// This must not be called while a TariWallet is open on the same database. All the sqlite backends share a main key
+/// that is encrypted with a key derived from the passphrase, so only the encrypted main key is re-keyed.
+#[no_mangle]
+pub unsafe extern "C" fn wallet_remove_encryption(
+    config: *mut TariCommsConfig,
+    passphrase: *const c_char,
+    error_out: *mut c_int,
+) -> bool {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if config.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("config".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+    if passphrase.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("passphrase".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+    let passphrase = match CStr::from_ptr(passphrase).to_str() {
+        Ok(v) => SafePassword::from(v.to_owned()),
+        _ => {
+            error = LibWalletError::from(InterfaceError::PointerError("passphrase".to_string())).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return false;
+        },
+    };
+    let sql_database_path = (*config)
+        .datastore_path
+        .join((*config).peer_database_name.clone())
         .with_extension("sqlite3");
-    match get_last_network(sql_database_path) {
-        Ok(None) => ptr::null_mut(),
-        Ok(Some(network)) => {
-            let network = CString::new(network).expect("failed to initialize CString");
-            network.into_raw()
+
+    match remove_database_encryption(sql_database_path, &passphrase) {
+        Ok(()) => true,
+        Err(e) => {
+            error = LibWalletError::from(WalletError::WalletStorageError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            false
+        },
+    }
+}
+
+/// Changes the passphrase used to encrypt the wallet databases
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer
+/// `existing` - The passphrase currently used to encrypt the wallet databases, may not be null
+/// `new` - The new passphrase to encrypt the wallet databases with, may not be null
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `bool` - Returns true if the passphrase was changed, false otherwise. If `existing` does not match the current
+/// passphrase the error code for an invalid passphrase (428) is set and nothing is changed.
+///
+/// # Safety
+/// All the sqlite backends (wallet, transaction, output manager, contacts and key manager) are encrypted with a shared
+/// main key that is itself encrypted with a key derived from the passphrase, so only the encrypted main key is replaced
+/// and the backends are re-keyed in a single write. The passphrases and derived key material are zeroized on drop.
+#[no_mangle]
+pub unsafe extern "C" fn wallet_change_passphrase(
+    wallet: *mut TariWallet,
+    existing: *const c_char,
+    new: *const c_char,
+    error_out: *mut c_int,
+) -> bool {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+    if existing.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("existing".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+    if new.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("new".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+
+    let existing = match CStr::from_ptr(existing).to_str() {
+        Ok(v) => SafePassword::from(v.to_owned()),
+        _ => {
+            error = LibWalletError::from(InterfaceError::PointerError("existing".to_string())).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return false;
+        },
+    };
+    let new = match CStr::from_ptr(new).to_str() {
+        Ok(v) => SafePassword::from(v.to_owned()),
+        _ => {
+            error = LibWalletError::from(InterfaceError::PointerError("new".to_string())).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return false;
+        },
+    };
+
+    match (*wallet).wallet.db.change_passphrase(&existing, &new) {
+        Ok(()) => true,
+        Err(e) => {
+            error = LibWalletError::from(WalletError::WalletStorageError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            false
+        },
+    }
+}
+
+/// Retrieves the balance from a wallet
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer.
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+/// ## Returns
+/// `*mut Balance` - Returns the pointer to the TariBalance or null if error occurs
+///
+/// # Safety
+/// The ```balance_destroy``` method must be called when finished with a TariBalance to prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn wallet_get_balance(wallet: *mut TariWallet, error_out: *mut c_int) -> *mut TariBalance {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+    let balance = block_on_runtime(
+        &(*wallet).runtime,
+        (*wallet).wallet.output_manager_service.get_balance(),
+    );
+    match balance {
+        Ok(Ok(balance)) => Box::into_raw(Box::new(balance)),
+        Err(e) => {
+            error = LibWalletError::from(e).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            ptr::null_mut()
+        },
+        Ok(Err(_)) => {
+            error = LibWalletError::from(InterfaceError::BalanceError).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            ptr::null_mut()
+        },
+    }
+}
+
+/// This function returns a list of unspent UTXO values and commitments.
+///
+/// ## Arguments
+/// * `wallet` - The TariWallet pointer,
+/// * `page` - Page offset,
+/// * `page_size` - A number of items per page,
+/// * `sorting` - An enum representing desired sorting,
+/// * `dust_threshold` - A value filtering threshold. Outputs whose values are <= `dust_threshold` are not listed in the
+///   result.
+/// * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
+///   Functions as an out parameter.
+///
+/// ## Returns
+/// `*mut TariVector` - Returns a struct with an array pointer, length and capacity (needed for proper destruction
+/// after use).
+///
+/// # Safety
+/// `destroy_tari_vector()` must be called after use.
+/// Items that fail to produce `.as_transaction_output()` are omitted from the list and a `warn!()` message is logged to
+/// LOG_TARGET.
+// casting here is okay as we wont have more than u32 utxos
+#[allow(clippy::cast_possible_truncation)]
+#[no_mangle]
+pub unsafe extern "C" fn wallet_get_utxos(
+    wallet: *mut TariWallet,
+    page: usize,
+    page_size: usize,
+    sorting: TariUtxoSort,
+    states: *mut TariVector,
+    dust_threshold: u64,
+    error_ptr: *mut i32,
+) -> *mut TariVector {
+    if wallet.is_null() {
+        error!(target: LOG_TARGET, "wallet pointer is null");
+        ptr::replace(
+            error_ptr,
+            LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code,
+        );
+        return ptr::null_mut();
+    }
+
+    let page = i64::from_usize(page).unwrap_or(i64::MAX);
+    let page_size = i64::from_usize(page_size).unwrap_or(i64::MAX);
+    let dust_threshold = i64::from_u64(dust_threshold).unwrap_or(0);
+
+    let status = {
+        if states.is_null() {
+            vec![]
+        } else {
+            Vec::from_raw_parts((*states).ptr as *mut u64, (*states).len, (*states).cap)
+                .into_iter()
+                .map(|x| OutputStatus::try_from(x as i32).unwrap())
+                .collect_vec()
+        }
+    };
+
+    use SortDirection::{Asc, Desc};
+    let q = OutputBackendQuery {
+        tip_height: i64::MAX,
+        status,
+        commitments: vec![],
+        pagination: Some((page, page_size)),
+        value_min: Some((dust_threshold, false)),
+        value_max: None,
+        maturity_min: None,
+        maturity_max: None,
+        sorting: vec![match sorting {
+            TariUtxoSort::MinedHeightAsc => ("mined_height", Asc),
+            TariUtxoSort::MinedHeightDesc => ("mined_height", Desc),
+            TariUtxoSort::MinedTimestampAsc => ("mined_timestamp", Asc),
+            TariUtxoSort::MinedTimestampDesc => ("mined_timestamp", Desc),
+            TariUtxoSort::ValueAsc => ("value", Asc),
+            TariUtxoSort::ValueDesc => ("value", Desc),
+        }],
+    };
+
+    match (*wallet).wallet.output_db.fetch_outputs_by_query(q) {
+        Ok(outputs) => {
+            ptr::replace(error_ptr, 0);
+            Box::into_raw(Box::new(TariVector::from(outputs)))
+        },
+
+        Err(e) => {
+            error!(target: LOG_TARGET, "failed to obtain outputs: {:#?}", e);
+            ptr::replace(
+                error_ptr,
+                LibWalletError::from(WalletError::OutputManagerError(
+                    OutputManagerError::OutputManagerStorageError(e),
+                ))
+                .code,
+            );
+            ptr::null_mut()
+        },
+    }
+}
+
+/// This function returns aggregate totals of the full set of UTXOs matched by the `wallet_get_utxos` filters, so that
+/// a paged listing can show the totals of all the matching outputs.
+///
+/// ## Arguments
+/// * `wallet` - The TariWallet pointer,
+/// * `states` - An optional `U64` vector of `TariUtxo` status values to filter by, null matches all statuses,
+/// * `dust_threshold` - A value filtering threshold. Outputs whose values are <= `dust_threshold` are not counted.
+/// * `error_ptr` - Pointer to an int which will be modified to an error code should one occur, may not be null.
+///   Functions as an out parameter.
+///
+/// ## Returns
+/// `*mut TariVector` - Returns a `U64` vector holding `[count, total_value, min_value, max_value]`, the values are
+/// zero if no outputs match.
+///
+/// # Safety
+/// `destroy_tari_vector()` must be called after use.
+#[no_mangle]
+pub unsafe extern "C" fn wallet_get_utxos_summary(
+    wallet: *mut TariWallet,
+    states: *mut TariVector,
+    dust_threshold: u64,
+    error_ptr: *mut i32,
+) -> *mut TariVector {
+    if wallet.is_null() {
+        error!(target: LOG_TARGET, "wallet pointer is null");
+        ptr::replace(
+            error_ptr,
+            LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code,
+        );
+        return ptr::null_mut();
+    }
+
+    let dust_threshold = i64::from_u64(dust_threshold).unwrap_or(0);
+
+    let status = if states.is_null() {
+        vec![]
+    } else {
+        let states = (*states).to_u64_vec();
+        match states.and_then(|v| {
+            v.iter()
+                .map(|x| {
+                    OutputStatus::try_from(*x as i32)
+                        .map_err(|_| InterfaceError::InvalidArgument(format!("invalid output status: {}", x)))
+                })
+                .try_collect::<OutputStatus, Vec<OutputStatus>, InterfaceError>()
+        }) {
+            Ok(v) => v,
+            Err(e) => {
+                error!(target: LOG_TARGET, "{:#?}", e);
+                ptr::replace(error_ptr, LibWalletError::from(e).code);
+                return ptr::null_mut();
+            },
+        }
+    };
+
+    let q = OutputBackendQuery {
+        tip_height: i64::MAX,
+        status,
+        commitments: vec![],
+        pagination: None,
+        value_min: Some((dust_threshold, false)),
+        value_max: None,
+        maturity_min: None,
+        maturity_max: None,
+        sorting: vec![],
+    };
+
+    match (*wallet).wallet.output_db.fetch_outputs_summary_by_query(q) {
+        Ok(summary) => {
+            ptr::replace(error_ptr, 0);
+            Box::into_raw(Box::new(TariVector::from(vec![
+                summary.count,
+                summary.total_value.as_u64(),
+                summary.min_value.as_u64(),
+                summary.max_value.as_u64(),
+            ])))
+        },
+
+        Err(e) => {
+            error!(target: LOG_TARGET, "failed to obtain outputs summary: {:#?}", e);
+            ptr::replace(
+                error_ptr,
+                LibWalletError::from(WalletError::OutputManagerError(
+                    OutputManagerError::OutputManagerStorageError(e),
+                ))
+                .code,
+            );
+            ptr::null_mut()
+        },
+    }
+}
+
+/// Counts the spendable outputs of the wallet, i.e. the unspent outputs that are mature at the given height, without
+/// fetching the outputs themselves.
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer
+/// `at_height` - The block height at which the outputs must be spendable, 0 uses the current chain tip height as last
+/// reported by the wallet's base node
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `c_ulonglong` - Returns the number of spendable outputs, or 0 if an error occurred, which includes `at_height` being
+/// 0 while no chain metadata has been received from a base node yet
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn wallet_get_spendable_outputs_count(
+    wallet: *mut TariWallet,
+    at_height: c_ulonglong,
+    error_out: *mut c_int,
+) -> c_ulonglong {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
+
+    let tip_height = if at_height == 0 {
+        let mut base_node_service = (*wallet).wallet.base_node_service.clone();
+        match (*wallet).runtime.block_on(base_node_service.get_chain_metadata()) {
+            Ok(Some(metadata)) => metadata.best_block_height(),
+            Ok(None) => {
+                error =
+                    LibWalletError::from(WalletError::BaseNodeServiceError(BaseNodeServiceError::NoChainMetadata)).code;
+                ptr::swap(error_out, &mut error as *mut c_int);
+                return 0;
+            },
+            Err(e) => {
+                error = LibWalletError::from(WalletError::BaseNodeServiceError(e)).code;
+                ptr::swap(error_out, &mut error as *mut c_int);
+                return 0;
+            },
+        }
+    } else {
+        at_height
+    };
+
+    let q = OutputBackendQuery {
+        tip_height: i64::try_from(tip_height).unwrap_or(i64::MAX),
+        status: vec![OutputStatus::Unspent],
+        commitments: vec![],
+        pagination: None,
+        value_min: None,
+        value_max: None,
+        maturity_min: None,
+        maturity_max: None,
+        sorting: vec![],
+    };
+    match (*wallet).wallet.output_db.fetch_outputs_summary_by_query(q) {
+        Ok(summary) => summary.count,
+        Err(e) => {
+            error = LibWalletError::from(WalletError::OutputManagerError(
+                OutputManagerError::OutputManagerStorageError(e),
+            ))
+            .code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            0
+        },
+    }
+}
+
+/// This function returns a list of all UTXO values, commitment's hex values and states.
+///
+/// ## Arguments
+/// * `wallet` - The TariWallet pointer,
+/// * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
+///   Functions as an out parameter.
+///
+/// ## Returns
+/// `*mut TariVector` - Returns a struct with an array pointer, length and capacity (needed for proper destruction
+///     after use).
+///
+/// ## States
+/// 0 - Unspent
+/// 1 - Spent
+/// 2 - EncumberedToBeReceived
+/// 3 - EncumberedToBeSpent
+/// 4 - Invalid
+/// 5 - CancelledInbound
+/// 6 - UnspentMinedUnconfirmed
+/// 7 - ShortTermEncumberedToBeReceived
+/// 8 - ShortTermEncumberedToBeSpent
+/// 9 - SpentMinedUnconfirmed
+/// 10 - AbandonedCoinbase
+/// 11 - NotStored
+///
+/// # Safety
+/// `destroy_tari_vector()` must be called after use.
+/// Items that fail to produce `.as_transaction_output()` are omitted from the list and a `warn!()` message is logged to
+/// LOG_TARGET.
+#[no_mangle]
+pub unsafe extern "C" fn wallet_get_all_utxos(wallet: *mut TariWallet, error_ptr: *mut i32) -> *mut TariVector {
+    if wallet.is_null() {
+        error!(target: LOG_TARGET, "wallet pointer is null");
+        ptr::replace(
+            error_ptr,
+            LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code,
+        );
+        return ptr::null_mut();
+    }
+
+    let q = OutputBackendQuery {
+        tip_height: i64::MAX,
+        status: vec![],
+        commitments: vec![],
+        pagination: None,
+        value_min: None,
+        value_max: None,
+        maturity_min: None,
+        maturity_max: None,
+        sorting: vec![],
+    };
+
+    match (*wallet).wallet.output_db.fetch_outputs_by_query(q) {
+        Ok(outputs) => {
+            ptr::replace(error_ptr, 0);
+            Box::into_raw(Box::new(TariVector::from(outputs)))
+        },
+
+        Err(e) => {
+            error!(target: LOG_TARGET, "failed to obtain outputs: {:#?}", e);
+            ptr::replace(
+                error_ptr,
+                LibWalletError::from(WalletError::OutputManagerError(
+                    OutputManagerError::OutputManagerStorageError(e),
+                ))
+                .code,
+            );
+            ptr::null_mut()
+        },
+    }
+}
+
+/// This function returns how many outputs the wallet holds in each state, without fetching the outputs themselves.
+///
+/// ## Arguments
+/// * `wallet` - The TariWallet pointer,
+/// * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
+///   Functions as an out parameter.
+///
+/// ## Returns
+/// `*mut TariVector` - Returns a `U64` vector with one count per state, indexed by the state values below. Note that
+/// it returns ptr::null_mut() if wallet is null or an error is encountered.
+///
+/// ## States
+/// 0 - Unspent
+/// 1 - Spent
+/// 2 - EncumberedToBeReceived
+/// 3 - EncumberedToBeSpent
+/// 4 - Invalid
+/// 5 - CancelledInbound
+/// 6 - UnspentMinedUnconfirmed
+/// 7 - ShortTermEncumberedToBeReceived
+/// 8 - ShortTermEncumberedToBeSpent
+/// 9 - SpentMinedUnconfirmed
+/// 10 - NotStored
+///
+/// # Safety
+/// `destroy_tari_vector()` must be called after use.
+#[no_mangle]
+pub unsafe extern "C" fn wallet_get_output_status_counts(
+    wallet: *mut TariWallet,
+    error_ptr: *mut i32,
+) -> *mut TariVector {
+    if wallet.is_null() {
+        error!(target: LOG_TARGET, "wallet pointer is null");
+        ptr::replace(
+            error_ptr,
+            LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code,
+        );
+        return ptr::null_mut();
+    }
+
+    match (*wallet).wallet.output_db.fetch_output_status_counts() {
+        Ok(status_counts) => {
+            let mut counts = vec![0u64; OutputStatus::NotStored as usize + 1];
+            for (status, count) in status_counts {
+                counts[status as usize] = count;
+            }
+            ptr::replace(error_ptr, 0);
+            Box::into_raw(Box::new(TariVector::from(counts)))
+        },
+        Err(e) => {
+            error!(target: LOG_TARGET, "failed to count outputs: {:#?}", e);
+            ptr::replace(
+                error_ptr,
+                LibWalletError::from(WalletError::OutputManagerError(
+                    OutputManagerError::OutputManagerStorageError(e),
+                ))
+                .code,
+            );
+            ptr::null_mut()
+        },
+    }
+}
+
+/// This function returns the UTXOs of the wallet whose maturity height lies in the given inclusive range, regardless
+/// of their state. This allows listing e.g. the coinbase outputs that mature within the next few blocks.
+///
+/// ## Arguments
+/// * `wallet` - The TariWallet pointer,
+/// * `min_height` - The lowest maturity height to include
+/// * `max_height` - The highest maturity height to include
+/// * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
+///   Functions as an out parameter.
+///
+/// ## Returns
+/// `*mut TariVector` - Returns a struct with an array pointer, length and capacity (needed for proper destruction
+///     after use).
+///
+/// # Safety
+/// `destroy_tari_vector()` must be called after use.
+#[no_mangle]
+pub unsafe extern "C" fn wallet_get_utxos_by_maturity(
+    wallet: *mut TariWallet,
+    min_height: c_ulonglong,
+    max_height: c_ulonglong,
+    error_ptr: *mut i32,
+) -> *mut TariVector {
+    if wallet.is_null() {
+        error!(target: LOG_TARGET, "wallet pointer is null");
+        ptr::replace(
+            error_ptr,
+            LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code,
+        );
+        return ptr::null_mut();
+    }
+    if min_height > max_height {
+        error!(target: LOG_TARGET, "min_height is greater than max_height");
+        ptr::replace(
+            error_ptr,
+            LibWalletError::from(InterfaceError::InvalidArgument("min_height".to_string())).code,
+        );
+        return ptr::null_mut();
+    }
+
+    let q = OutputBackendQuery {
+        tip_height: i64::MAX,
+        status: vec![],
+        commitments: vec![],
+        pagination: None,
+        value_min: None,
+        value_max: None,
+        maturity_min: Some((i64::try_from(min_height).unwrap_or(i64::MAX), true)),
+        maturity_max: Some((i64::try_from(max_height).unwrap_or(i64::MAX), true)),
+        sorting: vec![],
+    };
+
+    match (*wallet).wallet.output_db.fetch_outputs_by_query(q) {
+        Ok(outputs) => {
+            ptr::replace(error_ptr, 0);
+            Box::into_raw(Box::new(TariVector::from(outputs)))
+        },
+
+        Err(e) => {
+            error!(target: LOG_TARGET, "failed to obtain outputs: {:#?}", e);
+            ptr::replace(
+                error_ptr,
+                LibWalletError::from(WalletError::OutputManagerError(
+                    OutputManagerError::OutputManagerStorageError(e),
+                ))
+                .code,
+            );
+            ptr::null_mut()
+        },
+    }
+}
+
+/// This function returns the current derivation index of each of the wallet's key manager branches. The names of the
+/// branches are returned in the same order by `wallet_get_key_manager_branch_names`. This is intended as read-only
+/// diagnostic information, e.g. to detect a mismatch between derived keys and recovered outputs.
+///
+/// ## Arguments
+/// * `wallet` - The TariWallet pointer
+/// * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
+///   Functions as an out parameter.
+///
+/// ## Returns
+/// `*mut TariVector` - Returns a vector of `unsigned long long` tagged as `TariTypeTag::U64`, note that it returns
+/// ptr::null_mut() if the wallet is null or the indices could not be read
+///
+/// # Safety
+/// `destroy_tari_vector()` must be called after use.
+#[no_mangle]
+pub unsafe extern "C" fn wallet_get_key_manager_branch_indices(
+    wallet: *mut TariWallet,
+    error_out: *mut c_int,
+) -> *mut TariVector {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+
+    match block_on_runtime(&(*wallet).runtime, (*wallet).wallet.get_key_manager_branch_indices()) {
+        Ok(Ok(branches)) => Box::into_raw(Box::new(TariVector::from(
+            branches.into_iter().map(|(_, index)| index).collect::<Vec<u64>>(),
+        ))),
+        Ok(Err(e)) => {
+            error = LibWalletError::from(e).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            ptr::null_mut()
+        },
+        Err(e) => {
+            error = LibWalletError::from(e).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            ptr::null_mut()
+        },
+    }
+}
+
+/// This function returns the names of the wallet's key manager branches, in the same order as the indices returned by
+/// `wallet_get_key_manager_branch_indices`.
+///
+/// ## Arguments
+/// * `wallet` - The TariWallet pointer
+/// * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
+///   Functions as an out parameter.
+///
+/// ## Returns
+/// `*mut TariVector` - Returns a vector of strings tagged as `TariTypeTag::Text`, note that it returns
+/// ptr::null_mut() if the wallet is null or the branches could not be read
+///
+/// # Safety
+/// `destroy_tari_vector()` must be called after use.
+#[no_mangle]
+pub unsafe extern "C" fn wallet_get_key_manager_branch_names(
+    wallet: *mut TariWallet,
+    error_out: *mut c_int,
+) -> *mut TariVector {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+
+    match block_on_runtime(&(*wallet).runtime, (*wallet).wallet.get_key_manager_branch_indices()) {
+        Ok(Ok(branches)) => Box::into_raw(Box::new(TariVector::from(
+            branches.into_iter().map(|(name, _)| name).collect::<Vec<String>>(),
+        ))),
+        Ok(Err(e)) => {
+            error = LibWalletError::from(e).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            ptr::null_mut()
+        },
+        Err(e) => {
+            error = LibWalletError::from(e).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            ptr::null_mut()
+        },
+    }
+}
+
+/// Derives the private key at the given index of a key manager branch from the wallet seed, e.g. to sign outside of
+/// the wallet. This does not advance the branch index.
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer
+/// `branch` - The pointer to a char array containing the name of the key manager branch, see
+/// `wallet_get_key_manager_branch_names`
+/// `index` - The index of the key in the branch
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `*mut TariPrivateKey` - Returns the derived private key, note that it returns ptr::null_mut() if the wallet or
+/// branch is null, if the wallet is a watch-only wallet or if the key could not be derived
+///
+/// # Safety
+/// The ```private_key_destroy``` method must be called when finished with a TariPrivateKey to prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn wallet_derive_key(
+    wallet: *mut TariWallet,
+    branch: *const c_char,
+    index: c_ulonglong,
+    error_out: *mut c_int,
+) -> *mut TariPrivateKey {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+    if branch.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("branch".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+    if let Err(e) = ensure_wallet_can_spend(&*wallet) {
+        error = LibWalletError::from(e).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+
+    let branch = match CStr::from_ptr(branch).to_str() {
+        Ok(v) => v.to_owned(),
+        _ => {
+            error = LibWalletError::from(InterfaceError::PointerError("branch".to_string())).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return ptr::null_mut();
+        },
+    };
+
+    match block_on_runtime(&(*wallet).runtime, (*wallet).wallet.derive_key(branch, index)) {
+        Ok(Ok(key)) => Box::into_raw(Box::new(key)),
+        Ok(Err(e)) => {
+            error = LibWalletError::from(WalletError::KeyManagerServiceError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            ptr::null_mut()
+        },
+        Err(e) => {
+            error = LibWalletError::from(e).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            ptr::null_mut()
+        },
+    }
+}
+
+/// This function returns the single output matching a commitment from the wallet's storage, without fetching the
+/// whole UTXO set.
+///
+/// ## Arguments
+/// * `wallet` - The TariWallet pointer
+/// * `commitment` - The hex encoded commitment of the output (see `Commitment::to_hex()`)
+/// * `error_ptr` - Pointer to an int which will be modified to an error code should one occur, may not be null.
+///   Functions as an out parameter.
+///
+/// ## Returns
+/// `*mut TariUtxo` - Returns the matching output, note that it returns ptr::null_mut() if the wallet or commitment is
+/// null, the commitment is invalid or no output with the commitment is found
+///
+/// # Safety
+/// `destroy_tari_utxo()` must be called after use.
+#[no_mangle]
+pub unsafe extern "C" fn wallet_get_utxo_by_commitment(
+    wallet: *mut TariWallet,
+    commitment: *const c_char,
+    error_ptr: *mut i32,
+) -> *mut TariUtxo {
+    if wallet.is_null() {
+        error!(target: LOG_TARGET, "wallet pointer is null");
+        ptr::replace(
+            error_ptr,
+            LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code,
+        );
+        return ptr::null_mut();
+    }
+    if commitment.is_null() {
+        error!(target: LOG_TARGET, "commitment pointer is null");
+        ptr::replace(
+            error_ptr,
+            LibWalletError::from(InterfaceError::NullError("commitment".to_string())).code,
+        );
+        return ptr::null_mut();
+    }
+
+    let commitment = match CStr::from_ptr(commitment).to_str() {
+        Ok(hex) => match Commitment::from_hex(hex) {
+            Ok(commitment) => commitment,
+            Err(e) => {
+                error!(target: LOG_TARGET, "failed to parse commitment: {:#?}", e);
+                ptr::replace(
+                    error_ptr,
+                    LibWalletError::from(InterfaceError::InvalidArgument("commitment".to_string())).code,
+                );
+                return ptr::null_mut();
+            },
+        },
+        Err(e) => {
+            error!(target: LOG_TARGET, "failed to read commitment: {:#?}", e);
+            ptr::replace(
+                error_ptr,
+                LibWalletError::from(InterfaceError::PointerError("commitment".to_string())).code,
+            );
+            return ptr::null_mut();
+        },
+    };
+
+    let q = OutputBackendQuery {
+        tip_height: i64::MAX,
+        status: vec![],
+        commitments: vec![commitment],
+        pagination: None,
+        value_min: None,
+        value_max: None,
+        maturity_min: None,
+        maturity_max: None,
+        sorting: vec![],
+    };
+
+    match (*wallet).wallet.output_db.fetch_outputs_by_query(q) {
+        Ok(outputs) => match outputs.into_iter().next() {
+            Some(output) => {
+                ptr::replace(error_ptr, 0);
+                Box::into_raw(Box::new(TariUtxo::from(output)))
+            },
+            None => {
+                error!(target: LOG_TARGET, "no output found for the commitment");
+                ptr::replace(
+                    error_ptr,
+                    LibWalletError::from(WalletError::OutputManagerError(
+                        OutputManagerError::OutputManagerStorageError(OutputManagerStorageError::ValueNotFound),
+                    ))
+                    .code,
+                );
+                ptr::null_mut()
+            },
+        },
+        Err(e) => {
+            error!(target: LOG_TARGET, "failed to obtain outputs: {:#?}", e);
+            ptr::replace(
+                error_ptr,
+                LibWalletError::from(WalletError::OutputManagerError(
+                    OutputManagerError::OutputManagerStorageError(e),
+                ))
+                .code,
+            );
+            ptr::null_mut()
+        },
+    }
+}
+
+/// This function will tell the wallet to do a coin split.
+///
+/// ## Arguments
+/// * `wallet` - The TariWallet pointer
+/// * `commitments` - A `TariVector` of "strings", tagged as `TariTypeTag::String`, containing commitment's hex values
+///   (see `Commitment::to_hex()`)
+/// * `number_of_splits` - The number of times to split the amount
+/// * `fee_per_gram` - The transaction fee
+/// * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
+///   Functions as an out parameter.
+///
+/// ## Returns
+/// `c_ulonglong` - Returns the transaction id.
+///
+/// # Safety
+/// `TariVector` must be freed after use with `destroy_tari_vector()`
+#[no_mangle]
+pub unsafe extern "C" fn wallet_coin_split(
+    wallet: *mut TariWallet,
+    commitments: *mut TariVector,
+    number_of_splits: usize,
+    fee_per_gram: u64,
+    error_ptr: *mut i32,
+) -> u64 {
+    if wallet.is_null() {
+        error!(target: LOG_TARGET, "wallet pointer is null");
+        ptr::replace(
+            error_ptr,
+            LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code as c_int,
+        );
+        return 0;
+    }
+    if let Err(e) = ensure_wallet_can_spend(&*wallet) {
+        ptr::replace(error_ptr, LibWalletError::from(e).code as c_int);
+        return 0;
+    }
+
+    let commitments = match commitments.as_ref() {
+        None => {
+            error!(target: LOG_TARGET, "failed to obtain commitments as reference");
+            ptr::replace(
+                error_ptr,
+                LibWalletError::from(InterfaceError::NullError("commitments vector".to_string())).code as c_int,
+            );
+            return 0;
+        },
+        Some(cs) => match cs.to_commitment_vec() {
+            Ok(cs) => cs,
+            Err(e) => {
+                error!(target: LOG_TARGET, "failed to convert from tari vector: {:?}", e);
+                ptr::replace(error_ptr, LibWalletError::from(e).code as c_int);
+                return 0;
+            },
+        },
+    };
+
+    match block_on_runtime(
+        &(*wallet).runtime,
+        (*wallet).wallet.coin_split_even(
+            commitments,
+            number_of_splits,
+            MicroMinotari(fee_per_gram),
+            String::new(),
+        ),
+    ) {
+        Ok(Ok(tx_id)) => {
+            ptr::replace(error_ptr, 0);
+            tx_id.as_u64()
+        },
+        Ok(Err(e)) => {
+            error!(target: LOG_TARGET, "failed to join outputs: {:#?}", e);
+            ptr::replace(error_ptr, LibWalletError::from(e).code);
+            0
+        },
+        Err(e) => {
+            error!(target: LOG_TARGET, "runtime failure while splitting outputs: {:#?}", e);
+            ptr::replace(error_ptr, LibWalletError::from(e).code);
+            0
+        },
+    }
+}
+
+/// This function will tell the wallet to do a coin join, resulting in a new coin worth a sum of the joined coins minus
+/// the fee.
+///
+/// ## Arguments
+/// * `wallet` - The TariWallet pointer
+/// * `commitments` - A `TariVector` of "strings", tagged as `TariTypeTag::String`, containing commitment's hex values
+///   (see `Commitment::to_hex()`)
+/// * `fee_per_gram` - The transaction fee
+/// * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
+///   Functions as an out parameter.
+///
+/// ## Returns
+/// `TariVector` - Returns the transaction id.
+///
+/// # Safety
+/// `TariVector` must be freed after use with `destroy_tari_vector()`
+#[no_mangle]
+pub unsafe extern "C" fn wallet_coin_join(
+    wallet: *mut TariWallet,
+    commitments: *mut TariVector,
+    fee_per_gram: u64,
+    error_ptr: *mut i32,
+) -> u64 {
+    if wallet.is_null() {
+        error!(target: LOG_TARGET, "wallet pointer is null");
+        ptr::replace(
+            error_ptr,
+            LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code as c_int,
+        );
+        return 0;
+    }
+    if let Err(e) = ensure_wallet_can_spend(&*wallet) {
+        ptr::replace(error_ptr, LibWalletError::from(e).code as c_int);
+        return 0;
+    }
+
+    let commitments = match commitments.as_ref() {
+        None => {
+            error!(target: LOG_TARGET, "failed to obtain commitments as reference");
+            ptr::replace(
+                error_ptr,
+                LibWalletError::from(InterfaceError::NullError("commitments vector".to_string())).code as c_int,
+            );
+            return 0;
+        },
+        Some(cs) => match cs.to_commitment_vec() {
+            Ok(cs) => cs,
+            Err(e) => {
+                error!(target: LOG_TARGET, "failed to convert from tari vector: {:?}", e);
+                ptr::replace(error_ptr, LibWalletError::from(e).code as c_int);
+                return 0;
+            },
+        },
+    };
+
+    match block_on_runtime(
+        &(*wallet).runtime,
+        (*wallet).wallet.coin_join(commitments, fee_per_gram.into(), None),
+    ) {
+        Ok(Ok(tx_id)) => {
+            ptr::replace(error_ptr, 0);
+            tx_id.as_u64()
+        },
+
+        Ok(Err(e)) => {
+            error!(target: LOG_TARGET, "failed to join outputs: {:#?}", e);
+            ptr::replace(error_ptr, LibWalletError::from(e).code);
+            0
+        },
+        Err(e) => {
+            error!(target: LOG_TARGET, "runtime failure while joining outputs: {:#?}", e);
+            ptr::replace(error_ptr, LibWalletError::from(e).code);
+            0
+        },
+    }
+}
+
+/// This function will tell the wallet to join its smallest unspent outputs into a single new coin worth at least
+/// `target_amount` after the fee. Outputs are selected in ascending order of value until the target plus the fee is
+/// met.
+///
+/// ## Arguments
+/// * `wallet` - The TariWallet pointer
+/// * `target_amount` - The minimum value of the joined output in MicroMinotari
+/// * `fee_per_gram` - The transaction fee
+/// * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
+///   Functions as an out parameter.
+///
+/// ## Returns
+/// `u64` - Returns the transaction id, or 0 if an error occurred. If the unspent outputs of the wallet cannot reach the
+/// target `error_out` is set to the insufficient funds error code.
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn wallet_coin_join_to_amount(
+    wallet: *mut TariWallet,
+    target_amount: c_ulonglong,
+    fee_per_gram: u64,
+    error_ptr: *mut i32,
+) -> u64 {
+    if wallet.is_null() {
+        error!(target: LOG_TARGET, "wallet pointer is null");
+        ptr::replace(
+            error_ptr,
+            LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code as c_int,
+        );
+        return 0;
+    }
+    if let Err(e) = ensure_wallet_can_spend(&*wallet) {
+        ptr::replace(error_ptr, LibWalletError::from(e).code as c_int);
+        return 0;
+    }
+
+    match block_on_runtime(
+        &(*wallet).runtime,
+        (*wallet)
+            .wallet
+            .coin_join_to_amount(target_amount.into(), fee_per_gram.into(), None),
+    ) {
+        Ok(Ok(tx_id)) => {
+            ptr::replace(error_ptr, 0);
+            tx_id.as_u64()
+        },
+
+        Ok(Err(e)) => {
+            error!(target: LOG_TARGET, "failed to join outputs to amount: {:#?}", e);
+            ptr::replace(error_ptr, LibWalletError::from(e).code);
+            0
+        },
+        Err(e) => {
+            error!(target: LOG_TARGET, "runtime failure while joining outputs to amount: {:#?}", e);
+            ptr::replace(error_ptr, LibWalletError::from(e).code);
+            0
+        },
+    }
+}
+
+/// This function will tell what the outcome of a coin join would be.
+///
+/// ## Arguments
+/// * `wallet` - The TariWallet pointer
+/// * `commitments` - A `TariVector` of "strings", tagged as `TariTypeTag::String`, containing commitment's hex values
+///   (see `Commitment::to_hex()`)
+/// * `fee_per_gram` - The transaction fee
+/// * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
+///   Functions as an out parameter.
+///
+/// ## Returns
+/// `*mut TariCoinPreview` - A struct with expected output values and the fee.
+///
+/// # Safety
+/// `TariVector` must be freed after use with `destroy_tari_vector()`
+#[no_mangle]
+pub unsafe extern "C" fn wallet_preview_coin_join(
+    wallet: *mut TariWallet,
+    commitments: *mut TariVector,
+    fee_per_gram: u64,
+    error_ptr: *mut i32,
+) -> *mut TariCoinPreview {
+    if wallet.is_null() {
+        error!(target: LOG_TARGET, "wallet pointer is null");
+        ptr::replace(
+            error_ptr,
+            LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code as c_int,
+        );
+        return ptr::null_mut();
+    }
+
+    let commitments = match commitments.as_ref() {
+        None => {
+            error!(target: LOG_TARGET, "failed to obtain commitments as reference");
+            ptr::replace(
+                error_ptr,
+                LibWalletError::from(InterfaceError::NullError("commitments vector".to_string())).code as c_int,
+            );
+            return ptr::null_mut();
+        },
+        Some(cs) => match cs.to_commitment_vec() {
+            Ok(cs) => cs,
+            Err(e) => {
+                error!(target: LOG_TARGET, "failed to convert from tari vector: {:?}", e);
+                ptr::replace(error_ptr, LibWalletError::from(e).code as c_int);
+                return ptr::null_mut();
+            },
+        },
+    };
+
+    match (*wallet).runtime.block_on(
+        (*wallet)
+            .wallet
+            .preview_coin_join_with_commitments(commitments, MicroMinotari(fee_per_gram)),
+    ) {
+        Ok((expected_outputs, fee)) => {
+            ptr::replace(error_ptr, 0);
+            let mut expected_outputs = ManuallyDrop::new(expected_outputs);
+
+            Box::into_raw(Box::new(TariCoinPreview {
+                expected_outputs: Box::into_raw(Box::new(TariVector {
+                    tag: TariTypeTag::U64,
+                    len: expected_outputs.len(),
+                    cap: expected_outputs.capacity(),
+                    ptr: expected_outputs.as_mut_ptr() as *mut c_void,
+                })),
+                fee: fee.as_u64(),
+            }))
+        },
+        Err(e) => {
+            error!(
+                target: LOG_TARGET,
+                "failed to preview coin join with commitments: {:#?}", e
+            );
+            ptr::replace(error_ptr, LibWalletError::from(e).code);
+            ptr::null_mut()
+        },
+    }
+}
+
+/// This function will tell what the outcome of a coin split would be.
+///
+/// ## Arguments
+/// * `wallet` - The TariWallet pointer
+/// * `commitments` - A `TariVector` of "strings", tagged as `TariTypeTag::String`, containing commitment's hex values
+///   (see `Commitment::to_hex()`)
+/// * `number_of_splits` - The number of times to split the amount
+/// * `fee_per_gram` - The transaction fee
+/// * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
+///   Functions as an out parameter.
+///
+/// ## Returns
+/// `*mut TariCoinPreview` - A struct with expected output values and the fee.
+///
+/// # Safety
+/// `TariVector` must be freed after use with `destroy_tari_vector()`
+#[no_mangle]
+pub unsafe extern "C" fn wallet_preview_coin_split(
+    wallet: *mut TariWallet,
+    commitments: *mut TariVector,
+    number_of_splits: usize,
+    fee_per_gram: u64,
+    error_ptr: *mut i32,
+) -> *mut TariCoinPreview {
+    if wallet.is_null() {
+        error!(target: LOG_TARGET, "wallet pointer is null");
+        ptr::replace(
+            error_ptr,
+            LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code as c_int,
+        );
+        return ptr::null_mut();
+    }
+
+    let commitments = match commitments.as_ref() {
+        None => {
+            error!(target: LOG_TARGET, "failed to obtain commitments as reference");
+            ptr::replace(
+                error_ptr,
+                LibWalletError::from(InterfaceError::NullError("commitments vector".to_string())).code as c_int,
+            );
+            return ptr::null_mut();
+        },
+        Some(cs) => match cs.to_commitment_vec() {
+            Ok(cs) => cs,
+            Err(e) => {
+                error!(target: LOG_TARGET, "failed to convert from tari vector: {:?}", e);
+                ptr::replace(error_ptr, LibWalletError::from(e).code as c_int);
+                return ptr::null_mut();
+            },
+        },
+    };
+
+    match (*wallet)
+        .runtime
+        .block_on((*wallet).wallet.preview_coin_split_with_commitments_no_amount(
+            commitments,
+            number_of_splits,
+            MicroMinotari(fee_per_gram),
+        )) {
+        Ok((expected_outputs, fee)) => {
+            ptr::replace(error_ptr, 0);
+            let mut expected_outputs = ManuallyDrop::new(expected_outputs);
+
+            Box::into_raw(Box::new(TariCoinPreview {
+                expected_outputs: Box::into_raw(Box::new(TariVector {
+                    tag: TariTypeTag::U64,
+                    len: expected_outputs.len(),
+                    cap: expected_outputs.capacity(),
+                    ptr: expected_outputs.as_mut_ptr() as *mut c_void,
+                })),
+                fee: fee.as_u64(),
+            }))
+        },
+        Err(e) => {
+            error!(
+                target: LOG_TARGET,
+                "failed to preview split with commitments outputs (no amount): {:#?}", e
+            );
+            ptr::replace(error_ptr, LibWalletError::from(e).code);
+            ptr::null_mut()
+        },
+    }
+}
+
+/// This function will tell what the outcome of joining the given commitments into a single output and then splitting
+/// that output evenly would be.
+///
+/// ## Arguments
+/// * `wallet` - The TariWallet pointer
+/// * `commitments` - A `TariVector` of "strings", tagged as `TariTypeTag::String`, containing commitment's hex values
+///   (see `Commitment::to_hex()`)
+/// * `number_of_splits` - The number of outputs the joined amount is split into
+/// * `fee_per_gram` - The transaction fee
+/// * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
+///   Functions as an out parameter.
+///
+/// ## Returns
+/// `*mut TariCoinPreview` - A struct with the expected split output values and the combined fee of the join and the
+/// split.
+///
+/// # Safety
+/// `TariVector` must be freed after use with `destroy_tari_vector()`
+#[no_mangle]
+pub unsafe extern "C" fn wallet_preview_join_split(
+    wallet: *mut TariWallet,
+    commitments: *mut TariVector,
+    number_of_splits: usize,
+    fee_per_gram: u64,
+    error_ptr: *mut i32,
+) -> *mut TariCoinPreview {
+    if wallet.is_null() {
+        error!(target: LOG_TARGET, "wallet pointer is null");
+        ptr::replace(
+            error_ptr,
+            LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code as c_int,
+        );
+        return ptr::null_mut();
+    }
+
+    let commitments = match commitments.as_ref() {
+        None => {
+            error!(target: LOG_TARGET, "failed to obtain commitments as reference");
+            ptr::replace(
+                error_ptr,
+                LibWalletError::from(InterfaceError::NullError("commitments vector".to_string())).code as c_int,
+            );
+            return ptr::null_mut();
+        },
+        Some(cs) => match cs.to_commitment_vec() {
+            Ok(cs) => cs,
+            Err(e) => {
+                error!(target: LOG_TARGET, "failed to convert from tari vector: {:?}", e);
+                ptr::replace(error_ptr, LibWalletError::from(e).code as c_int);
+                return ptr::null_mut();
+            },
+        },
+    };
+
+    match (*wallet)
+        .runtime
+        .block_on((*wallet).wallet.preview_join_split_with_commitments(
+            commitments,
+            number_of_splits,
+            MicroMinotari(fee_per_gram),
+        )) {
+        Ok((expected_outputs, fee)) => {
+            ptr::replace(error_ptr, 0);
+            let mut expected_outputs = ManuallyDrop::new(expected_outputs);
+
+            Box::into_raw(Box::new(TariCoinPreview {
+                expected_outputs: Box::into_raw(Box::new(TariVector {
+                    tag: TariTypeTag::U64,
+                    len: expected_outputs.len(),
+                    cap: expected_outputs.capacity(),
+                    ptr: expected_outputs.as_mut_ptr() as *mut c_void,
+                })),
+                fee: fee.as_u64(),
+            }))
+        },
+        Err(e) => {
+            error!(target: LOG_TARGET, "failed to preview join split with commitments: {:#?}", e);
+            ptr::replace(error_ptr, LibWalletError::from(e).code);
+            ptr::null_mut()
+        },
+    }
+}
+
+/// Signs a message using the public key of the TariWallet
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer.
+/// `msg` - The message pointer.
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+/// ## Returns
+/// `*mut c_char` - Returns the pointer to the hexadecimal representation of the signature and
+/// public nonce, seperated by a pipe character. Empty if an error occured.
+///
+/// # Safety
+/// The ```string_destroy``` method must be called when finished with a string coming from rust to prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn wallet_sign_message(
+    wallet: *mut TariWallet,
+    msg: *const c_char,
+    error_out: *mut c_int,
+) -> *mut c_char {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return CString::new("").expect("Blank CString will not fail.").into_raw();
+    }
+
+    let mut secret = (*wallet).wallet.comms.node_identity().secret_key().clone();
+    wallet_sign_message_with_key(wallet, &mut secret as *mut TariPrivateKey, msg, error_out)
+}
+
+/// Signs a message with the given private key, using the same signing domain as `wallet_sign_message`
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer.
+/// `secret_key` - The pointer to the TariPrivateKey to sign the message with.
+/// `msg` - The message pointer.
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+/// ## Returns
+/// `*mut c_char` - Returns the pointer to the hexadecimal representation of the signature and
+/// public nonce, seperated by a pipe character. Empty if an error occured. The result can be checked with
+/// `wallet_verify_message_signature` using the public key of `secret_key`.
+///
+/// # Safety
+/// The ```string_destroy``` method must be called when finished with a string coming from rust to prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn wallet_sign_message_with_key(
+    wallet: *mut TariWallet,
+    secret_key: *mut TariPrivateKey,
+    msg: *const c_char,
+    error_out: *mut c_int,
+) -> *mut c_char {
+    let mut error = 0;
+    let mut result = CString::new("").expect("Blank CString will not fail.");
+
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return result.into_raw();
+    }
+
+    if secret_key.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("secret_key".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return result.into_raw();
+    }
+
+    if msg.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("message".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return result.into_raw();
+    }
+
+    let message = match CStr::from_ptr(msg).to_str() {
+        Ok(v) => v.to_owned(),
+        _ => {
+            error = LibWalletError::from(InterfaceError::PointerError("msg".to_string())).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return result.into_raw();
+        },
+    };
+
+    let signature = (*wallet).wallet.sign_message(&*secret_key, &message);
+
+    match signature {
+        Ok(s) => {
+            let hex_sig = s.get_signature().to_hex();
+            let hex_nonce = s.get_public_nonce().to_hex();
+            let hex_return = format!("{}|{}", hex_sig, hex_nonce);
+            result = CString::new(hex_return).expect("CString should not fail here.");
+        },
+        Err(e) => {
+            error = LibWalletError::from(e).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+        },
+    }
+
+    result.into_raw()
+}
+
+/// Verifies the signature of the message signed by a TariWallet
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer.
+/// `public_key` - The pointer to the TariPublicKey of the wallet which originally signed the message
+/// `hex_sig_nonce` - The pointer to the sting containing the hexadecimal representation of the
+/// signature and public nonce seperated by a pipe character.
+/// `msg` - The pointer to the msg the signature will be checked against.
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+/// ## Returns
+/// `bool` - Returns if the signature is valid or not, will be false if an error occurs.
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn wallet_verify_message_signature(
+    wallet: *mut TariWallet,
+    public_key: *mut TariPublicKey,
+    hex_sig_nonce: *const c_char,
+    msg: *const c_char,
+    error_out: *mut c_int,
+) -> bool {
+    wallet_verify_message_signature_detailed(wallet, public_key, hex_sig_nonce, msg, error_out) ==
+        SignatureVerificationResult::Valid as c_int
+}
+
+/// Verifies the signature of the message signed by a TariWallet, distinguishing a signature that does not match from
+/// input that could not be parsed
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer.
+/// `public_key` - The pointer to the TariPublicKey of the wallet which originally signed the message
+/// `hex_sig_nonce` - The pointer to the sting containing the hexadecimal representation of the
+/// signature and public nonce seperated by a pipe character.
+/// `msg` - The pointer to the msg the signature will be checked against.
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+/// ## Returns
+/// `c_int` - Returns an int version of the `SignatureVerificationResult` enum
+///     '0' -> Valid, the signature is valid for the message and public key
+///     '1' -> Invalid, the input is well formed but the signature does not match, `error_out` is not set
+///     '2' -> MalformedInput, a pointer is null or the signature could not be parsed, `error_out` is set to the
+///            corresponding error code
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn wallet_verify_message_signature_detailed(
+    wallet: *mut TariWallet,
+    public_key: *mut TariPublicKey,
+    hex_sig_nonce: *const c_char,
+    msg: *const c_char,
+    error_out: *mut c_int,
+) -> c_int {
+    let mut error = 0;
+    let malformed = SignatureVerificationResult::MalformedInput as c_int;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return malformed;
+    }
+    if public_key.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("public key".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return malformed;
+    }
+    if hex_sig_nonce.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("signature".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return malformed;
+    }
+    if msg.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("message".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return malformed;
+    }
+
+    let message = match CStr::from_ptr(msg).to_str() {
+        Ok(v) => v.to_owned(),
+        _ => {
+            error = LibWalletError::from(InterfaceError::PointerError("msg".to_string())).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return malformed;
+        },
+    };
+    let hex = match CStr::from_ptr(hex_sig_nonce).to_str() {
+        Ok(v) => v.to_owned(),
+        _ => {
+            error = LibWalletError::from(InterfaceError::PointerError("hex_sig_nonce".to_string())).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return malformed;
+        },
+    };
+    let hex_keys: Vec<&str> = hex.split('|').collect();
+    if hex_keys.len() != 2 {
+        error = LibWalletError::from(InterfaceError::PositionInvalidError).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return malformed;
+    }
+
+    let signature = match TariPrivateKey::from_hex(hex_keys[0]) {
+        Ok(p) => p,
+        Err(e) => {
+            error = LibWalletError::from(e).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return malformed;
+        },
+    };
+    let public_nonce = match TariPublicKey::from_hex(hex_keys[1]) {
+        Ok(pn) => pn,
+        Err(e) => {
+            error = LibWalletError::from(e).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return malformed;
+        },
+    };
+
+    let sig = SignatureWithDomain::<WalletMessageSigningDomain>::new(public_nonce, signature);
+    if (*wallet).wallet.verify_message_signature(&*public_key, &sig, &message) {
+        SignatureVerificationResult::Valid as c_int
+    } else {
+        SignatureVerificationResult::Invalid as c_int
+    }
+}
+
+/// Adds a base node peer to the TariWallet
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer
+/// `public_key` - The TariPublicKey pointer
+/// `address` - The pointer to a char array
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `bool` - Returns if successful or not
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn wallet_set_base_node_peer(
+    wallet: *mut TariWallet,
+    public_key: *mut TariPublicKey,
+    address: *const c_char,
+    error_out: *mut c_int,
+) -> bool {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+    if let Err(e) = ensure_wallet_running(&*wallet) {
+        error = LibWalletError::from(e).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+
+    if public_key.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("public_key".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+
+    let parsed_addr = if address.is_null() {
+        None
+    } else {
+        match CStr::from_ptr(address).to_str() {
+            Ok(v) => match Multiaddr::from_str(v) {
+                Ok(v) => Some(v),
+                Err(_) => {
+                    error =
+                        LibWalletError::from(InterfaceError::InvalidArgument("address is invalid".to_string())).code;
+                    ptr::swap(error_out, &mut error as *mut c_int);
+                    return false;
+                },
+            },
+            _ => {
+                error = LibWalletError::from(InterfaceError::PointerError("address".to_string())).code;
+                ptr::swap(error_out, &mut error as *mut c_int);
+                return false;
+            },
+        }
+    };
+
+    if let Err(e) = (*wallet).runtime.block_on((*wallet).wallet.set_base_node_peer(
+        (*public_key).clone(),
+        parsed_addr,
+        None,
+    )) {
+        error = LibWalletError::from(e).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+    true
+}
+
+/// Drops the connection to the wallet's current base node and re-establishes it
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `bool` - Returns true if a reconnection attempt was initiated, false if no base node peer has been set
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn wallet_reset_base_node_connection(wallet: *mut TariWallet, error_out: *mut c_int) -> bool {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+    if let Err(e) = ensure_wallet_running(&*wallet) {
+        error = LibWalletError::from(e).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+
+    let mut connectivity = (*wallet).wallet.wallet_connectivity.clone();
+    (*wallet)
+        .runtime
+        .block_on(async move { connectivity.reset_base_node_connection().await })
+}
+
+/// Gets all seed peers known by the wallet
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `TariPublicKeys` - Returns a list of all known public keys
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn wallet_get_seed_peers(wallet: *mut TariWallet, error_out: *mut c_int) -> *mut TariPublicKeys {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+    let peer_manager = (*wallet).wallet.comms.peer_manager();
+    let query = PeerQuery::new().select_where(|p| p.is_seed());
+    #[allow(clippy::blocks_in_conditions)]
+    match (*wallet).runtime.block_on(async move {
+        let peers = peer_manager.perform_query(query).await?;
+        let mut public_keys = Vec::with_capacity(peers.len());
+        for peer in peers {
+            public_keys.push(peer.public_key);
+        }
+        Result::<_, WalletError>::Ok(public_keys)
+    }) {
+        Ok(public_keys) => Box::into_raw(Box::new(TariPublicKeys(public_keys))),
+        Err(e) => {
+            error = LibWalletError::from(e).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            ptr::null_mut()
+        },
+    }
+}
+
+/// Gets the base node peers the wallet's connectivity service is configured to use, in priority order. Unlike
+/// `comms_list_connected_public_keys`, this lists the configured peers whether or not they are currently connected.
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `TariPublicKeys` - Returns the public keys of the configured base node peers, which is empty if no base node has
+/// been set. Returns null if wallet is null.
+///
+/// # Safety
+/// The ```public_keys_destroy``` method must be called when finished with a TariPublicKeys to prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn wallet_get_base_node_peers(
+    wallet: *mut TariWallet,
+    error_out: *mut c_int,
+) -> *mut TariPublicKeys {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+
+    let public_keys = (*wallet)
+        .wallet
+        .wallet_connectivity
+        .get_base_node_peer_manager_state()
+        .map(|(_, peers)| peers.into_iter().map(|peer| peer.public_key).collect())
+        .unwrap_or_default();
+    Box::into_raw(Box::new(TariPublicKeys(public_keys)))
+}
+
+/// Gets the current chain tip height as last reported by the wallet's base node
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `c_ulonglong` - Returns the height of the longest chain, or 0 if no chain metadata has been received from a base
+/// node yet, in which case `error_out` is set
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn wallet_get_network_tip_height(wallet: *mut TariWallet, error_out: *mut c_int) -> c_ulonglong {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
+    if let Err(e) = ensure_wallet_running(&*wallet) {
+        error = LibWalletError::from(e).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
+    let mut base_node_service = (*wallet).wallet.base_node_service.clone();
+    match (*wallet).runtime.block_on(base_node_service.get_chain_metadata()) {
+        Ok(Some(metadata)) => metadata.best_block_height(),
+        Ok(None) => {
+            error = LibWalletError::from(WalletError::BaseNodeServiceError(BaseNodeServiceError::NoChainMetadata)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            0
+        },
+        Err(e) => {
+            error = LibWalletError::from(WalletError::BaseNodeServiceError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            0
+        },
+    }
+}
+
+/// Gets the age of the chain tip, as the difference between the local time and the timestamp of the chain tip last
+/// reported by the wallet's base node. This is not the offset of the local clock from the network time: it includes
+/// the time since the tip was mined, which is expected to be up to a few block intervals, so only an age that is much
+/// larger than the block interval, or a negative age, suggests that the local clock is wrong.
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `i64` - Returns the age in seconds, negative if the tip timestamp is ahead of the local clock, or 0 if no chain
+/// metadata has been received from a base node yet, in which case `error_out` is set
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn wallet_get_tip_age(wallet: *mut TariWallet, error_out: *mut c_int) -> i64 {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
+    if let Err(e) = ensure_wallet_running(&*wallet) {
+        error = LibWalletError::from(e).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
+    let mut base_node_service = (*wallet).wallet.base_node_service.clone();
+    match (*wallet).runtime.block_on(base_node_service.get_chain_metadata()) {
+        Ok(Some(metadata)) => Utc::now()
+            .timestamp()
+            .saturating_sub(i64::try_from(metadata.timestamp()).unwrap_or(i64::MAX)),
+        Ok(None) => {
+            error = LibWalletError::from(WalletError::BaseNodeServiceError(BaseNodeServiceError::NoChainMetadata)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            0
+        },
+        Err(e) => {
+            error = LibWalletError::from(WalletError::BaseNodeServiceError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            0
+        },
+    }
+}
+
+/// Waits until the wallet's base node reports that it is synced, or until the timeout elapses
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer
+/// `timeout_secs` - The maximum number of seconds to wait
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `bool` - Returns true if the base node is synced, false if the timeout elapsed before that happened
+///
+/// # Safety
+/// This function blocks the calling thread until it returns, so it should not be called from a UI thread
+#[no_mangle]
+pub unsafe extern "C" fn wallet_wait_until_initial_sync(
+    wallet: *mut TariWallet,
+    timeout_secs: c_ulonglong,
+    error_out: *mut c_int,
+) -> bool {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+    if let Err(e) = ensure_wallet_running(&*wallet) {
+        error = LibWalletError::from(e).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+    let mut base_node_service = (*wallet).wallet.base_node_service.clone();
+    // Subscribe before querying the current state so that a state change in between is not missed
+    let mut event_stream = base_node_service.get_event_stream();
+    let wait_for_sync = async move {
+        if base_node_service.get_is_synced().await? == Some(true) {
+            return Ok(true);
+        }
+        loop {
+            match event_stream.recv().await {
+                Ok(event) => {
+                    if let BaseNodeEvent::BaseNodeStateChanged(state) = &*event {
+                        if state.is_synced == Some(true) {
+                            return Ok(true);
+                        }
+                    }
+                },
+                Err(broadcast::error::RecvError::Lagged(_)) => {
+                    if base_node_service.get_is_synced().await? == Some(true) {
+                        return Ok(true);
+                    }
+                },
+                Err(broadcast::error::RecvError::Closed) => return Ok(false),
+            }
+        }
+    };
+    match (*wallet).runtime.block_on(async move {
+        tokio::time::timeout(Duration::from_secs(timeout_secs), wait_for_sync)
+            .await
+            .unwrap_or(Ok(false))
+    }) {
+        Ok(is_synced) => is_synced,
+        Err(e) => {
+            error = LibWalletError::from(WalletError::BaseNodeServiceError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            false
+        },
+    }
+}
+
+/// Gets the current status of the connection to the wallet's base node, the same value that is pushed to
+/// `callback_connectivity_status` when it changes
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `c_int` - Returns the status encoded as an integer as follows, or -1 if there was an error:
+/// pub enum OnlineStatus {
+///     Connecting,     // 0
+///     Online,         // 1
+///     Offline,        // 2
+/// }
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn wallet_get_connectivity_status(wallet: *mut TariWallet, error_out: *mut c_int) -> c_int {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return -1;
+    }
+    let status = *(*wallet)
+        .wallet
+        .wallet_connectivity
+        .get_connectivity_status_watch()
+        .borrow();
+    status as c_int
+}
+
+/// Gets the round-trip latency of the most recent request to the wallet's base node, as measured by the base node
+/// monitor. This is the base node counterpart of `liveness_data_get_latency` for contacts.
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `c_int` - Returns the latency in milli-seconds (ms), or -1 if no latency has been measured yet or there was an error
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn wallet_get_base_node_latency(wallet: *mut TariWallet, error_out: *mut c_int) -> c_int {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return -1;
+    }
+    if let Err(e) = ensure_wallet_running(&*wallet) {
+        error = LibWalletError::from(e).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return -1;
+    }
+    let mut base_node_service = (*wallet).wallet.base_node_service.clone();
+    match block_on_runtime(&(*wallet).runtime, base_node_service.get_base_node_latency()) {
+        Ok(Ok(Some(latency))) => c_int::try_from(latency.as_millis()).unwrap_or(c_int::MAX),
+        Ok(Ok(None)) => -1,
+        Ok(Err(e)) => {
+            error = LibWalletError::from(WalletError::BaseNodeServiceError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            -1
+        },
+        Err(e) => {
+            error = LibWalletError::from(e).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            -1
+        },
+    }
+}
+
+/// Upserts a TariContact to the TariWallet. If the contact does not exist it will be Inserted. If it does exist the
+/// Alias will be updated.
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer
+/// `contact` - The TariContact pointer
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `bool` - Returns if successful or not
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn wallet_upsert_contact(
+    wallet: *mut TariWallet,
+    contact: *mut TariContact,
+    error_out: *mut c_int,
+) -> bool {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+    if contact.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("contact".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+
+    match (*wallet)
+        .runtime
+        .block_on((*wallet).wallet.contacts_service.upsert_contact((*contact).clone()))
+    {
+        Ok(_) => true,
+        Err(e) => {
+            error = LibWalletError::from(WalletError::ContactsServiceError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            false
+        },
+    }
+}
+
+/// Removes a TariContact from the TariWallet
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer
+/// `tx` - The TariPendingInboundTransaction pointer
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `bool` - Returns if successful or not
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn wallet_remove_contact(
+    wallet: *mut TariWallet,
+    contact: *mut TariContact,
+    error_out: *mut c_int,
+) -> bool {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+    if contact.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("contact".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+
+    match (*wallet).runtime.block_on(
+        (*wallet)
+            .wallet
+            .contacts_service
+            .remove_contact((*contact).address.clone()),
+    ) {
+        Ok(_) => true,
+        Err(e) => {
+            error = LibWalletError::from(WalletError::ContactsServiceError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            false
+        },
+    }
+}
+
+/// Gets the available balance from a TariBalance. This is the balance the user can spend.
+///
+/// ## Arguments
+/// `balance` - The TariBalance pointer
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `c_ulonglong` - The available balance, 0 if wallet is null
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn balance_get_available(balance: *mut TariBalance, error_out: *mut c_int) -> c_ulonglong {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if balance.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("balance".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
+
+    c_ulonglong::from((*balance).available_balance)
+}
+
+/// Gets the time locked balance from a TariBalance. This is the balance the user can spend.
+///
+/// ## Arguments
+/// `balance` - The TariBalance pointer
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `c_ulonglong` - The time locked balance, 0 if wallet is null
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn balance_get_time_locked(balance: *mut TariBalance, error_out: *mut c_int) -> c_ulonglong {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if balance.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("balance".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
+
+    let b = if let Some(bal) = (*balance).time_locked_balance {
+        bal
+    } else {
+        MicroMinotari::from(0)
+    };
+    c_ulonglong::from(b)
+}
+
+/// Gets the pending incoming balance from a TariBalance. This is the balance the user can spend.
+///
+/// ## Arguments
+/// `balance` - The TariBalance pointer
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `c_ulonglong` - The pending incoming, 0 if wallet is null
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn balance_get_pending_incoming(balance: *mut TariBalance, error_out: *mut c_int) -> c_ulonglong {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if balance.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("balance".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
+
+    c_ulonglong::from((*balance).pending_incoming_balance)
+}
+
+/// Gets the pending outgoing balance from a TariBalance. This is the balance the user can spend.
+///
+/// ## Arguments
+/// `balance` - The TariBalance pointer
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `c_ulonglong` - The pending outgoing balance, 0 if wallet is null
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn balance_get_pending_outgoing(balance: *mut TariBalance, error_out: *mut c_int) -> c_ulonglong {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if balance.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("balance".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
+
+    c_ulonglong::from((*balance).pending_outgoing_balance)
+}
+
+/// Frees memory for a TariBalance
+///
+/// ## Arguments
+/// `balance` - The pointer to a TariBalance
+///
+/// ## Returns
+/// `()` - Does not return a value, equivalent to void in C
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn balance_destroy(balance: *mut TariBalance) {
+    if !balance.is_null() {
+        drop(Box::from_raw(balance))
+    }
+}
+
+/// Sends a TariPendingOutboundTransaction
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer
+/// `destination` - The TariWalletAddress pointer of the peer
+/// `amount` - The amount
+/// `commitments` - A `TariVector` of "strings", tagged as `TariTypeTag::String`, containing commitment's hex values
+///   (see `Commitment::to_hex()`)
+/// `fee_per_gram` - The transaction fee
+/// `message` - The pointer to a char array, at most 256 bytes long, otherwise the send fails with a `MessageTooLong`
+/// error (code 11). If it is null or empty the message set with `wallet_set_default_message` is used instead
+/// `one_sided` - Whether to send a one-sided transaction to a stealth address of the destination, as
+/// `wallet_send_one_sided_transaction` does. The destination must then have the one-sided feature set, otherwise the
+/// send fails with an `InvalidArgument` error (code 7)
+/// `payment_id_string` - The pointer to a char array of the payment id of a one-sided transaction, can be null. A
+/// payment id longer than 189 bytes fails with an `InvalidArgument` error (code 7)
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `unsigned long long` - Returns 0 if unsuccessful or the TxId of the sent transaction if successful
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn wallet_send_transaction(
+    wallet: *mut TariWallet,
+    destination: *mut TariWalletAddress,
+    amount: c_ulonglong,
+    commitments: *mut TariVector,
+    fee_per_gram: c_ulonglong,
+    message: *const c_char,
+    one_sided: bool,
+    payment_id_string: *const c_char,
+    error_out: *mut c_int,
+) -> c_ulonglong {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
+    if let Err(e) = ensure_wallet_running(&*wallet) {
+        error = LibWalletError::from(e).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
+    if let Err(e) = ensure_wallet_can_spend(&*wallet) {
+        error = LibWalletError::from(e).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
+    if destination.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("dest_public_key".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
+
+    let selection_criteria = match commitments.as_ref() {
+        None => UtxoSelectionCriteria::default(),
+        Some(cs) => match cs.to_commitment_vec() {
+            Ok(cs) => UtxoSelectionCriteria::specific(cs),
+            Err(e) => {
+                error!(target: LOG_TARGET, "failed to convert from tari vector: {:?}", e);
+                ptr::replace(error_out, LibWalletError::from(e).code as c_int);
+                return 0;
+            },
+        },
+    };
+
+    let message_string;
+    if message.is_null() {
+        message_string = CString::new("")
+            .expect("Blank CString will not fail")
+            .to_str()
+            .expect("CString.to_str() will not fail")
+            .to_owned();
+    } else {
+        match CStr::from_ptr(message).to_str() {
+            Ok(v) => {
+                message_string = v.to_owned();
+            },
+            _ => {
+                error = LibWalletError::from(InterfaceError::NullError("message".to_string())).code;
+                ptr::swap(error_out, &mut error as *mut c_int);
+                return 0;
+            },
+        }
+    };
+    let message_string = match message_or_default(&*wallet, message_string) {
+        Ok(v) => v,
+        Err(e) => {
+            error = LibWalletError::from(WalletError::WalletStorageError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return 0;
+        },
+    };
+    if message_string.len() > MAX_TRANSACTION_MESSAGE_LENGTH {
+        error = LibWalletError::from(InterfaceError::MessageTooLong {
+            length: message_string.len(),
+            max: MAX_TRANSACTION_MESSAGE_LENGTH,
+        })
+        .code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
+
+    if one_sided {
+        let payment_id = if payment_id_string.is_null() {
+            PaymentId::Empty
+        } else {
+            match CStr::from_ptr(payment_id_string).to_str() {
+                Ok(v) => {
+                    let rust_str = v.to_owned();
+                    let bytes = rust_str.as_bytes().to_vec();
+                    PaymentId::Open(bytes)
+                },
+                _ => {
+                    error = LibWalletError::from(InterfaceError::NullError("payment_id".to_string())).code;
+                    ptr::swap(error_out, &mut error as *mut c_int);
+                    return 0;
+                },
+            }
+        };
+        match send_one_sided_to_stealth_address(
+            &*wallet,
+            &*destination,
+            MicroMinotari::from(amount),
+            selection_criteria,
+            MicroMinotari::from(fee_per_gram),
+            message_string,
+            payment_id,
+        ) {
+            Ok(tx_id) => tx_id.as_u64(),
+            Err(e) => {
+                error = e.code;
+                ptr::swap(error_out, &mut error as *mut c_int);
+                0
+            },
+        }
+    } else {
+        match block_on_runtime(
+            &(*wallet).runtime,
+            (*wallet).wallet.transaction_service.send_transaction(
+                (*destination).clone(),
+                MicroMinotari::from(amount),
+                selection_criteria,
+                OutputFeatures::default(),
+                MicroMinotari::from(fee_per_gram),
+                message_string,
+            ),
+        ) {
+            Ok(Ok(tx_id)) => tx_id.as_u64(),
+            Ok(Err(e)) => {
+                error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
+                ptr::swap(error_out, &mut error as *mut c_int);
+                0
+            },
+            Err(e) => {
+                error = LibWalletError::from(e).code;
+                ptr::swap(error_out, &mut error as *mut c_int);
+                0
+            },
+        }
+    }
+}
+
+/// Sends a one-sided transaction to a stealth address of the destination. The output is built entirely by this wallet,
+/// so the recipient does not need to be online or take part in the transaction.
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer
+/// `destination` - The TariWalletAddress pointer of the recipient, which must have the one-sided feature set
+/// `amount` - The amount
+/// `fee_per_gram` - The transaction fee
+/// `message` - The pointer to a char array, at most 256 bytes long, otherwise the send fails with a `MessageTooLong`
+/// error (code 11). If it is null or empty the message set with `wallet_set_default_message` is used instead
+/// `payment_id` - The pointer to a ByteVector containing the payment id to attach to the output, can be null if no
+/// payment id should be attached. A payment id longer than 189 bytes fails with an `InvalidArgument` error (code 7)
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `unsigned long long` - Returns 0 if unsuccessful or the TxId of the sent transaction if successful. If the
+/// destination does not support one-sided payments the send fails with an `InvalidArgument` error (code 7).
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn wallet_send_one_sided_transaction(
+    wallet: *mut TariWallet,
+    destination: *mut TariWalletAddress,
+    amount: c_ulonglong,
+    fee_per_gram: c_ulonglong,
+    message: *const c_char,
+    payment_id: *const ByteVector,
+    error_out: *mut c_int,
+) -> c_ulonglong {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
+    if let Err(e) = ensure_wallet_running(&*wallet) {
+        error = LibWalletError::from(e).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
+    if let Err(e) = ensure_wallet_can_spend(&*wallet) {
+        error = LibWalletError::from(e).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
+    if destination.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("destination".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
+
+    let message_string = if message.is_null() {
+        String::new()
+    } else {
+        match CStr::from_ptr(message).to_str() {
+            Ok(v) => v.to_owned(),
+            _ => {
+                error = LibWalletError::from(InterfaceError::PointerError("message".to_string())).code;
+                ptr::swap(error_out, &mut error as *mut c_int);
+                return 0;
+            },
+        }
+    };
+    let message_string = match message_or_default(&*wallet, message_string) {
+        Ok(v) => v,
+        Err(e) => {
+            error = LibWalletError::from(WalletError::WalletStorageError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return 0;
         },
+    };
+    if message_string.len() > MAX_TRANSACTION_MESSAGE_LENGTH {
+        error = LibWalletError::from(InterfaceError::MessageTooLong {
+            length: message_string.len(),
+            max: MAX_TRANSACTION_MESSAGE_LENGTH,
+        })
+        .code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
+
+    let payment_id = match payment_id.as_ref() {
+        None => PaymentId::Empty,
+        Some(bytes) => PaymentId::Open(bytes.0.clone()),
+    };
+
+    match send_one_sided_to_stealth_address(
+        &*wallet,
+        &*destination,
+        MicroMinotari::from(amount),
+        UtxoSelectionCriteria::default(),
+        MicroMinotari::from(fee_per_gram),
+        message_string,
+        payment_id,
+    ) {
+        Ok(tx_id) => tx_id.as_u64(),
         Err(e) => {
-            error = LibWalletError::from(WalletError::WalletStorageError(e)).code;
+            error = e.code;
             ptr::swap(error_out, &mut error as *mut c_int);
-            ptr::null_mut()
+            0
         },
     }
 }
 
-/// Retrieves the balance from a wallet
+/// Sends a one-sided transaction to a stealth address of `destination` for `wallet_send_transaction` and
+/// `wallet_send_one_sided_transaction`, once they have resolved the message. The destination must support one-sided
+/// payments and the payment id must fit in the encrypted data of the output.
+fn send_one_sided_to_stealth_address(
+    wallet: &TariWallet,
+    destination: &TariWalletAddress,
+    amount: MicroMinotari,
+    selection_criteria: UtxoSelectionCriteria,
+    fee_per_gram: MicroMinotari,
+    message: String,
+    payment_id: PaymentId,
+) -> Result<TxId, LibWalletError> {
+    if !destination.features().contains(TariAddressFeatures::ONE_SIDED) {
+        return Err(
+            InterfaceError::InvalidArgument("destination does not support one-sided payments".to_string()).into(),
+        );
+    }
+    if payment_id.get_size() > MAX_PAYMENT_ID_LENGTH {
+        return Err(InterfaceError::InvalidArgument(format!(
+            "payment id is {} bytes long, at most {} are allowed",
+            payment_id.get_size(),
+            MAX_PAYMENT_ID_LENGTH
+        ))
+        .into());
+    }
+
+    let mut transaction_service = wallet.wallet.transaction_service.clone();
+    block_on_runtime(
+        &wallet.runtime,
+        transaction_service.send_one_sided_to_stealth_address_transaction(
+            destination.clone(),
+            amount,
+            selection_criteria,
+            OutputFeatures::default(),
+            fee_per_gram,
+            message,
+            payment_id,
+        ),
+    )?
+    .map_err(|e| WalletError::TransactionServiceError(e).into())
+}
+
+/// Sends a single one-sided transaction that pays each of the given recipients
 ///
 /// ## Arguments
-/// `wallet` - The TariWallet pointer.
+/// `wallet` - The TariWallet pointer
+/// `addresses` - A `TariVector` of "strings", tagged as `TariTypeTag::Text`, containing the recipient addresses as
+/// base58, emoji or hex strings
+/// `amounts` - A `TariVector` of `unsigned long long`, tagged as `TariTypeTag::U64`, containing the amount for each
+/// recipient in the same order as `addresses`
+/// `fee_per_gram` - The transaction fee
+/// `message` - The pointer to a char array, at most 256 bytes long, otherwise the send fails with a `MessageTooLong`
+/// error (code 11). If it is null or empty the message set with `wallet_set_default_message` is used instead
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
+///
 /// ## Returns
-/// `*mut Balance` - Returns the pointer to the TariBalance or null if error occurs
+/// `unsigned long long` - Returns 0 if unsuccessful or the TxId of the sent transaction if successful. If `addresses`
+/// and `amounts` are empty or differ in length, or the amounts add up to more than fits in an `unsigned long long`,
+/// the send fails with an `InvalidArgument` error (code 7).
 ///
 /// # Safety
-/// The ```balance_destroy``` method must be called when finished with a TariBalance to prevent a memory leak
+/// None
 #[no_mangle]
-pub unsafe extern "C" fn wallet_get_balance(wallet: *mut TariWallet, error_out: *mut c_int) -> *mut TariBalance {
+pub unsafe extern "C" fn wallet_send_transaction_to_many(
+    wallet: *mut TariWallet,
+    addresses: *mut TariVector,
+    amounts: *mut TariVector,
+    fee_per_gram: c_ulonglong,
+    message: *const c_char,
+    error_out: *mut c_int,
+) -> c_ulonglong {
     let mut error = 0;
     ptr::swap(error_out, &mut error as *mut c_int);
     if wallet.is_null() {
         error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
         ptr::swap(error_out, &mut error as *mut c_int);
-        return ptr::null_mut();
+        return 0;
     }
-    let balance = (*wallet)
-        .runtime
-        .block_on((*wallet).wallet.output_manager_service.get_balance());
-    match balance {
-        Ok(balance) => Box::into_raw(Box::new(balance)),
-        Err(_) => {
-            error = LibWalletError::from(InterfaceError::BalanceError).code;
+    if let Err(e) = ensure_wallet_running(&*wallet) {
+        error = LibWalletError::from(e).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
+    if let Err(e) = ensure_wallet_can_spend(&*wallet) {
+        error = LibWalletError::from(e).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
+    if addresses.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("addresses".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
+    if amounts.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("amounts".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
+
+    let addresses = match (*addresses).to_string_vec() {
+        Ok(v) => v,
+        Err(e) => {
+            error = LibWalletError::from(e).code;
             ptr::swap(error_out, &mut error as *mut c_int);
-            ptr::null_mut()
+            return 0;
+        },
+    };
+    let amounts = match (*amounts).to_u64_vec() {
+        Ok(v) => v,
+        Err(e) => {
+            error = LibWalletError::from(e).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return 0;
         },
+    };
+    if addresses.is_empty() || addresses.len() != amounts.len() {
+        error = LibWalletError::from(InterfaceError::InvalidArgument(format!(
+            "expected the same non-zero number of addresses and amounts, got {} and {}",
+            addresses.len(),
+            amounts.len()
+        )))
+        .code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
     }
-}
-
-/// This function returns a list of unspent UTXO values and commitments.
-///
-/// ## Arguments
-/// * `wallet` - The TariWallet pointer,
-/// * `page` - Page offset,
-/// * `page_size` - A number of items per page,
-/// * `sorting` - An enum representing desired sorting,
-/// * `dust_threshold` - A value filtering threshold. Outputs whose values are <= `dust_threshold` are not listed in the
-///   result.
-/// * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
-///   Functions as an out parameter.
-///
-/// ## Returns
-/// `*mut TariVector` - Returns a struct with an array pointer, length and capacity (needed for proper destruction
-/// after use).
-///
-/// # Safety
-/// `destroy_tari_vector()` must be called after use.
-/// Items that fail to produce `.as_transaction_output()` are omitted from the list and a `warn!()` message is logged to
-/// LOG_TARGET.
-// casting here is okay as we wont have more than u32 utxos
-#[allow(clippy::cast_possible_truncation)]
-#[no_mangle]
-pub unsafe extern "C" fn wallet_get_utxos(
-    wallet: *mut TariWallet,
-    page: usize,
-    page_size: usize,
-    sorting: TariUtxoSort,
-    states: *mut TariVector,
-    dust_threshold: u64,
-    error_ptr: *mut i32,
-) -> *mut TariVector {
-    if wallet.is_null() {
-        error!(target: LOG_TARGET, "wallet pointer is null");
-        ptr::replace(
-            error_ptr,
-            LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code,
-        );
-        return ptr::null_mut();
+    if amounts
+        .iter()
+        .try_fold(0u64, |total, amount| total.checked_add(*amount))
+        .is_none()
+    {
+        error = LibWalletError::from(InterfaceError::InvalidArgument(
+            "the total of the amounts overflows".to_string(),
+        ))
+        .code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
     }
 
-    let page = i64::from_usize(page).unwrap_or(i64::MAX);
-    let page_size = i64::from_usize(page_size).unwrap_or(i64::MAX);
-    let dust_threshold = i64::from_u64(dust_threshold).unwrap_or(0);
+    let mut recipients = Vec::with_capacity(addresses.len());
+    for (address, amount) in addresses.iter().zip(amounts) {
+        match TariWalletAddress::from_str(address) {
+            Ok(address) => recipients.push((address, MicroMinotari::from(amount))),
+            Err(e) => {
+                error = LibWalletError::from(e).code;
+                ptr::swap(error_out, &mut error as *mut c_int);
+                return 0;
+            },
+        }
+    }
 
-    let status = {
-        if states.is_null() {
-            vec![]
-        } else {
-            Vec::from_raw_parts((*states).ptr as *mut u64, (*states).len, (*states).cap)
-                .into_iter()
-                .map(|x| OutputStatus::try_from(x as i32).unwrap())
-                .collect_vec()
+    let message_string = if message.is_null() {
+        String::new()
+    } else {
+        match CStr::from_ptr(message).to_str() {
+            Ok(v) => v.to_owned(),
+            _ => {
+                error = LibWalletError::from(InterfaceError::NullError("message".to_string())).code;
+                ptr::swap(error_out, &mut error as *mut c_int);
+                return 0;
+            },
         }
     };
-
-    use SortDirection::{Asc, Desc};
-    let q = OutputBackendQuery {
-        tip_height: i64::MAX,
-        status,
-        commitments: vec![],
-        pagination: Some((page, page_size)),
-        value_min: Some((dust_threshold, false)),
-        value_max: None,
-        sorting: vec![match sorting {
-            TariUtxoSort::MinedHeightAsc => ("mined_height", Asc),
-            TariUtxoSort::MinedHeightDesc => ("mined_height", Desc),
-            TariUtxoSort::ValueAsc => ("value", Asc),
-            TariUtxoSort::ValueDesc => ("value", Desc),
-        }],
+    let message_string = match message_or_default(&*wallet, message_string) {
+        Ok(v) => v,
+        Err(e) => {
+            error = LibWalletError::from(WalletError::WalletStorageError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return 0;
+        },
     };
+    if message_string.len() > MAX_TRANSACTION_MESSAGE_LENGTH {
+        error = LibWalletError::from(InterfaceError::MessageTooLong {
+            length: message_string.len(),
+            max: MAX_TRANSACTION_MESSAGE_LENGTH,
+        })
+        .code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
 
-    match (*wallet).wallet.output_db.fetch_outputs_by_query(q) {
-        Ok(outputs) => {
-            ptr::replace(error_ptr, 0);
-            Box::into_raw(Box::new(TariVector::from(outputs)))
+    match block_on_runtime(
+        &(*wallet).runtime,
+        (*wallet).wallet.transaction_service.send_one_sided_transaction_to_many(
+            recipients,
+            UtxoSelectionCriteria::default(),
+            MicroMinotari::from(fee_per_gram),
+            message_string,
+            PaymentId::Empty,
+        ),
+    ) {
+        Ok(Ok(tx_id)) => tx_id.as_u64(),
+        Ok(Err(e)) => {
+            error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            0
         },
-
         Err(e) => {
-            error!(target: LOG_TARGET, "failed to obtain outputs: {:#?}", e);
-            ptr::replace(
-                error_ptr,
-                LibWalletError::from(WalletError::OutputManagerError(
-                    OutputManagerError::OutputManagerStorageError(e),
-                ))
-                .code,
-            );
-            ptr::null_mut()
+            error = LibWalletError::from(e).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            0
         },
     }
 }
 
-/// This function returns a list of all UTXO values, commitment's hex values and states.
+/// Sends a TariPendingOutboundTransaction
 ///
 /// ## Arguments
-/// * `wallet` - The TariWallet pointer,
-/// * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
-///   Functions as an out parameter.
+/// `wallet` - The TariWallet pointer
+/// `destination` - The TariWalletAddress pointer of the peer
+/// `fee_per_gram` - The transaction fee
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
 ///
 /// ## Returns
-/// `*mut TariVector` - Returns a struct with an array pointer, length and capacity (needed for proper destruction
-///     after use).
-///
-/// ## States
-/// 0 - Unspent
-/// 1 - Spent
-/// 2 - EncumberedToBeReceived
-/// 3 - EncumberedToBeSpent
-/// 4 - Invalid
-/// 5 - CancelledInbound
-/// 6 - UnspentMinedUnconfirmed
-/// 7 - ShortTermEncumberedToBeReceived
-/// 8 - ShortTermEncumberedToBeSpent
-/// 9 - SpentMinedUnconfirmed
-/// 10 - AbandonedCoinbase
-/// 11 - NotStored
+/// `unsigned long long` - Returns 0 if unsuccessful or the TxId of the sent transaction if successful
 ///
 /// # Safety
-/// `destroy_tari_vector()` must be called after use.
-/// Items that fail to produce `.as_transaction_output()` are omitted from the list and a `warn!()` message is logged to
-/// LOG_TARGET.
+/// None
 #[no_mangle]
-pub unsafe extern "C" fn wallet_get_all_utxos(wallet: *mut TariWallet, error_ptr: *mut i32) -> *mut TariVector {
+pub unsafe extern "C" fn scrape_wallet(
+    wallet: *mut TariWallet,
+    destination: *mut TariWalletAddress,
+    fee_per_gram: c_ulonglong,
+    error_out: *mut c_int,
+) -> c_ulonglong {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
     if wallet.is_null() {
-        error!(target: LOG_TARGET, "wallet pointer is null");
-        ptr::replace(
-            error_ptr,
-            LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code,
-        );
-        return ptr::null_mut();
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
+    if let Err(e) = ensure_wallet_running(&*wallet) {
+        error = LibWalletError::from(e).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
+    if let Err(e) = ensure_wallet_can_spend(&*wallet) {
+        error = LibWalletError::from(e).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
+    if destination.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("dest_public_key".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
     }
 
-    let q = OutputBackendQuery {
-        tip_height: i64::MAX,
-        status: vec![],
-        commitments: vec![],
-        pagination: None,
-        value_min: None,
-        value_max: None,
-        sorting: vec![],
-    };
-
-    match (*wallet).wallet.output_db.fetch_outputs_by_query(q) {
-        Ok(outputs) => {
-            ptr::replace(error_ptr, 0);
-            Box::into_raw(Box::new(TariVector::from(outputs)))
-        },
-
+    match (*wallet).runtime.block_on(
+        (*wallet)
+            .wallet
+            .transaction_service
+            .scrape_wallet((*destination).clone(), MicroMinotari::from(fee_per_gram)),
+    ) {
+        Ok(tx_id) => tx_id.as_u64(),
         Err(e) => {
-            error!(target: LOG_TARGET, "failed to obtain outputs: {:#?}", e);
-            ptr::replace(
-                error_ptr,
-                LibWalletError::from(WalletError::OutputManagerError(
-                    OutputManagerError::OutputManagerStorageError(e),
-                ))
-                .code,
-            );
-            ptr::null_mut()
+            error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            0
         },
     }
 }
 
-/// This function will tell the wallet to do a coin split.
+/// Gets a fee estimate for an amount
 ///
 /// ## Arguments
-/// * `wallet` - The TariWallet pointer
-/// * `commitments` - A `TariVector` of "strings", tagged as `TariTypeTag::String`, containing commitment's hex values
+/// `wallet` - The TariWallet pointer
+/// `amount` - The amount
+/// `commitments` - A `TariVector` of "strings", tagged as `TariTypeTag::String`, containing commitment's hex values
 ///   (see `Commitment::to_hex()`)
-/// * `number_of_splits` - The number of times to split the amount
-/// * `fee_per_gram` - The transaction fee
-/// * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
-///   Functions as an out parameter.
+/// `fee_per_gram` - The fee per gram
+/// `num_kernels` - The number of transaction kernels
+/// `num_outputs` - The number of outputs
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
 ///
 /// ## Returns
-/// `c_ulonglong` - Returns the transaction id.
+/// `unsigned long long` - Returns 0 if unsuccessful or the fee estimate in MicroMinotari if successful
 ///
 /// # Safety
-/// `TariVector` must be freed after use with `destroy_tari_vector()`
+/// None
 #[no_mangle]
-pub unsafe extern "C" fn wallet_coin_split(
+pub unsafe extern "C" fn wallet_get_fee_estimate(
     wallet: *mut TariWallet,
+    amount: c_ulonglong,
     commitments: *mut TariVector,
-    number_of_splits: usize,
-    fee_per_gram: u64,
-    error_ptr: *mut i32,
-) -> u64 {
+    fee_per_gram: c_ulonglong,
+    num_kernels: c_uint,
+    num_outputs: c_uint,
+    error_out: *mut c_int,
+) -> c_ulonglong {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
     if wallet.is_null() {
-        error!(target: LOG_TARGET, "wallet pointer is null");
-        ptr::replace(
-            error_ptr,
-            LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code as c_int,
-        );
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
         return 0;
     }
 
-    let commitments = match commitments.as_ref() {
-        None => {
-            error!(target: LOG_TARGET, "failed to obtain commitments as reference");
-            ptr::replace(
-                error_ptr,
-                LibWalletError::from(InterfaceError::NullError("commitments vector".to_string())).code as c_int,
-            );
-            return 0;
-        },
+    let selection_criteria = match commitments.as_ref() {
+        None => UtxoSelectionCriteria::default(),
         Some(cs) => match cs.to_commitment_vec() {
-            Ok(cs) => cs,
+            Ok(cs) => UtxoSelectionCriteria::specific(cs),
             Err(e) => {
                 error!(target: LOG_TARGET, "failed to convert from tari vector: {:?}", e);
-                ptr::replace(error_ptr, LibWalletError::from(e).code as c_int);
+                ptr::replace(error_out, LibWalletError::from(e).code as c_int);
                 return 0;
             },
         },
     };
 
-    match (*wallet).runtime.block_on((*wallet).wallet.coin_split_even(
-        commitments,
-        number_of_splits,
-        MicroMinotari(fee_per_gram),
-        String::new(),
-    )) {
-        Ok(tx_id) => {
-            ptr::replace(error_ptr, 0);
-            tx_id.as_u64()
-        },
+    match (*wallet)
+        .runtime
+        .block_on((*wallet).wallet.output_manager_service.fee_estimate(
+            MicroMinotari::from(amount),
+            selection_criteria,
+            MicroMinotari::from(fee_per_gram),
+            num_kernels as usize,
+            num_outputs as usize,
+        )) {
+        Ok(fee) => fee.into(),
         Err(e) => {
-            error!(target: LOG_TARGET, "failed to join outputs: {:#?}", e);
-            ptr::replace(error_ptr, LibWalletError::from(e).code);
+            error = LibWalletError::from(WalletError::OutputManagerError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
             0
         },
     }
 }
 
-/// This function will tell the wallet to do a coin join, resulting in a new coin worth a sum of the joined coins minus
-/// the fee.
+/// Gets the lowest fee per gram the wallet should use for a transaction. UIs can use it as the floor for a
+/// user entered fee per gram, since a lower value gives a transaction without a fee. The network has no consensus
+/// minimum fee per gram, and base nodes may additionally refuse transactions below a locally configured total fee,
+/// which the wallet cannot see.
 ///
 /// ## Arguments
-/// * `wallet` - The TariWallet pointer
-/// * `commitments` - A `TariVector` of "strings", tagged as `TariTypeTag::String`, containing commitment's hex values
-///   (see `Commitment::to_hex()`)
-/// * `fee_per_gram` - The transaction fee
-/// * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
-///   Functions as an out parameter.
+/// `wallet` - The TariWallet pointer
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
 ///
 /// ## Returns
-/// `TariVector` - Returns the transaction id.
+/// `c_ulonglong` - Returns the minimum fee per gram in MicroMinotari, or 0 if wallet is null
 ///
 /// # Safety
-/// `TariVector` must be freed after use with `destroy_tari_vector()`
+/// None
 #[no_mangle]
-pub unsafe extern "C" fn wallet_coin_join(
+pub unsafe extern "C" fn wallet_get_minimum_fee_per_gram(
     wallet: *mut TariWallet,
-    commitments: *mut TariVector,
-    fee_per_gram: u64,
-    error_ptr: *mut i32,
-) -> u64 {
+    error_out: *mut c_int,
+) -> c_ulonglong {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
     if wallet.is_null() {
-        error!(target: LOG_TARGET, "wallet pointer is null");
-        ptr::replace(
-            error_ptr,
-            LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code as c_int,
-        );
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
         return 0;
     }
 
-    let commitments = match commitments.as_ref() {
-        None => {
-            error!(target: LOG_TARGET, "failed to obtain commitments as reference");
-            ptr::replace(
-                error_ptr,
-                LibWalletError::from(InterfaceError::NullError("commitments vector".to_string())).code as c_int,
-            );
-            return 0;
-        },
-        Some(cs) => match cs.to_commitment_vec() {
-            Ok(cs) => cs,
-            Err(e) => {
-                error!(target: LOG_TARGET, "failed to convert from tari vector: {:?}", e);
-                ptr::replace(error_ptr, LibWalletError::from(e).code as c_int);
-                return 0;
-            },
-        },
-    };
-
-    match (*wallet)
-        .runtime
-        .block_on((*wallet).wallet.coin_join(commitments, fee_per_gram.into(), None))
-    {
-        Ok(tx_id) => {
-            ptr::replace(error_ptr, 0);
-            tx_id.as_u64()
-        },
-
-        Err(e) => {
-            error!(target: LOG_TARGET, "failed to join outputs: {:#?}", e);
-            ptr::replace(error_ptr, LibWalletError::from(e).code);
-            0
-        },
-    }
+    MINIMUM_FEE_PER_GRAM.as_u64()
 }
 
-/// This function will tell what the outcome of a coin join would be.
+/// Estimates the fee of a transaction of the given shape without selecting any of the wallet's outputs, so unlike
+/// `wallet_get_fee_estimate` the wallet does not need to hold the funds. The transaction is assumed to spend a single
+/// input into outputs with default features and scripts, which is what the wallet assumes when it cannot select
+/// outputs for an estimate, and is weighed with the latest consensus weighting of the current network.
 ///
 /// ## Arguments
-/// * `wallet` - The TariWallet pointer
-/// * `commitments` - A `TariVector` of "strings", tagged as `TariTypeTag::String`, containing commitment's hex values
-///   (see `Commitment::to_hex()`)
-/// * `fee_per_gram` - The transaction fee
-/// * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
-///   Functions as an out parameter.
+/// `wallet` - The TariWallet pointer
+/// `amount` - The amount to send, must be greater than zero
+/// `fee_per_gram` - The fee per gram
+/// `num_outputs` - The number of outputs, must be greater than zero
+/// `num_kernels` - The number of transaction kernels, must be greater than zero
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
 ///
 /// ## Returns
-/// `*mut TariCoinPreview` - A struct with expected output values and the fee.
+/// `unsigned long long` - Returns 0 if unsuccessful or the fee estimate in MicroMinotari if successful
 ///
 /// # Safety
-/// `TariVector` must be freed after use with `destroy_tari_vector()`
+/// None
 #[no_mangle]
-pub unsafe extern "C" fn wallet_preview_coin_join(
+pub unsafe extern "C" fn wallet_estimate_fee(
     wallet: *mut TariWallet,
-    commitments: *mut TariVector,
-    fee_per_gram: u64,
-    error_ptr: *mut i32,
-) -> *mut TariCoinPreview {
+    amount: c_ulonglong,
+    fee_per_gram: c_ulonglong,
+    num_outputs: c_uint,
+    num_kernels: c_uint,
+    error_out: *mut c_int,
+) -> c_ulonglong {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
     if wallet.is_null() {
-        error!(target: LOG_TARGET, "wallet pointer is null");
-        ptr::replace(
-            error_ptr,
-            LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code as c_int,
-        );
-        return ptr::null_mut();
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
+    for (value, name) in [
+        (amount, "amount"),
+        (c_ulonglong::from(num_outputs), "num_outputs"),
+        (c_ulonglong::from(num_kernels), "num_kernels"),
+    ] {
+        if value == 0 {
+            error = LibWalletError::from(InterfaceError::InvalidArgument(name.to_string())).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return 0;
+        }
     }
 
-    let commitments = match commitments.as_ref() {
-        None => {
-            error!(target: LOG_TARGET, "failed to obtain commitments as reference");
-            ptr::replace(
-                error_ptr,
-                LibWalletError::from(InterfaceError::NullError("commitments vector".to_string())).code as c_int,
-            );
-            return ptr::null_mut();
+    let fee_calc = match transaction_weighting(None) {
+        Ok(weighting) => Fee::new(weighting),
+        Err(e) => {
+            error = e.code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return 0;
         },
-        Some(cs) => match cs.to_commitment_vec() {
-            Ok(cs) => cs,
-            Err(e) => {
-                error!(target: LOG_TARGET, "failed to convert from tari vector: {:?}", e);
-                ptr::replace(error_ptr, LibWalletError::from(e).code as c_int);
-                return ptr::null_mut();
-            },
+    };
+    let output_size = || -> std::io::Result<usize> {
+        Ok(OutputFeatures::default().get_serialized_size()? +
+            TariScript::default().get_serialized_size()? +
+            TariCovenant::new().get_serialized_size()?)
+    };
+    let features_and_scripts_size = match output_size() {
+        Ok(size) => fee_calc.weighting().round_up_features_and_scripts_size(size),
+        Err(e) => {
+            error = LibWalletError::from(InterfaceError::InternalError(e.to_string())).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return 0;
         },
     };
 
+    fee_calc
+        .calculate(
+            MicroMinotari::from(fee_per_gram),
+            num_kernels as usize,
+            1,
+            num_outputs as usize,
+            features_and_scripts_size * num_outputs as usize,
+        )
+        .as_u64()
+}
+
+/// Gets the largest amount that can be sent in a single transaction, i.e. the value of the spendable outputs that
+/// would be selected for the transaction less the exact fee for spending all of them. Use this for "send all" instead
+/// of subtracting a fee estimate from the available balance.
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer
+/// `fee_per_gram` - The fee per gram
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `unsigned long long` - Returns the maximum spendable amount in MicroMinotari, 0 if there is nothing spendable or if
+/// unsuccessful
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn wallet_get_max_spendable_amount(
+    wallet: *mut TariWallet,
+    fee_per_gram: c_ulonglong,
+    error_out: *mut c_int,
+) -> c_ulonglong {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
+
     match (*wallet).runtime.block_on(
         (*wallet)
             .wallet
-            .preview_coin_join_with_commitments(commitments, MicroMinotari(fee_per_gram)),
+            .output_manager_service
+            .get_max_spendable_amount(MicroMinotari::from(fee_per_gram)),
     ) {
-        Ok((expected_outputs, fee)) => {
-            ptr::replace(error_ptr, 0);
-            let mut expected_outputs = ManuallyDrop::new(expected_outputs);
-
-            Box::into_raw(Box::new(TariCoinPreview {
-                expected_outputs: Box::into_raw(Box::new(TariVector {
-                    tag: TariTypeTag::U64,
-                    len: expected_outputs.len(),
-                    cap: expected_outputs.capacity(),
-                    ptr: expected_outputs.as_mut_ptr() as *mut c_void,
-                })),
-                fee: fee.as_u64(),
-            }))
-        },
+        Ok(amount) => amount.into(),
         Err(e) => {
-            error!(
-                target: LOG_TARGET,
-                "failed to preview coin join with commitments: {:#?}", e
-            );
-            ptr::replace(error_ptr, LibWalletError::from(e).code);
-            ptr::null_mut()
+            error = LibWalletError::from(WalletError::OutputManagerError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            0
         },
     }
 }
 
-/// This function will tell what the outcome of a coin split would be.
+/// Gets the number of mining confirmations required
 ///
 /// ## Arguments
-/// * `wallet` - The TariWallet pointer
-/// * `commitments` - A `TariVector` of "strings", tagged as `TariTypeTag::String`, containing commitment's hex values
-///   (see `Commitment::to_hex()`)
-/// * `number_of_splits` - The number of times to split the amount
-/// * `fee_per_gram` - The transaction fee
-/// * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
-///   Functions as an out parameter.
+/// `wallet` - The TariWallet pointer
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
 ///
 /// ## Returns
-/// `*mut TariCoinPreview` - A struct with expected output values and the fee.
+/// `unsigned long long` - Returns the number of confirmations required
 ///
 /// # Safety
-/// `TariVector` must be freed after use with `destroy_tari_vector()`
+/// None
 #[no_mangle]
-pub unsafe extern "C" fn wallet_preview_coin_split(
+pub unsafe extern "C" fn wallet_get_num_confirmations_required(
     wallet: *mut TariWallet,
-    commitments: *mut TariVector,
-    number_of_splits: usize,
-    fee_per_gram: u64,
-    error_ptr: *mut i32,
-) -> *mut TariCoinPreview {
+    error_out: *mut c_int,
+) -> c_ulonglong {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
     if wallet.is_null() {
-        error!(target: LOG_TARGET, "wallet pointer is null");
-        ptr::replace(
-            error_ptr,
-            LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code as c_int,
-        );
-        return ptr::null_mut();
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
     }
 
-    let commitments = match commitments.as_ref() {
-        None => {
-            error!(target: LOG_TARGET, "failed to obtain commitments as reference");
-            ptr::replace(
-                error_ptr,
-                LibWalletError::from(InterfaceError::NullError("commitments vector".to_string())).code as c_int,
-            );
-            return ptr::null_mut();
-        },
-        Some(cs) => match cs.to_commitment_vec() {
-            Ok(cs) => cs,
-            Err(e) => {
-                error!(target: LOG_TARGET, "failed to convert from tari vector: {:?}", e);
-                ptr::replace(error_ptr, LibWalletError::from(e).code as c_int);
-                return ptr::null_mut();
-            },
-        },
-    };
-
     match (*wallet)
         .runtime
-        .block_on((*wallet).wallet.preview_coin_split_with_commitments_no_amount(
-            commitments,
-            number_of_splits,
-            MicroMinotari(fee_per_gram),
-        )) {
-        Ok((expected_outputs, fee)) => {
-            ptr::replace(error_ptr, 0);
-            let mut expected_outputs = ManuallyDrop::new(expected_outputs);
-
-            Box::into_raw(Box::new(TariCoinPreview {
-                expected_outputs: Box::into_raw(Box::new(TariVector {
-                    tag: TariTypeTag::U64,
-                    len: expected_outputs.len(),
-                    cap: expected_outputs.capacity(),
-                    ptr: expected_outputs.as_mut_ptr() as *mut c_void,
-                })),
-                fee: fee.as_u64(),
-            }))
-        },
+        .block_on((*wallet).wallet.transaction_service.get_num_confirmations_required())
+    {
+        Ok(num) => num,
         Err(e) => {
-            error!(
-                target: LOG_TARGET,
-                "failed to preview split with commitments outputs (no amount): {:#?}", e
-            );
-            ptr::replace(error_ptr, LibWalletError::from(e).code);
-            ptr::null_mut()
+            error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            0
         },
     }
 }
 
-/// Signs a message using the public key of the TariWallet
+/// Sets the number of mining confirmations required
 ///
 /// ## Arguments
-/// `wallet` - The TariWallet pointer.
-/// `msg` - The message pointer.
+/// `wallet` - The TariWallet pointer
+/// `num` - The number of confirmations to require
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
+///
 /// ## Returns
-/// `*mut c_char` - Returns the pointer to the hexadecimal representation of the signature and
-/// public nonce, seperated by a pipe character. Empty if an error occured.
+/// `()` - Does not return a value, equivalent to void in C
 ///
 /// # Safety
-/// The ```string_destroy``` method must be called when finished with a string coming from rust to prevent a memory leak
+/// None
 #[no_mangle]
-pub unsafe extern "C" fn wallet_sign_message(
+pub unsafe extern "C" fn wallet_set_num_confirmations_required(
     wallet: *mut TariWallet,
-    msg: *const c_char,
+    num: c_ulonglong,
     error_out: *mut c_int,
-) -> *mut c_char {
+) {
     let mut error = 0;
-    let mut result = CString::new("").expect("Blank CString will not fail.");
-
     ptr::swap(error_out, &mut error as *mut c_int);
     if wallet.is_null() {
         error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
-        ptr::swap(error_out, &mut error as *mut c_int);
-        return result.into_raw();
+        ptr::swap(error_out, &mut error as *mut c_int)
     }
 
-    if msg.is_null() {
-        error = LibWalletError::from(InterfaceError::NullError("message".to_string())).code;
-        ptr::swap(error_out, &mut error as *mut c_int);
-        return result.into_raw();
+    match (*wallet)
+        .runtime
+        .block_on((*wallet).wallet.transaction_service.set_num_confirmations_required(num))
+    {
+        Ok(()) => (),
+        Err(e) => {
+            error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int)
+        },
     }
+}
 
-    let secret = (*wallet).wallet.comms.node_identity().secret_key().clone();
-    let message = CStr::from_ptr(msg)
-        .to_str()
-        .expect("CString should not fail here.")
-        .to_owned();
-
-    let signature = (*wallet).wallet.sign_message(&secret, &message);
+/// Get the TariContacts from a TariWallet
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `*mut TariContacts` - returns the contacts, note that it returns ptr::null_mut() if
+/// wallet is null
+///
+/// # Safety
+/// The ```contacts_destroy``` method must be called when finished with a TariContacts to prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn wallet_get_contacts(wallet: *mut TariWallet, error_out: *mut c_int) -> *mut TariContacts {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    let mut contacts = Vec::new();
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
 
-    match signature {
-        Ok(s) => {
-            let hex_sig = s.get_signature().to_hex();
-            let hex_nonce = s.get_public_nonce().to_hex();
-            let hex_return = format!("{}|{}", hex_sig, hex_nonce);
-            result = CString::new(hex_return).expect("CString should not fail here.");
+    let retrieved_contacts = (*wallet)
+        .runtime
+        .block_on((*wallet).wallet.contacts_service.get_contacts());
+    match retrieved_contacts {
+        Ok(mut retrieved_contacts) => {
+            contacts.append(&mut retrieved_contacts);
+            Box::into_raw(Box::new(TariContacts(contacts)))
         },
         Err(e) => {
-            error = LibWalletError::from(e).code;
+            error = LibWalletError::from(WalletError::ContactsServiceError(e)).code;
             ptr::swap(error_out, &mut error as *mut c_int);
+            ptr::null_mut()
         },
     }
-
-    result.into_raw()
 }
 
-/// Verifies the signature of the message signed by a TariWallet
+/// Search the TariContacts of a TariWallet by alias
 ///
 /// ## Arguments
-/// `wallet` - The TariWallet pointer.
-/// `public_key` - The pointer to the TariPublicKey of the wallet which originally signed the message
-/// `hex_sig_nonce` - The pointer to the sting containing the hexadecimal representation of the
-/// signature and public nonce seperated by a pipe character.
-/// `msg` - The pointer to the msg the signature will be checked against.
+/// `wallet` - The TariWallet pointer
+/// `query` - The pointer to a char array. Contacts whose alias contains the query are returned; ASCII characters are
+/// matched case-insensitively. An empty query returns all contacts.
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
+///
 /// ## Returns
-/// `bool` - Returns if the signature is valid or not, will be false if an error occurs.
+/// `*mut TariContacts` - returns the matching contacts, note that it returns ptr::null_mut() if
+/// wallet or query is null or an error is encountered
 ///
 /// # Safety
-/// None
+/// The ```contacts_destroy``` method must be called when finished with a TariContacts to prevent a memory leak
 #[no_mangle]
-pub unsafe extern "C" fn wallet_verify_message_signature(
+pub unsafe extern "C" fn wallet_search_contacts(
     wallet: *mut TariWallet,
-    public_key: *mut TariPublicKey,
-    hex_sig_nonce: *const c_char,
-    msg: *const c_char,
+    query: *const c_char,
     error_out: *mut c_int,
-) -> bool {
+) -> *mut TariContacts {
     let mut error = 0;
-    let mut result = false;
     ptr::swap(error_out, &mut error as *mut c_int);
     if wallet.is_null() {
         error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
         ptr::swap(error_out, &mut error as *mut c_int);
-        return result;
-    }
-    if public_key.is_null() {
-        error = LibWalletError::from(InterfaceError::NullError("public key".to_string())).code;
-        ptr::swap(error_out, &mut error as *mut c_int);
-        return result;
-    }
-    if hex_sig_nonce.is_null() {
-        error = LibWalletError::from(InterfaceError::NullError("signature".to_string())).code;
-        ptr::swap(error_out, &mut error as *mut c_int);
-        return result;
-    }
-    if msg.is_null() {
-        error = LibWalletError::from(InterfaceError::NullError("message".to_string())).code;
-        ptr::swap(error_out, &mut error as *mut c_int);
-        return result;
-    }
-
-    let message = match CStr::from_ptr(msg).to_str() {
-        Ok(v) => v.to_owned(),
-        _ => {
-            error = LibWalletError::from(InterfaceError::PointerError("msg".to_string())).code;
-            ptr::swap(error_out, &mut error as *mut c_int);
-            return false;
-        },
-    };
-    let hex = match CStr::from_ptr(hex_sig_nonce).to_str() {
-        Ok(v) => v.to_owned(),
-        _ => {
-            error = LibWalletError::from(InterfaceError::PointerError("hex_sig_nonce".to_string())).code;
-            ptr::swap(error_out, &mut error as *mut c_int);
-            return false;
-        },
-    };
-    let hex_keys: Vec<&str> = hex.split('|').collect();
-    if hex_keys.len() != 2 {
-        error = LibWalletError::from(InterfaceError::PositionInvalidError).code;
-        ptr::swap(error_out, &mut error as *mut c_int);
-        return result;
-    }
-
-    if let Some(key1) = hex_keys.first() {
-        if let Some(key2) = hex_keys.get(1) {
-            let secret = TariPrivateKey::from_hex(key1);
-            match secret {
-                Ok(p) => {
-                    let public_nonce = TariPublicKey::from_hex(key2);
-                    match public_nonce {
-                        Ok(pn) => {
-                            let sig = SignatureWithDomain::<WalletMessageSigningDomain>::new(pn, p);
-                            result = (*wallet).wallet.verify_message_signature(&*public_key, &sig, &message)
-                        },
-                        Err(e) => {
-                            error = LibWalletError::from(e).code;
-                            ptr::swap(error_out, &mut error as *mut c_int);
-                        },
-                    }
-                },
-                Err(e) => {
-                    error = LibWalletError::from(e).code;
-                    ptr::swap(error_out, &mut error as *mut c_int);
-                },
-            }
-        } else {
-            error = LibWalletError::from(InterfaceError::InvalidArgument("hex_sig_nonce".to_string())).code;
-            ptr::swap(error_out, &mut error as *mut c_int);
-        }
-    } else {
-        error = LibWalletError::from(InterfaceError::InvalidArgument("hex_sig_nonce".to_string())).code;
+        return ptr::null_mut();
+    }
+    if query.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("query".to_string())).code;
         ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
     }
+    let query = match CStr::from_ptr(query).to_str() {
+        Ok(v) => v.to_owned(),
+        _ => {
+            error = LibWalletError::from(InterfaceError::PointerError("query".to_string())).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return ptr::null_mut();
+        },
+    };
 
-    result
+    match (*wallet)
+        .runtime
+        .block_on((*wallet).wallet.contacts_service.search_contacts(query))
+    {
+        Ok(contacts) => Box::into_raw(Box::new(TariContacts(contacts))),
+        Err(e) => {
+            error = LibWalletError::from(WalletError::ContactsServiceError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            ptr::null_mut()
+        },
+    }
 }
 
-/// Adds a base node peer to the TariWallet
+/// Get the TariCompletedTransactions from a TariWallet
 ///
 /// ## Arguments
 /// `wallet` - The TariWallet pointer
-/// `public_key` - The TariPublicKey pointer
-/// `address` - The pointer to a char array
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
 /// ## Returns
-/// `bool` - Returns if successful or not
+/// `*mut TariCompletedTransactions` - returns the transactions, note that it returns ptr::null_mut() if
+/// wallet is null or an error is encountered
 ///
 /// # Safety
-/// None
+/// The ```completed_transactions_destroy``` method must be called when finished with a TariCompletedTransactions to
+/// prevent a memory leak
 #[no_mangle]
-pub unsafe extern "C" fn wallet_set_base_node_peer(
+pub unsafe extern "C" fn wallet_get_completed_transactions(
     wallet: *mut TariWallet,
-    public_key: *mut TariPublicKey,
-    address: *const c_char,
     error_out: *mut c_int,
-) -> bool {
+) -> *mut TariCompletedTransactions {
     let mut error = 0;
     ptr::swap(error_out, &mut error as *mut c_int);
+    let mut completed = Vec::new();
     if wallet.is_null() {
         error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
         ptr::swap(error_out, &mut error as *mut c_int);
-        return false;
-    }
-
-    if public_key.is_null() {
-        error = LibWalletError::from(InterfaceError::NullError("public_key".to_string())).code;
-        ptr::swap(error_out, &mut error as *mut c_int);
-        return false;
+        return ptr::null_mut();
     }
 
-    let parsed_addr = if address.is_null() {
-        None
-    } else {
-        match CStr::from_ptr(address).to_str() {
-            Ok(v) => match Multiaddr::from_str(v) {
-                Ok(v) => Some(v),
-                Err(_) => {
-                    error =
-                        LibWalletError::from(InterfaceError::InvalidArgument("address is invalid".to_string())).code;
-                    ptr::swap(error_out, &mut error as *mut c_int);
-                    return false;
-                },
-            },
-            _ => {
-                error = LibWalletError::from(InterfaceError::PointerError("address".to_string())).code;
-                ptr::swap(error_out, &mut error as *mut c_int);
-                return false;
-            },
-        }
-    };
-
-    if let Err(e) = (*wallet).runtime.block_on((*wallet).wallet.set_base_node_peer(
-        (*public_key).clone(),
-        parsed_addr,
-        None,
-    )) {
-        error = LibWalletError::from(e).code;
-        ptr::swap(error_out, &mut error as *mut c_int);
-        return false;
+    let completed_transactions = (*wallet)
+        .runtime
+        .block_on((*wallet).wallet.transaction_service.get_completed_transactions());
+    match completed_transactions {
+        Ok(completed_transactions) => {
+            // The frontend specification calls for completed transactions that have not yet been mined to be
+            // classified as Pending Transactions. In order to support this logic without impacting the practical
+            // definitions and storage of a MimbleWimble CompletedTransaction we will remove CompletedTransactions with
+            // the Completed and Broadcast states from the list returned by this FFI function
+            for tx in completed_transactions
+                .values()
+                .filter(|ct| ct.status != TransactionStatus::Completed)
+                .filter(|ct| ct.status != TransactionStatus::Broadcast)
+                .filter(|ct| ct.status != TransactionStatus::Imported)
+            {
+                completed.push(tx.clone());
+            }
+            Box::into_raw(Box::new(TariCompletedTransactions(completed)))
+        },
+        Err(e) => {
+            error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            ptr::null_mut()
+        },
     }
-    true
 }
-/// Gets all seed peers known by the wallet
+
+/// Get the TariCompletedTransactions from a TariWallet, optionally leaving out coinbase transactions
 ///
 /// ## Arguments
 /// `wallet` - The TariWallet pointer
+/// `include_coinbase` - Whether coinbase transactions should be included, when false they are filtered out by the
+/// database query rather than after loading
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
 /// ## Returns
-/// `TariPublicKeys` - Returns a list of all known public keys
+/// `*mut TariCompletedTransactions` - returns the transactions, applying the same status filtering as
+/// `wallet_get_completed_transactions`, note that it returns ptr::null_mut() if wallet is null or an error is
+/// encountered
 ///
 /// # Safety
-/// None
+/// The ```completed_transactions_destroy``` method must be called when finished with a TariCompletedTransactions to
+/// prevent a memory leak
 #[no_mangle]
-pub unsafe extern "C" fn wallet_get_seed_peers(wallet: *mut TariWallet, error_out: *mut c_int) -> *mut TariPublicKeys {
+pub unsafe extern "C" fn wallet_get_completed_transactions_ex(
+    wallet: *mut TariWallet,
+    include_coinbase: bool,
+    error_out: *mut c_int,
+) -> *mut TariCompletedTransactions {
     let mut error = 0;
     ptr::swap(error_out, &mut error as *mut c_int);
     if wallet.is_null() {
@@ -7083,269 +12494,345 @@ pub unsafe extern "C" fn wallet_get_seed_peers(wallet: *mut TariWallet, error_ou
         ptr::swap(error_out, &mut error as *mut c_int);
         return ptr::null_mut();
     }
-    let peer_manager = (*wallet).wallet.comms.peer_manager();
-    let query = PeerQuery::new().select_where(|p| p.is_seed());
-    #[allow(clippy::blocks_in_conditions)]
-    match (*wallet).runtime.block_on(async move {
-        let peers = peer_manager.perform_query(query).await?;
-        let mut public_keys = Vec::with_capacity(peers.len());
-        for peer in peers {
-            public_keys.push(peer.public_key);
+
+    let mut transaction_service = (*wallet).wallet.transaction_service.clone();
+    let completed_transactions = (*wallet).runtime.block_on(async move {
+        if include_coinbase {
+            transaction_service.get_completed_transactions().await
+        } else {
+            transaction_service.get_non_coinbase_completed_transactions().await
         }
-        Result::<_, WalletError>::Ok(public_keys)
-    }) {
-        Ok(public_keys) => Box::into_raw(Box::new(TariPublicKeys(public_keys))),
+    });
+    match completed_transactions {
+        Ok(completed_transactions) => {
+            let completed = completed_transactions
+                .into_values()
+                .filter(|ct| ct.status != TransactionStatus::Completed)
+                .filter(|ct| ct.status != TransactionStatus::Broadcast)
+                .filter(|ct| ct.status != TransactionStatus::Imported)
+                .collect::<Vec<_>>();
+            Box::into_raw(Box::new(TariCompletedTransactions(completed)))
+        },
         Err(e) => {
-            error = LibWalletError::from(e).code;
+            error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
             ptr::swap(error_out, &mut error as *mut c_int);
             ptr::null_mut()
         },
     }
 }
 
-/// Upserts a TariContact to the TariWallet. If the contact does not exist it will be Inserted. If it does exist the
-/// Alias will be updated.
+/// Get the non-cancelled TariCompletedTransactions from a TariWallet that were created or mined at or after the given
+/// time, in any status, so that a transaction created before the checkpoint but mined after it is still returned
 ///
 /// ## Arguments
 /// `wallet` - The TariWallet pointer
-/// `contact` - The TariContact pointer
+/// `since_timestamp` - The checkpoint as seconds since the Unix epoch. Transactions with a timestamp in the same second
+/// are included so that none are missed between polls
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
 /// ## Returns
-/// `bool` - Returns if successful or not
+/// `*mut TariCompletedTransactions` - returns the transactions ordered by the later of their creation and mined
+/// timestamps, note that it returns ptr::null_mut() if wallet is null or an error is encountered
 ///
 /// # Safety
-/// None
+/// The ```completed_transactions_destroy``` method must be called when finished with a TariCompletedTransactions to
+/// prevent a memory leak
 #[no_mangle]
-pub unsafe extern "C" fn wallet_upsert_contact(
+pub unsafe extern "C" fn wallet_get_completed_transactions_since(
     wallet: *mut TariWallet,
-    contact: *mut TariContact,
+    since_timestamp: c_ulonglong,
     error_out: *mut c_int,
-) -> bool {
+) -> *mut TariCompletedTransactions {
     let mut error = 0;
     ptr::swap(error_out, &mut error as *mut c_int);
     if wallet.is_null() {
         error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
         ptr::swap(error_out, &mut error as *mut c_int);
-        return false;
-    }
-    if contact.is_null() {
-        error = LibWalletError::from(InterfaceError::NullError("contact".to_string())).code;
-        ptr::swap(error_out, &mut error as *mut c_int);
-        return false;
+        return ptr::null_mut();
     }
 
-    match (*wallet)
-        .runtime
-        .block_on((*wallet).wallet.contacts_service.upsert_contact((*contact).clone()))
+    let since = match i64::try_from(since_timestamp)
+        .ok()
+        .and_then(|t| NaiveDateTime::from_timestamp_opt(t, 0))
     {
-        Ok(_) => true,
+        Some(since) => since,
+        None => {
+            error = LibWalletError::from(InterfaceError::InvalidArgument("since_timestamp".to_string())).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return ptr::null_mut();
+        },
+    };
+
+    let completed_transactions = (*wallet).runtime.block_on(
+        (*wallet)
+            .wallet
+            .transaction_service
+            .get_completed_transactions_since(since),
+    );
+    match completed_transactions {
+        Ok(completed_transactions) => {
+            let mut completed = completed_transactions.into_values().collect::<Vec<_>>();
+            completed.sort_by_key(|ct| ct.mined_timestamp.map_or(ct.timestamp, |t| t.max(ct.timestamp)));
+            Box::into_raw(Box::new(TariCompletedTransactions(completed)))
+        },
         Err(e) => {
-            error = LibWalletError::from(WalletError::ContactsServiceError(e)).code;
+            error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
             ptr::swap(error_out, &mut error as *mut c_int);
-            false
+            ptr::null_mut()
         },
     }
 }
 
-/// Removes a TariContact from the TariWallet
+/// Get the number of non-cancelled completed transactions of a TariWallet, optionally only those with a given status
 ///
 /// ## Arguments
 /// `wallet` - The TariWallet pointer
-/// `tx` - The TariPendingInboundTransaction pointer
+/// `status` - The transaction status code, as returned by `completed_transaction_get_status`, or -1 to count all
+/// statuses
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
 /// ## Returns
-/// `bool` - Returns if successful or not
+/// `c_ulonglong` - Returns the number of transactions, note that it returns 0 if wallet is null, if status is not a
+/// valid transaction status code or if an error is encountered
 ///
 /// # Safety
 /// None
 #[no_mangle]
-pub unsafe extern "C" fn wallet_remove_contact(
+pub unsafe extern "C" fn wallet_get_transaction_count(
     wallet: *mut TariWallet,
-    contact: *mut TariContact,
+    status: c_int,
     error_out: *mut c_int,
-) -> bool {
+) -> c_ulonglong {
     let mut error = 0;
     ptr::swap(error_out, &mut error as *mut c_int);
     if wallet.is_null() {
         error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
         ptr::swap(error_out, &mut error as *mut c_int);
-        return false;
-    }
-    if contact.is_null() {
-        error = LibWalletError::from(InterfaceError::NullError("contact".to_string())).code;
-        ptr::swap(error_out, &mut error as *mut c_int);
-        return false;
+        return 0;
     }
 
+    let status = if status == -1 {
+        None
+    } else {
+        match TransactionStatus::try_from(status) {
+            Ok(status) => Some(status),
+            Err(e) => {
+                error = LibWalletError::from(InterfaceError::InvalidArgument(e.to_string())).code;
+                ptr::swap(error_out, &mut error as *mut c_int);
+                return 0;
+            },
+        }
+    };
+
     match (*wallet).runtime.block_on(
         (*wallet)
             .wallet
-            .contacts_service
-            .remove_contact((*contact).address.clone()),
+            .transaction_service
+            .get_completed_transaction_count(status),
     ) {
-        Ok(_) => true,
+        Ok(count) => count,
         Err(e) => {
-            error = LibWalletError::from(WalletError::ContactsServiceError(e)).code;
+            error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
             ptr::swap(error_out, &mut error as *mut c_int);
-            false
+            0
         },
     }
 }
 
-/// Gets the available balance from a TariBalance. This is the balance the user can spend.
+/// Get the TariPendingInboundTransactions from a TariWallet
+///
+/// Currently a CompletedTransaction with the Status of Completed and Broadcast is considered Pending by the frontend
 ///
 /// ## Arguments
-/// `balance` - The TariBalance pointer
+/// `wallet` - The TariWallet pointer
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
 /// ## Returns
-/// `c_ulonglong` - The available balance, 0 if wallet is null
+/// `*mut TariPendingInboundTransactions` - returns the transactions, note that it returns ptr::null_mut() if
+/// wallet is null or and error is encountered
 ///
 /// # Safety
-/// None
+/// The ```pending_inbound_transactions_destroy``` method must be called when finished with a
+/// TariPendingInboundTransactions to prevent a memory leak
 #[no_mangle]
-pub unsafe extern "C" fn balance_get_available(balance: *mut TariBalance, error_out: *mut c_int) -> c_ulonglong {
+pub unsafe extern "C" fn wallet_get_pending_inbound_transactions(
+    wallet: *mut TariWallet,
+    error_out: *mut c_int,
+) -> *mut TariPendingInboundTransactions {
     let mut error = 0;
     ptr::swap(error_out, &mut error as *mut c_int);
-    if balance.is_null() {
-        error = LibWalletError::from(InterfaceError::NullError("balance".to_string())).code;
+    let mut pending = Vec::new();
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
         ptr::swap(error_out, &mut error as *mut c_int);
-        return 0;
+        return ptr::null_mut();
     }
 
-    c_ulonglong::from((*balance).available_balance)
-}
+    let pending_transactions = (*wallet)
+        .runtime
+        .block_on((*wallet).wallet.transaction_service.get_pending_inbound_transactions());
+
+    match pending_transactions {
+        Ok(pending_transactions) => {
+            for tx in pending_transactions.values() {
+                pending.push(tx.clone());
+            }
+
+            if let Ok(completed_txs) = (*wallet)
+                .runtime
+                .block_on((*wallet).wallet.transaction_service.get_completed_transactions())
+            {
+                // The frontend specification calls for completed transactions that have not yet been mined to be
+                // classified as Pending Transactions. In order to support this logic without impacting the practical
+                // definitions and storage of a MimbleWimble CompletedTransaction we will add those transaction to the
+                // list here in the FFI interface
+                for ct in completed_txs
+                    .values()
+                    .filter(|ct| {
+                        ct.status == TransactionStatus::Completed ||
+                            ct.status == TransactionStatus::Broadcast ||
+                            ct.status == TransactionStatus::Imported
+                    })
+                    .filter(|ct| ct.direction == TransactionDirection::Inbound)
+                {
+                    pending.push(InboundTransaction::from(ct.clone()));
+                }
+            }
 
-/// Gets the time locked balance from a TariBalance. This is the balance the user can spend.
-///
-/// ## Arguments
-/// `balance` - The TariBalance pointer
-/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
-/// as an out parameter.
-///
-/// ## Returns
-/// `c_ulonglong` - The time locked balance, 0 if wallet is null
-///
-/// # Safety
-/// None
-#[no_mangle]
-pub unsafe extern "C" fn balance_get_time_locked(balance: *mut TariBalance, error_out: *mut c_int) -> c_ulonglong {
-    let mut error = 0;
-    ptr::swap(error_out, &mut error as *mut c_int);
-    if balance.is_null() {
-        error = LibWalletError::from(InterfaceError::NullError("balance".to_string())).code;
-        ptr::swap(error_out, &mut error as *mut c_int);
-        return 0;
+            Box::into_raw(Box::new(TariPendingInboundTransactions(pending)))
+        },
+        Err(e) => {
+            error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            ptr::null_mut()
+        },
     }
-
-    let b = if let Some(bal) = (*balance).time_locked_balance {
-        bal
-    } else {
-        MicroMinotari::from(0)
-    };
-    c_ulonglong::from(b)
 }
 
-/// Gets the pending incoming balance from a TariBalance. This is the balance the user can spend.
+/// Get the TariPendingOutboundTransactions from a TariWallet
+///
+/// Currently a CompletedTransaction with the Status of Completed and Broadcast is considered Pending by the frontend
 ///
 /// ## Arguments
-/// `balance` - The TariBalance pointer
+/// `wallet` - The TariWallet pointer
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
 /// ## Returns
-/// `c_ulonglong` - The pending incoming, 0 if wallet is null
+/// `*mut TariPendingOutboundTransactions` - returns the transactions, note that it returns ptr::null_mut() if
+/// wallet is null or and error is encountered
 ///
 /// # Safety
-/// None
+/// The ```pending_outbound_transactions_destroy``` method must be called when finished with a
+/// TariPendingOutboundTransactions to prevent a memory leak
 #[no_mangle]
-pub unsafe extern "C" fn balance_get_pending_incoming(balance: *mut TariBalance, error_out: *mut c_int) -> c_ulonglong {
+pub unsafe extern "C" fn wallet_get_pending_outbound_transactions(
+    wallet: *mut TariWallet,
+    error_out: *mut c_int,
+) -> *mut TariPendingOutboundTransactions {
     let mut error = 0;
     ptr::swap(error_out, &mut error as *mut c_int);
-    if balance.is_null() {
-        error = LibWalletError::from(InterfaceError::NullError("balance".to_string())).code;
+    let mut pending = Vec::new();
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
         ptr::swap(error_out, &mut error as *mut c_int);
-        return 0;
+        return ptr::null_mut();
     }
 
-    c_ulonglong::from((*balance).pending_incoming_balance)
+    let pending_transactions = (*wallet)
+        .runtime
+        .block_on((*wallet).wallet.transaction_service.get_pending_outbound_transactions());
+    match pending_transactions {
+        Ok(pending_transactions) => {
+            for tx in pending_transactions.values() {
+                pending.push(tx.clone());
+            }
+            if let Ok(completed_txs) = (*wallet)
+                .runtime
+                .block_on((*wallet).wallet.transaction_service.get_completed_transactions())
+            {
+                // The frontend specification calls for completed transactions that have not yet been mined to be
+                // classified as Pending Transactions. In order to support this logic without impacting the practical
+                // definitions and storage of a MimbleWimble CompletedTransaction we will add those transaction to the
+                // list here in the FFI interface
+                for ct in completed_txs
+                    .values()
+                    .filter(|ct| ct.status == TransactionStatus::Completed || ct.status == TransactionStatus::Broadcast)
+                    .filter(|ct| ct.direction == TransactionDirection::Outbound)
+                {
+                    pending.push(OutboundTransaction::from(ct.clone()));
+                }
+            }
+            Box::into_raw(Box::new(TariPendingOutboundTransactions(pending)))
+        },
+        Err(e) => {
+            error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            ptr::null_mut()
+        },
+    }
 }
 
-/// Gets the pending outgoing balance from a TariBalance. This is the balance the user can spend.
+/// Get the summed amount of all pending inbound transactions of a TariWallet, read directly from the pending
+/// inbound transactions without fetching them
 ///
 /// ## Arguments
-/// `balance` - The TariBalance pointer
+/// `wallet` - The TariWallet pointer
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
 /// ## Returns
-/// `c_ulonglong` - The pending outgoing balance, 0 if wallet is null
+/// `c_ulonglong` - Returns the total amount in MicroMinotari, note that it returns 0 if wallet is null or an error is
+/// encountered
 ///
 /// # Safety
 /// None
 #[no_mangle]
-pub unsafe extern "C" fn balance_get_pending_outgoing(balance: *mut TariBalance, error_out: *mut c_int) -> c_ulonglong {
+pub unsafe extern "C" fn wallet_get_pending_incoming_total(
+    wallet: *mut TariWallet,
+    error_out: *mut c_int,
+) -> c_ulonglong {
     let mut error = 0;
     ptr::swap(error_out, &mut error as *mut c_int);
-    if balance.is_null() {
-        error = LibWalletError::from(InterfaceError::NullError("balance".to_string())).code;
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
         ptr::swap(error_out, &mut error as *mut c_int);
         return 0;
     }
 
-    c_ulonglong::from((*balance).pending_outgoing_balance)
-}
-
-/// Frees memory for a TariBalance
-///
-/// ## Arguments
-/// `balance` - The pointer to a TariBalance
-///
-/// ## Returns
-/// `()` - Does not return a value, equivalent to void in C
-///
-/// # Safety
-/// None
-#[no_mangle]
-pub unsafe extern "C" fn balance_destroy(balance: *mut TariBalance) {
-    if !balance.is_null() {
-        drop(Box::from_raw(balance))
+    match (*wallet)
+        .runtime
+        .block_on((*wallet).wallet.transaction_service.get_pending_inbound_total())
+    {
+        Ok(total) => total.as_u64(),
+        Err(e) => {
+            error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            0
+        },
     }
 }
 
-/// Sends a TariPendingOutboundTransaction
+/// Get the summed amount of all pending outbound transactions of a TariWallet, read directly from the pending
+/// outbound transactions without fetching them. Fees are not included
 ///
 /// ## Arguments
 /// `wallet` - The TariWallet pointer
-/// `destination` - The TariWalletAddress pointer of the peer
-/// `amount` - The amount
-/// `commitments` - A `TariVector` of "strings", tagged as `TariTypeTag::String`, containing commitment's hex values
-///   (see `Commitment::to_hex()`)
-/// `fee_per_gram` - The transaction fee
-/// `message` - The pointer to a char array
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
 /// ## Returns
-/// `unsigned long long` - Returns 0 if unsuccessful or the TxId of the sent transaction if successful
+/// `c_ulonglong` - Returns the total amount in MicroMinotari, note that it returns 0 if wallet is null or an error is
+/// encountered
 ///
 /// # Safety
 /// None
 #[no_mangle]
-pub unsafe extern "C" fn wallet_send_transaction(
+pub unsafe extern "C" fn wallet_get_pending_outgoing_total(
     wallet: *mut TariWallet,
-    destination: *mut TariWalletAddress,
-    amount: c_ulonglong,
-    commitments: *mut TariVector,
-    fee_per_gram: c_ulonglong,
-    message: *const c_char,
-    one_sided: bool,
-    payment_id_string: *const c_char,
     error_out: *mut c_int,
 ) -> c_ulonglong {
     let mut error = 0;
@@ -7355,451 +12842,497 @@ pub unsafe extern "C" fn wallet_send_transaction(
         ptr::swap(error_out, &mut error as *mut c_int);
         return 0;
     }
-    if destination.is_null() {
-        error = LibWalletError::from(InterfaceError::NullError("dest_public_key".to_string())).code;
-        ptr::swap(error_out, &mut error as *mut c_int);
-        return 0;
-    }
 
-    let selection_criteria = match commitments.as_ref() {
-        None => UtxoSelectionCriteria::default(),
-        Some(cs) => match cs.to_commitment_vec() {
-            Ok(cs) => UtxoSelectionCriteria::specific(cs),
-            Err(e) => {
-                error!(target: LOG_TARGET, "failed to convert from tari vector: {:?}", e);
-                ptr::replace(error_out, LibWalletError::from(e).code as c_int);
-                return 0;
-            },
+    match (*wallet)
+        .runtime
+        .block_on((*wallet).wallet.transaction_service.get_pending_outbound_total())
+    {
+        Ok(total) => total.as_u64(),
+        Err(e) => {
+            error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            0
         },
-    };
-
-    let message_string;
-    if message.is_null() {
-        message_string = CString::new("")
-            .expect("Blank CString will not fail")
-            .to_str()
-            .expect("CString.to_str() will not fail")
-            .to_owned();
-    } else {
-        match CStr::from_ptr(message).to_str() {
-            Ok(v) => {
-                message_string = v.to_owned();
-            },
-            _ => {
-                error = LibWalletError::from(InterfaceError::NullError("message".to_string())).code;
-                ptr::swap(error_out, &mut error as *mut c_int);
-                return 0;
-            },
-        }
-    };
-
-    if one_sided {
-        let payment_id = if payment_id_string.is_null() {
-            PaymentId::Empty
-        } else {
-            match CStr::from_ptr(payment_id_string).to_str() {
-                Ok(v) => {
-                    let rust_str = v.to_owned();
-                    let bytes = rust_str.as_bytes().to_vec();
-                    PaymentId::Open(bytes)
-                },
-                _ => {
-                    error = LibWalletError::from(InterfaceError::NullError("payment_id".to_string())).code;
-                    ptr::swap(error_out, &mut error as *mut c_int);
-                    return 0;
-                },
-            }
-        };
-        match (*wallet).runtime.block_on(
-            (*wallet)
-                .wallet
-                .transaction_service
-                .send_one_sided_to_stealth_address_transaction(
-                    (*destination).clone(),
-                    MicroMinotari::from(amount),
-                    selection_criteria,
-                    OutputFeatures::default(),
-                    MicroMinotari::from(fee_per_gram),
-                    message_string,
-                    payment_id,
-                ),
-        ) {
-            Ok(tx_id) => tx_id.as_u64(),
-            Err(e) => {
-                error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
-                ptr::swap(error_out, &mut error as *mut c_int);
-                0
-            },
-        }
-    } else {
-        match (*wallet)
-            .runtime
-            .block_on((*wallet).wallet.transaction_service.send_transaction(
-                (*destination).clone(),
-                MicroMinotari::from(amount),
-                selection_criteria,
-                OutputFeatures::default(),
-                MicroMinotari::from(fee_per_gram),
-                message_string,
-            )) {
-            Ok(tx_id) => tx_id.as_u64(),
-            Err(e) => {
-                error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
-                ptr::swap(error_out, &mut error as *mut c_int);
-                0
-            },
-        }
     }
 }
 
-/// Sends a TariPendingOutboundTransaction
+/// Get the all Cancelled Transactions from a TariWallet. This function will also get cancelled pending inbound and
+/// outbound transaction and include them in this list by converting them to CompletedTransactions
 ///
 /// ## Arguments
 /// `wallet` - The TariWallet pointer
-/// `destination` - The TariWalletAddress pointer of the peer
-/// `fee_per_gram` - The transaction fee
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
 /// ## Returns
-/// `unsigned long long` - Returns 0 if unsuccessful or the TxId of the sent transaction if successful
+/// `*mut TariCompletedTransactions` - returns the transactions, note that it returns ptr::null_mut() if
+/// wallet is null or an error is encountered
 ///
 /// # Safety
-/// None
+/// The ```completed_transactions_destroy``` method must be called when finished with a TariCompletedTransactions to
+/// prevent a memory leak
 #[no_mangle]
-pub unsafe extern "C" fn scrape_wallet(
+pub unsafe extern "C" fn wallet_get_cancelled_transactions(
     wallet: *mut TariWallet,
-    destination: *mut TariWalletAddress,
-    fee_per_gram: c_ulonglong,
     error_out: *mut c_int,
-) -> c_ulonglong {
+) -> *mut TariCompletedTransactions {
     let mut error = 0;
     ptr::swap(error_out, &mut error as *mut c_int);
+
     if wallet.is_null() {
-        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
-        ptr::swap(error_out, &mut error as *mut c_int);
-        return 0;
-    }
-    if destination.is_null() {
-        error = LibWalletError::from(InterfaceError::NullError("dest_public_key".to_string())).code;
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
         ptr::swap(error_out, &mut error as *mut c_int);
-        return 0;
+        return ptr::null_mut();
     }
 
-    match (*wallet).runtime.block_on(
+    let completed_transactions = match (*wallet).runtime.block_on(
         (*wallet)
             .wallet
             .transaction_service
-            .scrape_wallet((*destination).clone(), MicroMinotari::from(fee_per_gram)),
+            .get_cancelled_completed_transactions(),
     ) {
-        Ok(tx_id) => tx_id.as_u64(),
+        Ok(txs) => txs,
         Err(e) => {
             error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
             ptr::swap(error_out, &mut error as *mut c_int);
-            0
+            return ptr::null_mut();
+        },
+    };
+    let inbound_transactions = match (*wallet).runtime.block_on(
+        (*wallet)
+            .wallet
+            .transaction_service
+            .get_cancelled_pending_inbound_transactions(),
+    ) {
+        Ok(txs) => txs,
+        Err(e) => {
+            error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return ptr::null_mut();
+        },
+    };
+    let outbound_transactions = match (*wallet).runtime.block_on(
+        (*wallet)
+            .wallet
+            .transaction_service
+            .get_cancelled_pending_outbound_transactions(),
+    ) {
+        Ok(txs) => txs,
+        Err(e) => {
+            error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return ptr::null_mut();
+        },
+    };
+
+    let mut completed = Vec::new();
+    for tx in completed_transactions.values() {
+        completed.push(tx.clone());
+    }
+    let runtime = match Runtime::new() {
+        Ok(r) => r,
+        Err(e) => {
+            error = LibWalletError::from(InterfaceError::TokioError(e.to_string())).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return ptr::null_mut();
+        },
+    };
+    let wallet_address = match runtime.block_on(async { (*wallet).wallet.get_wallet_interactive_address().await }) {
+        Ok(address) => address,
+        Err(e) => {
+            error = LibWalletError::from(e).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return ptr::null_mut();
         },
+    };
+    for tx in inbound_transactions.values() {
+        let mut inbound_tx = CompletedTransaction::from(tx.clone());
+        inbound_tx.destination_address = wallet_address.clone();
+        completed.push(inbound_tx);
+    }
+    for tx in outbound_transactions.values() {
+        let mut outbound_tx = CompletedTransaction::from(tx.clone());
+        outbound_tx.source_address = wallet_address.clone();
+        completed.push(outbound_tx);
     }
+
+    Box::into_raw(Box::new(TariCompletedTransactions(completed)))
 }
 
-/// Gets a fee estimate for an amount
+/// The version of the document produced by `wallet_export_transactions_json`
+const TRANSACTION_HISTORY_EXPORT_VERSION: u32 = 1;
+
+/// Exports the full transaction history of the wallet as a single JSON document. The document contains a `version`
+/// header followed by `completed`, `cancelled`, `pending_inbound`, `pending_outbound`, `cancelled_pending_inbound` and
+/// `cancelled_pending_outbound` arrays, each sorted by timestamp. Completed and cancelled entries use the same
+/// serialization as `tari_completed_transaction_to_json`.
 ///
 /// ## Arguments
 /// `wallet` - The TariWallet pointer
-/// `amount` - The amount
-/// `commitments` - A `TariVector` of "strings", tagged as `TariTypeTag::String`, containing commitment's hex values
-///   (see `Commitment::to_hex()`)
-/// `fee_per_gram` - The fee per gram
-/// `num_kernels` - The number of transaction kernels
-/// `num_outputs` - The number of outputs
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
 /// ## Returns
-/// `unsigned long long` - Returns 0 if unsuccessful or the fee estimate in MicroMinotari if successful
+/// `*mut c_char` - Returns a pointer to the JSON document. Note that it returns ptr::null_mut() if wallet is null or
+/// an error is encountered
 ///
 /// # Safety
-/// None
+/// The ```string_destroy``` method must be called when finished with a string coming from rust to prevent a memory
+/// leak. The whole history is held in memory while the document is built, so this may allocate a large string for
+/// wallets with a long history.
 #[no_mangle]
-pub unsafe extern "C" fn wallet_get_fee_estimate(
+pub unsafe extern "C" fn wallet_export_transactions_json(
     wallet: *mut TariWallet,
-    amount: c_ulonglong,
-    commitments: *mut TariVector,
-    fee_per_gram: c_ulonglong,
-    num_kernels: c_uint,
-    num_outputs: c_uint,
     error_out: *mut c_int,
-) -> c_ulonglong {
+) -> *mut c_char {
     let mut error = 0;
     ptr::swap(error_out, &mut error as *mut c_int);
+
     if wallet.is_null() {
         error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
         ptr::swap(error_out, &mut error as *mut c_int);
-        return 0;
+        return ptr::null_mut();
     }
 
-    let selection_criteria = match commitments.as_ref() {
-        None => UtxoSelectionCriteria::default(),
-        Some(cs) => match cs.to_commitment_vec() {
-            Ok(cs) => UtxoSelectionCriteria::specific(cs),
-            Err(e) => {
-                error!(target: LOG_TARGET, "failed to convert from tari vector: {:?}", e);
-                ptr::replace(error_out, LibWalletError::from(e).code as c_int);
-                return 0;
-            },
+    let mut transaction_service = (*wallet).wallet.transaction_service.clone();
+    let history = (*wallet).runtime.block_on(async move {
+        let completed = transaction_service.get_completed_transactions().await?;
+        let cancelled = transaction_service.get_cancelled_completed_transactions().await?;
+        let pending_inbound = transaction_service.get_pending_inbound_transactions().await?;
+        let pending_outbound = transaction_service.get_pending_outbound_transactions().await?;
+        let cancelled_pending_inbound = transaction_service.get_cancelled_pending_inbound_transactions().await?;
+        let cancelled_pending_outbound = transaction_service
+            .get_cancelled_pending_outbound_transactions()
+            .await?;
+        Result::<_, TransactionServiceError>::Ok(TransactionHistory {
+            completed,
+            cancelled,
+            pending_inbound,
+            pending_outbound,
+            cancelled_pending_inbound,
+            cancelled_pending_outbound,
+        })
+    });
+    let history = match history {
+        Ok(history) => history,
+        Err(e) => {
+            error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return ptr::null_mut();
         },
     };
 
-    match (*wallet)
-        .runtime
-        .block_on((*wallet).wallet.output_manager_service.fee_estimate(
-            MicroMinotari::from(amount),
-            selection_criteria,
-            MicroMinotari::from(fee_per_gram),
-            num_kernels as usize,
-            num_outputs as usize,
-        )) {
-        Ok(fee) => fee.into(),
+    let document = match transaction_history_to_json(history) {
+        Ok(document) => document,
         Err(e) => {
-            error = LibWalletError::from(WalletError::OutputManagerError(e)).code;
+            error = LibWalletError::from(InterfaceError::InternalError(e.to_string())).code;
             ptr::swap(error_out, &mut error as *mut c_int);
-            0
+            return ptr::null_mut();
+        },
+    };
+
+    match CString::new(document) {
+        Ok(v) => CString::into_raw(v),
+        Err(_) => {
+            error = LibWalletError::from(InterfaceError::PointerError("transactions".to_string())).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            ptr::null_mut()
         },
     }
 }
 
-/// Gets the number of mining confirmations required
+/// Every transaction of a wallet, as exported by `wallet_export_transactions_json`
+struct TransactionHistory {
+    completed: HashMap<TxId, CompletedTransaction>,
+    cancelled: HashMap<TxId, CompletedTransaction>,
+    pending_inbound: HashMap<TxId, InboundTransaction>,
+    pending_outbound: HashMap<TxId, OutboundTransaction>,
+    cancelled_pending_inbound: HashMap<TxId, InboundTransaction>,
+    cancelled_pending_outbound: HashMap<TxId, OutboundTransaction>,
+}
+
+fn transaction_history_to_json(history: TransactionHistory) -> Result<String, serde_json::Error> {
+    let mut completed = history.completed.into_values().collect::<Vec<_>>();
+    completed.sort_by_key(|tx| tx.timestamp);
+    let mut cancelled = history.cancelled.into_values().collect::<Vec<_>>();
+    cancelled.sort_by_key(|tx| tx.timestamp);
+    let mut pending_inbound = history.pending_inbound.into_values().collect::<Vec<_>>();
+    pending_inbound.sort_by_key(|tx| tx.timestamp);
+    let mut pending_outbound = history.pending_outbound.into_values().collect::<Vec<_>>();
+    pending_outbound.sort_by_key(|tx| tx.timestamp);
+    let mut cancelled_pending_inbound = history.cancelled_pending_inbound.into_values().collect::<Vec<_>>();
+    cancelled_pending_inbound.sort_by_key(|tx| tx.timestamp);
+    let mut cancelled_pending_outbound = history.cancelled_pending_outbound.into_values().collect::<Vec<_>>();
+    cancelled_pending_outbound.sort_by_key(|tx| tx.timestamp);
+
+    Ok(serde_json::json!({
+        "version": TRANSACTION_HISTORY_EXPORT_VERSION,
+        "completed": serde_json::to_value(completed)?,
+        "cancelled": serde_json::to_value(cancelled)?,
+        "pending_inbound": serde_json::to_value(pending_inbound)?,
+        "pending_outbound": serde_json::to_value(pending_outbound)?,
+        "cancelled_pending_inbound": serde_json::to_value(cancelled_pending_inbound)?,
+        "cancelled_pending_outbound": serde_json::to_value(cancelled_pending_outbound)?,
+    })
+    .to_string())
+}
+
+/// Get the TariCompletedTransaction from a TariWallet by its' TransactionId
 ///
 /// ## Arguments
 /// `wallet` - The TariWallet pointer
+/// `transaction_id` - The TransactionId
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
 /// ## Returns
-/// `unsigned long long` - Returns the number of confirmations required
+/// `*mut TariCompletedTransaction` - returns the transaction, note that it returns ptr::null_mut() if
+/// wallet is null, an error is encountered or if the transaction is not found
 ///
 /// # Safety
-/// None
+/// The ```completed_transaction_destroy``` method must be called when finished with a TariCompletedTransaction to
+/// prevent a memory leak
 #[no_mangle]
-pub unsafe extern "C" fn wallet_get_num_confirmations_required(
+pub unsafe extern "C" fn wallet_get_completed_transaction_by_id(
     wallet: *mut TariWallet,
+    transaction_id: c_ulonglong,
     error_out: *mut c_int,
-) -> c_ulonglong {
+) -> *mut TariCompletedTransaction {
     let mut error = 0;
     ptr::swap(error_out, &mut error as *mut c_int);
     if wallet.is_null() {
         error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
         ptr::swap(error_out, &mut error as *mut c_int);
-        return 0;
+        return ptr::null_mut();
     }
 
-    match (*wallet)
+    let completed_transactions = (*wallet)
         .runtime
-        .block_on((*wallet).wallet.transaction_service.get_num_confirmations_required())
-    {
-        Ok(num) => num,
+        .block_on((*wallet).wallet.transaction_service.get_completed_transactions());
+
+    match completed_transactions {
+        Ok(completed_transactions) => {
+            if let Some(tx) = completed_transactions.get(&TxId::from(transaction_id)) {
+                if tx.status != TransactionStatus::Completed && tx.status != TransactionStatus::Broadcast {
+                    let completed = tx.clone();
+                    return Box::into_raw(Box::new(completed));
+                }
+            }
+            error = 108;
+            ptr::swap(error_out, &mut error as *mut c_int);
+        },
         Err(e) => {
             error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
             ptr::swap(error_out, &mut error as *mut c_int);
-            0
         },
     }
+
+    ptr::null_mut()
 }
 
-/// Sets the number of mining confirmations required
+/// Get the number of confirmations of a completed transaction from a TariWallet by its TransactionId, without
+/// fetching all of the completed transactions
 ///
 /// ## Arguments
 /// `wallet` - The TariWallet pointer
-/// `num` - The number of confirmations to require
+/// `transaction_id` - The TransactionId
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
 /// ## Returns
-/// `()` - Does not return a value, equivalent to void in C
+/// `c_ulonglong` - Returns the number of confirmations of the transaction, note that it returns 0 and sets
+/// `error_out` if the wallet is null or the transaction is not found
 ///
 /// # Safety
 /// None
 #[no_mangle]
-pub unsafe extern "C" fn wallet_set_num_confirmations_required(
+pub unsafe extern "C" fn wallet_get_transaction_confirmations(
     wallet: *mut TariWallet,
-    num: c_ulonglong,
+    transaction_id: c_ulonglong,
     error_out: *mut c_int,
-) {
+) -> c_ulonglong {
     let mut error = 0;
     ptr::swap(error_out, &mut error as *mut c_int);
     if wallet.is_null() {
         error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
-        ptr::swap(error_out, &mut error as *mut c_int)
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
     }
 
-    match (*wallet)
-        .runtime
-        .block_on((*wallet).wallet.transaction_service.set_num_confirmations_required(num))
-    {
-        Ok(()) => (),
+    match (*wallet).runtime.block_on(
+        (*wallet)
+            .wallet
+            .transaction_service
+            .get_completed_transaction(TxId::from(transaction_id)),
+    ) {
+        Ok(tx) => tx.confirmations.unwrap_or(0),
         Err(e) => {
             error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
-            ptr::swap(error_out, &mut error as *mut c_int)
+            ptr::swap(error_out, &mut error as *mut c_int);
+            0
         },
     }
 }
 
-/// Get the TariContacts from a TariWallet
+/// Get the direction of a transaction from a TariWallet by its TransactionId. Pending and completed transactions are
+/// both looked up, but only the direction is returned so the caller does not need to fetch, inspect and destroy a
+/// transaction pointer.
 ///
 /// ## Arguments
 /// `wallet` - The TariWallet pointer
+/// `transaction_id` - The TransactionId
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
 /// ## Returns
-/// `*mut TariContacts` - returns the contacts, note that it returns ptr::null_mut() if
-/// wallet is null
+/// `c_int` - Returns the direction of the transaction encoded as an integer as follows:
+/// 0 - Inbound
+/// 1 - Outbound
+/// -1 - The transaction was not found or its direction is unknown. `error_out` is only set if the wallet is null or
+/// there was an error looking up the transaction
 ///
 /// # Safety
-/// The ```contacts_destroy``` method must be called when finished with a TariContacts to prevent a memory leak
+/// None
 #[no_mangle]
-pub unsafe extern "C" fn wallet_get_contacts(wallet: *mut TariWallet, error_out: *mut c_int) -> *mut TariContacts {
+pub unsafe extern "C" fn wallet_get_transaction_direction(
+    wallet: *mut TariWallet,
+    transaction_id: c_ulonglong,
+    error_out: *mut c_int,
+) -> c_int {
     let mut error = 0;
     ptr::swap(error_out, &mut error as *mut c_int);
-    let mut contacts = Vec::new();
     if wallet.is_null() {
         error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
         ptr::swap(error_out, &mut error as *mut c_int);
-        return ptr::null_mut();
+        return -1;
     }
 
-    let retrieved_contacts = (*wallet)
-        .runtime
-        .block_on((*wallet).wallet.contacts_service.get_contacts());
-    match retrieved_contacts {
-        Ok(mut retrieved_contacts) => {
-            contacts.append(&mut retrieved_contacts);
-            Box::into_raw(Box::new(TariContacts(contacts)))
-        },
+    match (*wallet).runtime.block_on(
+        (*wallet)
+            .wallet
+            .transaction_service
+            .get_transaction_direction(TxId::from(transaction_id)),
+    ) {
+        Ok(Some(TransactionDirection::Inbound)) => 0,
+        Ok(Some(TransactionDirection::Outbound)) => 1,
+        Ok(Some(TransactionDirection::Unknown)) | Ok(None) => -1,
         Err(e) => {
-            error = LibWalletError::from(WalletError::ContactsServiceError(e)).code;
+            error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
             ptr::swap(error_out, &mut error as *mut c_int);
-            ptr::null_mut()
+            -1
         },
     }
 }
 
-/// Get the TariCompletedTransactions from a TariWallet
+/// Gets the reason a transaction is cancelled from a TariWallet by its TransactionId. Pending and completed
+/// transactions are both looked up, but only the reason is returned so the caller does not need to fetch, inspect
+/// and destroy a transaction pointer.
 ///
 /// ## Arguments
 /// `wallet` - The TariWallet pointer
+/// `transaction_id` - The TransactionId
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
 /// ## Returns
-/// `*mut TariCompletedTransactions` - returns the transactions, note that it returns ptr::null_mut() if
-/// wallet is null or an error is encountered
+/// `c_int` - Returns the same reason codes as `completed_transaction_get_cancellation_reason`, with -1 meaning the
+/// transaction is not cancelled. Cancelled pending transactions are reported as UserCancelled. If the transaction is
+/// not found -1 is returned and `error_out` is set to 204.
 ///
 /// # Safety
-/// The ```completed_transactions_destroy``` method must be called when finished with a TariCompletedTransactions to
-/// prevent a memory leak
+/// None
 #[no_mangle]
-pub unsafe extern "C" fn wallet_get_completed_transactions(
+pub unsafe extern "C" fn wallet_get_transaction_cancellation_reason(
     wallet: *mut TariWallet,
+    transaction_id: c_ulonglong,
     error_out: *mut c_int,
-) -> *mut TariCompletedTransactions {
+) -> c_int {
     let mut error = 0;
     ptr::swap(error_out, &mut error as *mut c_int);
-    let mut completed = Vec::new();
     if wallet.is_null() {
         error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
         ptr::swap(error_out, &mut error as *mut c_int);
-        return ptr::null_mut();
+        return -1;
     }
 
-    let completed_transactions = (*wallet)
-        .runtime
-        .block_on((*wallet).wallet.transaction_service.get_completed_transactions());
-    match completed_transactions {
-        Ok(completed_transactions) => {
-            // The frontend specification calls for completed transactions that have not yet been mined to be
-            // classified as Pending Transactions. In order to support this logic without impacting the practical
-            // definitions and storage of a MimbleWimble CompletedTransaction we will remove CompletedTransactions with
-            // the Completed and Broadcast states from the list returned by this FFI function
-            for tx in completed_transactions
-                .values()
-                .filter(|ct| ct.status != TransactionStatus::Completed)
-                .filter(|ct| ct.status != TransactionStatus::Broadcast)
-                .filter(|ct| ct.status != TransactionStatus::Imported)
-            {
-                completed.push(tx.clone());
-            }
-            Box::into_raw(Box::new(TariCompletedTransactions(completed)))
+    let cancelled = match (*wallet).runtime.block_on(
+        (*wallet)
+            .wallet
+            .transaction_service
+            .get_any_transaction(TxId::from(transaction_id)),
+    ) {
+        Ok(Some(WalletTransaction::PendingInbound(tx))) => CompletedTransaction::from(tx).cancelled,
+        Ok(Some(WalletTransaction::PendingOutbound(tx))) => CompletedTransaction::from(tx).cancelled,
+        Ok(Some(WalletTransaction::Completed(tx))) => tx.cancelled,
+        Ok(None) => {
+            error = LibWalletError::from(WalletError::TransactionServiceError(
+                TransactionServiceError::TransactionDoesNotExistError,
+            ))
+            .code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return -1;
         },
         Err(e) => {
             error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
             ptr::swap(error_out, &mut error as *mut c_int);
-            ptr::null_mut()
+            return -1;
         },
+    };
+
+    match cancelled {
+        None => -1,
+        Some(reason) => reason as c_int,
     }
 }
 
-/// Get the TariPendingInboundTransactions from a TariWallet
-///
-/// Currently a CompletedTransaction with the Status of Completed and Broadcast is considered Pending by the frontend
+/// Get the TariPendingInboundTransaction from a TariWallet by its' TransactionId, without fetching all of the pending
+/// inbound transactions. Completed inbound transactions that are not yet mined are returned as pending.
 ///
 /// ## Arguments
 /// `wallet` - The TariWallet pointer
+/// `transaction_id` - The TransactionId
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
 /// ## Returns
-/// `*mut TariPendingInboundTransactions` - returns the transactions, note that it returns ptr::null_mut() if
-/// wallet is null or and error is encountered
+/// `*mut TariPendingInboundTransaction` - returns the transaction, note that it returns ptr::null_mut() if
+/// wallet is null, an error is encountered or if the transaction is not found. A transaction that is not found sets
+/// `error_out` to 108, which is distinct from the null wallet error
 ///
 /// # Safety
-/// The ```pending_inbound_transactions_destroy``` method must be called when finished with a
-/// TariPendingInboundTransactions to prevent a memory leak
+/// The ```pending_inbound_transaction_destroy``` method must be called when finished with a
+/// TariPendingInboundTransaction to prevent a memory leak
 #[no_mangle]
-pub unsafe extern "C" fn wallet_get_pending_inbound_transactions(
+pub unsafe extern "C" fn wallet_get_pending_inbound_transaction_by_id(
     wallet: *mut TariWallet,
+    transaction_id: c_ulonglong,
     error_out: *mut c_int,
-) -> *mut TariPendingInboundTransactions {
+) -> *mut TariPendingInboundTransaction {
     let mut error = 0;
+    let transaction_id = TxId::from(transaction_id);
     ptr::swap(error_out, &mut error as *mut c_int);
-    let mut pending = Vec::new();
     if wallet.is_null() {
         error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
         ptr::swap(error_out, &mut error as *mut c_int);
         return ptr::null_mut();
     }
 
-    let pending_transactions = (*wallet)
+    match (*wallet)
         .runtime
-        .block_on((*wallet).wallet.transaction_service.get_pending_inbound_transactions());
-
-    match pending_transactions {
-        Ok(pending_transactions) => {
-            for tx in pending_transactions.values() {
-                pending.push(tx.clone());
-            }
-
-            if let Ok(completed_txs) = (*wallet)
-                .runtime
-                .block_on((*wallet).wallet.transaction_service.get_completed_transactions())
-            {
-                // The frontend specification calls for completed transactions that have not yet been mined to be
-                // classified as Pending Transactions. In order to support this logic without impacting the practical
-                // definitions and storage of a MimbleWimble CompletedTransaction we will add those transaction to the
-                // list here in the FFI interface
-                for ct in completed_txs
-                    .values()
-                    .filter(|ct| {
-                        ct.status == TransactionStatus::Completed ||
-                            ct.status == TransactionStatus::Broadcast ||
-                            ct.status == TransactionStatus::Imported
-                    })
-                    .filter(|ct| ct.direction == TransactionDirection::Inbound)
-                {
-                    pending.push(InboundTransaction::from(ct.clone()));
-                }
-            }
-
-            Box::into_raw(Box::new(TariPendingInboundTransactions(pending)))
+        .block_on((*wallet).wallet.transaction_service.get_any_transaction(transaction_id))
+    {
+        Ok(Some(WalletTransaction::PendingInbound(tx))) if !tx.cancelled => Box::into_raw(Box::new(tx)),
+        Ok(Some(WalletTransaction::Completed(tx)))
+            if tx.cancelled.is_none() &&
+                (tx.status == TransactionStatus::Broadcast || tx.status == TransactionStatus::Completed) &&
+                tx.direction == TransactionDirection::Inbound =>
+        {
+            Box::into_raw(Box::new(TariPendingInboundTransaction::from(tx)))
+        },
+        Ok(_) => {
+            error = 108;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            ptr::null_mut()
         },
         Err(e) => {
             error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
@@ -7809,30 +13342,30 @@ pub unsafe extern "C" fn wallet_get_pending_inbound_transactions(
     }
 }
 
-/// Get the TariPendingOutboundTransactions from a TariWallet
-///
-/// Currently a CompletedTransaction with the Status of Completed and Broadcast is considered Pending by the frontend
+/// Get the TariPendingOutboundTransaction from a TariWallet by its' TransactionId
 ///
 /// ## Arguments
 /// `wallet` - The TariWallet pointer
+/// `transaction_id` - The TransactionId
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
 /// ## Returns
-/// `*mut TariPendingOutboundTransactions` - returns the transactions, note that it returns ptr::null_mut() if
-/// wallet is null or and error is encountered
+/// `*mut TariPendingOutboundTransaction` - returns the transaction, note that it returns ptr::null_mut() if
+/// wallet is null, an error is encountered or if the transaction is not found
 ///
 /// # Safety
-/// The ```pending_outbound_transactions_destroy``` method must be called when finished with a
-/// TariPendingOutboundTransactions to prevent a memory leak
+/// The ```pending_outbound_transaction_destroy``` method must be called when finished with a
+/// TariPendingOutboundtransaction to prevent a memory leak
 #[no_mangle]
-pub unsafe extern "C" fn wallet_get_pending_outbound_transactions(
+pub unsafe extern "C" fn wallet_get_pending_outbound_transaction_by_id(
     wallet: *mut TariWallet,
+    transaction_id: c_ulonglong,
     error_out: *mut c_int,
-) -> *mut TariPendingOutboundTransactions {
+) -> *mut TariPendingOutboundTransaction {
     let mut error = 0;
+    let transaction_id = TxId::from(transaction_id);
     ptr::swap(error_out, &mut error as *mut c_int);
-    let mut pending = Vec::new();
     if wallet.is_null() {
         error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
         ptr::swap(error_out, &mut error as *mut c_int);
@@ -7842,67 +13375,81 @@ pub unsafe extern "C" fn wallet_get_pending_outbound_transactions(
     let pending_transactions = (*wallet)
         .runtime
         .block_on((*wallet).wallet.transaction_service.get_pending_outbound_transactions());
-    match pending_transactions {
-        Ok(pending_transactions) => {
-            for tx in pending_transactions.values() {
-                pending.push(tx.clone());
-            }
-            if let Ok(completed_txs) = (*wallet)
-                .runtime
-                .block_on((*wallet).wallet.transaction_service.get_completed_transactions())
-            {
-                // The frontend specification calls for completed transactions that have not yet been mined to be
-                // classified as Pending Transactions. In order to support this logic without impacting the practical
-                // definitions and storage of a MimbleWimble CompletedTransaction we will add those transaction to the
-                // list here in the FFI interface
-                for ct in completed_txs
-                    .values()
-                    .filter(|ct| ct.status == TransactionStatus::Completed || ct.status == TransactionStatus::Broadcast)
-                    .filter(|ct| ct.direction == TransactionDirection::Outbound)
+
+    let completed_transactions = (*wallet)
+        .runtime
+        .block_on((*wallet).wallet.transaction_service.get_completed_transactions());
+
+    match completed_transactions {
+        Ok(completed_transactions) => {
+            if let Some(tx) = completed_transactions.get(&transaction_id) {
+                if (tx.status == TransactionStatus::Broadcast || tx.status == TransactionStatus::Completed) &&
+                    tx.direction == TransactionDirection::Outbound
                 {
-                    pending.push(OutboundTransaction::from(ct.clone()));
+                    let completed = tx.clone();
+                    let pending_tx = TariPendingOutboundTransaction::from(completed);
+                    return Box::into_raw(Box::new(pending_tx));
                 }
             }
-            Box::into_raw(Box::new(TariPendingOutboundTransactions(pending)))
         },
         Err(e) => {
             error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
             ptr::swap(error_out, &mut error as *mut c_int);
-            ptr::null_mut()
         },
     }
+
+    match pending_transactions {
+        Ok(pending_transactions) => {
+            if let Some(tx) = pending_transactions.get(&transaction_id) {
+                let pending = tx.clone();
+                return Box::into_raw(Box::new(pending));
+            }
+            error = 108;
+            ptr::swap(error_out, &mut error as *mut c_int);
+        },
+        Err(e) => {
+            error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+        },
+    }
+
+    ptr::null_mut()
 }
 
-/// Get the all Cancelled Transactions from a TariWallet. This function will also get cancelled pending inbound and
-/// outbound transaction and include them in this list by converting them to CompletedTransactions
+/// Get a Cancelled transaction from a TariWallet by its TransactionId. Pending Inbound or Outbound transaction will be
+/// converted to a CompletedTransaction
 ///
 /// ## Arguments
 /// `wallet` - The TariWallet pointer
+/// `transaction_id` - The TransactionId
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
 /// ## Returns
-/// `*mut TariCompletedTransactions` - returns the transactions, note that it returns ptr::null_mut() if
-/// wallet is null or an error is encountered
+/// `*mut TariCompletedTransaction` - returns the transaction, note that it returns ptr::null_mut() if
+/// wallet is null, an error is encountered or if the transaction is not found
 ///
 /// # Safety
-/// The ```completed_transactions_destroy``` method must be called when finished with a TariCompletedTransactions to
+/// The ```completed_transaction_destroy``` method must be called when finished with a TariCompletedTransaction to
 /// prevent a memory leak
 #[no_mangle]
-pub unsafe extern "C" fn wallet_get_cancelled_transactions(
+pub unsafe extern "C" fn wallet_get_cancelled_transaction_by_id(
     wallet: *mut TariWallet,
+    transaction_id: c_ulonglong,
     error_out: *mut c_int,
-) -> *mut TariCompletedTransactions {
+) -> *mut TariCompletedTransaction {
     let mut error = 0;
+    let transaction_id = TxId::from(transaction_id);
     ptr::swap(error_out, &mut error as *mut c_int);
-
     if wallet.is_null() {
         error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
         ptr::swap(error_out, &mut error as *mut c_int);
         return ptr::null_mut();
     }
 
-    let completed_transactions = match (*wallet).runtime.block_on(
+    let mut transaction = None;
+
+    let mut completed_transactions = match (*wallet).runtime.block_on(
         (*wallet)
             .wallet
             .transaction_service
@@ -7915,36 +13462,149 @@ pub unsafe extern "C" fn wallet_get_cancelled_transactions(
             return ptr::null_mut();
         },
     };
-    let inbound_transactions = match (*wallet).runtime.block_on(
-        (*wallet)
-            .wallet
-            .transaction_service
-            .get_cancelled_pending_inbound_transactions(),
-    ) {
-        Ok(txs) => txs,
+
+    if let Some(tx) = completed_transactions.remove(&transaction_id) {
+        transaction = Some(tx);
+    } else {
+        let mut outbound_transactions = match (*wallet).runtime.block_on(
+            (*wallet)
+                .wallet
+                .transaction_service
+                .get_cancelled_pending_outbound_transactions(),
+        ) {
+            Ok(txs) => txs,
+            Err(e) => {
+                error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
+                ptr::swap(error_out, &mut error as *mut c_int);
+                return ptr::null_mut();
+            },
+        };
+        let runtime = match Runtime::new() {
+            Ok(r) => r,
+            Err(e) => {
+                error = LibWalletError::from(InterfaceError::TokioError(e.to_string())).code;
+                ptr::swap(error_out, &mut error as *mut c_int);
+                return ptr::null_mut();
+            },
+        };
+        let address = match runtime.block_on(async { (*wallet).wallet.get_wallet_interactive_address().await }) {
+            Ok(address) => address,
+            Err(e) => {
+                error = LibWalletError::from(e).code;
+                ptr::swap(error_out, &mut error as *mut c_int);
+                return ptr::null_mut();
+            },
+        };
+        if let Some(tx) = outbound_transactions.remove(&transaction_id) {
+            let mut outbound_tx = CompletedTransaction::from(tx);
+            outbound_tx.source_address = address;
+            transaction = Some(outbound_tx);
+        } else {
+            let mut inbound_transactions = match (*wallet).runtime.block_on(
+                (*wallet)
+                    .wallet
+                    .transaction_service
+                    .get_cancelled_pending_inbound_transactions(),
+            ) {
+                Ok(txs) => txs,
+                Err(e) => {
+                    error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
+                    ptr::swap(error_out, &mut error as *mut c_int);
+                    return ptr::null_mut();
+                },
+            };
+            if let Some(tx) = inbound_transactions.remove(&transaction_id) {
+                let mut inbound_tx = CompletedTransaction::from(tx);
+                inbound_tx.destination_address = address;
+                transaction = Some(inbound_tx);
+            }
+        }
+    }
+
+    match transaction {
+        Some(tx) => {
+            return Box::into_raw(Box::new(tx));
+        },
+        None => {
+            error = LibWalletError::from(WalletError::TransactionServiceError(
+                TransactionServiceError::TransactionDoesNotExistError,
+            ))
+            .code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+        },
+    }
+
+    ptr::null_mut()
+}
+
+/// Get the interactive TariWalletAddress from a TariWallet
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `*mut TariWalletAddress` - returns the address, note that ptr::null_mut() is returned
+/// if wc is null
+///
+/// # Safety
+/// The ```tari_address_destroy``` method must be called when finished with a TariWalletAddress to prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn wallet_get_tari_interactive_address(
+    wallet: *mut TariWallet,
+    error_out: *mut c_int,
+) -> *mut TariWalletAddress {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+    let runtime = match Runtime::new() {
+        Ok(r) => r,
         Err(e) => {
-            error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
+            error = LibWalletError::from(InterfaceError::TokioError(e.to_string())).code;
             ptr::swap(error_out, &mut error as *mut c_int);
             return ptr::null_mut();
         },
     };
-    let outbound_transactions = match (*wallet).runtime.block_on(
-        (*wallet)
-            .wallet
-            .transaction_service
-            .get_cancelled_pending_outbound_transactions(),
-    ) {
-        Ok(txs) => txs,
+    let address = match runtime.block_on(async { (*wallet).wallet.get_wallet_interactive_address().await }) {
+        Ok(address) => address,
         Err(e) => {
-            error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
+            error = LibWalletError::from(e).code;
             ptr::swap(error_out, &mut error as *mut c_int);
             return ptr::null_mut();
         },
     };
+    Box::into_raw(Box::new(address))
+}
 
-    let mut completed = Vec::new();
-    for tx in completed_transactions.values() {
-        completed.push(tx.clone());
+/// Get the one_sided only TariWalletAddress from a TariWallet
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `*mut TariWalletAddress` - returns the address, note that ptr::null_mut() is returned
+/// if wc is null
+///
+/// # Safety
+/// The ```tari_address_destroy``` method must be called when finished with a TariWalletAddress to prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn wallet_get_tari_one_sided_address(
+    wallet: *mut TariWallet,
+    error_out: *mut c_int,
+) -> *mut TariWalletAddress {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
     }
     let runtime = match Runtime::new() {
         Ok(r) => r,
@@ -7954,7 +13614,7 @@ pub unsafe extern "C" fn wallet_get_cancelled_transactions(
             return ptr::null_mut();
         },
     };
-    let wallet_address = match runtime.block_on(async { (*wallet).wallet.get_wallet_interactive_address().await }) {
+    let address = match runtime.block_on(async { (*wallet).wallet.get_wallet_one_sided_address().await }) {
         Ok(address) => address,
         Err(e) => {
             error = LibWalletError::from(e).code;
@@ -7962,41 +13622,62 @@ pub unsafe extern "C" fn wallet_get_cancelled_transactions(
             return ptr::null_mut();
         },
     };
-    for tx in inbound_transactions.values() {
-        let mut inbound_tx = CompletedTransaction::from(tx.clone());
-        inbound_tx.destination_address = wallet_address.clone();
-        completed.push(inbound_tx);
+    Box::into_raw(Box::new(address))
+}
+
+/// Checks whether a TariWalletAddress belongs to the same network as the TariWallet, so that sends to an address for
+/// the wrong network can be rejected up front
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer
+/// `address` - The pointer to a TariWalletAddress
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `bool` - Returns true if the address is on the wallet's network, false if it is not or if wallet or address is null
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn wallet_can_send_to_address(
+    wallet: *mut TariWallet,
+    address: *mut TariWalletAddress,
+    error_out: *mut c_int,
+) -> bool {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
     }
-    for tx in outbound_transactions.values() {
-        let mut outbound_tx = CompletedTransaction::from(tx.clone());
-        outbound_tx.source_address = wallet_address.clone();
-        completed.push(outbound_tx);
+    if address.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("address".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
     }
-
-    Box::into_raw(Box::new(TariCompletedTransactions(completed)))
+    (*address).network() == (*wallet).wallet.network.as_network()
 }
 
-/// Get the TariCompletedTransaction from a TariWallet by its' TransactionId
+/// Get the comms public key of the node identity of a TariWallet
 ///
 /// ## Arguments
 /// `wallet` - The TariWallet pointer
-/// `transaction_id` - The TransactionId
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
 /// ## Returns
-/// `*mut TariCompletedTransaction` - returns the transaction, note that it returns ptr::null_mut() if
-/// wallet is null, an error is encountered or if the transaction is not found
+/// `*mut TariPublicKey` - returns the comms public key, note that ptr::null_mut() is returned
+/// if wallet is null
 ///
 /// # Safety
-/// The ```completed_transaction_destroy``` method must be called when finished with a TariCompletedTransaction to
-/// prevent a memory leak
+/// The ```public_key_destroy``` method must be called when finished with a TariPublicKey to prevent a memory leak
 #[no_mangle]
-pub unsafe extern "C" fn wallet_get_completed_transaction_by_id(
+pub unsafe extern "C" fn wallet_get_comms_public_key(
     wallet: *mut TariWallet,
-    transaction_id: c_ulonglong,
     error_out: *mut c_int,
-) -> *mut TariCompletedTransaction {
+) -> *mut TariPublicKey {
     let mut error = 0;
     ptr::swap(error_out, &mut error as *mut c_int);
     if wallet.is_null() {
@@ -8005,53 +13686,54 @@ pub unsafe extern "C" fn wallet_get_completed_transaction_by_id(
         return ptr::null_mut();
     }
 
-    let completed_transactions = (*wallet)
-        .runtime
-        .block_on((*wallet).wallet.transaction_service.get_completed_transactions());
+    let public_key = (*wallet).wallet.comms.node_identity().public_key().clone();
+    Box::into_raw(Box::new(public_key))
+}
 
-    match completed_transactions {
-        Ok(completed_transactions) => {
-            if let Some(tx) = completed_transactions.get(&TxId::from(transaction_id)) {
-                if tx.status != TransactionStatus::Completed && tx.status != TransactionStatus::Broadcast {
-                    let completed = tx.clone();
-                    return Box::into_raw(Box::new(completed));
-                }
-            }
-            error = 108;
-            ptr::swap(error_out, &mut error as *mut c_int);
-        },
-        Err(e) => {
-            error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
-            ptr::swap(error_out, &mut error as *mut c_int);
-        },
+/// Get the hex encoded comms node id of the node identity of a TariWallet
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `*mut c_char` - returns the hex encoded node id, note that ptr::null_mut() is returned
+/// if wallet is null
+///
+/// # Safety
+/// The ```string_destroy``` method must be called when finished with a string coming from rust to prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn wallet_get_node_id(wallet: *mut TariWallet, error_out: *mut c_int) -> *mut c_char {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
     }
 
-    ptr::null_mut()
+    let node_id = (*wallet).wallet.comms.node_identity().node_id().to_hex();
+    CString::new(node_id).expect("failed to initialize CString").into_raw()
 }
 
-/// Get the TariPendingInboundTransaction from a TariWallet by its' TransactionId
+/// Export the tor identity of a TariWallet as JSON, to be provided to `transport_tor_create_with_identity` so that
+/// the onion address of the wallet is stable across restarts
 ///
 /// ## Arguments
 /// `wallet` - The TariWallet pointer
-/// `transaction_id` - The TransactionId
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
 /// ## Returns
-/// `*mut TariPendingInboundTransaction` - returns the transaction, note that it returns ptr::null_mut() if
-/// wallet is null, an error is encountered or if the transaction is not found
+/// `*mut c_char` - returns the tor identity JSON, note that ptr::null_mut() is returned if the wallet is null or has
+/// no tor identity
 ///
 /// # Safety
-/// The ```pending_inbound_transaction_destroy``` method must be called when finished with a
-/// TariPendingInboundTransaction to prevent a memory leak
+/// The ```string_destroy``` method must be called when finished with a string coming from rust to prevent a memory leak
 #[no_mangle]
-pub unsafe extern "C" fn wallet_get_pending_inbound_transaction_by_id(
-    wallet: *mut TariWallet,
-    transaction_id: c_ulonglong,
-    error_out: *mut c_int,
-) -> *mut TariPendingInboundTransaction {
+pub unsafe extern "C" fn wallet_export_tor_identity(wallet: *mut TariWallet, error_out: *mut c_int) -> *mut c_char {
     let mut error = 0;
-    let transaction_id = TxId::from(transaction_id);
     ptr::swap(error_out, &mut error as *mut c_int);
     if wallet.is_null() {
         error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
@@ -8059,246 +13741,213 @@ pub unsafe extern "C" fn wallet_get_pending_inbound_transaction_by_id(
         return ptr::null_mut();
     }
 
-    let pending_transactions = (*wallet)
-        .runtime
-        .block_on((*wallet).wallet.transaction_service.get_pending_inbound_transactions());
-
-    let completed_transactions = (*wallet)
-        .runtime
-        .block_on((*wallet).wallet.transaction_service.get_completed_transactions());
-
-    match completed_transactions {
-        Ok(completed_transactions) => {
-            if let Some(tx) = completed_transactions.get(&transaction_id) {
-                if (tx.status == TransactionStatus::Broadcast || tx.status == TransactionStatus::Completed) &&
-                    tx.direction == TransactionDirection::Inbound
-                {
-                    let completed = tx.clone();
-                    let pending_tx = TariPendingInboundTransaction::from(completed);
-                    return Box::into_raw(Box::new(pending_tx));
-                }
-            }
-        },
-        Err(e) => {
-            error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
-            ptr::swap(error_out, &mut error as *mut c_int);
+    match (*wallet).wallet.db.get_tor_id() {
+        Ok(Some(identity)) => match serde_json::to_string(&identity) {
+            Ok(json) => CString::new(json).expect("failed to initialize CString").into_raw(),
+            Err(_) => {
+                error = LibWalletError::from(InterfaceError::PointerError("tor_identity".to_string())).code;
+                ptr::swap(error_out, &mut error as *mut c_int);
+                ptr::null_mut()
+            },
         },
-    }
-
-    match pending_transactions {
-        Ok(pending_transactions) => {
-            if let Some(tx) = pending_transactions.get(&transaction_id) {
-                let pending = tx.clone();
-                return Box::into_raw(Box::new(pending));
-            }
-            error = 108;
+        Ok(None) => {
+            error = LibWalletError::from(WalletError::WalletStorageError(WalletStorageError::ValuesNotFound)).code;
             ptr::swap(error_out, &mut error as *mut c_int);
+            ptr::null_mut()
         },
         Err(e) => {
-            error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
+            error = LibWalletError::from(WalletError::WalletStorageError(e)).code;
             ptr::swap(error_out, &mut error as *mut c_int);
+            ptr::null_mut()
         },
     }
-
-    ptr::null_mut()
 }
 
-/// Get the TariPendingOutboundTransaction from a TariWallet by its' TransactionId
+/// Get the onion address a TariWallet using the Tor transport is reachable at, as published in its node identity
 ///
 /// ## Arguments
 /// `wallet` - The TariWallet pointer
-/// `transaction_id` - The TransactionId
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
 /// ## Returns
-/// `*mut TariPendingOutboundTransaction` - returns the transaction, note that it returns ptr::null_mut() if
-/// wallet is null, an error is encountered or if the transaction is not found
+/// `*mut c_char` - returns the onion address as a multiaddr string, note that an empty string is returned and
+/// `error_out` is set if the wallet is null or is not using the Tor transport
 ///
 /// # Safety
-/// The ```pending_outbound_transaction_destroy``` method must be called when finished with a
-/// TariPendingOutboundtransaction to prevent a memory leak
+/// The ```string_destroy``` method must be called when finished with a string coming from rust to prevent a memory leak
 #[no_mangle]
-pub unsafe extern "C" fn wallet_get_pending_outbound_transaction_by_id(
-    wallet: *mut TariWallet,
-    transaction_id: c_ulonglong,
-    error_out: *mut c_int,
-) -> *mut TariPendingOutboundTransaction {
+pub unsafe extern "C" fn wallet_get_onion_address(wallet: *mut TariWallet, error_out: *mut c_int) -> *mut c_char {
     let mut error = 0;
-    let transaction_id = TxId::from(transaction_id);
     ptr::swap(error_out, &mut error as *mut c_int);
+    let mut address = CString::new("").expect("Blank CString will not fail.");
     if wallet.is_null() {
         error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
         ptr::swap(error_out, &mut error as *mut c_int);
-        return ptr::null_mut();
+        return address.into_raw();
     }
 
-    let pending_transactions = (*wallet)
-        .runtime
-        .block_on((*wallet).wallet.transaction_service.get_pending_outbound_transactions());
-
-    let completed_transactions = (*wallet)
-        .runtime
-        .block_on((*wallet).wallet.transaction_service.get_completed_transactions());
-
-    match completed_transactions {
-        Ok(completed_transactions) => {
-            if let Some(tx) = completed_transactions.get(&transaction_id) {
-                if (tx.status == TransactionStatus::Broadcast || tx.status == TransactionStatus::Completed) &&
-                    tx.direction == TransactionDirection::Outbound
-                {
-                    let completed = tx.clone();
-                    let pending_tx = TariPendingOutboundTransaction::from(completed);
-                    return Box::into_raw(Box::new(pending_tx));
-                }
-            }
+    match (*wallet)
+        .wallet
+        .comms
+        .node_identity()
+        .public_addresses()
+        .into_iter()
+        .find(is_onion_address)
+    {
+        Some(onion_address) => match CString::new(onion_address.to_string()) {
+            Ok(v) => address = v,
+            Err(_) => {
+                error = LibWalletError::from(InterfaceError::PointerError("onion_address".to_string())).code;
+                ptr::swap(error_out, &mut error as *mut c_int);
+            },
         },
-        Err(e) => {
-            error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
+        None => {
+            error = LibWalletError::from(InterfaceError::InvalidArgument(
+                "wallet is not using the tor transport".to_string(),
+            ))
+            .code;
             ptr::swap(error_out, &mut error as *mut c_int);
         },
     }
 
-    match pending_transactions {
-        Ok(pending_transactions) => {
-            if let Some(tx) = pending_transactions.get(&transaction_id) {
-                let pending = tx.clone();
-                return Box::into_raw(Box::new(pending));
-            }
-            error = 108;
-            ptr::swap(error_out, &mut error as *mut c_int);
-        },
+    address.into_raw()
+}
+
+/// Cancel a Pending Transaction
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer
+/// `transaction_id` - The TransactionId
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `bool` - returns whether the transaction could be cancelled
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn wallet_cancel_pending_transaction(
+    wallet: *mut TariWallet,
+    transaction_id: c_ulonglong,
+    error_out: *mut c_int,
+) -> bool {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+    if let Err(e) = ensure_wallet_running(&*wallet) {
+        error = LibWalletError::from(e).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+
+    match (*wallet).runtime.block_on(
+        (*wallet)
+            .wallet
+            .transaction_service
+            .cancel_transaction(TxId::from(transaction_id)),
+    ) {
+        Ok(_) => true,
         Err(e) => {
             error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
             ptr::swap(error_out, &mut error as *mut c_int);
+            false
         },
     }
-
-    ptr::null_mut()
 }
 
-/// Get a Cancelled transaction from a TariWallet by its TransactionId. Pending Inbound or Outbound transaction will be
-/// converted to a CompletedTransaction
+/// Cancel every pending outbound transaction that is older than the given age, releasing the outputs it encumbered
 ///
 /// ## Arguments
 /// `wallet` - The TariWallet pointer
-/// `transaction_id` - The TransactionId
+/// `older_than_secs` - The age in seconds a pending outbound transaction must exceed to be cancelled
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
 /// ## Returns
-/// `*mut TariCompletedTransaction` - returns the transaction, note that it returns ptr::null_mut() if
-/// wallet is null, an error is encountered or if the transaction is not found
+/// `c_uint` - Returns the number of transactions that were cancelled, note that it returns 0 if wallet is null or an
+/// error is encountered. A transaction that fails to cancel is left pending and not counted, the others are still
+/// cancelled
 ///
 /// # Safety
-/// The ```completed_transaction_destroy``` method must be called when finished with a TariCompletedTransaction to
-/// prevent a memory leak
+/// None
+// casting here is okay as the number of pending transactions wont go larger than u32
+#[allow(clippy::cast_possible_truncation)]
 #[no_mangle]
-pub unsafe extern "C" fn wallet_get_cancelled_transaction_by_id(
+pub unsafe extern "C" fn wallet_cancel_expired_pending_transactions(
     wallet: *mut TariWallet,
-    transaction_id: c_ulonglong,
+    older_than_secs: c_ulonglong,
     error_out: *mut c_int,
-) -> *mut TariCompletedTransaction {
+) -> c_uint {
     let mut error = 0;
-    let transaction_id = TxId::from(transaction_id);
     ptr::swap(error_out, &mut error as *mut c_int);
     if wallet.is_null() {
         error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
         ptr::swap(error_out, &mut error as *mut c_int);
-        return ptr::null_mut();
+        return 0;
     }
 
-    let mut transaction = None;
-
-    let mut completed_transactions = match (*wallet).runtime.block_on(
+    match (*wallet).runtime.block_on(
         (*wallet)
             .wallet
             .transaction_service
-            .get_cancelled_completed_transactions(),
+            .cancel_expired_pending_transactions(Duration::from_secs(older_than_secs)),
     ) {
-        Ok(txs) => txs,
+        Ok(cancelled) => cancelled.len() as c_uint,
         Err(e) => {
             error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
             ptr::swap(error_out, &mut error as *mut c_int);
-            return ptr::null_mut();
+            0
         },
-    };
-
-    if let Some(tx) = completed_transactions.remove(&transaction_id) {
-        transaction = Some(tx);
-    } else {
-        let mut outbound_transactions = match (*wallet).runtime.block_on(
-            (*wallet)
-                .wallet
-                .transaction_service
-                .get_cancelled_pending_outbound_transactions(),
-        ) {
-            Ok(txs) => txs,
-            Err(e) => {
-                error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
-                ptr::swap(error_out, &mut error as *mut c_int);
-                return ptr::null_mut();
-            },
-        };
-        let runtime = match Runtime::new() {
-            Ok(r) => r,
-            Err(e) => {
-                error = LibWalletError::from(InterfaceError::TokioError(e.to_string())).code;
-                ptr::swap(error_out, &mut error as *mut c_int);
-                return ptr::null_mut();
-            },
-        };
-        let address = match runtime.block_on(async { (*wallet).wallet.get_wallet_interactive_address().await }) {
-            Ok(address) => address,
-            Err(e) => {
-                error = LibWalletError::from(e).code;
-                ptr::swap(error_out, &mut error as *mut c_int);
-                return ptr::null_mut();
-            },
-        };
-        if let Some(tx) = outbound_transactions.remove(&transaction_id) {
-            let mut outbound_tx = CompletedTransaction::from(tx);
-            outbound_tx.source_address = address;
-            transaction = Some(outbound_tx);
-        } else {
-            let mut inbound_transactions = match (*wallet).runtime.block_on(
-                (*wallet)
-                    .wallet
-                    .transaction_service
-                    .get_cancelled_pending_inbound_transactions(),
-            ) {
-                Ok(txs) => txs,
-                Err(e) => {
-                    error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
-                    ptr::swap(error_out, &mut error as *mut c_int);
-                    return ptr::null_mut();
-                },
-            };
-            if let Some(tx) = inbound_transactions.remove(&transaction_id) {
-                let mut inbound_tx = CompletedTransaction::from(tx);
-                inbound_tx.destination_address = address;
-                transaction = Some(inbound_tx);
-            }
-        }
     }
+}
 
-    match transaction {
-        Some(tx) => {
-            return Box::into_raw(Box::new(tx));
-        },
-        None => {
-            error = LibWalletError::from(WalletError::TransactionServiceError(
-                TransactionServiceError::TransactionDoesNotExistError,
-            ))
-            .code;
-            ptr::swap(error_out, &mut error as *mut c_int);
-        },
+/// Advance the clock the wallet's transaction service reads the current time from. This is only available when the
+/// library is built with the `ffi-test` feature, so that integration tests can exercise time-based behaviour such as
+/// pending transaction timeouts deterministically. The service observes the new time the next time it measures the
+/// age of a transaction.
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer
+/// `seconds` - The number of seconds to move the clock forward by
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `bool` - Returns true if the clock was advanced, false if wallet is null
+///
+/// # Safety
+/// None
+#[cfg(feature = "ffi-test")]
+#[no_mangle]
+pub unsafe extern "C" fn wallet_test_advance_time(
+    wallet: *mut TariWallet,
+    seconds: c_ulonglong,
+    error_out: *mut c_int,
+) -> bool {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
     }
 
-    ptr::null_mut()
+    (*wallet)
+        .wallet
+        .transaction_service
+        .advance_clock(Duration::from_secs(seconds));
+    true
 }
 
-/// Get the interactive TariWalletAddress from a TariWallet
+/// Simulate a counterparty sending this wallet a transaction. This is only available when the library is built with
+/// the `ffi-test` feature. The transaction is accepted as if it had arrived over the network, so it is stored as a
+/// pending inbound transaction and the `callback_received_transaction` callback is called.
 ///
 /// ## Arguments
 /// `wallet` - The TariWallet pointer
@@ -8306,144 +13955,155 @@ pub unsafe extern "C" fn wallet_get_cancelled_transaction_by_id(
 /// as an out parameter.
 ///
 /// ## Returns
-/// `*mut TariWalletAddress` - returns the address, note that ptr::null_mut() is returned
-/// if wc is null
+/// `bool` - Returns true if the transaction was received, false if wallet is null or an error occurred
 ///
 /// # Safety
-/// The ```tari_address_destroy``` method must be called when finished with a TariWalletAddress to prevent a memory leak
+/// None
+#[cfg(feature = "ffi-test")]
 #[no_mangle]
-pub unsafe extern "C" fn wallet_get_tari_interactive_address(
-    wallet: *mut TariWallet,
-    error_out: *mut c_int,
-) -> *mut TariWalletAddress {
+pub unsafe extern "C" fn wallet_test_receive_transaction(wallet: *mut TariWallet, error_out: *mut c_int) -> bool {
     let mut error = 0;
     ptr::swap(error_out, &mut error as *mut c_int);
     if wallet.is_null() {
         error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
         ptr::swap(error_out, &mut error as *mut c_int);
-        return ptr::null_mut();
+        return false;
     }
-    let runtime = match Runtime::new() {
-        Ok(r) => r,
-        Err(e) => {
-            error = LibWalletError::from(InterfaceError::TokioError(e.to_string())).code;
-            ptr::swap(error_out, &mut error as *mut c_int);
-            return ptr::null_mut();
-        },
-    };
-    let address = match runtime.block_on(async { (*wallet).wallet.get_wallet_interactive_address().await }) {
-        Ok(address) => address,
+
+    let mut transaction_service = (*wallet).wallet.transaction_service.clone();
+    match (*wallet)
+        .runtime
+        .block_on(transaction_service.test_receive_transaction())
+    {
+        Ok(_) => true,
         Err(e) => {
-            error = LibWalletError::from(e).code;
+            error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
             ptr::swap(error_out, &mut error as *mut c_int);
-            return ptr::null_mut();
+            false
         },
-    };
-    Box::into_raw(Box::new(address))
+    }
 }
 
-/// Get the one_sided only TariWalletAddress from a TariWallet
+/// Simulate the recipient of a pending outbound transaction replying to it. This is only available when the library
+/// is built with the `ffi-test` feature. The transaction is finalized with a recipient output built from this wallet's
+/// keys, moved to the completed transactions and broadcast like any other. The `callback_received_transaction_reply`
+/// callback is called once it has been completed, which may happen after this function returns.
 ///
 /// ## Arguments
 /// `wallet` - The TariWallet pointer
+/// `tx` - The pending outbound transaction to complete as a TariCompletedTransaction, only its transaction id is used
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
 /// ## Returns
-/// `*mut TariWalletAddress` - returns the address, note that ptr::null_mut() is returned
-/// if wc is null
+/// `bool` - Returns true if the transaction was completed, false if wallet or tx is null or an error occurred
 ///
 /// # Safety
-/// The ```tari_address_destroy``` method must be called when finished with a TariWalletAddress to prevent a memory leak
+/// None
+#[cfg(feature = "ffi-test")]
 #[no_mangle]
-pub unsafe extern "C" fn wallet_get_tari_one_sided_address(
+pub unsafe extern "C" fn wallet_test_complete_sent_transaction(
     wallet: *mut TariWallet,
+    tx: *mut TariCompletedTransaction,
     error_out: *mut c_int,
-) -> *mut TariWalletAddress {
+) -> bool {
     let mut error = 0;
     ptr::swap(error_out, &mut error as *mut c_int);
     if wallet.is_null() {
         error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
         ptr::swap(error_out, &mut error as *mut c_int);
-        return ptr::null_mut();
+        return false;
     }
-    let runtime = match Runtime::new() {
-        Ok(r) => r,
-        Err(e) => {
-            error = LibWalletError::from(InterfaceError::TokioError(e.to_string())).code;
-            ptr::swap(error_out, &mut error as *mut c_int);
-            return ptr::null_mut();
-        },
-    };
-    let address = match runtime.block_on(async { (*wallet).wallet.get_wallet_one_sided_address().await }) {
-        Ok(address) => address,
+    if tx.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("tx".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+
+    let mut transaction_service = (*wallet).wallet.transaction_service.clone();
+    match (*wallet)
+        .runtime
+        .block_on(transaction_service.test_complete_sent_transaction((*tx).tx_id))
+    {
+        Ok(_) => true,
         Err(e) => {
-            error = LibWalletError::from(e).code;
+            error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
             ptr::swap(error_out, &mut error as *mut c_int);
-            return ptr::null_mut();
+            false
         },
-    };
-    Box::into_raw(Box::new(address))
+    }
 }
 
-/// Cancel a Pending Transaction
+/// This function will tell the wallet to query the set base node to confirm the status of transaction outputs
+/// (TXOs).
 ///
 /// ## Arguments
 /// `wallet` - The TariWallet pointer
-/// `transaction_id` - The TransactionId
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
 /// ## Returns
-/// `bool` - returns whether the transaction could be cancelled
+/// `c_ulonglong` -  Returns a unique Request Key that is used to identify which callbacks refer to this specific sync
+/// request. Note the result will be 0 if there was an error
 ///
 /// # Safety
 /// None
 #[no_mangle]
-pub unsafe extern "C" fn wallet_cancel_pending_transaction(
-    wallet: *mut TariWallet,
-    transaction_id: c_ulonglong,
-    error_out: *mut c_int,
-) -> bool {
+pub unsafe extern "C" fn wallet_start_txo_validation(wallet: *mut TariWallet, error_out: *mut c_int) -> c_ulonglong {
     let mut error = 0;
     ptr::swap(error_out, &mut error as *mut c_int);
     if wallet.is_null() {
         error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
         ptr::swap(error_out, &mut error as *mut c_int);
-        return false;
+        return 0;
+    }
+    if let Err(e) = ensure_wallet_running(&*wallet) {
+        error = LibWalletError::from(e).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
     }
 
-    match (*wallet).runtime.block_on(
+    if let Err(e) = (*wallet).runtime.block_on(
         (*wallet)
             .wallet
-            .transaction_service
-            .cancel_transaction(TxId::from(transaction_id)),
+            .store_and_forward_requester
+            .request_saf_messages_from_neighbours(),
     ) {
-        Ok(_) => true,
+        error = LibWalletError::from(e).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
+
+    match (*wallet)
+        .runtime
+        .block_on((*wallet).wallet.output_manager_service.validate_txos())
+    {
+        Ok(request_key) => request_key,
         Err(e) => {
-            error = LibWalletError::from(WalletError::TransactionServiceError(e)).code;
+            error = LibWalletError::from(WalletError::OutputManagerError(e)).code;
             ptr::swap(error_out, &mut error as *mut c_int);
-            false
+            0
         },
     }
 }
 
-/// This function will tell the wallet to query the set base node to confirm the status of transaction outputs
-/// (TXOs).
+/// This function will tell the wallet to reset the validation state of all its transaction outputs (TXOs) and
+/// revalidate every one of them against the set base node. Unlike `wallet_start_txo_validation`, outputs that were
+/// already validated are checked again, which makes this the fix for a wallet whose balance has gone out of sync.
 ///
 /// ## Arguments
 /// `wallet` - The TariWallet pointer
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
-/// as an out parameter.
+/// as an out parameter. It is set to 116 if a TXO validation is already running, in which case nothing is reset.
 ///
 /// ## Returns
-/// `c_ulonglong` -  Returns a unique Request Key that is used to identify which callbacks refer to this specific sync
-/// request. Note the result will be 0 if there was an error
+/// `c_ulonglong` -  Returns a unique Request Key that is used to identify which `callback_txo_validation_complete`
+/// call refers to this revalidation. Note the result will be 0 if there was an error
 ///
 /// # Safety
 /// None
 #[no_mangle]
-pub unsafe extern "C" fn wallet_start_txo_validation(wallet: *mut TariWallet, error_out: *mut c_int) -> c_ulonglong {
+pub unsafe extern "C" fn wallet_revalidate_all_outputs(wallet: *mut TariWallet, error_out: *mut c_int) -> c_ulonglong {
     let mut error = 0;
     ptr::swap(error_out, &mut error as *mut c_int);
     if wallet.is_null() {
@@ -8451,13 +14111,7 @@ pub unsafe extern "C" fn wallet_start_txo_validation(wallet: *mut TariWallet, er
         ptr::swap(error_out, &mut error as *mut c_int);
         return 0;
     }
-
-    if let Err(e) = (*wallet).runtime.block_on(
-        (*wallet)
-            .wallet
-            .store_and_forward_requester
-            .request_saf_messages_from_neighbours(),
-    ) {
+    if let Err(e) = ensure_wallet_running(&*wallet) {
         error = LibWalletError::from(e).code;
         ptr::swap(error_out, &mut error as *mut c_int);
         return 0;
@@ -8465,7 +14119,7 @@ pub unsafe extern "C" fn wallet_start_txo_validation(wallet: *mut TariWallet, er
 
     match (*wallet)
         .runtime
-        .block_on((*wallet).wallet.output_manager_service.validate_txos())
+        .block_on((*wallet).wallet.output_manager_service.revalidate_all_outputs())
     {
         Ok(request_key) => request_key,
         Err(e) => {
@@ -8501,6 +14155,11 @@ pub unsafe extern "C" fn wallet_start_transaction_validation(
         ptr::swap(error_out, &mut error as *mut c_int);
         return 0;
     }
+    if let Err(e) = ensure_wallet_running(&*wallet) {
+        error = LibWalletError::from(e).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
 
     if let Err(e) = (*wallet).runtime.block_on(
         (*wallet)
@@ -8548,6 +14207,11 @@ pub unsafe extern "C" fn wallet_restart_transaction_broadcast(wallet: *mut TariW
         ptr::swap(error_out, &mut error as *mut c_int);
         return false;
     }
+    if let Err(e) = ensure_wallet_running(&*wallet) {
+        error = LibWalletError::from(e).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
 
     if let Err(e) = (*wallet).runtime.block_on(
         (*wallet)
@@ -8607,6 +14271,65 @@ pub unsafe extern "C" fn wallet_get_seed_words(wallet: *mut TariWallet, error_ou
     }
 }
 
+/// Gets the seed words representing the seed private key of the provided `TariWallet` in the requested mnemonic
+/// language.
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer
+/// `language` - The required language as a string, as accepted by `seed_words_get_mnemonic_word_list_for_language`
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `*mut TariSeedWords` - A collection of the seed words. Note that it returns ptr::null_mut() if wallet or language is
+/// null or if the language is not supported
+///
+/// # Safety
+/// The ```tari_seed_words_destroy``` method must be called when finished with a
+/// TariSeedWords to prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn wallet_get_seed_words_in_language(
+    wallet: *mut TariWallet,
+    language: *const c_char,
+    error_out: *mut c_int,
+) -> *mut TariSeedWords {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+    if language.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("language".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+
+    let language = match CStr::from_ptr(language)
+        .to_str()
+        .ok()
+        .and_then(|l| TariMnemonicLanguage::from_str(l).ok())
+    {
+        Some(language) => language,
+        None => {
+            error = LibWalletError::from(MnemonicError::UnknownLanguage).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return ptr::null_mut();
+        },
+    };
+
+    match (*wallet).wallet.get_seed_words(&language) {
+        Ok(seed_words) => Box::into_raw(Box::new(TariSeedWords(seed_words))),
+        Err(e) => {
+            error = LibWalletError::from(e).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            ptr::null_mut()
+        },
+    }
+}
+
 /// Set the power mode of the wallet to Low Power mode which will reduce the amount of network operations the wallet
 /// performs to conserve power
 ///
@@ -8666,7 +14389,8 @@ pub unsafe extern "C" fn wallet_set_normal_power_mode(wallet: *mut TariWallet, e
 ///
 /// ## Arguments
 /// `wallet` - The TariWallet pointer.
-/// `key` - The pointer to a Utf8 string representing the Key
+/// `key` - The pointer to a Utf8 string representing the Key. Keys reserved by this library, such as the one holding
+/// the default message, fail with an `InvalidArgument` error (code 7)
 /// `value` - The pointer to a Utf8 string representing the Value ot be stored
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
@@ -8729,6 +14453,16 @@ pub unsafe extern "C" fn wallet_set_key_value(
         }
     }
 
+    if key_string == DEFAULT_MESSAGE_KEY {
+        error = LibWalletError::from(InterfaceError::InvalidArgument(format!(
+            "key {} is reserved",
+            key_string
+        )))
+        .code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+
     match (*wallet).wallet.db.set_client_key_value(key_string, value_string) {
         Ok(_) => true,
         Err(e) => {
@@ -8743,7 +14477,8 @@ pub unsafe extern "C" fn wallet_set_key_value(
 ///
 /// ## Arguments
 /// `wallet` - The TariWallet pointer.
-/// `key` - The pointer to a Utf8 string representing the Key
+/// `key` - The pointer to a Utf8 string representing the Key. Keys reserved by this library, such as the one holding
+/// the default message, fail with an `InvalidArgument` error (code 7)
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
@@ -8786,6 +14521,16 @@ pub unsafe extern "C" fn wallet_get_value(
         }
     }
 
+    if key_string == DEFAULT_MESSAGE_KEY {
+        error = LibWalletError::from(InterfaceError::InvalidArgument(format!(
+            "key {} is reserved",
+            key_string
+        )))
+        .code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+
     match (*wallet).wallet.db.get_client_key_value(key_string) {
         Ok(result) => match result {
             None => {
@@ -8806,26 +14551,241 @@ pub unsafe extern "C" fn wallet_get_value(
     }
 }
 
-/// Clears a Value for the provided Key Value in the Wallet storage used for Client Key Value store
+/// get a stored Value that was previously stored in the Wallet storage used for Client Key Value store. Unlike
+/// `wallet_get_value` a missing Key is not treated as an error.
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer.
+/// `key` - The pointer to a Utf8 string representing the Key. Keys reserved by this library, such as the one holding
+/// the default message, fail with an `InvalidArgument` error (code 7)
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `*mut c_char` - Returns a pointer to a char array of the Value string. Note that it returns a null pointer if the
+/// Key is not found, in which case the error_ptr is not set, or if an error occured.
+///
+/// # Safety
+/// The ```string_destroy``` method must be called when finished with a string from rust to prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn wallet_get_key_value(
+    wallet: *mut TariWallet,
+    key: *const c_char,
+    error_out: *mut c_int,
+) -> *mut c_char {
+    let value = wallet_get_value(wallet, key, error_out);
+    if value.is_null() &&
+        *error_out == LibWalletError::from(WalletError::WalletStorageError(WalletStorageError::ValuesNotFound)).code
+    {
+        let mut error = 0;
+        ptr::swap(error_out, &mut error as *mut c_int);
+    }
+    value
+}
+
+/// Clears a Value for the provided Key Value in the Wallet storage used for Client Key Value store
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer.
+/// `key` - The pointer to a Utf8 string representing the Key. Keys reserved by this library, such as the one holding
+/// the default message, fail with an `InvalidArgument` error (code 7)
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `bool` - Return a boolean value indicating the operation's success or failure. The error_ptr will hold the error
+/// code if there was a failure
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn wallet_clear_value(
+    wallet: *mut TariWallet,
+    key: *const c_char,
+    error_out: *mut c_int,
+) -> bool {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+
+    let key_string;
+    if key.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("key".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    } else {
+        match CStr::from_ptr(key).to_str() {
+            Ok(v) => {
+                key_string = v.to_owned();
+            },
+            _ => {
+                error = LibWalletError::from(InterfaceError::PointerError("key".to_string())).code;
+                ptr::swap(error_out, &mut error as *mut c_int);
+                return false;
+            },
+        }
+    }
+
+    if key_string == DEFAULT_MESSAGE_KEY {
+        error = LibWalletError::from(InterfaceError::InvalidArgument(format!(
+            "key {} is reserved",
+            key_string
+        )))
+        .code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+
+    match (*wallet).wallet.db.clear_client_value(key_string) {
+        Ok(result) => result,
+        Err(e) => {
+            error = LibWalletError::from(WalletError::WalletStorageError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            false
+        },
+    }
+}
+
+/// Sets the message that `wallet_send_transaction`, `wallet_send_one_sided_transaction` and
+/// `wallet_send_transaction_to_many` attach to a transaction when they are called without a message. The message is
+/// kept under a reserved key of the Wallet storage used for Client Key Value store, so it persists across
+/// restarts but can not be changed through `wallet_set_key_value` or `wallet_clear_value`.
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer.
+/// `message` - The pointer to a char array, at most 256 bytes long, otherwise it fails with a `MessageTooLong` error
+/// (code 11). If it is null or empty the default message is cleared
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `bool` - Return a boolean value indicating the operation's success or failure. The error_ptr will hold the error
+/// code if there was a failure
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn wallet_set_default_message(
+    wallet: *mut TariWallet,
+    message: *const c_char,
+    error_out: *mut c_int,
+) -> bool {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+
+    let message_string = if message.is_null() {
+        String::new()
+    } else {
+        match CStr::from_ptr(message).to_str() {
+            Ok(v) => v.to_owned(),
+            _ => {
+                error = LibWalletError::from(InterfaceError::PointerError("message".to_string())).code;
+                ptr::swap(error_out, &mut error as *mut c_int);
+                return false;
+            },
+        }
+    };
+    if message_string.len() > MAX_TRANSACTION_MESSAGE_LENGTH {
+        error = LibWalletError::from(InterfaceError::MessageTooLong {
+            length: message_string.len(),
+            max: MAX_TRANSACTION_MESSAGE_LENGTH,
+        })
+        .code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+
+    let result = if message_string.is_empty() {
+        (*wallet)
+            .wallet
+            .db
+            .clear_client_value(DEFAULT_MESSAGE_KEY.to_owned())
+            .map(|_| ())
+    } else {
+        (*wallet)
+            .wallet
+            .db
+            .set_client_key_value(DEFAULT_MESSAGE_KEY.to_owned(), message_string)
+    };
+    match result {
+        Ok(_) => true,
+        Err(e) => {
+            error = LibWalletError::from(WalletError::WalletStorageError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            false
+        },
+    }
+}
+
+/// Gets the message that the send functions attach to a transaction when they are called without a message, as set
+/// with `wallet_set_default_message`
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer.
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `*mut c_char` - Returns a pointer to a char array of the default message, which is empty if no default message is
+/// set. Note that it returns a null pointer if an error occured.
+///
+/// # Safety
+/// The ```string_destroy``` method must be called when finished with a string from rust to prevent a memory leak
+#[no_mangle]
+pub unsafe extern "C" fn wallet_get_default_message(wallet: *mut TariWallet, error_out: *mut c_int) -> *mut c_char {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+
+    match (*wallet).wallet.db.get_client_key_value(DEFAULT_MESSAGE_KEY.to_owned()) {
+        Ok(message) => match CString::new(message.unwrap_or_default()) {
+            Ok(v) => CString::into_raw(v),
+            _ => {
+                error = LibWalletError::from(InterfaceError::PointerError("message".to_string())).code;
+                ptr::swap(error_out, &mut error as *mut c_int);
+                ptr::null_mut()
+            },
+        },
+        Err(e) => {
+            error = LibWalletError::from(WalletError::WalletStorageError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            ptr::null_mut()
+        },
+    }
+}
+
+/// Check if a Wallet has the data of an In Progress Recovery in its database.
 ///
 /// ## Arguments
 /// `wallet` - The TariWallet pointer.
-/// `key` - The pointer to a Utf8 string representing the Key
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
 /// ## Returns
-/// `bool` - Return a boolean value indicating the operation's success or failure. The error_ptr will hold the error
-/// code if there was a failure
+/// `bool` - Return a boolean value indicating whether there is an in progress recovery or not. An error will also
+/// result in a false result. Unlike the `recovery_in_progress` out parameter of `wallet_create`, which is only written
+/// once at startup, this reads the recovery marker from the database on every call so it can be polled.
 ///
 /// # Safety
 /// None
 #[no_mangle]
-pub unsafe extern "C" fn wallet_clear_value(
-    wallet: *mut TariWallet,
-    key: *const c_char,
-    error_out: *mut c_int,
-) -> bool {
+pub unsafe extern "C" fn wallet_is_recovery_in_progress(wallet: *mut TariWallet, error_out: *mut c_int) -> bool {
     let mut error = 0;
     ptr::swap(error_out, &mut error as *mut c_int);
 
@@ -8835,35 +14795,19 @@ pub unsafe extern "C" fn wallet_clear_value(
         return false;
     }
 
-    let key_string;
-    if key.is_null() {
-        error = LibWalletError::from(InterfaceError::NullError("key".to_string())).code;
-        ptr::swap(error_out, &mut error as *mut c_int);
-        return false;
-    } else {
-        match CStr::from_ptr(key).to_str() {
-            Ok(v) => {
-                key_string = v.to_owned();
-            },
-            _ => {
-                error = LibWalletError::from(InterfaceError::PointerError("key".to_string())).code;
-                ptr::swap(error_out, &mut error as *mut c_int);
-                return false;
-            },
-        }
-    }
-
-    match (*wallet).wallet.db.clear_client_value(key_string) {
+    match (*wallet).wallet.is_recovery_in_progress() {
         Ok(result) => result,
         Err(e) => {
-            error = LibWalletError::from(WalletError::WalletStorageError(e)).code;
+            error = LibWalletError::from(e).code;
             ptr::swap(error_out, &mut error as *mut c_int);
             false
         },
     }
 }
 
-/// Check if a Wallet has the data of an In Progress Recovery in its database.
+/// Gets the output counts from the most recent UTXO scan, which is either the last recovery or the last background
+/// scan that checked outputs. This helps tell an empty chain history apart from outputs that were found with this
+/// wallet's keys but could not be recovered.
 ///
 /// ## Arguments
 /// `wallet` - The TariWallet pointer.
@@ -8871,13 +14815,49 @@ pub unsafe extern "C" fn wallet_clear_value(
 /// as an out parameter.
 ///
 /// ## Returns
-/// `bool` - Return a boolean value indicating whether there is an in progress recovery or not. An error will also
-/// result in a false result.
+/// `*mut TariVector` - Returns a `U64` vector of `[outputs_scanned, outputs_recovered, outputs_failed_decode]`, where
+/// `outputs_failed_decode` counts outputs whose spending key was recovered but whose script key could not be derived.
+/// All counts are zero if no scan has completed a round since the wallet started. Note that it returns ptr::null_mut()
+/// if wallet is null.
+///
+/// # Safety
+/// `destroy_tari_vector()` must be called after use.
+#[no_mangle]
+pub unsafe extern "C" fn wallet_get_recovery_stats(wallet: *mut TariWallet, error_out: *mut c_int) -> *mut TariVector {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
+
+    let stats = (*wallet).wallet.utxo_scanner_service.get_recovery_stats();
+    Box::into_raw(Box::new(TariVector::from(vec![
+        stats.outputs_scanned,
+        stats.outputs_recovered,
+        stats.outputs_failed_decode,
+    ])))
+}
+
+/// Abandons an in progress recovery by clearing the recovery marker and the UTXO scanner progress from the wallet
+/// database, so that the next scan starts from the wallet birthday. Outputs that have already been recovered are not
+/// removed.
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer.
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `bool` - Return a boolean value indicating whether any recovery state was cleared. An error will also result in a
+/// false result.
 ///
 /// # Safety
 /// None
 #[no_mangle]
-pub unsafe extern "C" fn wallet_is_recovery_in_progress(wallet: *mut TariWallet, error_out: *mut c_int) -> bool {
+pub unsafe extern "C" fn wallet_clear_recovery_state(wallet: *mut TariWallet, error_out: *mut c_int) -> bool {
     let mut error = 0;
     ptr::swap(error_out, &mut error as *mut c_int);
 
@@ -8887,10 +14867,23 @@ pub unsafe extern "C" fn wallet_is_recovery_in_progress(wallet: *mut TariWallet,
         return false;
     }
 
-    match (*wallet).wallet.is_recovery_in_progress() {
-        Ok(result) => result,
+    let had_scanned_blocks = match (*wallet).wallet.db.get_scanned_blocks() {
+        Ok(blocks) => !blocks.is_empty(),
         Err(e) => {
-            error = LibWalletError::from(e).code;
+            error = LibWalletError::from(WalletError::WalletStorageError(e)).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return false;
+        },
+    };
+    if let Err(e) = (*wallet).wallet.db.clear_scanned_blocks() {
+        error = LibWalletError::from(WalletError::WalletStorageError(e)).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+    match (*wallet).wallet.db.clear_client_value(RECOVERY_KEY.to_owned()) {
+        Ok(had_recovery_key) => had_recovery_key || had_scanned_blocks,
+        Err(e) => {
+            error = LibWalletError::from(WalletError::WalletStorageError(e)).code;
             ptr::swap(error_out, &mut error as *mut c_int);
             false
         },
@@ -8968,86 +14961,281 @@ pub unsafe extern "C" fn wallet_start_recovery(
         ptr::swap(error_out, &mut error as *mut c_int);
         return false;
     }
+    if let Err(e) = ensure_wallet_running(&*wallet) {
+        error = LibWalletError::from(e).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+
+    let recovery_task = match build_recovery_task(&*wallet, base_node_public_keys, recovered_output_message) {
+        Ok(task) => task,
+        Err(e) => {
+            error = e.code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return false;
+        },
+    };
+    spawn_recovery_task(&*wallet, recovery_task, recovery_progress_callback);
 
-    let shutdown_signal = (*wallet).shutdown.to_signal();
+    true
+}
+
+/// Builds the UTXO scanner task used by `wallet_start_recovery` and `wallet_rescan_from_height`. It scans against
+/// the given base nodes, or against the seed peers of the wallet if `base_node_public_keys` is null.
+unsafe fn build_recovery_task(
+    wallet: &TariWallet,
+    base_node_public_keys: *mut TariPublicKeys,
+    recovered_output_message: *const c_char,
+) -> Result<UtxoScannerService<WalletSqliteDatabase, WalletConnectivityHandle>, LibWalletError> {
+    let shutdown_signal = wallet.shutdown.to_signal();
     let peer_public_keys = if base_node_public_keys.is_null() {
-        let peer_manager = (*wallet).wallet.comms.peer_manager();
+        let peer_manager = wallet.wallet.comms.peer_manager();
         let query = PeerQuery::new().select_where(|p| p.is_seed());
-        #[allow(clippy::blocks_in_conditions)]
-        match (*wallet).runtime.block_on(async move {
-            let peers = peer_manager.perform_query(query).await?;
-            let mut public_keys = Vec::with_capacity(peers.len());
-            for peer in peers {
-                public_keys.push(peer.public_key);
-            }
-            Result::<_, WalletError>::Ok(public_keys)
-        }) {
-            Ok(public_keys) => public_keys,
-            Err(e) => {
-                error = LibWalletError::from(InterfaceError::NullError(format!("{}", e))).code;
-                ptr::swap(error_out, &mut error as *mut c_int);
-                return false;
-            },
-        }
+        wallet
+            .runtime
+            .block_on(async move {
+                let peers = peer_manager.perform_query(query).await?;
+                let mut public_keys = Vec::with_capacity(peers.len());
+                for peer in peers {
+                    public_keys.push(peer.public_key);
+                }
+                Result::<_, WalletError>::Ok(public_keys)
+            })
+            .map_err(|e| LibWalletError::from(InterfaceError::NullError(format!("{}", e))))?
     } else {
         (*base_node_public_keys).0.clone()
     };
     let mut recovery_task_builder = UtxoScannerService::<WalletSqliteDatabase, WalletConnectivityHandle>::builder();
 
     if !recovered_output_message.is_null() {
-        let message_str = match CStr::from_ptr(recovered_output_message).to_str() {
-            Ok(v) => v.to_owned(),
-            _ => {
-                error = LibWalletError::from(InterfaceError::PointerError("recovered_output_message".to_string())).code;
-                ptr::swap(error_out, &mut error as *mut c_int);
-                return false;
-            },
-        };
+        let message_str = CStr::from_ptr(recovered_output_message)
+            .to_str()
+            .map_err(|_| LibWalletError::from(InterfaceError::PointerError("recovered_output_message".to_string())))?
+            .to_owned();
         recovery_task_builder.with_recovery_message(message_str);
     }
-    let runtime = match Runtime::new() {
-        Ok(r) => r,
+    let runtime = Runtime::new().map_err(|e| LibWalletError::from(InterfaceError::TokioError(e.to_string())))?;
+    runtime
+        .block_on(async {
+            recovery_task_builder
+                .with_peers(peer_public_keys)
+                .with_retry_limit(10)
+                .build_with_wallet(&wallet.wallet, shutdown_signal)
+                .await
+        })
+        .map_err(|e| LibWalletError::from(WalletError::KeyManagerServiceError(e)))
+}
+
+/// Builds the UTXO scanner task used by `wallet_rescan_from_height`. The scan start height is set first, as the task
+/// takes its start height when it is built.
+unsafe fn build_rescan_task(
+    wallet: &mut TariWallet,
+    height: u64,
+) -> Result<UtxoScannerService<WalletSqliteDatabase, WalletConnectivityHandle>, LibWalletError> {
+    wallet.wallet.utxo_scanner_service.set_scan_start_height(height);
+    build_recovery_task(wallet, ptr::null_mut(), ptr::null())
+}
+
+/// Runs a task built by `build_recovery_task` on the wallet runtime, reporting its progress to
+/// `recovery_progress_callback`
+fn spawn_recovery_task(
+    wallet: &TariWallet,
+    mut recovery_task: UtxoScannerService<WalletSqliteDatabase, WalletConnectivityHandle>,
+    recovery_progress_callback: unsafe extern "C" fn(context: *mut c_void, u8, u64, u64),
+) {
+    let event_stream = recovery_task.get_event_receiver();
+    let recovery_join_handle = wallet.runtime.spawn(recovery_task.run());
+
+    // Spawn a task to monitor the recovery process events and call the callback appropriately
+    wallet.runtime.spawn(recovery_event_monitoring(
+        event_stream,
+        recovery_join_handle,
+        recovery_progress_callback,
+        wallet.context,
+    ));
+}
+
+/// Rescans the blockchain for the outputs of this wallet from the given height. The progress of the UTXO scanner is
+/// cleared, the scan start height is set as with `wallet_set_scan_start_height` and the scanner is started against
+/// the seed peers, as when `wallet_start_recovery` is called without base node public keys. Outputs that have already
+/// been found are kept.
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer.
+/// `height` - The block height to start scanning from
+/// `recovery_progress_callback` - The callback function pointer that will be used to asynchronously communicate
+/// progress to the client, with the same events as the `recovery_progress_callback` of `wallet_start_recovery`
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `bool` - Return a boolean value indicating whether the rescan started successfully or not. If a recovery or rescan
+/// is already in progress no new rescan is started and `error_out` is set to a "busy" error.
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn wallet_rescan_from_height(
+    wallet: *mut TariWallet,
+    height: c_ulonglong,
+    recovery_progress_callback: unsafe extern "C" fn(context: *mut c_void, u8, u64, u64),
+    error_out: *mut c_int,
+) -> bool {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+    if let Err(e) = ensure_wallet_running(&*wallet) {
+        error = LibWalletError::from(e).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+
+    match (*wallet).wallet.is_recovery_in_progress() {
+        Ok(false) => {},
+        Ok(true) => {
+            error = LibWalletError::from(InterfaceError::ScanInProgress).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return false;
+        },
         Err(e) => {
-            error = LibWalletError::from(InterfaceError::TokioError(e.to_string())).code;
+            error = LibWalletError::from(e).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            return false;
+        },
+    }
+    // The scanner is built before any scan progress is cleared, so a failure to build it leaves the scanned blocks as
+    // they were
+    let recovery_task = match build_rescan_task(&mut *wallet, height) {
+        Ok(task) => task,
+        Err(e) => {
+            error = e.code;
             ptr::swap(error_out, &mut error as *mut c_int);
             return false;
         },
     };
-    let mut recovery_task = match runtime.block_on(async {
-        recovery_task_builder
-            .with_peers(peer_public_keys)
-            .with_retry_limit(10)
-            .build_with_wallet(&(*wallet).wallet, shutdown_signal)
-            .await
-    }) {
-        Ok(v) => v,
+    if let Err(e) = (*wallet).wallet.db.clear_scanned_blocks() {
+        error = LibWalletError::from(WalletError::WalletStorageError(e)).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+    // The recovery marker is set here already, rather than only once the scanner task runs, so that a rescan
+    // requested straight after this one is reported as busy
+    if let Err(e) = (*wallet)
+        .wallet
+        .db
+        .set_client_key_value(RECOVERY_KEY.to_owned(), Utc::now().to_string())
+    {
+        error = LibWalletError::from(WalletError::WalletStorageError(e)).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+    spawn_recovery_task(&*wallet, recovery_task, recovery_progress_callback);
+
+    true
+}
+
+/// Set the text message that is applied to a detected One-Side payment transaction when it is scanned from the
+/// blockchain
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer.
+/// `message` - The pointer to a Utf8 string representing the Message
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `bool` - Return a boolean value indicating the operation's success or failure. The error_ptr will hold the error
+/// code if there was a failure
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn wallet_set_one_sided_payment_message(
+    wallet: *mut TariWallet,
+    message: *const c_char,
+    error_out: *mut c_int,
+) -> bool {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+
+    let message_string;
+    if message.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("message".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    } else {
+        match CStr::from_ptr(message).to_str() {
+            Ok(v) => {
+                message_string = v.to_owned();
+            },
+            _ => {
+                error = LibWalletError::from(InterfaceError::PointerError("message".to_string())).code;
+                ptr::swap(error_out, &mut error as *mut c_int);
+                return false;
+            },
+        }
+    }
+
+    (*wallet)
+        .wallet
+        .utxo_scanner_service
+        .set_one_sided_payment_message(message_string);
+
+    true
+}
+
+/// Gets the birthday of the wallet, as stored from its `CipherSeed` when the wallet was created. Recovery and scanning
+/// start from shortly before this date unless a start height is set with `wallet_set_scan_start_height`.
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer.
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `c_ulonglong` - Returns the birthday as the number of days since 2022-01-01 UTC, or 0 if there was an error
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn wallet_get_birthday(wallet: *mut TariWallet, error_out: *mut c_int) -> c_ulonglong {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return 0;
+    }
+
+    match (*wallet).wallet.db.get_wallet_birthday() {
+        Ok(birthday) => c_ulonglong::from(birthday),
         Err(e) => {
-            error = LibWalletError::from(WalletError::KeyManagerServiceError(e)).code;
+            error = LibWalletError::from(WalletError::WalletStorageError(e)).code;
             ptr::swap(error_out, &mut error as *mut c_int);
-            return false;
+            0
         },
-    };
-
-    let event_stream = recovery_task.get_event_receiver();
-    let recovery_join_handle = (*wallet).runtime.spawn(recovery_task.run());
-
-    // Spawn a task to monitor the recovery process events and call the callback appropriately
-    (*wallet).runtime.spawn(recovery_event_monitoring(
-        event_stream,
-        recovery_join_handle,
-        recovery_progress_callback,
-        (*wallet).context,
-    ));
-
-    true
+    }
 }
 
-/// Set the text message that is applied to a detected One-Side payment transaction when it is scanned from the
-/// blockchain
+/// Sets the block height from which the UTXO scanner starts when it has no previously scanned blocks to continue from,
+/// i.e. for a recovery started with `wallet_start_recovery`. This replaces the start height derived from the wallet
+/// birthday and allows a targeted rescan from a known height. A height beyond the chain tip starts the scan at the tip.
 ///
 /// ## Arguments
 /// `wallet` - The TariWallet pointer.
-/// `message` - The pointer to a Utf8 string representing the Message
+/// `height` - The block height to start scanning from
 /// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
 /// as an out parameter.
 ///
@@ -9058,9 +15246,9 @@ pub unsafe extern "C" fn wallet_start_recovery(
 /// # Safety
 /// None
 #[no_mangle]
-pub unsafe extern "C" fn wallet_set_one_sided_payment_message(
+pub unsafe extern "C" fn wallet_set_scan_start_height(
     wallet: *mut TariWallet,
-    message: *const c_char,
+    height: c_ulonglong,
     error_out: *mut c_int,
 ) -> bool {
     let mut error = 0;
@@ -9072,28 +15260,7 @@ pub unsafe extern "C" fn wallet_set_one_sided_payment_message(
         return false;
     }
 
-    let message_string;
-    if message.is_null() {
-        error = LibWalletError::from(InterfaceError::NullError("message".to_string())).code;
-        ptr::swap(error_out, &mut error as *mut c_int);
-        return false;
-    } else {
-        match CStr::from_ptr(message).to_str() {
-            Ok(v) => {
-                message_string = v.to_owned();
-            },
-            _ => {
-                error = LibWalletError::from(InterfaceError::PointerError("message".to_string())).code;
-                ptr::swap(error_out, &mut error as *mut c_int);
-                return false;
-            },
-        }
-    }
-
-    (*wallet)
-        .wallet
-        .utxo_scanner_service
-        .set_one_sided_payment_message(message_string);
+    (*wallet).wallet.utxo_scanner_service.set_scan_start_height(height);
 
     true
 }
@@ -9199,6 +15366,71 @@ pub unsafe extern "C" fn emoji_set_destroy(emoji_set: *mut EmojiSet) {
     }
 }
 
+/// Stops the wallet services and the network activity of a TariWallet without freeing it. Once stopped, functions that
+/// need the wallet services fail with a "wallet stopped" error. The handle must still be freed with `wallet_destroy`.
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `bool` - Returns true once the wallet services have shut down, false if the wallet is null or was already stopped
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn wallet_stop(wallet: *mut TariWallet, error_out: *mut c_int) -> bool {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+    if let Err(e) = ensure_wallet_running(&*wallet) {
+        error = LibWalletError::from(e).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+
+    debug!(target: LOG_TARGET, "Wallet stop called");
+    (*wallet).shutdown.trigger();
+    let w = (*wallet).wallet.clone();
+    match block_on_runtime(&(*wallet).runtime, w.wait_until_shutdown()) {
+        Ok(()) => true,
+        Err(e) => {
+            error = LibWalletError::from(e).code;
+            ptr::swap(error_out, &mut error as *mut c_int);
+            false
+        },
+    }
+}
+
+/// Checks whether the wallet services of a TariWallet are running, i.e. `wallet_stop` has not been called on it
+///
+/// ## Arguments
+/// `wallet` - The TariWallet pointer
+/// `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+/// as an out parameter.
+///
+/// ## Returns
+/// `bool` - Returns true if the wallet is running, false if it has been stopped or the wallet is null
+///
+/// # Safety
+/// None
+#[no_mangle]
+pub unsafe extern "C" fn wallet_is_running(wallet: *mut TariWallet, error_out: *mut c_int) -> bool {
+    let mut error = 0;
+    ptr::swap(error_out, &mut error as *mut c_int);
+    if wallet.is_null() {
+        error = LibWalletError::from(InterfaceError::NullError("wallet".to_string())).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return false;
+    }
+    ensure_wallet_running(&*wallet).is_ok()
+}
+
 /// Frees memory for a TariWallet
 ///
 /// ## Arguments
@@ -9295,6 +15527,11 @@ pub unsafe extern "C" fn wallet_get_fee_per_gram_stats(
         ptr::swap(error_out, &mut error as *mut c_int);
         return ptr::null_mut();
     }
+    if let Err(e) = ensure_wallet_running(&*wallet) {
+        error = LibWalletError::from(e).code;
+        ptr::swap(error_out, &mut error as *mut c_int);
+        return ptr::null_mut();
+    }
 
     match (*wallet).runtime.block_on(
         (*wallet)
@@ -9584,21 +15821,28 @@ mod test {
     use std::{ffi::c_void, path::Path, str::from_utf8, sync::Mutex};
 
     use minotari_wallet::{
+        connectivity_service::OnlineStatus,
         storage::sqlite_utilities::run_migration_and_create_sqlite_connection,
         transaction_service::handle::TransactionSendStatus,
     };
     use once_cell::sync::Lazy;
-    use tari_common_types::{emoji, tari_address::TariAddressFeatures, types::PrivateKey};
-    use tari_comms::peer_manager::PeerFeatures;
+    use tari_common_types::{
+        emoji,
+        key_branches::TransactionKeyManagerBranch,
+        tari_address::TariAddressFeatures,
+        types::PrivateKey,
+    };
+    use tari_comms::{peer_manager::PeerFeatures, tor};
     use tari_contacts::contacts_service::types::{ChatBody, Direction, Message, MessageId, MessageMetadata};
     use tari_core::{
         covenant,
         transactions::{
             key_manager::{create_memory_db_key_manager, SecretTransactionKeyManagerInterface},
-            test_helpers::{create_test_input, create_wallet_output_with_data, TestParams},
+            test_helpers::{create_test_input, create_test_kernel, create_wallet_output_with_data, TestParams},
+            transaction_components::{KernelFeatures, Transaction},
         },
     };
-    use tari_key_manager::mnemonic_wordlists;
+    use tari_key_manager::{error::KeyManagerError, mnemonic_wordlists};
     use tari_p2p::initialization::MESSAGING_PROTOCOL_ID;
     use tari_script::script;
     use tari_test_utils::random;
@@ -9739,6 +15983,33 @@ mod test {
         let excess_hex_ptr = transaction_kernel_get_excess_hex(kernel, error_ptr);
         let excess_hex = CString::from_raw(excess_hex_ptr).to_str().unwrap().to_owned();
         assert!(!excess_hex.is_empty());
+        let kernels = completed_transaction_get_kernels(tx, error_ptr);
+        assert_eq!((*kernels).tag, TariTypeTag::Kernel);
+        assert_eq!((*kernels).len, 1);
+        let kernel_ptrs = slice::from_raw_parts((*kernels).ptr as *mut *mut TariTransactionKernel, (*kernels).len);
+        assert_eq!(*kernel_ptrs[0], *kernel);
+        transaction_kernel_destroy(kernel_ptrs[0]);
+        destroy_tari_vector(kernels);
+        let input_commitments = completed_transaction_get_input_commitments(tx, error_ptr);
+        assert_eq!(error, 0);
+        assert_eq!((*input_commitments).len, (*tx).transaction.body().inputs().len());
+        destroy_tari_vector(input_commitments);
+        let output_commitments = completed_transaction_get_output_commitments(tx, error_ptr);
+        assert_eq!(error, 0);
+        assert_eq!((*output_commitments).tag, TariTypeTag::Commitment);
+        assert_eq!((*output_commitments).len, (*tx).transaction.body().outputs().len());
+        destroy_tari_vector(output_commitments);
+        assert!(!completed_transaction_is_one_sided(tx, error_ptr));
+        assert_eq!(error, 0);
+        let fee_per_gram = completed_transaction_get_fee_per_gram(tx, error_ptr);
+        assert_eq!(error, 0);
+        let weight = (*tx)
+            .transaction
+            .calculate_weight(&transaction_weighting((*tx).mined_height).unwrap())
+            .unwrap();
+        assert_eq!(fee_per_gram, (*tx).fee.as_u64() / weight);
+        assert_eq!(completed_transaction_get_weight(tx, error_ptr), weight);
+        assert_eq!(error, 0);
         let nonce_hex_ptr = transaction_kernel_get_excess_public_nonce_hex(kernel, error_ptr);
         let nonce_hex = CString::from_raw(nonce_hex_ptr).to_str().unwrap().to_owned();
         assert!(!nonce_hex.is_empty());
@@ -9760,6 +16031,10 @@ mod test {
             std::any::type_name::<TariCompletedTransaction>()
         );
         assert_eq!((*tx).status, TransactionStatus::OneSidedConfirmed);
+        let mut error = 0;
+        let error_ptr = &mut error as *mut c_int;
+        assert!(completed_transaction_is_one_sided(tx, error_ptr));
+        assert_eq!(error, 0);
         let mut lock = CALLBACK_STATE_FFI.lock().unwrap();
         lock.scanned_tx_callback_called = true;
         drop(lock);
@@ -9869,6 +16144,10 @@ mod test {
         // assert!(true); //optimized out by compiler
     }
 
+    unsafe extern "C" fn recovery_progress_callback(_context: *mut c_void, _event: u8, _arg1: u64, _arg2: u64) {
+        // assert!(true); //optimized out by compiler
+    }
+
     #[cfg(tari_target_network_mainnet)]
     const NETWORK_STRING: &str = "stagenet";
     #[cfg(tari_target_network_nextnet)]
@@ -9892,6 +16171,21 @@ mod test {
             let byte = byte_vector_get_at(bytes_ptr, 2, error_ptr);
             assert_eq!(error, 0);
             assert_eq!(byte, bytes[2]);
+
+            let joined_ptr = byte_vector_concat(bytes_ptr, bytes_ptr, error_ptr);
+            assert_eq!(error, 0);
+            assert_eq!((*joined_ptr).0, [bytes, bytes].concat());
+            let slice_ptr = byte_vector_slice(joined_ptr, 3, 2, error_ptr);
+            assert_eq!(error, 0);
+            assert_eq!((*slice_ptr).0, vec![255, 2]);
+            assert!(byte_vector_slice(joined_ptr, 7, 2, error_ptr).is_null());
+            assert_eq!(error, LibWalletError::from(InterfaceError::PositionInvalidError).code);
+            assert!(byte_vector_slice(joined_ptr, c_uint::MAX, 2, error_ptr).is_null());
+            assert_eq!(error, LibWalletError::from(InterfaceError::PositionInvalidError).code);
+            assert!(byte_vector_concat(bytes_ptr, ptr::null(), error_ptr).is_null());
+            assert_eq!(error, 1);
+            byte_vector_destroy(slice_ptr);
+            byte_vector_destroy(joined_ptr);
             byte_vector_destroy(bytes_ptr);
         }
     }
@@ -9959,6 +16253,20 @@ mod test {
             assert_eq!(address.features().as_u8(), ffi_features);
             assert_eq!(*error_ptr, 0, "No error expected");
 
+            assert!(tari_address_supports_one_sided(test_address, error_ptr));
+            assert_eq!(*error_ptr, 0, "No error expected");
+            let interactive_address = Box::into_raw(Box::new(TariAddress::new_dual_address(
+                view_key.clone(),
+                spend_key.clone(),
+                Network::Esmeralda,
+                TariAddressFeatures::create_interactive_only(),
+            )));
+            assert!(!tari_address_supports_one_sided(interactive_address, error_ptr));
+            assert_eq!(*error_ptr, 0, "No error expected");
+            assert_eq!(tari_address_features_u8(ptr::null_mut(), error_ptr), 0);
+            assert_eq!(*error_ptr, 1, "Null error expected");
+            tari_address_destroy(interactive_address);
+
             let ffi_checksum = tari_address_checksum_u8(test_address, error_ptr);
             assert_eq!(address.calculate_checksum(), ffi_checksum);
             assert_eq!(*error_ptr, 0, "No error expected");
@@ -9966,7 +16274,89 @@ mod test {
             let ffi_network = tari_address_network_u8(test_address, error_ptr);
             assert_eq!(address.network() as u8, ffi_network);
             assert_eq!(*error_ptr, 0, "No error expected");
+            assert!(tari_address_matches_network(
+                test_address,
+                c_uint::from(Network::Esmeralda.as_byte()),
+                error_ptr
+            ));
+            assert_eq!(*error_ptr, 0, "No error expected");
+            assert!(!tari_address_matches_network(
+                test_address,
+                c_uint::from(Network::NextNet.as_byte()),
+                error_ptr
+            ));
+            assert_eq!(*error_ptr, 0, "No error expected");
+            assert!(!tari_address_matches_network(test_address, 255, error_ptr));
+            assert_eq!(*error_ptr, 701, "Invalid network error expected");
+            assert!(!tari_address_matches_network(ptr::null_mut(), 0, error_ptr));
+            assert_eq!(*error_ptr, 1, "Null error expected");
+
+            let same_address = Box::into_raw(Box::new(address.clone()));
+            assert!(tari_address_equals(test_address, same_address, error_ptr));
+            assert_eq!(*error_ptr, 0, "No error expected");
+            let other_network_address = Box::into_raw(Box::new(TariAddress::new_dual_address(
+                view_key.clone(),
+                spend_key.clone(),
+                Network::NextNet,
+                TariAddressFeatures::create_one_sided_only(),
+            )));
+            assert!(!tari_address_equals(test_address, other_network_address, error_ptr));
+            assert_eq!(*error_ptr, 0, "No error expected");
+            assert!(!tari_address_equals(test_address, ptr::null_mut(), error_ptr));
+            assert_ne!(*error_ptr, 0, "Error expected");
+
+            let ffi_view_key = tari_address_view_key(test_address, error_ptr);
+            let ffi_spend_key = tari_address_spend_key(test_address, error_ptr);
+            assert!(!public_key_equals(ffi_view_key, ffi_spend_key, error_ptr));
+            assert_eq!(*error_ptr, 0, "No error expected");
+            assert!(public_key_equals(ffi_view_key, &mut view_key.clone(), error_ptr));
+            assert_eq!(*error_ptr, 0, "No error expected");
+            assert!(!public_key_equals(ptr::null_mut(), ffi_view_key, error_ptr));
+            assert_ne!(*error_ptr, 0, "Error expected");
 
+            assert_eq!(*ffi_spend_key, spend_key);
+            let single_address = Box::into_raw(Box::new(TariAddress::new_single_address_with_interactive_only(
+                spend_key.clone(),
+                Network::Esmeralda,
+            )));
+            assert!(tari_address_view_key(single_address, error_ptr).is_null());
+            assert_eq!(*error_ptr, 7, "Invalid argument error expected");
+            let ffi_single_spend_key = tari_address_spend_key(single_address, error_ptr);
+            assert_eq!(*error_ptr, 0, "No error expected");
+            assert_eq!(*ffi_single_spend_key, spend_key);
+
+            let mut ffi_spend = spend_key.clone();
+            let mut ffi_view = view_key.clone();
+            let dual_address = tari_address_create_dual(
+                &mut ffi_spend,
+                &mut ffi_view,
+                c_uint::from(Network::Esmeralda.as_byte()),
+                c_int::from(TariAddressFeatures::ONE_SIDED.as_u8()),
+                error_ptr,
+            );
+            assert_eq!(*error_ptr, 0, "No error expected");
+            assert_eq!(*dual_address, address);
+            assert!(tari_address_create_dual(&mut ffi_spend, &mut ffi_view, 200, 1, error_ptr).is_null());
+            assert_eq!(*error_ptr, 701, "Invalid network error expected");
+            assert!(tari_address_create_dual(
+                &mut ffi_spend,
+                &mut ffi_view,
+                c_uint::from(Network::Esmeralda.as_byte()),
+                0,
+                error_ptr
+            )
+            .is_null());
+            assert_eq!(*error_ptr, 705, "Invalid features error expected");
+            assert!(tari_address_create_dual(ptr::null_mut(), &mut ffi_view, 0, 1, error_ptr).is_null());
+            assert_eq!(*error_ptr, 1, "Null error expected");
+            tari_address_destroy(dual_address);
+
+            public_key_destroy(ffi_single_spend_key);
+            tari_address_destroy(single_address);
+            public_key_destroy(ffi_view_key);
+            public_key_destroy(ffi_spend_key);
+            tari_address_destroy(same_address);
+            tari_address_destroy(other_network_address);
             tari_address_destroy(test_address);
         }
     }
@@ -9994,10 +16384,185 @@ mod test {
                 let seed_word = seed_words.get_word(i).unwrap();
                 assert_eq!(ffi_seed_word.to_str().unwrap().to_string(), seed_word.to_string());
             }
+
+            seed_words_clear(ffi_seed_words, error_ptr);
+            assert_eq!(*error_ptr, 0, "No error expected");
+            assert_eq!(seed_words_get_length(ffi_seed_words, error_ptr), 0);
+            seed_words_clear(ptr::null_mut(), error_ptr);
+            assert_eq!(*error_ptr, 1);
+            seed_words_destroy(ffi_seed_words);
+        }
+    }
+
+    #[test]
+    fn test_seed_words_create_from_string() {
+        unsafe {
+            let mut error = 0;
+            let error_ptr = &mut error as *mut c_int;
+            let cipher = CipherSeed::new();
+            let seed_words = cipher.to_mnemonic(MnemonicLanguage::English, None).unwrap();
+
+            let phrase = CString::new(format!("  {}\n", seed_words.join("  ").reveal())).unwrap();
+            let ffi_seed_words = seed_words_create_from_string(phrase.as_ptr(), error_ptr);
+            assert_eq!(error, 0, "No error expected");
+            assert_eq!((*ffi_seed_words).0, seed_words);
+            seed_words_destroy(ffi_seed_words);
+
+            let short_phrase = CString::new("scan couch work").unwrap();
+            let ffi_seed_words = seed_words_create_from_string(short_phrase.as_ptr(), error_ptr);
+            assert!(ffi_seed_words.is_null());
+            assert_eq!(error, 912);
+
+            let mut words = (0..seed_words.len())
+                .map(|i| seed_words.get_word(i).unwrap().clone())
+                .collect::<Vec<_>>();
+            words[3] = "hodl".to_string();
+            let bad_word_phrase = CString::new(words.join(" ")).unwrap();
+            let ffi_seed_words = seed_words_create_from_string(bad_word_phrase.as_ptr(), error_ptr);
+            assert!(ffi_seed_words.is_null());
+            assert_eq!(error, 911);
+            // the phrase specific codes are not used for mnemonic errors from other functions
+            assert_eq!(
+                LibWalletError::from(MnemonicError::WordNotFound(String::new())).code,
+                910
+            );
+            assert_eq!(LibWalletError::from(MnemonicError::EncodeInvalidLength).code, 910);
+
+            words[3] = seed_words.get_word(3).unwrap().clone();
+            words.swap(10, 11);
+            if words[10] != words[11] {
+                let bad_checksum_phrase = CString::new(words.join(" ")).unwrap();
+                let ffi_seed_words = seed_words_create_from_string(bad_checksum_phrase.as_ptr(), error_ptr);
+                assert!(ffi_seed_words.is_null());
+                assert_eq!(
+                    error,
+                    LibWalletError::from(WalletError::KeyManagerError(KeyManagerError::CrcError)).code
+                );
+            }
+
+            let ffi_seed_words = seed_words_create_from_string(ptr::null(), error_ptr);
+            assert!(ffi_seed_words.is_null());
+            assert_eq!(
+                error,
+                LibWalletError::from(InterfaceError::NullError("phrase".to_string())).code
+            );
+        }
+    }
+
+    #[test]
+    fn test_seed_words_validate_with_passphrase() {
+        unsafe {
+            let mut error = 0;
+            let error_ptr = &mut error as *mut c_int;
+            let cipher = CipherSeed::new();
+            let seed_words = cipher
+                .to_mnemonic(
+                    MnemonicLanguage::English,
+                    Some(SafePassword::from("my 25th word".to_string())),
+                )
+                .unwrap();
+            let ffi_seed_words = Box::into_raw(Box::new(TariSeedWords(seed_words)));
+
+            let passphrase = CString::new("my 25th word").unwrap();
+            assert!(seed_words_validate_with_passphrase(
+                ffi_seed_words,
+                passphrase.as_ptr(),
+                error_ptr
+            ));
+            assert_eq!(error, 0);
+
+            // Round trip, the recovered seed must match the original one
+            let recovered = CipherSeed::from_mnemonic(
+                &(*ffi_seed_words).0,
+                Some(SafePassword::from("my 25th word".to_string())),
+            )
+            .unwrap();
+            assert_eq!(recovered.entropy(), cipher.entropy());
+            assert_eq!(recovered.birthday(), cipher.birthday());
+
+            let wrong_passphrase = CString::new("not my 25th word").unwrap();
+            assert!(!seed_words_validate_with_passphrase(
+                ffi_seed_words,
+                wrong_passphrase.as_ptr(),
+                error_ptr
+            ));
+            assert_eq!(
+                error,
+                LibWalletError::from(WalletError::KeyManagerError(KeyManagerError::DecryptionFailed)).code
+            );
+
+            assert!(!seed_words_validate_with_passphrase(
+                ffi_seed_words,
+                ptr::null(),
+                error_ptr
+            ));
+            assert_ne!(error, 0);
+
             seed_words_destroy(ffi_seed_words);
         }
     }
 
+    #[test]
+    fn test_wallet_get_version_and_commit_hash() {
+        unsafe {
+            let version = wallet_get_version();
+            assert_eq!(CStr::from_ptr(version).to_str().unwrap(), consts::APP_VERSION);
+            let commit_hash = wallet_get_commit_hash();
+            assert_eq!(CStr::from_ptr(commit_hash).to_str().unwrap(), consts::APP_COMMIT);
+            assert!(consts::APP_VERSION.contains(consts::APP_COMMIT));
+            string_destroy(version);
+            string_destroy(commit_hash);
+        }
+    }
+
+    #[test]
+    fn test_emoji_id_to_tari_address_errors() {
+        unsafe {
+            let mut error = 0;
+            let error_ptr = &mut error as *mut c_int;
+            // pin the process network the same way wallet_create does so the comparison below is stable
+            let _result = set_network_if_choice_valid(Network::from_str(NETWORK_STRING).unwrap());
+            let current_network = Network::get_current_or_user_setting_or_default();
+            let other_network = if current_network == Network::MainNet {
+                Network::StageNet
+            } else {
+                Network::MainNet
+            };
+            let mut rng = rand::thread_rng();
+            let public_key = PublicKey::from_secret_key(&PrivateKey::random(&mut rng));
+
+            let address =
+                TariWalletAddress::new_single_address_with_interactive_only(public_key.clone(), current_network);
+            let emoji = CString::new(address.to_emoji_string()).unwrap();
+            let parsed = emoji_id_to_tari_address(emoji.as_ptr(), error_ptr);
+            assert_eq!(error, 0);
+            assert_eq!(*parsed, address);
+            tari_address_destroy(parsed);
+
+            let address = TariWalletAddress::new_single_address_with_interactive_only(public_key, other_network);
+            let emoji = CString::new(address.to_emoji_string()).unwrap();
+            assert!(emoji_id_to_tari_address(emoji.as_ptr(), error_ptr).is_null());
+            assert_eq!(error, 701);
+
+            let mut bytes = address.to_vec();
+            let last = bytes.len() - 1;
+            bytes[last] = bytes[last].wrapping_add(1);
+            let emoji_string = bytes.iter().map(|b| EMOJI[*b as usize]).collect::<String>();
+            let emoji = CString::new(emoji_string.clone()).unwrap();
+            assert!(emoji_id_to_tari_address(emoji.as_ptr(), error_ptr).is_null());
+            assert_eq!(error, 706);
+
+            let mut emoji_string = emoji_string.chars().collect::<Vec<_>>();
+            emoji_string[last] = 'a';
+            let emoji = CString::new(emoji_string.into_iter().collect::<String>()).unwrap();
+            assert!(emoji_id_to_tari_address(emoji.as_ptr(), error_ptr).is_null());
+            assert_eq!(error, 704);
+
+            assert!(emoji_id_to_tari_address(ptr::null(), error_ptr).is_null());
+            assert_eq!(error, 1);
+        }
+    }
+
     #[test]
     fn test_emoji_set() {
         unsafe {
@@ -10134,44 +16699,119 @@ mod test {
             let error_ptr = &mut error as *mut c_int;
             let address_listener = CString::new("/ip4/127.0.0.1/tcp/0").unwrap();
             let address_listener_str: *const c_char = CString::into_raw(address_listener) as *const c_char;
-            let transport = transport_tcp_create(address_listener_str, error_ptr);
+            let transport = transport_tcp_create(address_listener_str, 0, false, error_ptr);
+            assert_eq!(error, 0);
+            assert_eq!((*transport).tcp.dial_timeout, None);
+            assert!(!(*transport).tcp.tor_socks_bypass_for_tcp);
+            transport_config_destroy(transport);
+
+            let transport = transport_tcp_create(address_listener_str, 10, true, error_ptr);
+            assert_eq!(error, 0);
+            assert_eq!((*transport).tcp.dial_timeout, Some(Duration::from_secs(10)));
+            assert!((*transport).tcp.tor_socks_bypass_for_tcp);
+            transport_config_destroy(transport);
+
+            let proxy_address_str = CString::new("/ip4/127.0.0.1/tcp/1080").unwrap();
+            let username_str = CString::new("user").unwrap();
+            let password_str = CString::new("pass").unwrap();
+            let transport = transport_tcp_create_with_proxy(
+                address_listener_str,
+                proxy_address_str.as_ptr(),
+                username_str.as_ptr(),
+                password_str.as_ptr(),
+                error_ptr,
+            );
+            assert_eq!(error, 0);
+            assert_eq!((*transport).transport_type, TransportType::Socks5);
+            assert_eq!((*transport).socks.proxy_address.to_string(), "/ip4/127.0.0.1/tcp/1080");
+            assert!(matches!(
+                (*transport).socks.auth,
+                SocksAuthentication::UsernamePassword { .. }
+            ));
+            transport_config_destroy(transport);
+
+            let invalid_proxy_address_str = CString::new("127.0.0.1:1080").unwrap();
+            let transport = transport_tcp_create_with_proxy(
+                address_listener_str,
+                invalid_proxy_address_str.as_ptr(),
+                ptr::null(),
+                ptr::null(),
+                error_ptr,
+            );
+            assert!(transport.is_null());
+            assert_eq!(error, 7);
+        }
+    }
+
+    #[test]
+    fn test_transport_type_tor() {
+        unsafe {
+            let mut error = 0;
+            let error_ptr = &mut error as *mut c_int;
+            let address_control = CString::new("/ip4/127.0.0.1/tcp/8080").unwrap();
+            let mut bypass = false;
+            let address_control_str: *const c_char = CString::into_raw(address_control) as *const c_char;
+            let mut transport = transport_tor_create(
+                address_control_str,
+                ptr::null(),
+                8080,
+                bypass,
+                ptr::null(),
+                ptr::null(),
+                error_ptr,
+            );
+            assert_eq!(error, 0);
+            transport_config_destroy(transport);
+
+            bypass = true;
+            transport = transport_tor_create(
+                address_control_str,
+                ptr::null(),
+                8080,
+                bypass,
+                ptr::null(),
+                ptr::null(),
+                error_ptr,
+            );
             assert_eq!(error, 0);
             transport_config_destroy(transport);
-        }
-    }
 
-    #[test]
-    fn test_transport_type_tor() {
-        unsafe {
-            let mut error = 0;
-            let error_ptr = &mut error as *mut c_int;
-            let address_control = CString::new("/ip4/127.0.0.1/tcp/8080").unwrap();
-            let mut bypass = false;
-            let address_control_str: *const c_char = CString::into_raw(address_control) as *const c_char;
-            let mut transport = transport_tor_create(
+            // a saved identity is restored into the transport config
+            let identity = TorIdentity {
+                private_key: tor::PrivateKey::Ed25519V3("dummy-key".to_string()),
+                service_id: "dummy-service-id".to_string(),
+                onion_port: 8080,
+            };
+            let identity_json = CString::new(serde_json::to_string(&identity).unwrap()).unwrap();
+            transport = transport_tor_create_with_identity(
                 address_control_str,
                 ptr::null(),
                 8080,
                 bypass,
                 ptr::null(),
                 ptr::null(),
+                identity_json.as_ptr(),
                 error_ptr,
             );
             assert_eq!(error, 0);
+            let restored = (*transport).tor.identity.as_ref().unwrap();
+            assert_eq!(restored.service_id, identity.service_id);
+            assert_eq!(restored.onion_port, identity.onion_port);
             transport_config_destroy(transport);
 
-            bypass = true;
-            transport = transport_tor_create(
+            let invalid_json = CString::new("not an identity").unwrap();
+            transport = transport_tor_create_with_identity(
                 address_control_str,
                 ptr::null(),
                 8080,
                 bypass,
                 ptr::null(),
                 ptr::null(),
+                invalid_json.as_ptr(),
                 error_ptr,
             );
-            assert_eq!(error, 0);
-            transport_config_destroy(transport);
+            assert!(transport.is_null());
+            assert_eq!(error, 7);
         }
     }
 
@@ -10199,16 +16839,66 @@ mod test {
 
             assert_eq!(chars.len(), 32);
 
+            let public_key_hex = public_key_to_hex(public_key, error_ptr);
+            assert_eq!(error, 0);
+            assert_eq!(CStr::from_ptr(public_key_hex).to_str().unwrap(), (*public_key).to_hex());
+            let public_key_from_hex = public_key_from_hex(public_key_hex, error_ptr);
+            assert_eq!(error, 0);
+            assert_eq!(*public_key_from_hex, *public_key);
+
             assert_eq!(private_key_length, 32);
             assert_eq!(public_key_length, 32);
             assert_ne!((*private_bytes), (*public_bytes));
             private_key_destroy(private_key);
             public_key_destroy(public_key);
+            public_key_destroy(public_key_from_hex);
+            string_destroy(public_key_hex);
             byte_vector_destroy(public_bytes);
             byte_vector_destroy(private_bytes);
         }
     }
 
+    #[test]
+    fn test_crypto_factories_are_shared() {
+        assert!(ptr::eq(crypto_factories(), crypto_factories()));
+    }
+
+    #[test]
+    fn test_commitment_create_and_verify() {
+        unsafe {
+            let mut error = 0;
+            let error_ptr = &mut error as *mut c_int;
+            let blinding_key = private_key_generate();
+            let commitment_hex = commitment_create(12345, blinding_key, error_ptr);
+            assert_eq!(error, 0);
+            let expected = CryptoFactories::default()
+                .commitment
+                .commit_value(&*blinding_key, 12345);
+            assert_eq!(CStr::from_ptr(commitment_hex).to_str().unwrap(), expected.to_hex());
+
+            assert!(commitment_verify(commitment_hex, 12345, blinding_key, error_ptr));
+            assert_eq!(error, 0);
+            assert!(!commitment_verify(commitment_hex, 12346, blinding_key, error_ptr));
+            assert_eq!(error, 0);
+            let other_key = private_key_generate();
+            assert!(!commitment_verify(commitment_hex, 12345, other_key, error_ptr));
+            assert_eq!(error, 0);
+
+            let bad_hex = CString::into_raw(CString::new("not a commitment").unwrap());
+            assert!(!commitment_verify(bad_hex, 12345, blinding_key, error_ptr));
+            assert_eq!(error, 7);
+            assert!(!commitment_verify(ptr::null(), 12345, blinding_key, error_ptr));
+            assert_eq!(error, 1);
+            assert!(commitment_create(12345, ptr::null_mut(), error_ptr).is_null());
+            assert_eq!(error, 1);
+
+            private_key_destroy(blinding_key);
+            private_key_destroy(other_key);
+            string_destroy(commitment_hex);
+            string_destroy(bad_hex);
+        }
+    }
+
     #[test]
     fn test_covenant_create_empty() {
         unsafe {
@@ -10293,6 +16983,34 @@ mod test {
 
             assert_eq!((*encrypted_data_1_as_bytes).0, encrypted_data_bytes.to_vec());
 
+            let encryption_key_ptr = Box::into_raw(Box::new(encryption_key.clone()));
+            let commitment_hex = CString::into_raw(CString::new(commitment.to_hex()).unwrap());
+            let opening = encrypted_data_decrypt(encrypted_data_1, encryption_key_ptr, commitment_hex, error_ptr);
+            assert_eq!(error, 0);
+            assert_eq!((*opening).tag, TariTypeTag::U64);
+            let opening_values = slice::from_raw_parts((*opening).ptr as *const u64, (*opening).len);
+            assert_eq!(opening_values[0], amount.as_u64());
+            assert_eq!(
+                opening_values[1..]
+                    .iter()
+                    .map(|b| u8::try_from(*b).unwrap())
+                    .collect::<Vec<u8>>(),
+                spending_key.as_bytes().to_vec()
+            );
+            destroy_tari_vector(opening);
+
+            let wrong_key_ptr = private_key_generate();
+            let opening = encrypted_data_decrypt(encrypted_data_1, wrong_key_ptr, commitment_hex, error_ptr);
+            assert!(opening.is_null());
+            assert_eq!(error, 16);
+            let opening = encrypted_data_decrypt(encrypted_data_1, encryption_key_ptr, ptr::null(), error_ptr);
+            assert!(opening.is_null());
+            assert_eq!(error, 1);
+
+            private_key_destroy(encryption_key_ptr);
+            private_key_destroy(wrong_key_ptr);
+            string_destroy(commitment_hex);
+
             encrypted_data_destroy(encrypted_data_2);
             encrypted_data_destroy(encrypted_data_1);
             byte_vector_destroy(encrypted_data_1_as_bytes);
@@ -10375,6 +17093,40 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_output_features_create_coinbase() {
+        unsafe {
+            let mut error = 0;
+            let error_ptr = &mut error as *mut c_int;
+
+            let maturity: c_ulonglong = 1_000;
+            let expected_extra = vec![7; 32];
+            let extra = Box::into_raw(Box::new(ByteVector(expected_extra.clone())));
+
+            let output_features = output_features_create_coinbase(maturity, extra, error_ptr);
+            assert_eq!(error, 0);
+            assert_eq!((*output_features).output_type, OutputType::Coinbase);
+            assert_eq!((*output_features).range_proof_type, RangeProofType::BulletProofPlus);
+            assert_eq!((*output_features).maturity, maturity);
+            assert_eq!((*output_features).coinbase_extra.to_vec(), expected_extra);
+            output_features_destroy(output_features);
+
+            let output_features = output_features_create_coinbase(maturity, ptr::null(), error_ptr);
+            assert_eq!(error, 0);
+            assert_eq!((*output_features).output_type, OutputType::Coinbase);
+            assert!((*output_features).coinbase_extra.is_empty());
+            output_features_destroy(output_features);
+
+            let too_long = Box::into_raw(Box::new(ByteVector(vec![1; 1024])));
+            let output_features = output_features_create_coinbase(maturity, too_long, error_ptr);
+            assert_eq!(error, 7);
+            assert!(output_features.is_null());
+
+            byte_vector_destroy(extra);
+            byte_vector_destroy(too_long);
+        }
+    }
+
     #[test]
     fn test_keys_dont_panic() {
         unsafe {
@@ -10419,6 +17171,169 @@ mod test {
         }
     }
 
+    #[test]
+    fn test_completed_transaction_net_amount() {
+        unsafe {
+            let mut error = 0;
+            let error_ptr = &mut error as *mut c_int;
+            let mut tx = CompletedTransaction::new(
+                TxId::from(1u64),
+                TariAddress::default(),
+                TariAddress::default(),
+                MicroMinotari::from(1000),
+                MicroMinotari::from(25),
+                Transaction::new(vec![], vec![], vec![], PrivateKey::default(), PrivateKey::default()),
+                TransactionStatus::Completed,
+                "net amount".to_string(),
+                Utc::now().naive_utc(),
+                TransactionDirection::Inbound,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+            let inbound = Box::into_raw(Box::new(tx.clone()));
+            assert_eq!(completed_transaction_get_net_amount(inbound, error_ptr), 1000);
+            assert_eq!(error, 0);
+
+            tx.direction = TransactionDirection::Outbound;
+            let outbound = Box::into_raw(Box::new(tx.clone()));
+            assert_eq!(completed_transaction_get_net_amount(outbound, error_ptr), -1025);
+            assert_eq!(error, 0);
+
+            tx.direction = TransactionDirection::Unknown;
+            let unknown = Box::into_raw(Box::new(tx));
+            assert_eq!(completed_transaction_get_net_amount(unknown, error_ptr), 0);
+            assert_eq!(error, 7);
+
+            assert_eq!(completed_transaction_get_net_amount(ptr::null_mut(), error_ptr), 0);
+            assert_eq!(error, 1);
+
+            completed_transaction_destroy(inbound);
+            completed_transaction_destroy(outbound);
+            completed_transaction_destroy(unknown);
+        }
+    }
+
+    #[test]
+    fn test_completed_transaction_mined_timestamp() {
+        unsafe {
+            let mut error = 0;
+            let error_ptr = &mut error as *mut c_int;
+            let mut tx = CompletedTransaction::new(
+                TxId::from(1u64),
+                TariAddress::default(),
+                TariAddress::default(),
+                MicroMinotari::from(1000),
+                MicroMinotari::from(25),
+                Transaction::new(vec![], vec![], vec![], PrivateKey::default(), PrivateKey::default()),
+                TransactionStatus::Completed,
+                "mined timestamp".to_string(),
+                Utc::now().naive_utc(),
+                TransactionDirection::Inbound,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+            let unmined = Box::into_raw(Box::new(tx.clone()));
+            assert_eq!(completed_transaction_get_mined_timestamp(unmined, error_ptr), 0);
+            assert_eq!(error, 0);
+
+            tx.status = TransactionStatus::MinedConfirmed;
+            tx.mined_height = Some(10);
+            tx.mined_timestamp = NaiveDateTime::from_timestamp_opt(1_700_000_000, 250_000_000);
+            let mined = Box::into_raw(Box::new(tx));
+            assert_eq!(
+                completed_transaction_get_mined_timestamp(mined, error_ptr),
+                1_700_000_000_250
+            );
+            assert_eq!(error, 0);
+
+            assert_eq!(completed_transaction_get_mined_timestamp(ptr::null_mut(), error_ptr), 0);
+            assert_eq!(error, 1);
+
+            completed_transaction_destroy(unmined);
+            completed_transaction_destroy(mined);
+        }
+    }
+
+    #[test]
+    fn test_completed_transaction_weight_requires_a_body() {
+        unsafe {
+            let mut error = 0;
+            let error_ptr = &mut error as *mut c_int;
+            let mut tx = CompletedTransaction::new(
+                TxId::from(1u64),
+                TariAddress::default(),
+                TariAddress::default(),
+                MicroMinotari::from(1000),
+                MicroMinotari::from(25),
+                Transaction::new(vec![], vec![], vec![], PrivateKey::default(), PrivateKey::default()),
+                TransactionStatus::Completed,
+                "weight".to_string(),
+                Utc::now().naive_utc(),
+                TransactionDirection::Inbound,
+                None,
+                None,
+                None,
+            )
+            .unwrap();
+
+            // a completed transaction without kernels has no body to weigh either
+            let completed = Box::into_raw(Box::new(tx.clone()));
+            assert_eq!(completed_transaction_get_weight(completed, error_ptr), 0);
+            assert_eq!(error, 640);
+
+            let with_kernel = Box::into_raw(Box::new(CompletedTransaction {
+                transaction: Transaction::new(
+                    vec![],
+                    vec![],
+                    vec![create_test_kernel(MicroMinotari::from(25), 0, KernelFeatures::empty())],
+                    PrivateKey::default(),
+                    PrivateKey::default(),
+                ),
+                ..tx.clone()
+            }));
+            assert!(completed_transaction_get_weight(with_kernel, error_ptr) > 0);
+            assert_eq!(error, 0);
+
+            tx.status = TransactionStatus::Pending;
+            let pending = Box::into_raw(Box::new(tx.clone()));
+            assert_eq!(completed_transaction_get_weight(pending, error_ptr), 0);
+            assert_eq!(error, 640);
+
+            tx.status = TransactionStatus::Imported;
+            let imported = Box::into_raw(Box::new(tx));
+            assert_eq!(completed_transaction_get_weight(imported, error_ptr), 0);
+            assert_eq!(error, 640);
+
+            assert_eq!(completed_transaction_get_weight(ptr::null_mut(), error_ptr), 0);
+            assert_eq!(error, 1);
+
+            completed_transaction_destroy(completed);
+            completed_transaction_destroy(with_kernel);
+            completed_transaction_destroy(pending);
+            completed_transaction_destroy(imported);
+        }
+    }
+
+    #[test]
+    fn test_transaction_status_to_string() {
+        unsafe {
+            let mut error = 0;
+            let error_ptr = &mut error as *mut c_int;
+            let status = transaction_status_to_string(TransactionStatus::MinedUnconfirmed as c_int, error_ptr);
+            assert_eq!(error, 0);
+            assert_eq!(CStr::from_ptr(status).to_str().unwrap(), "Mined Unconfirmed");
+            string_destroy(status);
+            assert!(transaction_status_to_string(-1, error_ptr).is_null());
+            assert_eq!(error, 7);
+        }
+    }
+
     #[test]
     fn test_contact() {
         unsafe {
@@ -10443,6 +17358,22 @@ mod test {
             let contact_key_bytes = tari_address_get_bytes(contact_address, error_ptr);
             let contact_bytes_len = byte_vector_get_length(contact_key_bytes, error_ptr);
             assert_eq!(contact_bytes_len, 35);
+            let new_str = "Renamed Contact";
+            let new_alias = CString::into_raw(CString::new(new_str).unwrap()) as *const c_char;
+            assert!(contact_set_alias(test_contact, new_alias, error_ptr));
+            assert_eq!(error, 0);
+            let alias = contact_get_alias(test_contact, error_ptr);
+            assert_eq!(CString::from_raw(alias).to_str().unwrap(), new_str);
+            assert!(contact_set_favourite(test_contact, false, error_ptr));
+            assert_eq!(error, 0);
+            assert!(!contact_get_favourite(test_contact, error_ptr));
+            assert!(!contact_set_alias(test_contact, ptr::null(), error_ptr));
+            assert_eq!(error, 1);
+            assert!(!contact_set_alias(ptr::null_mut(), new_alias, error_ptr));
+            assert_eq!(error, 1);
+            assert!(!contact_set_favourite(ptr::null_mut(), true, error_ptr));
+            assert_eq!(error, 1);
+            string_destroy(new_alias as *mut c_char);
             contact_destroy(test_contact);
             tari_address_destroy(test_address);
             private_key_destroy(test_contact_private_key);
@@ -10545,12 +17476,24 @@ mod test {
                 20,
                 10800,
                 false,
+                true,
+                true,
+                0,
+                0,
+                0,
                 error_ptr,
             );
 
             let passphrase: *const c_char =
                 CString::into_raw(CString::new("Hello from Alasca").unwrap()) as *const c_char;
 
+            // there is no database to protect yet, and none is created by trying
+            assert!(!wallet_apply_encryption(alice_config, passphrase, error_ptr));
+            assert_eq!(error, 436);
+            assert!(!wallet_remove_encryption(alice_config, passphrase, error_ptr));
+            assert_eq!(error, 436);
+            assert!(!sql_database_path.exists());
+
             let dns_string: *const c_char = CString::into_raw(CString::new("").unwrap()) as *const c_char;
 
             let void_ptr: *mut c_void = &mut (5) as *mut _ as *mut c_void;
@@ -10642,14 +17585,53 @@ mod test {
             );
             assert_eq!(error, 0);
             assert!(!(*recovery_in_progress_ptr), "no recovery in progress");
+            assert!(!wallet_is_recovery_in_progress(alice_wallet2, error_ptr));
+            assert_eq!(error, 0);
+            (*alice_wallet2)
+                .wallet
+                .db
+                .set_client_key_value(RECOVERY_KEY.to_owned(), "recovery".to_string())
+                .unwrap();
+            assert!(wallet_is_recovery_in_progress(alice_wallet2, error_ptr));
+            assert_eq!(error, 0);
+            assert!(wallet_clear_recovery_state(alice_wallet2, error_ptr));
+            assert_eq!(error, 0);
+            assert!(!wallet_is_recovery_in_progress(alice_wallet2, error_ptr));
+            assert_eq!(error, 0);
+            assert!(!wallet_clear_recovery_state(alice_wallet2, error_ptr));
+            assert_eq!(error, 0);
 
             assert_eq!(*error_ptr, 0, "No error expected");
+
+            // Change the passphrase, a wrong existing passphrase must be rejected
+            let wrong_passphrase = CString::new("Hello from Alaska").unwrap();
+            let new_passphrase = CString::new("Goodbye from Alasca").unwrap();
+            assert!(!wallet_change_passphrase(
+                alice_wallet2,
+                wrong_passphrase.as_ptr(),
+                new_passphrase.as_ptr(),
+                error_ptr
+            ));
+            assert_eq!(
+                error,
+                LibWalletError::from(WalletError::WalletStorageError(WalletStorageError::InvalidPassphrase)).code
+            );
+            assert!(wallet_change_passphrase(
+                alice_wallet2,
+                passphrase,
+                new_passphrase.as_ptr(),
+                error_ptr
+            ));
+            assert_eq!(error, 0);
             wallet_destroy(alice_wallet2);
 
             let connection =
                 run_migration_and_create_sqlite_connection(&sql_database_path, 16).expect("Could not open Sqlite db");
+            assert!(WalletSqliteDatabase::new(connection, SafePassword::from("Hello from Alasca")).is_err());
 
-            let passphrase = SafePassword::from("Hello from Alasca");
+            let connection =
+                run_migration_and_create_sqlite_connection(&sql_database_path, 16).expect("Could not open Sqlite db");
+            let passphrase = SafePassword::from("Goodbye from Alasca");
             let wallet_backend = WalletDatabase::new(WalletSqliteDatabase::new(connection, passphrase).unwrap());
 
             let stored_seed2 = wallet_backend.get_master_seed().unwrap().unwrap();
@@ -10719,6 +17701,11 @@ mod test {
                 20,
                 10800,
                 false,
+                true,
+                true,
+                0,
+                0,
+                0,
                 error_ptr,
             );
 
@@ -10808,6 +17795,54 @@ mod test {
             assert_eq!(found_value, ptr::null_mut());
             assert_eq!(*error_ptr, 424i32);
 
+            let found_value = wallet_get_key_value(alice_wallet, k_str, error_ptr);
+            assert_eq!(found_value, ptr::null_mut());
+            assert_eq!(*error_ptr, 0);
+
+            // the default message is empty until it is set, and is cleared again by an empty message
+            let default_message = wallet_get_default_message(alice_wallet, error_ptr);
+            assert_eq!(error, 0);
+            assert_eq!(CStr::from_ptr(default_message).to_str().unwrap(), "");
+            string_destroy(default_message);
+            let invoice = CString::new("Invoice payment").unwrap();
+            assert!(wallet_set_default_message(alice_wallet, invoice.as_ptr(), error_ptr));
+            assert_eq!(error, 0);
+            let default_message = wallet_get_default_message(alice_wallet, error_ptr);
+            assert_eq!(error, 0);
+            assert_eq!(CStr::from_ptr(default_message).to_str().unwrap(), "Invoice payment");
+            string_destroy(default_message);
+            // the generic key value functions can not bypass the length check or clear the default message
+            let reserved_key = CString::new(DEFAULT_MESSAGE_KEY).unwrap();
+            let too_long = CString::new("m".repeat(MAX_TRANSACTION_MESSAGE_LENGTH + 1)).unwrap();
+            assert!(!wallet_set_key_value(
+                alice_wallet,
+                reserved_key.as_ptr(),
+                too_long.as_ptr(),
+                error_ptr
+            ));
+            assert_eq!(error, 7);
+            assert!(!wallet_clear_value(alice_wallet, reserved_key.as_ptr(), error_ptr));
+            assert_eq!(error, 7);
+            assert!(wallet_get_value(alice_wallet, reserved_key.as_ptr(), error_ptr).is_null());
+            assert_eq!(error, 7);
+            let default_message = wallet_get_default_message(alice_wallet, error_ptr);
+            assert_eq!(error, 0);
+            assert_eq!(CStr::from_ptr(default_message).to_str().unwrap(), "Invoice payment");
+            string_destroy(default_message);
+            assert!(!wallet_set_default_message(alice_wallet, too_long.as_ptr(), error_ptr));
+            assert_eq!(error, 11);
+            assert!(wallet_set_default_message(alice_wallet, ptr::null(), error_ptr));
+            assert_eq!(error, 0);
+            let default_message = wallet_get_default_message(alice_wallet, error_ptr);
+            assert_eq!(CStr::from_ptr(default_message).to_str().unwrap(), "");
+            string_destroy(default_message);
+            assert!(wallet_get_default_message(ptr::null_mut(), error_ptr).is_null());
+            assert_eq!(error, 1);
+            let k = CString::new(client_key_values[1].0.as_str()).unwrap();
+            let found_value = wallet_get_key_value(alice_wallet, k.as_ptr(), error_ptr);
+            assert_eq!(*error_ptr, 0);
+            assert_eq!(CString::from_raw(found_value).to_str().unwrap(), client_key_values[1].1);
+
             string_destroy(network_str as *mut c_char);
             string_destroy(k_str as *mut c_char);
             string_destroy(db_name_alice_str as *mut c_char);
@@ -10930,7 +17965,153 @@ mod test {
                 }
             }
 
-            // create a new wallet
+            // create a new wallet
+            let db_name = CString::new(random::string(8).as_str()).unwrap();
+            let db_name_str: *const c_char = CString::into_raw(db_name) as *const c_char;
+            let temp_dir = tempdir().unwrap();
+            let db_path = CString::new(temp_dir.path().to_str().unwrap()).unwrap();
+            let db_path_str: *const c_char = CString::into_raw(db_path) as *const c_char;
+            let transport_type = transport_memory_create();
+            let address = transport_memory_get_address(transport_type, error_ptr);
+            let address_str = CStr::from_ptr(address).to_str().unwrap().to_owned();
+            let address_str = CString::new(address_str).unwrap().into_raw() as *const c_char;
+            let network = CString::new(NETWORK_STRING).unwrap();
+            let network_str: *const c_char = CString::into_raw(network) as *const c_char;
+
+            let config = comms_config_create(
+                address_str,
+                transport_type,
+                db_name_str,
+                db_path_str,
+                20,
+                10800,
+                false,
+                true,
+                true,
+                0,
+                0,
+                0,
+                error_ptr,
+            );
+
+            let passphrase: *const c_char =
+                CString::into_raw(CString::new("a cat outside in Istanbul").unwrap()) as *const c_char;
+            let dns_string: *const c_char = CString::into_raw(CString::new("").unwrap()) as *const c_char;
+            let void_ptr: *mut c_void = &mut (5) as *mut _ as *mut c_void;
+            let wallet = wallet_create(
+                void_ptr,
+                config,
+                ptr::null(),
+                0,
+                0,
+                0,
+                passphrase,
+                ptr::null(),
+                ptr::null(),
+                network_str,
+                dns_string,
+                ptr::null(),
+                true,
+                received_tx_callback,
+                received_tx_reply_callback,
+                received_tx_finalized_callback,
+                broadcast_callback,
+                mined_callback,
+                mined_unconfirmed_callback,
+                scanned_callback,
+                scanned_unconfirmed_callback,
+                transaction_send_result_callback,
+                tx_cancellation_callback,
+                txo_validation_complete_callback,
+                contacts_liveness_data_updated_callback,
+                balance_updated_callback,
+                transaction_validation_complete_callback,
+                saf_messages_received_callback,
+                connectivity_status_callback,
+                wallet_scanned_height_callback,
+                base_node_state_callback,
+                recovery_in_progress_ptr,
+                error_ptr,
+            );
+
+            assert_eq!(error, 0);
+            let seed_words = wallet_get_seed_words(wallet, error_ptr);
+            assert_eq!(error, 0);
+            let language = CString::new("Spanish").unwrap();
+            let spanish_seed_words = wallet_get_seed_words_in_language(wallet, language.as_ptr(), error_ptr);
+            assert_eq!(error, 0);
+            assert_eq!((*spanish_seed_words).0.len(), (*seed_words).0.len());
+            assert_ne!(
+                (*spanish_seed_words).0.join(" ").reveal(),
+                (*seed_words).0.join(" ").reveal()
+            );
+            seed_words_destroy(spanish_seed_words);
+            let language = CString::new("Klingon").unwrap();
+            assert!(wallet_get_seed_words_in_language(wallet, language.as_ptr(), error_ptr).is_null());
+            assert_eq!(error, LibWalletError::from(MnemonicError::UnknownLanguage).code);
+            let public_address = wallet_get_tari_interactive_address(wallet, error_ptr);
+            assert_eq!(error, 0);
+            let comms_public_key = wallet_get_comms_public_key(wallet, error_ptr);
+            assert_eq!(error, 0);
+            assert_eq!(&*comms_public_key, (*wallet).wallet.comms.node_identity().public_key());
+            let node_id = wallet_get_node_id(wallet, error_ptr);
+            assert_eq!(error, 0);
+            assert_eq!(
+                CStr::from_ptr(node_id).to_str().unwrap(),
+                TariNodeId::from_public_key(&*comms_public_key).to_hex()
+            );
+            public_key_destroy(comms_public_key);
+            string_destroy(node_id);
+
+            // sign with a key other than the comms key and verify against its public key
+            let message = CString::new("my message").unwrap();
+            let signing_key = private_key_generate();
+            let signing_public_key = public_key_from_private_key(signing_key, error_ptr);
+            let signature = wallet_sign_message_with_key(wallet, signing_key, message.as_ptr(), error_ptr);
+            assert_eq!(error, 0);
+            assert!(wallet_verify_message_signature(
+                wallet,
+                signing_public_key,
+                signature,
+                message.as_ptr(),
+                error_ptr
+            ));
+            assert_eq!(error, 0);
+            let null_key_signature = wallet_sign_message_with_key(wallet, ptr::null_mut(), message.as_ptr(), error_ptr);
+            assert_eq!(
+                error,
+                LibWalletError::from(InterfaceError::NullError("secret_key".to_string())).code
+            );
+            string_destroy(null_key_signature);
+            let other_message = CString::new("not my message").unwrap();
+            assert_eq!(
+                wallet_verify_message_signature_detailed(
+                    wallet,
+                    signing_public_key,
+                    signature,
+                    other_message.as_ptr(),
+                    error_ptr
+                ),
+                SignatureVerificationResult::Invalid as c_int
+            );
+            assert_eq!(error, 0);
+            let malformed_signature = CString::new("not|hex").unwrap();
+            assert_eq!(
+                wallet_verify_message_signature_detailed(
+                    wallet,
+                    signing_public_key,
+                    malformed_signature.as_ptr(),
+                    message.as_ptr(),
+                    error_ptr
+                ),
+                SignatureVerificationResult::MalformedInput as c_int
+            );
+            assert_ne!(error, 0);
+            string_destroy(signature);
+            private_key_destroy(signing_key);
+            public_key_destroy(signing_public_key);
+
+            // use seed words to create recovery wallet
             let db_name = CString::new(random::string(8).as_str()).unwrap();
             let db_name_str: *const c_char = CString::into_raw(db_name) as *const c_char;
             let temp_dir = tempdir().unwrap();
@@ -10940,8 +18121,6 @@ mod test {
             let address = transport_memory_get_address(transport_type, error_ptr);
             let address_str = CStr::from_ptr(address).to_str().unwrap().to_owned();
             let address_str = CString::new(address_str).unwrap().into_raw() as *const c_char;
-            let network = CString::new(NETWORK_STRING).unwrap();
-            let network_str: *const c_char = CString::into_raw(network) as *const c_char;
 
             let config = comms_config_create(
                 address_str,
@@ -10951,16 +18130,115 @@ mod test {
                 20,
                 10800,
                 false,
+                true,
+                true,
+                0,
+                0,
+                0,
                 error_ptr,
             );
 
             let passphrase: *const c_char =
-                CString::into_raw(CString::new("a cat outside in Istanbul").unwrap()) as *const c_char;
+                CString::into_raw(CString::new("a wave in teahupoo").unwrap()) as *const c_char;
+
+            let log_path: *const c_char =
+                CString::into_raw(CString::new(temp_dir.path().join("asdf").to_str().unwrap()).unwrap())
+                    as *const c_char;
             let dns_string: *const c_char = CString::into_raw(CString::new("").unwrap()) as *const c_char;
+
             let void_ptr: *mut c_void = &mut (5) as *mut _ as *mut c_void;
-            let wallet = wallet_create(
+            let recovered_wallet = wallet_create(
                 void_ptr,
                 config,
+                log_path,
+                0,
+                0,
+                0,
+                passphrase,
+                ptr::null(),
+                seed_words,
+                network_str,
+                dns_string,
+                ptr::null(),
+                true,
+                received_tx_callback,
+                received_tx_reply_callback,
+                received_tx_finalized_callback,
+                broadcast_callback,
+                mined_callback,
+                mined_unconfirmed_callback,
+                scanned_callback,
+                scanned_unconfirmed_callback,
+                transaction_send_result_callback,
+                tx_cancellation_callback,
+                txo_validation_complete_callback,
+                contacts_liveness_data_updated_callback,
+                balance_updated_callback,
+                transaction_validation_complete_callback,
+                saf_messages_received_callback,
+                connectivity_status_callback,
+                wallet_scanned_height_callback,
+                base_node_state_callback,
+                recovery_in_progress_ptr,
+                error_ptr,
+            );
+            assert_eq!(error, 0);
+
+            let recovered_seed_words = wallet_get_seed_words(recovered_wallet, error_ptr);
+            assert_eq!(error, 0);
+            let recovered_address = wallet_get_tari_interactive_address(recovered_wallet, error_ptr);
+            assert_eq!(error, 0);
+
+            assert_eq!(*seed_words, *recovered_seed_words);
+            assert_eq!(*public_address, *recovered_address);
+        }
+    }
+
+    #[test]
+    #[allow(clippy::too_many_lines)]
+    fn test_wallet_get_utxos() {
+        unsafe {
+            let mut error = 0;
+            let error_ptr = &mut error as *mut c_int;
+            let mut recovery_in_progress = true;
+            let recovery_in_progress_ptr = &mut recovery_in_progress as *mut bool;
+
+            let secret_key_alice = private_key_generate();
+            let db_name_alice = CString::new(random::string(8).as_str()).unwrap();
+            let db_name_alice_str: *const c_char = CString::into_raw(db_name_alice) as *const c_char;
+            let alice_temp_dir = tempdir().unwrap();
+            let db_path_alice = CString::new(alice_temp_dir.path().to_str().unwrap()).unwrap();
+            let db_path_alice_str: *const c_char = CString::into_raw(db_path_alice) as *const c_char;
+            let transport_config_alice = transport_memory_create();
+            let address_alice = transport_memory_get_address(transport_config_alice, error_ptr);
+            let address_alice_str = CStr::from_ptr(address_alice).to_str().unwrap().to_owned();
+            let address_alice_str: *const c_char = CString::new(address_alice_str).unwrap().into_raw() as *const c_char;
+            let network = CString::new(NETWORK_STRING).unwrap();
+            let network_str: *const c_char = CString::into_raw(network) as *const c_char;
+
+            let alice_config = comms_config_create(
+                address_alice_str,
+                transport_config_alice,
+                db_name_alice_str,
+                db_path_alice_str,
+                20,
+                10800,
+                false,
+                true,
+                true,
+                0,
+                0,
+                0,
+                error_ptr,
+            );
+
+            let passphrase: *const c_char =
+                CString::into_raw(CString::new("Satoshi Nakamoto").unwrap()) as *const c_char;
+            let dns_string: *const c_char = CString::into_raw(CString::new("").unwrap()) as *const c_char;
+            let void_ptr: *mut c_void = &mut (5) as *mut _ as *mut c_void;
+            let alice_wallet = wallet_create(
+                void_ptr,
+                alice_config,
                 ptr::null(),
                 0,
                 0,
@@ -10993,54 +18271,217 @@ mod test {
                 recovery_in_progress_ptr,
                 error_ptr,
             );
+            let alice_wallet_runtime = &(*alice_wallet).runtime;
+            let key_manager = &(*alice_wallet).wallet.key_manager_service;
 
             assert_eq!(error, 0);
-            let seed_words = wallet_get_seed_words(wallet, error_ptr);
+            let mut test_outputs = Vec::with_capacity(10);
+            for i in 0..10u8 {
+                let uout = alice_wallet_runtime.block_on(create_test_input(
+                    (1000u64 * u64::from(i)).into(),
+                    0,
+                    key_manager,
+                    vec![i, i + 1, i + 2, i + 3, i + 4],
+                ));
+                test_outputs.push(uout.clone());
+                alice_wallet_runtime
+                    .block_on((*alice_wallet).wallet.output_manager_service.add_output(uout, None))
+                    .unwrap();
+            }
+
+            // ascending order
+            let outputs = wallet_get_utxos(
+                alice_wallet,
+                0,
+                20,
+                TariUtxoSort::ValueAsc,
+                ptr::null_mut(),
+                3000,
+                error_ptr,
+            );
+            let utxos: &[TariUtxo] = slice::from_raw_parts((*outputs).ptr as *mut TariUtxo, (*outputs).len);
             assert_eq!(error, 0);
-            let public_address = wallet_get_tari_interactive_address(wallet, error_ptr);
+            assert_eq!((*outputs).len, 6);
+            assert_eq!(utxos.len(), 6);
+            assert!(
+                utxos
+                    .iter()
+                    .skip(1)
+                    .fold((true, utxos[0].value), |acc, x| { (acc.0 && x.value > acc.1, x.value) })
+                    .0
+            );
+            for utxo in utxos {
+                let output = test_outputs
+                    .iter()
+                    .find(|val| {
+                        alice_wallet_runtime
+                            .block_on(val.commitment(key_manager))
+                            .unwrap()
+                            .to_hex() ==
+                            CStr::from_ptr(utxo.commitment).to_str().unwrap()
+                    })
+                    .unwrap();
+                assert_eq!(output.value.as_u64(), utxo.value);
+                assert_eq!(output.features.maturity, utxo.lock_height);
+                assert_eq!(
+                    output.features.coinbase_extra.to_hex(),
+                    CStr::from_ptr(utxo.coinbase_extra).to_str().unwrap()
+                );
+                assert_eq!(output.features.is_coinbase(), utxo.is_coinbase);
+                assert_eq!(tari_utxo_blocks_until_mature(utxo, utxo.lock_height), 0);
+                assert_eq!(tari_utxo_blocks_until_mature(utxo, u64::MAX), 0);
+                if utxo.lock_height > 0 {
+                    assert_eq!(tari_utxo_blocks_until_mature(utxo, utxo.lock_height - 1), 1);
+                }
+            }
+            println!();
+            destroy_tari_vector(outputs);
+
+            // descending order
+            let outputs = wallet_get_utxos(
+                alice_wallet,
+                0,
+                20,
+                TariUtxoSort::ValueDesc,
+                ptr::null_mut(),
+                3000,
+                error_ptr,
+            );
+            let utxos: &[TariUtxo] = slice::from_raw_parts((*outputs).ptr as *mut TariUtxo, (*outputs).len);
             assert_eq!(error, 0);
+            assert_eq!((*outputs).len, 6);
+            assert_eq!(utxos.len(), 6);
+            assert!(
+                utxos
+                    .iter()
+                    .skip(1)
+                    .fold((true, utxos[0].value), |acc, x| (acc.0 && x.value < acc.1, x.value))
+                    .0
+            );
+            destroy_tari_vector(outputs);
 
-            // use seed words to create recovery wallet
-            let db_name = CString::new(random::string(8).as_str()).unwrap();
-            let db_name_str: *const c_char = CString::into_raw(db_name) as *const c_char;
-            let temp_dir = tempdir().unwrap();
-            let db_path = CString::new(temp_dir.path().to_str().unwrap()).unwrap();
-            let db_path_str: *const c_char = CString::into_raw(db_path) as *const c_char;
-            let transport_type = transport_memory_create();
-            let address = transport_memory_get_address(transport_type, error_ptr);
-            let address_str = CStr::from_ptr(address).to_str().unwrap().to_owned();
-            let address_str = CString::new(address_str).unwrap().into_raw() as *const c_char;
+            // summary of the same filter
+            let summary = wallet_get_utxos_summary(alice_wallet, ptr::null_mut(), 3000, error_ptr);
+            assert_eq!(error, 0);
+            let summary_values: &[u64] = slice::from_raw_parts((*summary).ptr as *mut u64, (*summary).len);
+            assert_eq!(summary_values, &[6, 39000, 4000, 9000]);
+            destroy_tari_vector(summary);
+
+            // all the test outputs are unspent and mature from genesis
+            assert_eq!(wallet_get_spendable_outputs_count(alice_wallet, 1, error_ptr), 10);
+            assert_eq!(error, 0);
+            // without a base node there is no tip height to default to
+            assert_eq!(wallet_get_spendable_outputs_count(alice_wallet, 0, error_ptr), 0);
+            assert_eq!(error, 303);
+
+            // the nine non-zero outputs total 45000, less the fee to sweep them
+            let max_spendable = wallet_get_max_spendable_amount(alice_wallet, 5, error_ptr);
+            assert_eq!(error, 0);
+            assert!(max_spendable > 0 && max_spendable < 45000);
+            let max_spendable_higher_fee = wallet_get_max_spendable_amount(alice_wallet, 10, error_ptr);
+            assert_eq!(error, 0);
+            assert!(max_spendable_higher_fee < max_spendable);
+            assert_eq!(wallet_get_max_spendable_amount(alice_wallet, 1_000_000, error_ptr), 0);
+            assert_eq!(error, 0);
+
+            // single output lookup by commitment
+            let commitment_hex = alice_wallet_runtime
+                .block_on(test_outputs[5].commitment(key_manager))
+                .unwrap()
+                .to_hex();
+            let commitment_str = CString::new(commitment_hex.clone()).unwrap().into_raw();
+            let utxo = wallet_get_utxo_by_commitment(alice_wallet, commitment_str, error_ptr);
+            assert_eq!(error, 0);
+            assert_eq!((*utxo).value, 5000);
+            assert_eq!(CStr::from_ptr((*utxo).commitment).to_str().unwrap(), commitment_hex);
+            destroy_tari_utxo(utxo);
+            string_destroy(commitment_str);
+
+            let unknown_commitment_str = CString::new(Commitment::default().to_hex()).unwrap().into_raw();
+            let utxo = wallet_get_utxo_by_commitment(alice_wallet, unknown_commitment_str, error_ptr);
+            assert!(utxo.is_null());
+            assert_eq!(error, 108);
+            string_destroy(unknown_commitment_str);
+
+            // result must be empty due to high dust threshold
+            let outputs = wallet_get_utxos(
+                alice_wallet,
+                0,
+                20,
+                TariUtxoSort::ValueAsc,
+                ptr::null_mut(),
+                15000,
+                error_ptr,
+            );
+            let utxos: &[TariUtxo] = slice::from_raw_parts_mut((*outputs).ptr as *mut TariUtxo, (*outputs).len);
+            assert_eq!(error, 0);
+            assert_eq!((*outputs).len, 0);
+            assert_eq!(utxos.len(), 0);
+            destroy_tari_vector(outputs);
+
+            string_destroy(network_str as *mut c_char);
+            string_destroy(db_name_alice_str as *mut c_char);
+            string_destroy(db_path_alice_str as *mut c_char);
+            string_destroy(address_alice_str as *mut c_char);
+            private_key_destroy(secret_key_alice);
+            transport_config_destroy(transport_config_alice);
+            comms_config_destroy(alice_config);
+            wallet_destroy(alice_wallet);
+        }
+    }
+
+    #[test]
+    #[allow(clippy::too_many_lines)]
+    fn test_wallet_get_all_utxos() {
+        unsafe {
+            let mut error = 0;
+            let error_ptr = &mut error as *mut c_int;
+            let mut recovery_in_progress = true;
+            let recovery_in_progress_ptr = &mut recovery_in_progress as *mut bool;
 
-            let config = comms_config_create(
-                address_str,
-                transport_type,
-                db_name_str,
-                db_path_str,
+            let secret_key_alice = private_key_generate();
+            let db_name_alice = CString::new(random::string(8).as_str()).unwrap();
+            let db_name_alice_str: *const c_char = CString::into_raw(db_name_alice) as *const c_char;
+            let alice_temp_dir = tempdir().unwrap();
+            let db_path_alice = CString::new(alice_temp_dir.path().to_str().unwrap()).unwrap();
+            let db_path_alice_str: *const c_char = CString::into_raw(db_path_alice) as *const c_char;
+            let transport_config_alice = transport_memory_create();
+            let address_alice = transport_memory_get_address(transport_config_alice, error_ptr);
+            let address_alice_str = CStr::from_ptr(address_alice).to_str().unwrap().to_owned();
+            let address_alice_str: *const c_char = CString::new(address_alice_str).unwrap().into_raw() as *const c_char;
+            let network = CString::new(NETWORK_STRING).unwrap();
+            let network_str: *const c_char = CString::into_raw(network) as *const c_char;
+
+            let alice_config = comms_config_create(
+                address_alice_str,
+                transport_config_alice,
+                db_name_alice_str,
+                db_path_alice_str,
                 20,
                 10800,
                 false,
+                true,
+                true,
+                0,
+                0,
+                0,
                 error_ptr,
             );
 
             let passphrase: *const c_char =
-                CString::into_raw(CString::new("a wave in teahupoo").unwrap()) as *const c_char;
-
-            let log_path: *const c_char =
-                CString::into_raw(CString::new(temp_dir.path().join("asdf").to_str().unwrap()).unwrap())
-                    as *const c_char;
+                CString::into_raw(CString::new("J-bay open corona").unwrap()) as *const c_char;
             let dns_string: *const c_char = CString::into_raw(CString::new("").unwrap()) as *const c_char;
-
             let void_ptr: *mut c_void = &mut (5) as *mut _ as *mut c_void;
-            let recovered_wallet = wallet_create(
+            let alice_wallet = wallet_create(
                 void_ptr,
-                config,
-                log_path,
+                alice_config,
+                ptr::null(),
                 0,
                 0,
                 0,
                 passphrase,
                 ptr::null(),
-                seed_words,
+                ptr::null(),
                 network_str,
                 dns_string,
                 ptr::null(),
@@ -11068,19 +18509,86 @@ mod test {
             );
             assert_eq!(error, 0);
 
-            let recovered_seed_words = wallet_get_seed_words(recovered_wallet, error_ptr);
+            for i in 0..10 {
+                let uo = (*alice_wallet).runtime.block_on(create_test_input(
+                    (1000 * i).into(),
+                    0,
+                    &(*alice_wallet).wallet.key_manager_service,
+                    vec![],
+                ));
+                (*alice_wallet)
+                    .runtime
+                    .block_on(
+                        (*alice_wallet)
+                            .wallet
+                            .output_manager_service
+                            .add_output(uo.clone(), None),
+                    )
+                    .unwrap();
+                (*alice_wallet)
+                    .wallet
+                    .output_db
+                    .mark_outputs_as_unspent(vec![(
+                        (*alice_wallet)
+                            .runtime
+                            .block_on(uo.hash(&(*alice_wallet).wallet.key_manager_service))
+                            .unwrap(),
+                        true,
+                    )])
+                    .unwrap();
+            }
+
+            let outputs = wallet_get_utxos(
+                alice_wallet,
+                0,
+                100,
+                TariUtxoSort::ValueAsc,
+                ptr::null_mut(),
+                0,
+                error_ptr,
+            );
+            let utxos: &[TariUtxo] = slice::from_raw_parts_mut((*outputs).ptr as *mut TariUtxo, (*outputs).len);
             assert_eq!(error, 0);
-            let recovered_address = wallet_get_tari_interactive_address(recovered_wallet, error_ptr);
+
+            let payload = utxos[0..3]
+                .iter()
+                .map(|x| CStr::from_ptr(x.commitment).to_str().unwrap().to_owned())
+                .collect::<Vec<String>>();
+
+            let commitments = Box::into_raw(Box::new(TariVector::from(payload)));
+            let result = wallet_coin_join(alice_wallet, commitments, 5, error_ptr);
             assert_eq!(error, 0);
+            assert!(result > 0);
 
-            assert_eq!(*seed_words, *recovered_seed_words);
-            assert_eq!(*public_address, *recovered_address);
+            let outputs = wallet_get_all_utxos(alice_wallet, error_ptr);
+            let utxos: &[TariUtxo] = slice::from_raw_parts_mut((*outputs).ptr as *mut TariUtxo, (*outputs).len);
+            assert_eq!(error, 0);
+            assert_eq!((*outputs).len, 11);
+            assert_eq!(utxos.len(), 11);
+            destroy_tari_vector(outputs);
+
+            let status_counts = wallet_get_output_status_counts(alice_wallet, error_ptr);
+            assert_eq!(error, 0);
+            let counts = (*status_counts).to_u64_vec().unwrap();
+            assert_eq!(counts.len(), 11);
+            assert_eq!(counts.iter().sum::<u64>(), 11);
+            assert_eq!(counts[0], 7);
+            destroy_tari_vector(status_counts);
+
+            string_destroy(network_str as *mut c_char);
+            string_destroy(db_name_alice_str as *mut c_char);
+            string_destroy(db_path_alice_str as *mut c_char);
+            string_destroy(address_alice_str as *mut c_char);
+            private_key_destroy(secret_key_alice);
+            transport_config_destroy(transport_config_alice);
+            comms_config_destroy(alice_config);
+            wallet_destroy(alice_wallet);
         }
     }
 
     #[test]
-    #[allow(clippy::too_many_lines)]
-    fn test_wallet_get_utxos() {
+    #[allow(clippy::too_many_lines, clippy::needless_collect)]
+    fn test_wallet_coin_join() {
         unsafe {
             let mut error = 0;
             let error_ptr = &mut error as *mut c_int;
@@ -11108,11 +18616,16 @@ mod test {
                 20,
                 10800,
                 false,
+                true,
+                true,
+                0,
+                0,
+                0,
                 error_ptr,
             );
 
             let passphrase: *const c_char =
-                CString::into_raw(CString::new("Satoshi Nakamoto").unwrap()) as *const c_char;
+                CString::into_raw(CString::new("The master and margarita").unwrap()) as *const c_char;
             let dns_string: *const c_char = CString::into_raw(CString::new("").unwrap()) as *const c_char;
             let void_ptr: *mut c_void = &mut (5) as *mut _ as *mut c_void;
             let alice_wallet = wallet_create(
@@ -11150,104 +18663,175 @@ mod test {
                 recovery_in_progress_ptr,
                 error_ptr,
             );
-            let alice_wallet_runtime = &(*alice_wallet).runtime;
-            let key_manager = &(*alice_wallet).wallet.key_manager_service;
 
             assert_eq!(error, 0);
-            let mut test_outputs = Vec::with_capacity(10);
-            for i in 0..10u8 {
-                let uout = alice_wallet_runtime.block_on(create_test_input(
-                    (1000u64 * u64::from(i)).into(),
+            for i in 1..=5 {
+                let uo = (*alice_wallet).runtime.block_on(create_test_input(
+                    (15000 * i).into(),
                     0,
-                    key_manager,
-                    vec![i, i + 1, i + 2, i + 3, i + 4],
+                    &(*alice_wallet).wallet.key_manager_service,
+                    vec![],
                 ));
-                test_outputs.push(uout.clone());
-                alice_wallet_runtime
-                    .block_on((*alice_wallet).wallet.output_manager_service.add_output(uout, None))
+                (*alice_wallet)
+                    .runtime
+                    .block_on(
+                        (*alice_wallet)
+                            .wallet
+                            .output_manager_service
+                            .add_output(uo.clone(), None),
+                    )
+                    .unwrap();
+                (*alice_wallet)
+                    .wallet
+                    .output_db
+                    .mark_outputs_as_unspent(vec![(
+                        (*alice_wallet)
+                            .runtime
+                            .block_on(uo.hash(&(*alice_wallet).wallet.key_manager_service))
+                            .unwrap(),
+                        true,
+                    )])
                     .unwrap();
             }
 
-            // ascending order
+            // ----------------------------------------------------------------------------
+            // preview
+
             let outputs = wallet_get_utxos(
                 alice_wallet,
                 0,
-                20,
+                100,
                 TariUtxoSort::ValueAsc,
                 ptr::null_mut(),
-                3000,
+                0,
                 error_ptr,
             );
-            let utxos: &[TariUtxo] = slice::from_raw_parts((*outputs).ptr as *mut TariUtxo, (*outputs).len);
+            let utxos: &[TariUtxo] = slice::from_raw_parts_mut((*outputs).ptr as *mut TariUtxo, (*outputs).len);
             assert_eq!(error, 0);
-            assert_eq!((*outputs).len, 6);
-            assert_eq!(utxos.len(), 6);
-            assert!(
-                utxos
-                    .iter()
-                    .skip(1)
-                    .fold((true, utxos[0].value), |acc, x| { (acc.0 && x.value > acc.1, x.value) })
-                    .0
+
+            let pre_join_total_amount = utxos[0..3].iter().fold(0u64, |acc, x| acc + x.value);
+
+            let payload = utxos[0..3]
+                .iter()
+                .map(|x| CStr::from_ptr(x.commitment).to_str().unwrap().to_owned())
+                .collect::<Vec<String>>();
+
+            let commitments = Box::into_raw(Box::new(TariVector::from(payload)));
+            let preview = wallet_preview_coin_join(alice_wallet, commitments, 5, error_ptr);
+            assert_eq!(error, 0);
+
+            let join_split_preview = wallet_preview_join_split(alice_wallet, commitments, 2, 5, error_ptr);
+            assert_eq!(error, 0);
+            let join_split_outputs: &[u64] = slice::from_raw_parts_mut(
+                (*(*join_split_preview).expected_outputs).ptr as *mut u64,
+                (*(*join_split_preview).expected_outputs).len,
+            );
+            assert_eq!(join_split_outputs.len(), 2);
+            assert!((*join_split_preview).fee > (*preview).fee);
+            assert_eq!(
+                join_split_outputs.iter().sum::<u64>() + (*join_split_preview).fee,
+                pre_join_total_amount
             );
-            for utxo in utxos {
-                let output = test_outputs
-                    .iter()
-                    .find(|val| {
-                        alice_wallet_runtime
-                            .block_on(val.commitment(key_manager))
-                            .unwrap()
-                            .to_hex() ==
-                            CStr::from_ptr(utxo.commitment).to_str().unwrap()
-                    })
-                    .unwrap();
-                assert_eq!(output.value.as_u64(), utxo.value);
-                assert_eq!(output.features.maturity, utxo.lock_height);
-                assert_eq!(
-                    output.features.coinbase_extra.to_hex(),
-                    CStr::from_ptr(utxo.coinbase_extra).to_str().unwrap()
-                );
-            }
-            println!();
-            destroy_tari_vector(outputs);
 
-            // descending order
+            // ----------------------------------------------------------------------------
+            // join
+
             let outputs = wallet_get_utxos(
                 alice_wallet,
                 0,
-                20,
-                TariUtxoSort::ValueDesc,
+                100,
+                TariUtxoSort::ValueAsc,
                 ptr::null_mut(),
-                3000,
+                0,
                 error_ptr,
             );
-            let utxos: &[TariUtxo] = slice::from_raw_parts((*outputs).ptr as *mut TariUtxo, (*outputs).len);
+            let utxos: &[TariUtxo] = slice::from_raw_parts_mut((*outputs).ptr as *mut TariUtxo, (*outputs).len);
             assert_eq!(error, 0);
-            assert_eq!((*outputs).len, 6);
-            assert_eq!(utxos.len(), 6);
-            assert!(
-                utxos
-                    .iter()
-                    .skip(1)
-                    .fold((true, utxos[0].value), |acc, x| (acc.0 && x.value < acc.1, x.value))
-                    .0
+
+            let payload = utxos[0..3]
+                .iter()
+                .map(|x| CStr::from_ptr(x.commitment).to_str().unwrap().to_owned())
+                .collect::<Vec<String>>();
+
+            let commitments = Box::into_raw(Box::new(TariVector::from(payload)));
+            let result = wallet_coin_join(alice_wallet, commitments, 5, error_ptr);
+            assert_eq!(error, 0);
+            assert!(result > 0);
+
+            let unspent_outputs = (*alice_wallet)
+                .wallet
+                .output_db
+                .fetch_outputs_by_query(OutputBackendQuery {
+                    status: vec![OutputStatus::Unspent],
+                    ..Default::default()
+                })
+                .unwrap()
+                .into_iter()
+                .map(|x| x.wallet_output.value)
+                .collect::<Vec<MicroMinotari>>();
+
+            let new_pending_outputs = (*alice_wallet)
+                .wallet
+                .output_db
+                .fetch_outputs_by_query(OutputBackendQuery {
+                    status: vec![OutputStatus::EncumberedToBeReceived],
+                    ..Default::default()
+                })
+                .unwrap()
+                .into_iter()
+                .map(|x| x.wallet_output.value)
+                .collect::<Vec<MicroMinotari>>();
+
+            let post_join_total_amount = new_pending_outputs.iter().fold(0u64, |acc, x| acc + x.as_u64());
+            let expected_output_values: Vec<u64> = Vec::from_raw_parts(
+                (*(*preview).expected_outputs).ptr as *mut u64,
+                (*(*preview).expected_outputs).len,
+                (*(*preview).expected_outputs).cap,
             );
-            destroy_tari_vector(outputs);
 
-            // result must be empty due to high dust threshold
             let outputs = wallet_get_utxos(
                 alice_wallet,
                 0,
                 20,
                 TariUtxoSort::ValueAsc,
-                ptr::null_mut(),
-                15000,
+                Box::into_raw(Box::new(TariVector::from(vec![OutputStatus::Unspent]))),
+                0,
                 error_ptr,
             );
             let utxos: &[TariUtxo] = slice::from_raw_parts_mut((*outputs).ptr as *mut TariUtxo, (*outputs).len);
             assert_eq!(error, 0);
-            assert_eq!((*outputs).len, 0);
-            assert_eq!(utxos.len(), 0);
+            assert_eq!(utxos.len(), 2);
+            assert_eq!(unspent_outputs.len(), 2);
+
+            // lengths
+            assert_eq!(new_pending_outputs.len(), 1);
+            assert_eq!(new_pending_outputs.len(), expected_output_values.len());
+
+            // comparing result with expected
+            assert_eq!(new_pending_outputs[0].as_u64(), expected_output_values[0]);
+
+            // checking fee
+            assert_eq!(pre_join_total_amount - post_join_total_amount, (*preview).fee);
+
+            // joining to a target amount, only 60000 and 75000 remain unspent
+            assert_eq!(wallet_coin_join_to_amount(alice_wallet, 1_000_000, 5, error_ptr), 0);
+            assert_eq!(error, 101);
+            assert!(wallet_coin_join_to_amount(alice_wallet, 100_000, 5, error_ptr) > 0);
+            assert_eq!(error, 0);
+            let unspent_outputs = (*alice_wallet)
+                .wallet
+                .output_db
+                .fetch_outputs_by_query(OutputBackendQuery {
+                    status: vec![OutputStatus::Unspent],
+                    ..Default::default()
+                })
+                .unwrap();
+            assert!(unspent_outputs.is_empty());
+
             destroy_tari_vector(outputs);
+            destroy_tari_vector(commitments);
+            destroy_tari_coin_preview(preview);
+            destroy_tari_coin_preview(join_split_preview);
 
             string_destroy(network_str as *mut c_char);
             string_destroy(db_name_alice_str as *mut c_char);
@@ -11261,8 +18845,8 @@ mod test {
     }
 
     #[test]
-    #[allow(clippy::too_many_lines)]
-    fn test_wallet_get_all_utxos() {
+    #[allow(clippy::too_many_lines, clippy::needless_collect)]
+    fn test_wallet_coin_split() {
         unsafe {
             let mut error = 0;
             let error_ptr = &mut error as *mut c_int;
@@ -11290,12 +18874,17 @@ mod test {
                 20,
                 10800,
                 false,
+                true,
+                true,
+                0,
+                0,
+                0,
                 error_ptr,
             );
 
-            let passphrase: *const c_char =
-                CString::into_raw(CString::new("J-bay open corona").unwrap()) as *const c_char;
+            let passphrase: *const c_char = CString::into_raw(CString::new("niao").unwrap()) as *const c_char;
             let dns_string: *const c_char = CString::into_raw(CString::new("").unwrap()) as *const c_char;
+
             let void_ptr: *mut c_void = &mut (5) as *mut _ as *mut c_void;
             let alice_wallet = wallet_create(
                 void_ptr,
@@ -11333,10 +18922,176 @@ mod test {
                 error_ptr,
             );
             assert_eq!(error, 0);
+            for i in 1..=5 {
+                let uo = (*alice_wallet).runtime.block_on(create_test_input(
+                    (15000 * i).into(),
+                    0,
+                    &(*alice_wallet).wallet.key_manager_service,
+                    vec![],
+                ));
+                (*alice_wallet)
+                    .runtime
+                    .block_on(
+                        (*alice_wallet)
+                            .wallet
+                            .output_manager_service
+                            .add_output(uo.clone(), None),
+                    )
+                    .unwrap();
+                (*alice_wallet)
+                    .wallet
+                    .output_db
+                    .mark_outputs_as_unspent(vec![(
+                        (*alice_wallet)
+                            .runtime
+                            .block_on(uo.hash(&(*alice_wallet).wallet.key_manager_service))
+                            .unwrap(),
+                        true,
+                    )])
+                    .unwrap();
+            }
 
-            for i in 0..10 {
+            // ----------------------------------------------------------------------------
+            // preview
+
+            let outputs = wallet_get_utxos(
+                alice_wallet,
+                0,
+                100,
+                TariUtxoSort::ValueAsc,
+                ptr::null_mut(),
+                0,
+                error_ptr,
+            );
+            let utxos: &[TariUtxo] = slice::from_raw_parts_mut((*outputs).ptr as *mut TariUtxo, (*outputs).len);
+            assert_eq!(error, 0);
+
+            let pre_split_total_amount = utxos[0..3].iter().fold(0u64, |acc, x| acc + x.value);
+
+            let payload = utxos[0..3]
+                .iter()
+                .map(|x| CStr::from_ptr(x.commitment).to_str().unwrap().to_owned())
+                .collect::<Vec<String>>();
+
+            let commitments = Box::into_raw(Box::new(TariVector::from(payload)));
+
+            let preview = wallet_preview_coin_split(alice_wallet, commitments, 3, 5, error_ptr);
+            assert_eq!(error, 0);
+            destroy_tari_vector(commitments);
+
+            // ----------------------------------------------------------------------------
+            // split
+
+            let outputs = wallet_get_utxos(
+                alice_wallet,
+                0,
+                100,
+                TariUtxoSort::ValueAsc,
+                ptr::null_mut(),
+                0,
+                error_ptr,
+            );
+            let utxos: &[TariUtxo] = slice::from_raw_parts_mut((*outputs).ptr as *mut TariUtxo, (*outputs).len);
+            assert_eq!(error, 0);
+
+            let payload = utxos[0..3]
+                .iter()
+                .map(|x| CStr::from_ptr(x.commitment).to_str().unwrap().to_owned())
+                .collect::<Vec<String>>();
+
+            let commitments = Box::into_raw(Box::new(TariVector::from(payload)));
+
+            let result = wallet_coin_split(alice_wallet, commitments, 3, 5, error_ptr);
+            assert_eq!(error, 0);
+            assert!(result > 0);
+
+            let unspent_outputs = (*alice_wallet)
+                .wallet
+                .output_db
+                .fetch_outputs_by_query(OutputBackendQuery {
+                    status: vec![OutputStatus::Unspent],
+                    ..Default::default()
+                })
+                .unwrap()
+                .into_iter()
+                .map(|x| x.wallet_output.value)
+                .collect::<Vec<_>>();
+
+            let new_pending_outputs = (*alice_wallet)
+                .wallet
+                .output_db
+                .fetch_outputs_by_query(OutputBackendQuery {
+                    status: vec![OutputStatus::EncumberedToBeReceived],
+                    ..Default::default()
+                })
+                .unwrap()
+                .into_iter()
+                .map(|x| x.wallet_output.value)
+                .collect::<Vec<_>>();
+
+            let post_split_total_amount = new_pending_outputs.iter().fold(0u64, |acc, x| acc + x.as_u64());
+            let expected_output_values: Vec<u64> = Vec::from_raw_parts(
+                (*(*preview).expected_outputs).ptr as *mut u64,
+                (*(*preview).expected_outputs).len,
+                (*(*preview).expected_outputs).cap,
+            );
+
+            let outputs = wallet_get_utxos(
+                alice_wallet,
+                0,
+                20,
+                TariUtxoSort::ValueAsc,
+                Box::into_raw(Box::new(TariVector::from(vec![OutputStatus::Unspent]))),
+                0,
+                error_ptr,
+            );
+            let utxos: &[TariUtxo] = slice::from_raw_parts_mut((*outputs).ptr as *mut TariUtxo, (*outputs).len);
+            assert_eq!(error, 0);
+            assert_eq!(utxos.len(), 2);
+            assert_eq!(unspent_outputs.len(), 2);
+
+            // lengths
+            assert_eq!(new_pending_outputs.len(), 3);
+            assert_eq!(new_pending_outputs.len(), expected_output_values.len());
+
+            // comparing resulting output values relative to itself
+            assert_eq!(new_pending_outputs[0], new_pending_outputs[1]);
+            assert_eq!(new_pending_outputs[2], new_pending_outputs[1] + MicroMinotari(1));
+
+            // comparing resulting output values to the expected
+            assert_eq!(new_pending_outputs[0].as_u64(), expected_output_values[0]);
+            assert_eq!(new_pending_outputs[1].as_u64(), expected_output_values[1]);
+            assert_eq!(new_pending_outputs[2].as_u64(), expected_output_values[2]);
+
+            // checking fee
+            assert_eq!(pre_split_total_amount - post_split_total_amount, (*preview).fee);
+
+            destroy_tari_vector(outputs);
+            destroy_tari_vector(commitments);
+            destroy_tari_coin_preview(preview);
+
+            // a message that is too long is rejected before attempting the send
+            let destination = Box::into_raw(Box::<TariWalletAddress>::default());
+            let long_message = CString::new("a".repeat(MAX_TRANSACTION_MESSAGE_LENGTH + 1)).unwrap();
+            let tx_id = wallet_send_transaction(
+                alice_wallet,
+                destination,
+                1000,
+                ptr::null_mut(),
+                5,
+                long_message.as_ptr(),
+                false,
+                ptr::null(),
+                error_ptr,
+            );
+            assert_eq!(tx_id, 0);
+            assert_eq!(error, 11);
+            tari_address_destroy(destination);
+
+            // every send function falls back to the default message, and an explicit message overrides it
+            for value in [20000u64, 25000u64, 30000u64] {
                 let uo = (*alice_wallet).runtime.block_on(create_test_input(
-                    (1000 * i).into(),
+                    value.into(),
                     0,
                     &(*alice_wallet).wallet.key_manager_service,
                     vec![],
@@ -11346,51 +19101,111 @@ mod test {
                     .block_on(
                         (*alice_wallet)
                             .wallet
-                            .output_manager_service
-                            .add_output(uo.clone(), None),
+                            .output_manager_service
+                            .add_output(uo.clone(), None),
+                    )
+                    .unwrap();
+                (*alice_wallet)
+                    .wallet
+                    .output_db
+                    .mark_outputs_as_unspent(vec![(
+                        (*alice_wallet)
+                            .runtime
+                            .block_on(uo.hash(&(*alice_wallet).wallet.key_manager_service))
+                            .unwrap(),
+                        true,
+                    )])
+                    .unwrap();
+            }
+            let default_message = CString::new("Invoice payment").unwrap();
+            assert!(wallet_set_default_message(
+                alice_wallet,
+                default_message.as_ptr(),
+                error_ptr
+            ));
+            let destination = wallet_get_tari_one_sided_address(alice_wallet, error_ptr);
+            let empty_message = CString::new("").unwrap();
+            let explicit_message = CString::new("Refund").unwrap();
+            let null_message_tx_id = wallet_send_transaction(
+                alice_wallet,
+                destination,
+                1000,
+                ptr::null_mut(),
+                5,
+                ptr::null(),
+                true,
+                ptr::null(),
+                error_ptr,
+            );
+            assert_eq!(error, 0);
+            let empty_message_tx_id = wallet_send_one_sided_transaction(
+                alice_wallet,
+                destination,
+                1000,
+                5,
+                empty_message.as_ptr(),
+                ptr::null(),
+                error_ptr,
+            );
+            assert_eq!(error, 0);
+            let explicit_message_tx_id = wallet_send_transaction(
+                alice_wallet,
+                destination,
+                1000,
+                ptr::null_mut(),
+                5,
+                explicit_message.as_ptr(),
+                true,
+                ptr::null(),
+                error_ptr,
+            );
+            assert_eq!(error, 0);
+            for (tx_id, expected_message) in [
+                (null_message_tx_id, "Invoice payment"),
+                (empty_message_tx_id, "Invoice payment"),
+                (explicit_message_tx_id, "Refund"),
+            ] {
+                let tx = (*alice_wallet)
+                    .runtime
+                    .block_on(
+                        (*alice_wallet)
+                            .wallet
+                            .transaction_service
+                            .get_completed_transaction(TxId::from(tx_id)),
                     )
                     .unwrap();
-                (*alice_wallet)
-                    .wallet
-                    .output_db
-                    .mark_outputs_as_unspent(vec![(
-                        (*alice_wallet)
-                            .runtime
-                            .block_on(uo.hash(&(*alice_wallet).wallet.key_manager_service))
-                            .unwrap(),
-                        true,
-                    )])
-                    .unwrap();
+                assert_eq!(tx.message, expected_message);
             }
-
-            let outputs = wallet_get_utxos(
+            // a one-sided send records the amount and the payment id, prefixed by the sender's address
+            let payment_id = Box::into_raw(Box::new(ByteVector(b"order-1234".to_vec())));
+            let payment_id_tx_id = wallet_send_one_sided_transaction(
                 alice_wallet,
-                0,
-                100,
-                TariUtxoSort::ValueAsc,
-                ptr::null_mut(),
-                0,
+                destination,
+                2000,
+                5,
+                explicit_message.as_ptr(),
+                payment_id,
                 error_ptr,
             );
-            let utxos: &[TariUtxo] = slice::from_raw_parts_mut((*outputs).ptr as *mut TariUtxo, (*outputs).len);
-            assert_eq!(error, 0);
-
-            let payload = utxos[0..3]
-                .iter()
-                .map(|x| CStr::from_ptr(x.commitment).to_str().unwrap().to_owned())
-                .collect::<Vec<String>>();
-
-            let commitments = Box::into_raw(Box::new(TariVector::from(payload)));
-            let result = wallet_coin_join(alice_wallet, commitments, 5, error_ptr);
-            assert_eq!(error, 0);
-            assert!(result > 0);
-
-            let outputs = wallet_get_all_utxos(alice_wallet, error_ptr);
-            let utxos: &[TariUtxo] = slice::from_raw_parts_mut((*outputs).ptr as *mut TariUtxo, (*outputs).len);
             assert_eq!(error, 0);
-            assert_eq!((*outputs).len, 11);
-            assert_eq!(utxos.len(), 11);
-            destroy_tari_vector(outputs);
+            assert_ne!(payment_id_tx_id, 0);
+            let tx = (*alice_wallet)
+                .runtime
+                .block_on(
+                    (*alice_wallet)
+                        .wallet
+                        .transaction_service
+                        .get_completed_transaction(TxId::from(payment_id_tx_id)),
+                )
+                .unwrap();
+            assert_eq!(tx.amount, MicroMinotari::from(2000));
+            assert_eq!(tx.direction, TransactionDirection::Outbound);
+            match tx.payment_id {
+                Some(PaymentId::AddressAndData(_, data)) => assert_eq!(data, b"order-1234".to_vec()),
+                other => panic!("Unexpected payment id {:?}", other),
+            }
+            byte_vector_destroy(payment_id);
+            tari_address_destroy(destination);
 
             string_destroy(network_str as *mut c_char);
             string_destroy(db_name_alice_str as *mut c_char);
@@ -11403,16 +19218,16 @@ mod test {
         }
     }
 
+    #[cfg(feature = "ffi-test")]
     #[test]
-    #[allow(clippy::too_many_lines, clippy::needless_collect)]
-    fn test_wallet_coin_join() {
+    #[allow(clippy::too_many_lines)]
+    fn test_wallet_test_receive_and_complete_transactions() {
         unsafe {
             let mut error = 0;
             let error_ptr = &mut error as *mut c_int;
             let mut recovery_in_progress = true;
             let recovery_in_progress_ptr = &mut recovery_in_progress as *mut bool;
 
-            let secret_key_alice = private_key_generate();
             let db_name_alice = CString::new(random::string(8).as_str()).unwrap();
             let db_name_alice_str: *const c_char = CString::into_raw(db_name_alice) as *const c_char;
             let alice_temp_dir = tempdir().unwrap();
@@ -11433,11 +19248,15 @@ mod test {
                 20,
                 10800,
                 false,
+                true,
+                true,
+                0,
+                0,
+                0,
                 error_ptr,
             );
 
-            let passphrase: *const c_char =
-                CString::into_raw(CString::new("The master and margarita").unwrap()) as *const c_char;
+            let passphrase: *const c_char = CString::into_raw(CString::new("niao").unwrap()) as *const c_char;
             let dns_string: *const c_char = CString::into_raw(CString::new("").unwrap()) as *const c_char;
             let void_ptr: *mut c_void = &mut (5) as *mut _ as *mut c_void;
             let alice_wallet = wallet_create(
@@ -11475,152 +19294,119 @@ mod test {
                 recovery_in_progress_ptr,
                 error_ptr,
             );
+            assert_eq!(error, 0);
 
+            // receive: the simulated counterparty's transaction shows up as pending inbound
+            assert!(wallet_test_receive_transaction(alice_wallet, error_ptr));
             assert_eq!(error, 0);
-            for i in 1..=5 {
-                let uo = (*alice_wallet).runtime.block_on(create_test_input(
-                    (15000 * i).into(),
-                    0,
-                    &(*alice_wallet).wallet.key_manager_service,
-                    vec![],
-                ));
+            let mut num_inbound = 0;
+            for _ in 0..20 {
+                let inbound = wallet_get_pending_inbound_transactions(alice_wallet, error_ptr);
+                assert_eq!(error, 0);
+                num_inbound = pending_inbound_transactions_get_length(inbound, error_ptr);
+                pending_inbound_transactions_destroy(inbound);
+                if num_inbound > 0 {
+                    break;
+                }
                 (*alice_wallet)
                     .runtime
-                    .block_on(
-                        (*alice_wallet)
-                            .wallet
-                            .output_manager_service
-                            .add_output(uo.clone(), None),
-                    )
-                    .unwrap();
-                (*alice_wallet)
-                    .wallet
-                    .output_db
-                    .mark_outputs_as_unspent(vec![(
-                        (*alice_wallet)
-                            .runtime
-                            .block_on(uo.hash(&(*alice_wallet).wallet.key_manager_service))
-                            .unwrap(),
-                        true,
-                    )])
-                    .unwrap();
+                    .block_on(async { tokio::time::sleep(Duration::from_millis(250)).await });
             }
+            assert_eq!(num_inbound, 1);
 
-            // ----------------------------------------------------------------------------
-            // preview
-
-            let outputs = wallet_get_utxos(
-                alice_wallet,
-                0,
-                100,
-                TariUtxoSort::ValueAsc,
-                ptr::null_mut(),
+            // send and complete: the simulated recipient's reply finalizes the transaction
+            let uo = (*alice_wallet).runtime.block_on(create_test_input(
+                100000.into(),
                 0,
-                error_ptr,
-            );
-            let utxos: &[TariUtxo] = slice::from_raw_parts_mut((*outputs).ptr as *mut TariUtxo, (*outputs).len);
-            assert_eq!(error, 0);
-
-            let pre_join_total_amount = utxos[0..3].iter().fold(0u64, |acc, x| acc + x.value);
-
-            let payload = utxos[0..3]
-                .iter()
-                .map(|x| CStr::from_ptr(x.commitment).to_str().unwrap().to_owned())
-                .collect::<Vec<String>>();
-
-            let commitments = Box::into_raw(Box::new(TariVector::from(payload)));
-            let preview = wallet_preview_coin_join(alice_wallet, commitments, 5, error_ptr);
-            assert_eq!(error, 0);
-
-            // ----------------------------------------------------------------------------
-            // join
-
-            let outputs = wallet_get_utxos(
+                &(*alice_wallet).wallet.key_manager_service,
+                vec![],
+            ));
+            (*alice_wallet)
+                .runtime
+                .block_on(
+                    (*alice_wallet)
+                        .wallet
+                        .output_manager_service
+                        .add_output(uo.clone(), None),
+                )
+                .unwrap();
+            (*alice_wallet)
+                .wallet
+                .output_db
+                .mark_outputs_as_unspent(vec![(
+                    (*alice_wallet)
+                        .runtime
+                        .block_on(uo.hash(&(*alice_wallet).wallet.key_manager_service))
+                        .unwrap(),
+                    true,
+                )])
+                .unwrap();
+            let own_address = wallet_get_tari_interactive_address(alice_wallet, error_ptr);
+            let destination = Box::into_raw(Box::new(TariAddress::new_single_address_with_interactive_only(
+                PublicKey::from_secret_key(&PrivateKey::random(&mut OsRng)),
+                (*own_address).network(),
+            )));
+            let tx_id = wallet_send_transaction(
                 alice_wallet,
-                0,
-                100,
-                TariUtxoSort::ValueAsc,
+                destination,
+                10000,
                 ptr::null_mut(),
-                0,
+                5,
+                ptr::null(),
+                false,
+                ptr::null(),
                 error_ptr,
             );
-            let utxos: &[TariUtxo] = slice::from_raw_parts_mut((*outputs).ptr as *mut TariUtxo, (*outputs).len);
             assert_eq!(error, 0);
-
-            let payload = utxos[0..3]
-                .iter()
-                .map(|x| CStr::from_ptr(x.commitment).to_str().unwrap().to_owned())
-                .collect::<Vec<String>>();
-
-            let commitments = Box::into_raw(Box::new(TariVector::from(payload)));
-            let result = wallet_coin_join(alice_wallet, commitments, 5, error_ptr);
+            // the pending transaction is only stored once the initial send attempt to the unknown recipient is over
+            let mut pending_outbound = None;
+            for _ in 0..120 {
+                pending_outbound = (*alice_wallet)
+                    .runtime
+                    .block_on(
+                        (*alice_wallet)
+                            .wallet
+                            .transaction_service
+                            .get_pending_outbound_transactions(),
+                    )
+                    .unwrap()
+                    .remove(&TxId::from(tx_id));
+                if pending_outbound.is_some() {
+                    break;
+                }
+                (*alice_wallet)
+                    .runtime
+                    .block_on(async { tokio::time::sleep(Duration::from_millis(250)).await });
+            }
+            let tx = Box::into_raw(Box::new(CompletedTransaction::from(pending_outbound.unwrap())));
+            assert!(wallet_test_complete_sent_transaction(alice_wallet, tx, error_ptr));
             assert_eq!(error, 0);
-            assert!(result > 0);
-
-            let unspent_outputs = (*alice_wallet)
-                .wallet
-                .output_db
-                .fetch_outputs_by_query(OutputBackendQuery {
-                    status: vec![OutputStatus::Unspent],
-                    ..Default::default()
-                })
-                .unwrap()
-                .into_iter()
-                .map(|x| x.wallet_output.value)
-                .collect::<Vec<MicroMinotari>>();
-
-            let new_pending_outputs = (*alice_wallet)
-                .wallet
-                .output_db
-                .fetch_outputs_by_query(OutputBackendQuery {
-                    status: vec![OutputStatus::EncumberedToBeReceived],
-                    ..Default::default()
-                })
-                .unwrap()
-                .into_iter()
-                .map(|x| x.wallet_output.value)
-                .collect::<Vec<MicroMinotari>>();
+            completed_transaction_destroy(tx);
 
-            let post_join_total_amount = new_pending_outputs.iter().fold(0u64, |acc, x| acc + x.as_u64());
-            let expected_output_values: Vec<u64> = Vec::from_raw_parts(
-                (*(*preview).expected_outputs).ptr as *mut u64,
-                (*(*preview).expected_outputs).len,
-                (*(*preview).expected_outputs).cap,
-            );
-
-            let outputs = wallet_get_utxos(
-                alice_wallet,
-                0,
-                20,
-                TariUtxoSort::ValueAsc,
-                Box::into_raw(Box::new(TariVector::from(vec![OutputStatus::Unspent]))),
-                0,
-                error_ptr,
+            let mut completed = ptr::null_mut();
+            for _ in 0..20 {
+                completed = wallet_get_completed_transaction_by_id(alice_wallet, tx_id, error_ptr);
+                if !completed.is_null() {
+                    break;
+                }
+                (*alice_wallet)
+                    .runtime
+                    .block_on(async { tokio::time::sleep(Duration::from_millis(250)).await });
+            }
+            assert!(!completed.is_null());
+            assert_eq!(
+                completed_transaction_get_status(completed, error_ptr),
+                TransactionStatus::Completed as c_int
             );
-            let utxos: &[TariUtxo] = slice::from_raw_parts_mut((*outputs).ptr as *mut TariUtxo, (*outputs).len);
-            assert_eq!(error, 0);
-            assert_eq!(utxos.len(), 2);
-            assert_eq!(unspent_outputs.len(), 2);
-
-            // lengths
-            assert_eq!(new_pending_outputs.len(), 1);
-            assert_eq!(new_pending_outputs.len(), expected_output_values.len());
-
-            // comparing result with expected
-            assert_eq!(new_pending_outputs[0].as_u64(), expected_output_values[0]);
-
-            // checking fee
-            assert_eq!(pre_join_total_amount - post_join_total_amount, (*preview).fee);
-
-            destroy_tari_vector(outputs);
-            destroy_tari_vector(commitments);
-            destroy_tari_coin_preview(preview);
+            assert_eq!((*completed).transaction.body.kernels().len(), 1);
+            completed_transaction_destroy(completed);
 
+            tari_address_destroy(destination);
+            tari_address_destroy(own_address);
             string_destroy(network_str as *mut c_char);
             string_destroy(db_name_alice_str as *mut c_char);
             string_destroy(db_path_alice_str as *mut c_char);
             string_destroy(address_alice_str as *mut c_char);
-            private_key_destroy(secret_key_alice);
             transport_config_destroy(transport_config_alice);
             comms_config_destroy(alice_config);
             wallet_destroy(alice_wallet);
@@ -11629,7 +19415,7 @@ mod test {
 
     #[test]
     #[allow(clippy::too_many_lines, clippy::needless_collect)]
-    fn test_wallet_coin_split() {
+    fn test_wallet_get_network_and_version() {
         unsafe {
             let mut error = 0;
             let error_ptr = &mut error as *mut c_int;
@@ -11657,12 +19443,43 @@ mod test {
                 20,
                 10800,
                 false,
+                true,
+                true,
+                0,
+                0,
+                0,
+                error_ptr,
+            );
+            assert!((*alice_config).dht.saf.auto_request);
+            assert!((*alice_config).dht.auto_join);
+            assert_eq!((*alice_config).dht.num_neighbouring_nodes, 5);
+            assert_eq!((*alice_config).dht.network_discovery.min_desired_peers, 16);
+
+            let quiet_config = comms_config_create(
+                address_alice_str,
+                transport_config_alice,
+                db_name_alice_str,
+                db_path_alice_str,
+                20,
+                10800,
+                false,
+                false,
+                false,
+                2,
+                0,
+                4,
                 error_ptr,
             );
+            assert_eq!(error, 0);
+            assert!(!(*quiet_config).dht.saf.auto_request);
+            assert!(!(*quiet_config).dht.auto_join);
+            assert_eq!((*quiet_config).dht.num_neighbouring_nodes, 2);
+            assert_eq!((*quiet_config).dht.num_random_nodes, 1);
+            assert_eq!((*quiet_config).dht.network_discovery.min_desired_peers, 4);
+            comms_config_destroy(quiet_config);
 
             let passphrase: *const c_char = CString::into_raw(CString::new("niao").unwrap()) as *const c_char;
             let dns_string: *const c_char = CString::into_raw(CString::new("").unwrap()) as *const c_char;
-
             let void_ptr: *mut c_void = &mut (5) as *mut _ as *mut c_void;
             let alice_wallet = wallet_create(
                 void_ptr,
@@ -11700,155 +19517,520 @@ mod test {
                 error_ptr,
             );
             assert_eq!(error, 0);
+
+            let key_manager = &(*alice_wallet).wallet.key_manager_service;
             for i in 1..=5 {
-                let uo = (*alice_wallet).runtime.block_on(create_test_input(
-                    (15000 * i).into(),
-                    0,
-                    &(*alice_wallet).wallet.key_manager_service,
-                    vec![],
-                ));
                 (*alice_wallet)
                     .runtime
                     .block_on(
-                        (*alice_wallet)
-                            .wallet
-                            .output_manager_service
-                            .add_output(uo.clone(), None),
+                        (*alice_wallet).wallet.output_manager_service.add_output(
+                            (*alice_wallet).runtime.block_on(create_test_input(
+                                (15000 * i).into(),
+                                0,
+                                key_manager,
+                                vec![],
+                            )),
+                            None,
+                        ),
                     )
                     .unwrap();
-                (*alice_wallet)
-                    .wallet
-                    .output_db
-                    .mark_outputs_as_unspent(vec![(
-                        (*alice_wallet)
-                            .runtime
-                            .block_on(uo.hash(&(*alice_wallet).wallet.key_manager_service))
-                            .unwrap(),
-                        true,
-                    )])
-                    .unwrap();
             }
 
-            // ----------------------------------------------------------------------------
-            // preview
+            // obtaining network and version
+            let last_version = wallet_get_last_version(alice_config, &mut error as *mut c_int);
+            let last_network = wallet_get_last_network(alice_config, &mut error as *mut c_int);
 
-            let outputs = wallet_get_utxos(
-                alice_wallet,
-                0,
-                100,
-                TariUtxoSort::ValueAsc,
-                ptr::null_mut(),
-                0,
-                error_ptr,
+            let sql_database_path = (*alice_config)
+                .datastore_path
+                .join((*alice_config).peer_database_name.clone())
+                .with_extension("sqlite3");
+            let db_path = CString::new(sql_database_path.to_str().unwrap()).unwrap();
+            let db_last_version = wallet_db_get_last_version(db_path.as_ptr(), error_ptr);
+            assert_eq!(error, 0);
+            let db_last_network = wallet_db_get_last_network(db_path.as_ptr(), error_ptr);
+            assert_eq!(error, 0);
+            assert!(!db_last_network.is_null());
+            assert_eq!(
+                CStr::from_ptr(db_last_network).to_str().unwrap(),
+                CStr::from_ptr(last_network).to_str().unwrap()
             );
-            let utxos: &[TariUtxo] = slice::from_raw_parts_mut((*outputs).ptr as *mut TariUtxo, (*outputs).len);
+            assert_eq!(db_last_version.is_null(), last_version.is_null());
+            if !db_last_version.is_null() {
+                assert_eq!(
+                    CStr::from_ptr(db_last_version).to_str().unwrap(),
+                    CStr::from_ptr(last_version).to_str().unwrap()
+                );
+            }
+            string_destroy(last_version);
+            string_destroy(last_network);
+            string_destroy(db_last_version);
+            string_destroy(db_last_network);
+
+            // no base node has reported chain metadata yet
+            let tip_height = wallet_get_network_tip_height(alice_wallet, error_ptr);
+            assert_eq!(tip_height, 0);
+            assert_eq!(error, 303);
+            assert_eq!(wallet_get_tip_age(alice_wallet, error_ptr), 0);
+            assert_eq!(error, 303);
+
+            // the memory transport has no tor identity to export
+            assert!(wallet_export_tor_identity(alice_wallet, error_ptr).is_null());
+            assert_eq!(error, 424);
+
+            // the memory transport has no onion address
+            let onion_address = wallet_get_onion_address(alice_wallet, error_ptr);
+            assert_eq!(error, 7);
+            assert_eq!(CStr::from_ptr(onion_address).to_str().unwrap(), "");
+            string_destroy(onion_address);
+
+            // a fresh wallet has nothing pending
+            assert_eq!(wallet_get_pending_incoming_total(alice_wallet, error_ptr), 0);
+            assert_eq!(error, 0);
+            assert_eq!(wallet_get_pending_outgoing_total(alice_wallet, error_ptr), 0);
             assert_eq!(error, 0);
 
-            let pre_split_total_amount = utxos[0..3].iter().fold(0u64, |acc, x| acc + x.value);
-
-            let payload = utxos[0..3]
-                .iter()
-                .map(|x| CStr::from_ptr(x.commitment).to_str().unwrap().to_owned())
-                .collect::<Vec<String>>();
-
-            let commitments = Box::into_raw(Box::new(TariVector::from(payload)));
-
-            let preview = wallet_preview_coin_split(alice_wallet, commitments, 3, 5, error_ptr);
+            // without a base node the wallet never syncs, so waiting times out
+            assert!(!wallet_wait_until_initial_sync(alice_wallet, 1, error_ptr));
             assert_eq!(error, 0);
-            destroy_tari_vector(commitments);
 
-            // ----------------------------------------------------------------------------
-            // split
+            // there is no base node connection to reset yet
+            assert!(!wallet_reset_base_node_connection(alice_wallet, error_ptr));
+            assert_eq!(error, 0);
+            assert!(!wallet_reset_base_node_connection(ptr::null_mut(), error_ptr));
+            assert_eq!(error, 1);
 
-            let outputs = wallet_get_utxos(
+            // listeners can be replaced after the wallet has been created
+            assert!(wallet_set_balance_updated_callback(
                 alice_wallet,
-                0,
-                100,
-                TariUtxoSort::ValueAsc,
+                balance_updated_callback,
+                error_ptr
+            ));
+            assert_eq!(error, 0);
+            assert!(wallet_set_connectivity_status_callback(
+                alice_wallet,
+                connectivity_status_callback,
+                error_ptr
+            ));
+            assert_eq!(error, 0);
+            assert!(wallet_set_base_node_state_callback(
+                alice_wallet,
+                base_node_state_callback,
+                error_ptr
+            ));
+            assert_eq!(error, 0);
+            assert!(!wallet_set_balance_updated_callback(
                 ptr::null_mut(),
-                0,
-                error_ptr,
-            );
-            let utxos: &[TariUtxo] = slice::from_raw_parts_mut((*outputs).ptr as *mut TariUtxo, (*outputs).len);
+                balance_updated_callback,
+                error_ptr
+            ));
+            assert_eq!(error, 1);
+
+            let connectivity_status = wallet_get_connectivity_status(alice_wallet, error_ptr);
             assert_eq!(error, 0);
+            assert_ne!(connectivity_status, OnlineStatus::Online as c_int);
+            assert_eq!(wallet_get_connectivity_status(ptr::null_mut(), error_ptr), -1);
+            assert_eq!(error, 1);
 
-            let payload = utxos[0..3]
-                .iter()
-                .map(|x| CStr::from_ptr(x.commitment).to_str().unwrap().to_owned())
-                .collect::<Vec<String>>();
+            // no request has been made to a base node yet
+            assert_eq!(wallet_get_base_node_latency(alice_wallet, error_ptr), -1);
+            assert_eq!(error, 0);
+            assert_eq!(wallet_get_base_node_latency(ptr::null_mut(), error_ptr), -1);
+            assert_eq!(error, 1);
 
-            let commitments = Box::into_raw(Box::new(TariVector::from(payload)));
+            let connected_peers = comms_list_connected_peers(alice_wallet, error_ptr);
+            assert_eq!(error, 0);
+            assert_eq!((*connected_peers).tag, TariTypeTag::Text);
+            assert_eq!((*connected_peers).len, 0);
+            destroy_tari_vector(connected_peers);
+
+            // dialing a peer that is not known to the wallet fails with a dial error
+            let unknown_peer_secret = private_key_generate();
+            let unknown_peer = public_key_from_private_key(unknown_peer_secret, error_ptr);
+            assert!(!wallet_dial_peer(alice_wallet, unknown_peer, error_ptr));
+            assert_eq!(error, 17);
+            assert!(!wallet_dial_peer(alice_wallet, ptr::null_mut(), error_ptr));
+            assert_eq!(error, 1);
+            public_key_destroy(unknown_peer);
+            private_key_destroy(unknown_peer_secret);
 
-            let result = wallet_coin_split(alice_wallet, commitments, 3, 5, error_ptr);
+            // a fee can be estimated for an empty wallet and grows with the number of outputs
+            let fee_one_output = wallet_estimate_fee(alice_wallet, 10_000, 5, 1, 1, error_ptr);
             assert_eq!(error, 0);
-            assert!(result > 0);
+            assert!(fee_one_output > 0);
+            assert!(wallet_estimate_fee(alice_wallet, 10_000, 5, 3, 1, error_ptr) > fee_one_output);
+            assert_eq!(error, 0);
+            assert_eq!(wallet_estimate_fee(alice_wallet, 10_000, 5, 0, 1, error_ptr), 0);
+            assert_eq!(error, 7);
+            assert_eq!(wallet_estimate_fee(alice_wallet, 10_000, 5, 1, 0, error_ptr), 0);
+            assert_eq!(error, 7);
+            assert_eq!(wallet_estimate_fee(alice_wallet, 0, 5, 1, 1, error_ptr), 0);
+            assert_eq!(error, 7);
+            assert_eq!(wallet_estimate_fee(ptr::null_mut(), 10_000, 5, 1, 1, error_ptr), 0);
+            assert_eq!(error, 1);
 
-            let unspent_outputs = (*alice_wallet)
-                .wallet
-                .output_db
-                .fetch_outputs_by_query(OutputBackendQuery {
-                    status: vec![OutputStatus::Unspent],
-                    ..Default::default()
-                })
+            // sending to many recipients requires one amount per address
+            let address_str = wallet_get_tari_one_sided_address(alice_wallet, error_ptr);
+            let address_string = tari_address_to_emoji_id(address_str, error_ptr);
+            let recipients = Box::into_raw(Box::new(TariVector::from(vec![CStr::from_ptr(address_string)
+                .to_str()
                 .unwrap()
-                .into_iter()
-                .map(|x| x.wallet_output.value)
-                .collect::<Vec<_>>();
+                .to_string()])));
+            let mismatched_amounts = Box::into_raw(Box::new(TariVector::from(vec![1_000u64, 2_000u64])));
+            assert_eq!(
+                wallet_send_transaction_to_many(
+                    alice_wallet,
+                    recipients,
+                    mismatched_amounts,
+                    5,
+                    ptr::null(),
+                    error_ptr
+                ),
+                0
+            );
+            assert_eq!(error, 7);
+            assert_eq!(
+                wallet_send_transaction_to_many(alice_wallet, recipients, ptr::null_mut(), 5, ptr::null(), error_ptr),
+                0
+            );
+            assert_eq!(error, 1);
+            let address = CStr::from_ptr(address_string).to_str().unwrap().to_string();
+            let two_recipients = Box::into_raw(Box::new(TariVector::from(vec![address.clone(), address])));
+            let overflowing_amounts = Box::into_raw(Box::new(TariVector::from(vec![u64::MAX, 1u64])));
+            assert_eq!(
+                wallet_send_transaction_to_many(
+                    alice_wallet,
+                    two_recipients,
+                    overflowing_amounts,
+                    5,
+                    ptr::null(),
+                    error_ptr
+                ),
+                0
+            );
+            assert_eq!(error, 7);
+            // the amounts built from C are only read, they still belong to the caller after the send
+            let amounts = create_tari_vector(TariTypeTag::U64);
+            tari_vector_push_u64(amounts, 1_000, error_ptr);
+            assert_eq!(error, 0);
+            tari_vector_push_u64(amounts, 2_000, error_ptr);
+            assert_eq!(error, 0);
+            assert_eq!(
+                wallet_send_transaction_to_many(alice_wallet, two_recipients, amounts, 5, ptr::null(), error_ptr),
+                0
+            );
+            // the wallet has no funds to send
+            assert_ne!(error, 0);
+            assert_eq!(tari_vector_get_length(amounts, error_ptr), 2);
+            assert_eq!(tari_vector_get_u64_at(amounts, 0, error_ptr), 1_000);
+            assert_eq!(error, 0);
+            assert_eq!(tari_vector_get_u64_at(amounts, 1, error_ptr), 2_000);
+            assert_eq!(error, 0);
+            tari_vector_push_u64(amounts, 3_000, error_ptr);
+            assert_eq!(error, 0);
+            assert_eq!(tari_vector_get_u64_at(amounts, 2, error_ptr), 3_000);
+            destroy_tari_vector(amounts);
+            destroy_tari_vector(overflowing_amounts);
+            destroy_tari_vector(two_recipients);
+            destroy_tari_vector(mismatched_amounts);
+            destroy_tari_vector(recipients);
+            string_destroy(address_string);
+            tari_address_destroy(address_str);
+
+            // every key manager branch index is reported alongside its name
+            let branch_indices = wallet_get_key_manager_branch_indices(alice_wallet, error_ptr);
+            assert_eq!(error, 0);
+            let branch_names = wallet_get_key_manager_branch_names(alice_wallet, error_ptr);
+            assert_eq!(error, 0);
+            assert!((*branch_indices).len > 0);
+            assert_eq!((*branch_indices).len, (*branch_names).len);
+            destroy_tari_vector(branch_indices);
+            destroy_tari_vector(branch_names);
+
+            // keys are derived deterministically from the seed without advancing the branch
+            let branch = CString::new(TransactionKeyManagerBranch::CommitmentMask.get_branch_key()).unwrap();
+            let key_0 = wallet_derive_key(alice_wallet, branch.as_ptr(), 0, error_ptr);
+            assert_eq!(error, 0);
+            let key_0_again = wallet_derive_key(alice_wallet, branch.as_ptr(), 0, error_ptr);
+            assert_eq!(error, 0);
+            let key_1 = wallet_derive_key(alice_wallet, branch.as_ptr(), 1, error_ptr);
+            assert_eq!(error, 0);
+            assert_eq!(*key_0, *key_0_again);
+            assert_ne!(*key_0, *key_1);
+            private_key_destroy(key_0);
+            private_key_destroy(key_0_again);
+            private_key_destroy(key_1);
+            assert!(wallet_derive_key(alice_wallet, ptr::null(), 0, error_ptr).is_null());
+            assert_eq!(error, 1);
+            assert!(wallet_get_key_manager_branch_indices(ptr::null_mut(), error_ptr).is_null());
+            assert_eq!(error, 1);
 
-            let new_pending_outputs = (*alice_wallet)
-                .wallet
-                .output_db
-                .fetch_outputs_by_query(OutputBackendQuery {
-                    status: vec![OutputStatus::EncumberedToBeReceived],
-                    ..Default::default()
-                })
-                .unwrap()
-                .into_iter()
-                .map(|x| x.wallet_output.value)
-                .collect::<Vec<_>>();
+            assert_eq!(wallet_revalidate_all_outputs(ptr::null_mut(), error_ptr), 0);
+            assert_eq!(error, 1);
 
-            let post_split_total_amount = new_pending_outputs.iter().fold(0u64, |acc, x| acc + x.as_u64());
-            let expected_output_values: Vec<u64> = Vec::from_raw_parts(
-                (*(*preview).expected_outputs).ptr as *mut u64,
-                (*(*preview).expected_outputs).len,
-                (*(*preview).expected_outputs).cap,
+            assert!(wallet_get_pending_inbound_transaction_by_id(alice_wallet, 0xdead, error_ptr).is_null());
+            assert_eq!(error, 108);
+            assert!(wallet_get_pending_inbound_transaction_by_id(ptr::null_mut(), 0xdead, error_ptr).is_null());
+            assert_eq!(error, 1);
+
+            assert_eq!(wallet_get_minimum_fee_per_gram(alice_wallet, error_ptr), 1);
+            assert_eq!(error, 0);
+            assert_eq!(wallet_get_minimum_fee_per_gram(ptr::null_mut(), error_ptr), 0);
+            assert_eq!(error, 1);
+
+            let interactive_only_address =
+                Box::into_raw(Box::new(TariWalletAddress::new_single_address_with_interactive_only(
+                    PublicKey::from_secret_key(&PrivateKey::random(&mut OsRng)),
+                    Network::default(),
+                )));
+            assert_eq!(
+                wallet_send_one_sided_transaction(
+                    alice_wallet,
+                    interactive_only_address,
+                    1000,
+                    5,
+                    ptr::null(),
+                    ptr::null(),
+                    error_ptr
+                ),
+                0
+            );
+            assert_eq!(error, 7);
+            assert_eq!(
+                wallet_send_transaction(
+                    alice_wallet,
+                    interactive_only_address,
+                    1000,
+                    ptr::null_mut(),
+                    5,
+                    ptr::null(),
+                    true,
+                    ptr::null(),
+                    error_ptr
+                ),
+                0
+            );
+            assert_eq!(error, 7);
+            assert_eq!(
+                wallet_send_one_sided_transaction(
+                    ptr::null_mut(),
+                    interactive_only_address,
+                    1000,
+                    5,
+                    ptr::null(),
+                    ptr::null(),
+                    error_ptr
+                ),
+                0
+            );
+            assert_eq!(error, 1);
+            tari_address_destroy(interactive_only_address);
+            // a payment id that does not fit in the encrypted data of the output is rejected before sending
+            let one_sided_address = wallet_get_tari_one_sided_address(alice_wallet, error_ptr);
+            let long_payment_id = Box::into_raw(Box::new(ByteVector(vec![1u8; MAX_PAYMENT_ID_LENGTH + 1])));
+            assert_eq!(
+                wallet_send_one_sided_transaction(
+                    alice_wallet,
+                    one_sided_address,
+                    1000,
+                    5,
+                    ptr::null(),
+                    long_payment_id,
+                    error_ptr
+                ),
+                0
+            );
+            assert_eq!(error, 7);
+            let long_payment_id_string = CString::new("p".repeat(MAX_PAYMENT_ID_LENGTH + 1)).unwrap();
+            assert_eq!(
+                wallet_send_transaction(
+                    alice_wallet,
+                    one_sided_address,
+                    1000,
+                    ptr::null_mut(),
+                    5,
+                    ptr::null(),
+                    true,
+                    long_payment_id_string.as_ptr(),
+                    error_ptr
+                ),
+                0
             );
+            assert_eq!(error, 7);
+            byte_vector_destroy(long_payment_id);
+            tari_address_destroy(one_sided_address);
 
-            let outputs = wallet_get_utxos(
+            let recovery_stats = wallet_get_recovery_stats(alice_wallet, error_ptr);
+            assert_eq!(error, 0);
+            assert_eq!((*recovery_stats).tag, TariTypeTag::U64);
+            assert_eq!(
+                slice::from_raw_parts((*recovery_stats).ptr as *const u64, (*recovery_stats).len),
+                &[0, 0, 0]
+            );
+            destroy_tari_vector(recovery_stats);
+            assert!(wallet_get_recovery_stats(ptr::null_mut(), error_ptr).is_null());
+            assert_eq!(error, 1);
+
+            let completed_transactions = wallet_get_completed_transactions_ex(alice_wallet, false, error_ptr);
+            assert_eq!(error, 0);
+            assert_eq!(completed_transactions_get_length(completed_transactions, error_ptr), 0);
+            completed_transactions_destroy(completed_transactions);
+            assert!(wallet_get_completed_transactions_ex(ptr::null_mut(), true, error_ptr).is_null());
+            assert_eq!(error, 1);
+
+            let base_node_public_key = PublicKey::from_secret_key(&PrivateKey::random(&mut OsRng));
+            let base_node_public_key_ptr = Box::into_raw(Box::new(base_node_public_key.clone()));
+            let base_node_address_ptr =
+                CString::into_raw(CString::new("/ip4/127.0.0.1/tcp/18189").unwrap()) as *const c_char;
+            assert!(wallet_set_base_node_peer(
                 alice_wallet,
-                0,
-                20,
-                TariUtxoSort::ValueAsc,
-                Box::into_raw(Box::new(TariVector::from(vec![OutputStatus::Unspent]))),
-                0,
-                error_ptr,
+                base_node_public_key_ptr,
+                base_node_address_ptr,
+                error_ptr
+            ));
+            string_destroy(base_node_address_ptr as *mut c_char);
+            public_key_destroy(base_node_public_key_ptr);
+            let base_node_peers = wallet_get_base_node_peers(alice_wallet, error_ptr);
+            assert_eq!(error, 0);
+            assert_eq!(public_keys_get_length(base_node_peers, error_ptr), 1);
+            assert_eq!((*base_node_peers).0[0], base_node_public_key);
+            public_keys_destroy(base_node_peers);
+            assert!(wallet_get_base_node_peers(ptr::null_mut(), error_ptr).is_null());
+            assert_eq!(error, 1);
+
+            assert_eq!(
+                wallet_cancel_expired_pending_transactions(alice_wallet, 0, error_ptr),
+                0
             );
-            let utxos: &[TariUtxo] = slice::from_raw_parts_mut((*outputs).ptr as *mut TariUtxo, (*outputs).len);
             assert_eq!(error, 0);
-            assert_eq!(utxos.len(), 2);
-            assert_eq!(unspent_outputs.len(), 2);
+            assert_eq!(
+                wallet_cancel_expired_pending_transactions(ptr::null_mut(), 0, error_ptr),
+                0
+            );
+            assert_eq!(error, 1);
+            #[cfg(feature = "ffi-test")]
+            {
+                assert!(wallet_test_advance_time(alice_wallet, 3600, error_ptr));
+                assert_eq!(error, 0);
+                assert!(!wallet_test_advance_time(ptr::null_mut(), 3600, error_ptr));
+                assert_eq!(error, 1);
+                assert!(!wallet_test_receive_transaction(ptr::null_mut(), error_ptr));
+                assert_eq!(error, 1);
+                assert!(!wallet_test_complete_sent_transaction(
+                    alice_wallet,
+                    ptr::null_mut(),
+                    error_ptr
+                ));
+                assert_eq!(error, 1);
+            }
 
-            // lengths
-            assert_eq!(new_pending_outputs.len(), 3);
-            assert_eq!(new_pending_outputs.len(), expected_output_values.len());
+            assert_eq!(wallet_get_transaction_count(alice_wallet, -1, error_ptr), 0);
+            assert_eq!(error, 0);
+            assert_eq!(
+                wallet_get_transaction_count(alice_wallet, TransactionStatus::Broadcast as c_int, error_ptr),
+                0
+            );
+            assert_eq!(error, 0);
+            assert_eq!(wallet_get_transaction_count(alice_wallet, 99, error_ptr), 0);
+            assert_eq!(error, 7);
+            assert_eq!(wallet_get_transaction_count(ptr::null_mut(), -1, error_ptr), 0);
+            assert_eq!(error, 1);
 
-            // comparing resulting output values relative to itself
-            assert_eq!(new_pending_outputs[0], new_pending_outputs[1]);
-            assert_eq!(new_pending_outputs[2], new_pending_outputs[1] + MicroMinotari(1));
+            let own_address = wallet_get_tari_interactive_address(alice_wallet, error_ptr);
+            assert!(wallet_can_send_to_address(alice_wallet, own_address, error_ptr));
+            assert_eq!(error, 0);
+            let other_network = if (*own_address).network() == Network::NextNet {
+                Network::Esmeralda
+            } else {
+                Network::NextNet
+            };
+            let other_network_address = Box::into_raw(Box::new(TariAddress::new_single_address_with_interactive_only(
+                (*own_address).public_spend_key().clone(),
+                other_network,
+            )));
+            assert!(!wallet_can_send_to_address(
+                alice_wallet,
+                other_network_address,
+                error_ptr
+            ));
+            assert_eq!(error, 0);
+            assert!(!wallet_can_send_to_address(alice_wallet, ptr::null_mut(), error_ptr));
+            assert_eq!(error, 1);
+            tari_address_destroy(own_address);
+            tari_address_destroy(other_network_address);
 
-            // comparing resulting output values to the expected
-            assert_eq!(new_pending_outputs[0].as_u64(), expected_output_values[0]);
-            assert_eq!(new_pending_outputs[1].as_u64(), expected_output_values[1]);
-            assert_eq!(new_pending_outputs[2].as_u64(), expected_output_values[2]);
+            let export_str = wallet_export_transactions_json(alice_wallet, error_ptr);
+            assert_eq!(error, 0);
+            let export: serde_json::Value = serde_json::from_str(CStr::from_ptr(export_str).to_str().unwrap()).unwrap();
+            assert_eq!(export["version"], 1);
+            assert!(export["completed"].is_array());
+            assert!(export["cancelled"].is_array());
+            assert!(export["pending_inbound"].is_array());
+            assert!(export["pending_outbound"].is_array());
+            assert!(export["cancelled_pending_inbound"].is_array());
+            assert!(export["cancelled_pending_outbound"].is_array());
+            string_destroy(export_str);
+            assert!(wallet_export_transactions_json(ptr::null_mut(), error_ptr).is_null());
+            assert_eq!(error, 1);
 
-            // checking fee
-            assert_eq!(pre_split_total_amount - post_split_total_amount, (*preview).fee);
+            // the birthday is stored when the wallet is created and counts days since 2022-01-01
+            assert!(wallet_get_birthday(alice_wallet, error_ptr) > 0);
+            assert_eq!(error, 0);
+            assert_eq!(wallet_get_birthday(ptr::null_mut(), error_ptr), 0);
+            assert_eq!(error, 1);
+            assert!(wallet_set_scan_start_height(alice_wallet, 1000, error_ptr));
+            assert_eq!(error, 0);
+            assert!(!wallet_set_scan_start_height(ptr::null_mut(), 1000, error_ptr));
+            assert_eq!(error, 1);
 
-            destroy_tari_vector(outputs);
-            destroy_tari_vector(commitments);
-            destroy_tari_coin_preview(preview);
+            // the requested height is the height the rescan task starts from
+            assert_eq!(
+                build_rescan_task(&mut *alice_wallet, 1234).unwrap().scan_start_height(),
+                Some(1234)
+            );
+
+            // a second rescan is refused while the first one has not completed
+            assert!(wallet_rescan_from_height(
+                alice_wallet,
+                0,
+                recovery_progress_callback,
+                error_ptr
+            ));
+            assert_eq!(error, 0);
+            assert!(!wallet_rescan_from_height(
+                alice_wallet,
+                0,
+                recovery_progress_callback,
+                error_ptr
+            ));
+            assert_eq!(error, 14);
+            assert!(!wallet_rescan_from_height(
+                ptr::null_mut(),
+                0,
+                recovery_progress_callback,
+                error_ptr
+            ));
+            assert_eq!(error, 1);
+
+            // once stopped the handle stays valid, but network calls fail instead of waiting on the services
+            assert!(wallet_is_running(alice_wallet, error_ptr));
+            assert_eq!(error, 0);
+            assert!(wallet_stop(alice_wallet, error_ptr));
+            assert_eq!(error, 0);
+            assert!(!wallet_is_running(alice_wallet, error_ptr));
+            assert_eq!(error, 0);
+            assert_eq!(wallet_get_base_node_latency(alice_wallet, error_ptr), -1);
+            assert_eq!(error, 13);
+            assert!(!wallet_stop(alice_wallet, error_ptr));
+            assert_eq!(error, 13);
+            assert!(!wallet_reset_base_node_connection(alice_wallet, error_ptr));
+            assert_eq!(error, 13);
+            assert_eq!(scrape_wallet(alice_wallet, ptr::null_mut(), 5, error_ptr), 0);
+            assert_eq!(error, 13);
+            assert!(!wallet_cancel_pending_transaction(alice_wallet, 1, error_ptr));
+            assert_eq!(error, 13);
+            assert!(!wallet_is_running(ptr::null_mut(), error_ptr));
+            assert_eq!(error, 1);
 
-            string_destroy(network_str as *mut c_char);
             string_destroy(db_name_alice_str as *mut c_char);
             string_destroy(db_path_alice_str as *mut c_char);
             string_destroy(address_alice_str as *mut c_char);
@@ -11860,15 +20042,14 @@ mod test {
     }
 
     #[test]
-    #[allow(clippy::too_many_lines, clippy::needless_collect)]
-    fn test_wallet_get_network_and_version() {
+    #[allow(clippy::too_many_lines)]
+    fn test_wallet_create_watch_only() {
         unsafe {
             let mut error = 0;
             let error_ptr = &mut error as *mut c_int;
             let mut recovery_in_progress = true;
             let recovery_in_progress_ptr = &mut recovery_in_progress as *mut bool;
 
-            let secret_key_alice = private_key_generate();
             let db_name_alice = CString::new(random::string(8).as_str()).unwrap();
             let db_name_alice_str: *const c_char = CString::into_raw(db_name_alice) as *const c_char;
             let alice_temp_dir = tempdir().unwrap();
@@ -11889,13 +20070,21 @@ mod test {
                 20,
                 10800,
                 false,
+                true,
+                true,
+                0,
+                0,
+                0,
                 error_ptr,
             );
 
-            let passphrase: *const c_char = CString::into_raw(CString::new("niao").unwrap()) as *const c_char;
+            let view_key = private_key_generate();
+            let spend_private_key = private_key_generate();
+            let spend_public_key = public_key_from_private_key(spend_private_key, error_ptr);
+            let passphrase: *const c_char = CString::into_raw(CString::new("watcher").unwrap()) as *const c_char;
             let dns_string: *const c_char = CString::into_raw(CString::new("").unwrap()) as *const c_char;
             let void_ptr: *mut c_void = &mut (5) as *mut _ as *mut c_void;
-            let alice_wallet = wallet_create(
+            let alice_wallet = wallet_create_watch_only(
                 void_ptr,
                 alice_config,
                 ptr::null(),
@@ -11903,8 +20092,8 @@ mod test {
                 0,
                 0,
                 passphrase,
-                ptr::null(),
-                ptr::null(),
+                view_key,
+                spend_public_key,
                 network_str,
                 dns_string,
                 ptr::null(),
@@ -11931,39 +20120,97 @@ mod test {
                 error_ptr,
             );
             assert_eq!(error, 0);
+            assert!((*alice_wallet).wallet.is_watch_only());
+            assert_eq!(wallet_get_wallet_type(alice_wallet, error_ptr), 3);
+            assert_eq!(error, 0);
+            assert_eq!(wallet_get_wallet_type(ptr::null_mut(), error_ptr), -1);
+            assert_eq!(error, 1);
 
-            let key_manager = &(*alice_wallet).wallet.key_manager_service;
-            for i in 1..=5 {
-                (*alice_wallet)
-                    .runtime
-                    .block_on(
-                        (*alice_wallet).wallet.output_manager_service.add_output(
-                            (*alice_wallet).runtime.block_on(create_test_input(
-                                (15000 * i).into(),
-                                0,
-                                key_manager,
-                                vec![],
-                            )),
-                            None,
-                        ),
-                    )
-                    .unwrap();
-            }
-
-            // obtaining network and version
-            let _ = wallet_get_last_version(alice_config, &mut error as *mut c_int);
-            let _ = wallet_get_last_network(alice_config, &mut error as *mut c_int);
+            let destination = Box::into_raw(Box::new(TariAddress::default()));
+            let tx_id = wallet_send_transaction(
+                alice_wallet,
+                destination,
+                1000,
+                ptr::null_mut(),
+                5,
+                ptr::null(),
+                true,
+                ptr::null(),
+                error_ptr,
+            );
+            assert_eq!(tx_id, 0);
+            assert_eq!(error, 15);
+            assert_eq!(wallet_coin_join_to_amount(alice_wallet, 1000, 5, error_ptr), 0);
+            assert_eq!(error, 15);
+            assert_eq!(scrape_wallet(alice_wallet, destination, 5, error_ptr), 0);
+            assert_eq!(error, 15);
+            let branch = CString::new("commitment mask").unwrap();
+            assert!(wallet_derive_key(alice_wallet, branch.as_ptr(), 0, error_ptr).is_null());
+            assert_eq!(error, 15);
+
+            assert!(wallet_create_watch_only(
+                void_ptr,
+                alice_config,
+                ptr::null(),
+                0,
+                0,
+                0,
+                passphrase,
+                ptr::null_mut(),
+                spend_public_key,
+                network_str,
+                dns_string,
+                ptr::null(),
+                true,
+                received_tx_callback,
+                received_tx_reply_callback,
+                received_tx_finalized_callback,
+                broadcast_callback,
+                mined_callback,
+                mined_unconfirmed_callback,
+                scanned_callback,
+                scanned_unconfirmed_callback,
+                transaction_send_result_callback,
+                tx_cancellation_callback,
+                txo_validation_complete_callback,
+                contacts_liveness_data_updated_callback,
+                balance_updated_callback,
+                transaction_validation_complete_callback,
+                saf_messages_received_callback,
+                connectivity_status_callback,
+                wallet_scanned_height_callback,
+                base_node_state_callback,
+                recovery_in_progress_ptr,
+                error_ptr,
+            )
+            .is_null());
+            assert_eq!(error, 1);
 
+            tari_address_destroy(destination);
+            string_destroy(network_str as *mut c_char);
             string_destroy(db_name_alice_str as *mut c_char);
             string_destroy(db_path_alice_str as *mut c_char);
             string_destroy(address_alice_str as *mut c_char);
-            private_key_destroy(secret_key_alice);
+            string_destroy(passphrase as *mut c_char);
+            string_destroy(dns_string as *mut c_char);
+            private_key_destroy(view_key);
+            private_key_destroy(spend_private_key);
+            public_key_destroy(spend_public_key);
             transport_config_destroy(transport_config_alice);
             comms_config_destroy(alice_config);
             wallet_destroy(alice_wallet);
         }
     }
 
+    #[test]
+    fn test_block_on_runtime() {
+        let runtime = Runtime::new().unwrap();
+        assert_eq!(block_on_runtime(&runtime, async { 5 }), Ok(5));
+        let result = block_on_runtime(&runtime, async { panic!("runtime failure") });
+        assert_eq!(result, Err(InterfaceError::RuntimeError("runtime failure".to_string())));
+        assert_eq!(LibWalletError::from(result.unwrap_err()).code, 12);
+    }
+
     #[test]
     fn test_tari_vector() {
         let mut error = 0;
@@ -12005,6 +20252,60 @@ mod test {
             assert_eq!((*tv).cap, 12);
 
             destroy_tari_vector(tv);
+
+            let tv = Box::into_raw(Box::new(TariVector::from(vec![1u64, u64::MAX, 3u64])));
+            assert_eq!(tari_vector_get_length(tv, &mut error as *mut c_int), 3);
+            assert_eq!(error, 0);
+            assert_eq!(tari_vector_get_tag(tv, &mut error as *mut c_int), TariTypeTag::U64);
+            assert_eq!(error, 0);
+            assert_eq!(tari_vector_get_length(ptr::null(), &mut error as *mut c_int), 0);
+            assert_ne!(error, 0);
+            assert_eq!(tari_vector_get_u64_at(tv, 1, &mut error as *mut c_int), u64::MAX);
+            assert_eq!(error, 0);
+            assert_eq!(tari_vector_get_u64_at(tv, 3, &mut error as *mut c_int), 0);
+            assert_eq!(error, LibWalletError::from(InterfaceError::PositionInvalidError).code);
+            assert_eq!(tari_vector_get_i64_at(tv, 0, &mut error as *mut c_int), 0);
+            assert_ne!(error, 0);
+            destroy_tari_vector(tv);
+
+            let tv = Box::into_raw(Box::new(TariVector::from(Vec::<u64>::new())));
+            for i in 0..10u64 {
+                tari_vector_push_u64(tv, i * 1000, &mut error as *mut c_int);
+                assert_eq!(error, 0);
+            }
+            assert_eq!((*tv).len, 10);
+            assert_eq!(tari_vector_get_u64_at(tv, 7, &mut error as *mut c_int), 7000);
+            assert_eq!(error, 0);
+            destroy_tari_vector(tv);
+
+            // a vector created from C starts with a small untyped buffer, pushing must not write past it
+            let tv = create_tari_vector(TariTypeTag::U64);
+            for i in 0..10u64 {
+                tari_vector_push_u64(tv, u64::MAX - i, &mut error as *mut c_int);
+                assert_eq!(error, 0);
+            }
+            assert_eq!(tari_vector_get_length(tv, &mut error as *mut c_int), 10);
+            for i in 0..10u64 {
+                assert_eq!(
+                    tari_vector_get_u64_at(tv, i as usize, &mut error as *mut c_int),
+                    u64::MAX - i
+                );
+                assert_eq!(error, 0);
+            }
+            destroy_tari_vector(tv);
+
+            let tv = create_tari_vector(TariTypeTag::Text);
+            tari_vector_push_u64(tv, 1, &mut error as *mut c_int);
+            assert_ne!(error, 0);
+            assert_eq!((*tv).len, 0);
+            destroy_tari_vector(tv);
+
+            let tv = Box::into_raw(Box::new(TariVector::from(vec![-1i64, 2i64])));
+            assert_eq!(tari_vector_get_i64_at(tv, 0, &mut error as *mut c_int), -1);
+            assert_eq!(error, 0);
+            assert_eq!(tari_vector_get_u64_at(tv, 0, &mut error as *mut c_int), 0);
+            assert_ne!(error, 0);
+            destroy_tari_vector(tv);
         }
     }
 
@@ -12155,6 +20456,11 @@ mod test {
                 20,
                 10800,
                 false,
+                true,
+                true,
+                0,
+                0,
+                0,
                 error_ptr,
             );
             let passphrase: *const c_char = CString::into_raw(CString::new("niao").unwrap()) as *const c_char;
@@ -12367,9 +20673,95 @@ mod test {
             assert_eq!(error, 0);
             assert!(tx_id_2 > 0);
 
+            // import statuses that are not used for imported outputs are rejected before anything is imported
+            assert_eq!(
+                wallet_import_external_utxo_as_non_rewindable_with_metadata(
+                    wallet_ptr,
+                    tari_utxo_ptr_2,
+                    source_address_ptr,
+                    message_ptr,
+                    ptr::null(),
+                    TransactionStatus::CoinbaseConfirmed as c_int,
+                    error_ptr,
+                ),
+                0
+            );
+            assert_eq!(error, 7);
+            assert_eq!(
+                wallet_import_external_utxo_as_non_rewindable_with_metadata(
+                    ptr::null_mut(),
+                    tari_utxo_ptr_2,
+                    source_address_ptr,
+                    message_ptr,
+                    ptr::null(),
+                    -1,
+                    error_ptr,
+                ),
+                0
+            );
+            assert_eq!(error, 1);
+            // a confirmed status needs the block the output was mined in, so it can not be supplied on import
+            assert_eq!(
+                wallet_import_external_utxo_as_non_rewindable_with_metadata(
+                    wallet_ptr,
+                    tari_utxo_ptr_2,
+                    source_address_ptr,
+                    message_ptr,
+                    ptr::null(),
+                    TransactionStatus::OneSidedConfirmed as c_int,
+                    error_ptr,
+                ),
+                0
+            );
+            assert_eq!(error, 7);
+
+            // the imported outputs have not been mined yet
+            assert_eq!(wallet_get_transaction_confirmations(wallet_ptr, tx_id_1, error_ptr), 0);
+            assert_eq!(error, 0);
+            let unknown_tx_id = tx_id_1.wrapping_add(tx_id_2).wrapping_add(1);
+            assert_eq!(
+                wallet_get_transaction_confirmations(wallet_ptr, unknown_tx_id, error_ptr),
+                0
+            );
+            assert_eq!(error, 111);
+            // imported outputs are received as inbound transactions
+            assert_eq!(wallet_get_transaction_direction(wallet_ptr, tx_id_1, error_ptr), 0);
+            assert_eq!(error, 0);
+            assert_eq!(
+                wallet_get_transaction_direction(wallet_ptr, unknown_tx_id, error_ptr),
+                -1
+            );
+            assert_eq!(error, 0);
+            assert_eq!(
+                wallet_get_transaction_direction(ptr::null_mut(), tx_id_1, error_ptr),
+                -1
+            );
+            assert_eq!(error, 1);
+            assert_eq!(
+                wallet_get_transaction_cancellation_reason(wallet_ptr, tx_id_1, error_ptr),
+                -1
+            );
+            assert_eq!(error, 0);
+            assert_eq!(
+                wallet_get_transaction_cancellation_reason(wallet_ptr, unknown_tx_id, error_ptr),
+                -1
+            );
+            assert_eq!(error, 204);
+            assert_eq!(
+                wallet_get_transaction_cancellation_reason(ptr::null_mut(), tx_id_1, error_ptr),
+                -1
+            );
+            assert_eq!(error, 1);
+
             let outputs_vec = wallet_get_all_utxos(wallet_ptr, error_ptr);
             let outputs = (*outputs_vec).to_utxo_vec().unwrap();
             assert_eq!(outputs.len(), 2);
+            let maturing_vec = wallet_get_utxos_by_maturity(wallet_ptr, 0, c_ulonglong::MAX, error_ptr);
+            assert_eq!(error, 0);
+            assert_eq!((*maturing_vec).len, 2);
+            destroy_tari_vector(maturing_vec);
+            assert!(wallet_get_utxos_by_maturity(wallet_ptr, 10, 5, error_ptr).is_null());
+            assert_eq!(error, 7);
 
             let unspent_outputs_ptr = wallet_get_unspent_outputs(wallet_ptr, error_ptr);
             let unblinded_output_ptr_1 = unblinded_outputs_get_at(unspent_outputs_ptr, 0, error_ptr);
@@ -12390,6 +20782,266 @@ mod test {
             );
             assert_eq!((*proof_ptr_2).0, (*range_proof_ptr_2).0);
 
+            // pages are taken in insertion order and only hold the requested slice
+            let first_page_ptr = wallet_get_unspent_outputs_paged(wallet_ptr, 0, 1, error_ptr);
+            assert_eq!(error, 0);
+            assert_eq!(unblinded_outputs_get_length(first_page_ptr, error_ptr), 1);
+            assert_eq!((*first_page_ptr).0[0].spending_key, (*tari_utxo_ptr_1).spending_key);
+            let second_page_ptr = wallet_get_unspent_outputs_paged(wallet_ptr, 1, 5, error_ptr);
+            assert_eq!(error, 0);
+            assert_eq!(unblinded_outputs_get_length(second_page_ptr, error_ptr), 1);
+            assert_eq!((*second_page_ptr).0[0].spending_key, (*tari_utxo_ptr_2).spending_key);
+            let empty_page_ptr = wallet_get_unspent_outputs_paged(wallet_ptr, 2, 5, error_ptr);
+            assert_eq!(error, 0);
+            assert_eq!(unblinded_outputs_get_length(empty_page_ptr, error_ptr), 0);
+            assert!(wallet_get_unspent_outputs_paged(ptr::null_mut(), 0, 5, error_ptr).is_null());
+            assert_eq!(error, 1);
+            unblinded_outputs_destroy(first_page_ptr);
+            unblinded_outputs_destroy(second_page_ptr);
+            unblinded_outputs_destroy(empty_page_ptr);
+
+            // the faux transaction can be promoted to confirmed exactly once, in a real block
+            let block_hash = CString::new(BlockHash::from([7u8; 32]).to_hex()).unwrap();
+            assert!(!wallet_mark_imported_transaction_confirmed(
+                wallet_ptr,
+                tx_id_1,
+                10,
+                ptr::null(),
+                1_700_000_000,
+                error_ptr
+            ));
+            assert_eq!(error, 1);
+            let invalid_hash = CString::new("not a hash").unwrap();
+            assert!(!wallet_mark_imported_transaction_confirmed(
+                wallet_ptr,
+                tx_id_1,
+                10,
+                invalid_hash.as_ptr(),
+                1_700_000_000,
+                error_ptr
+            ));
+            assert_eq!(error, 7);
+            assert!(wallet_mark_imported_transaction_confirmed(
+                wallet_ptr,
+                tx_id_1,
+                10,
+                block_hash.as_ptr(),
+                1_700_000_000,
+                error_ptr
+            ));
+            assert_eq!(error, 0);
+            assert!(wallet_get_transaction_confirmations(wallet_ptr, tx_id_1, error_ptr) > 0);
+            assert_eq!(error, 0);
+            let confirmed_outputs_vec = wallet_get_all_utxos(wallet_ptr, error_ptr);
+            let confirmed_outputs = (*confirmed_outputs_vec).to_utxo_vec().unwrap();
+            assert!(confirmed_outputs.iter().any(|o| o.mined_height == 10 && o.status == 0));
+            destroy_tari_vector(confirmed_outputs_vec);
+            // being mined gives the import a one-sided status, but it was not received as a one-sided payment
+            let confirmed_tx = wallet_get_completed_transaction_by_id(wallet_ptr, tx_id_1, error_ptr);
+            assert_eq!(error, 0);
+            assert_eq!(
+                completed_transaction_get_status(confirmed_tx, error_ptr),
+                TransactionStatus::OneSidedConfirmed as c_int
+            );
+            assert!(!completed_transaction_is_one_sided(confirmed_tx, error_ptr));
+            assert_eq!(error, 0);
+            completed_transaction_destroy(confirmed_tx);
+            assert!(!wallet_mark_imported_transaction_confirmed(
+                wallet_ptr,
+                tx_id_1,
+                10,
+                block_hash.as_ptr(),
+                1_700_000_000,
+                error_ptr
+            ));
+            assert_eq!(error, 201);
+            assert!(!wallet_mark_imported_transaction_confirmed(
+                wallet_ptr,
+                unknown_tx_id,
+                10,
+                block_hash.as_ptr(),
+                1_700_000_000,
+                error_ptr
+            ));
+            assert_ne!(error, 0);
+
+            // an output that is already in the wallet cannot be imported again through the rewindable path
+            let tx_id = wallet_import_unblinded_output_as_rewindable(
+                wallet_ptr,
+                tari_utxo_ptr_1,
+                source_address_ptr,
+                message_ptr,
+                error_ptr,
+            );
+            assert_eq!(tx_id, 0);
+            assert_eq!(error, 999);
+            assert_eq!(
+                wallet_import_unblinded_output_as_rewindable(
+                    wallet_ptr,
+                    ptr::null_mut(),
+                    source_address_ptr,
+                    message_ptr,
+                    error_ptr
+                ),
+                0
+            );
+            assert_eq!(error, 1);
+
+            // the payment id and import status are stored on the faux transaction
+            let utxo_3 = runtime
+                .block_on(create_wallet_output_with_data(
+                    script!(Nop).unwrap(),
+                    OutputFeatures::default(),
+                    &runtime.block_on(TestParams::new(key_manager)),
+                    MicroMinotari(5432u64),
+                    key_manager,
+                ))
+                .unwrap();
+            let spending_key = runtime
+                .block_on(key_manager.get_private_key(&utxo_3.spending_key_id))
+                .unwrap();
+            let script_private_key = runtime
+                .block_on(key_manager.get_private_key(&utxo_3.script_key_id))
+                .unwrap();
+            let spending_key_ptr_3 = Box::into_raw(Box::new(spending_key));
+            let features_ptr_3 = Box::into_raw(Box::new(utxo_3.features.clone()));
+            let proof_ptr_3 = range_proof_default();
+            let metadata_signature_ptr_3 = Box::into_raw(Box::new(utxo_3.metadata_signature.clone()));
+            let sender_offset_public_key_ptr_3 = Box::into_raw(Box::new(utxo_3.sender_offset_public_key.clone()));
+            let script_private_key_ptr_3 = Box::into_raw(Box::new(script_private_key));
+            let covenant_ptr_3 = Box::into_raw(Box::new(utxo_3.covenant.clone()));
+            let encrypted_data_ptr_3 = Box::into_raw(Box::new(utxo_3.encrypted_data));
+            let script_ptr_3 =
+                CString::into_raw(CString::new(script!(Nop).unwrap().to_hex()).unwrap()) as *const c_char;
+            let input_data_ptr_3 =
+                CString::into_raw(CString::new(utxo_3.input_data.to_hex()).unwrap()) as *const c_char;
+            let tari_utxo_ptr_3 = create_tari_unblinded_output(
+                utxo_3.value.as_u64(),
+                spending_key_ptr_3,
+                features_ptr_3,
+                script_ptr_3,
+                input_data_ptr_3,
+                metadata_signature_ptr_3,
+                sender_offset_public_key_ptr_3,
+                script_private_key_ptr_3,
+                covenant_ptr_3,
+                encrypted_data_ptr_3,
+                utxo_3.minimum_value_promise.as_u64(),
+                0,
+                proof_ptr_3,
+                error_ptr,
+            );
+            assert_eq!(error, 0);
+            let payment_id_bytes = b"ledger-entry-42";
+            let payment_id_ptr =
+                byte_vector_create(payment_id_bytes.as_ptr(), payment_id_bytes.len() as c_uint, error_ptr);
+            let tx_id_3 = wallet_import_external_utxo_as_non_rewindable_with_metadata(
+                wallet_ptr,
+                tari_utxo_ptr_3,
+                source_address_ptr,
+                message_ptr,
+                payment_id_ptr,
+                TransactionStatus::OneSidedUnconfirmed as c_int,
+                error_ptr,
+            );
+            assert_eq!(error, 0);
+            assert!(tx_id_3 > 0);
+            let imported_tx = wallet_get_completed_transaction_by_id(wallet_ptr, tx_id_3, error_ptr);
+            assert_eq!(error, 0);
+            assert_eq!(
+                completed_transaction_get_status(imported_tx, error_ptr),
+                TransactionStatus::OneSidedUnconfirmed as c_int
+            );
+            // the status was chosen by the caller, the output was not found by scanning for one-sided payments
+            assert!(!completed_transaction_is_one_sided(imported_tx, error_ptr));
+            let imported_payment_id = completed_transaction_get_payment_id(imported_tx, error_ptr);
+            assert_eq!(error, 0);
+            assert_eq!(CStr::from_ptr(imported_payment_id).to_str().unwrap(), "ledger-entry-42");
+            string_destroy(imported_payment_id as *mut c_char);
+            completed_transaction_destroy(imported_tx);
+            byte_vector_destroy(payment_id_ptr);
+
+            // an output whose encrypted data was made with this wallet's keys is imported as a rewindable output
+            let wallet_key_manager = &(*wallet_ptr).wallet.key_manager_service;
+            let utxo_4 = runtime
+                .block_on(create_wallet_output_with_data(
+                    script!(Nop).unwrap(),
+                    OutputFeatures::default(),
+                    &runtime.block_on(TestParams::new(wallet_key_manager)),
+                    MicroMinotari(6543u64),
+                    wallet_key_manager,
+                ))
+                .unwrap();
+            let spending_key = runtime
+                .block_on(wallet_key_manager.get_private_key(&utxo_4.spending_key_id))
+                .unwrap();
+            let script_private_key = runtime
+                .block_on(wallet_key_manager.get_private_key(&utxo_4.script_key_id))
+                .unwrap();
+            let spending_key_ptr_4 = Box::into_raw(Box::new(spending_key));
+            let features_ptr_4 = Box::into_raw(Box::new(utxo_4.features.clone()));
+            let proof_ptr_4 = range_proof_default();
+            let metadata_signature_ptr_4 = Box::into_raw(Box::new(utxo_4.metadata_signature.clone()));
+            let sender_offset_public_key_ptr_4 = Box::into_raw(Box::new(utxo_4.sender_offset_public_key.clone()));
+            let script_private_key_ptr_4 = Box::into_raw(Box::new(script_private_key));
+            let covenant_ptr_4 = Box::into_raw(Box::new(utxo_4.covenant.clone()));
+            let encrypted_data_ptr_4 = Box::into_raw(Box::new(utxo_4.encrypted_data));
+            let script_ptr_4 =
+                CString::into_raw(CString::new(script!(Nop).unwrap().to_hex()).unwrap()) as *const c_char;
+            let input_data_ptr_4 =
+                CString::into_raw(CString::new(utxo_4.input_data.to_hex()).unwrap()) as *const c_char;
+            let tari_utxo_ptr_4 = create_tari_unblinded_output(
+                utxo_4.value.as_u64(),
+                spending_key_ptr_4,
+                features_ptr_4,
+                script_ptr_4,
+                input_data_ptr_4,
+                metadata_signature_ptr_4,
+                sender_offset_public_key_ptr_4,
+                script_private_key_ptr_4,
+                covenant_ptr_4,
+                encrypted_data_ptr_4,
+                utxo_4.minimum_value_promise.as_u64(),
+                0,
+                proof_ptr_4,
+                error_ptr,
+            );
+            assert_eq!(error, 0);
+            let tx_id_4 = wallet_import_unblinded_output_as_rewindable(
+                wallet_ptr,
+                tari_utxo_ptr_4,
+                source_address_ptr,
+                message_ptr,
+                error_ptr,
+            );
+            assert_eq!(error, 0);
+            assert!(tx_id_4 > 0);
+            let imported_tx = wallet_get_completed_transaction_by_id(wallet_ptr, tx_id_4, error_ptr);
+            assert_eq!(error, 0);
+            assert_eq!(
+                completed_transaction_get_status(imported_tx, error_ptr),
+                TransactionStatus::Imported as c_int
+            );
+            assert_eq!(completed_transaction_get_amount(imported_tx, error_ptr), 6543);
+            completed_transaction_destroy(imported_tx);
+            // the output is stored against the faux transaction and can be rewound with the wallet's own keys, so it
+            // will be found again when the wallet is recovered from its seed words
+            let stored_outputs = (*wallet_ptr)
+                .wallet
+                .output_db
+                .fetch_outputs_by_tx_id(TxId::from(tx_id_4))
+                .unwrap();
+            assert_eq!(stored_outputs.len(), 1);
+            assert_eq!(stored_outputs[0].wallet_output.value, MicroMinotari(6543u64));
+            let transaction_output = runtime
+                .block_on(utxo_4.to_transaction_output(wallet_key_manager))
+                .unwrap();
+            assert_eq!(stored_outputs[0].commitment, transaction_output.commitment);
+            let (_, recovered_value, _) = runtime
+                .block_on(wallet_key_manager.try_output_key_recovery(&transaction_output, None))
+                .unwrap();
+            assert_eq!(recovered_value, MicroMinotari(6543u64));
+
             // Cleanup
             string_destroy(script_ptr_1 as *mut c_char);
             string_destroy(input_data_ptr_1 as *mut c_char);
@@ -12417,6 +21069,28 @@ mod test {
             range_proof_destroy(range_proof_ptr_2);
             tari_unblinded_output_destroy(unblinded_output_ptr_2);
 
+            string_destroy(script_ptr_3 as *mut c_char);
+            string_destroy(input_data_ptr_3 as *mut c_char);
+            let _covenant = Box::from_raw(covenant_ptr_3);
+            let _script_private_key = Box::from_raw(script_private_key_ptr_3);
+            let _sender_offset_public_key = Box::from_raw(sender_offset_public_key_ptr_3);
+            let _metadata_signature = Box::from_raw(metadata_signature_ptr_3);
+            let _features = Box::from_raw(features_ptr_3);
+            range_proof_destroy(proof_ptr_3);
+            let _spending_key = Box::from_raw(spending_key_ptr_3);
+            tari_unblinded_output_destroy(tari_utxo_ptr_3);
+
+            string_destroy(script_ptr_4 as *mut c_char);
+            string_destroy(input_data_ptr_4 as *mut c_char);
+            let _covenant = Box::from_raw(covenant_ptr_4);
+            let _script_private_key = Box::from_raw(script_private_key_ptr_4);
+            let _sender_offset_public_key = Box::from_raw(sender_offset_public_key_ptr_4);
+            let _metadata_signature = Box::from_raw(metadata_signature_ptr_4);
+            let _features = Box::from_raw(features_ptr_4);
+            range_proof_destroy(proof_ptr_4);
+            let _spending_key = Box::from_raw(spending_key_ptr_4);
+            tari_unblinded_output_destroy(tari_utxo_ptr_4);
+
             string_destroy(message_ptr as *mut c_char);
             let _source_address = Box::from_raw(source_address_ptr);
             unblinded_outputs_destroy(unspent_outputs_ptr);
@@ -12494,9 +21168,31 @@ mod test {
             let tari_utxo2 = create_tari_unblinded_output_from_json(json_string, error_ptr);
             assert_eq!(error, 0);
             assert_eq!(*tari_utxo, *tari_utxo2);
+
+            let cbor_bytes = tari_unblinded_output_to_cbor(tari_utxo, error_ptr);
+            assert_eq!(error, 0);
+            let tari_utxo3 = create_tari_unblinded_output_from_cbor(cbor_bytes, error_ptr);
+            assert_eq!(error, 0);
+            assert_eq!(*tari_utxo, *tari_utxo3);
+            let cbor_bytes2 = tari_unblinded_output_to_cbor(tari_utxo3, error_ptr);
+            assert_eq!(error, 0);
+            assert_eq!((*cbor_bytes).0, (*cbor_bytes2).0);
+            assert!((*cbor_bytes).0.len() < CStr::from_ptr(json_string).to_bytes().len());
+            let truncated_cbor_bytes = Box::into_raw(Box::new(ByteVector(
+                (*cbor_bytes).0[..(*cbor_bytes).0.len() / 2].to_vec(),
+            )));
+            assert!(create_tari_unblinded_output_from_cbor(truncated_cbor_bytes, error_ptr).is_null());
+            assert_eq!(error, 7);
+            assert!(create_tari_unblinded_output_from_cbor(ptr::null(), error_ptr).is_null());
+            assert_eq!(error, 1);
+            byte_vector_destroy(truncated_cbor_bytes);
             // Cleanup
             tari_unblinded_output_destroy(tari_utxo);
             tari_unblinded_output_destroy(tari_utxo2);
+            tari_unblinded_output_destroy(tari_utxo3);
+            byte_vector_destroy(cbor_bytes);
+            byte_vector_destroy(cbor_bytes2);
+            string_destroy(json_string);
             string_destroy(message_ptr as *mut c_char);
             string_destroy(script_ptr as *mut c_char);
             string_destroy(input_data_ptr as *mut c_char);
@@ -12540,6 +21236,11 @@ mod test {
                 20,
                 10800,
                 false,
+                true,
+                true,
+                0,
+                0,
+                0,
                 error_ptr,
             );
             let passphrase: *const c_char = CString::into_raw(CString::new("niao").unwrap()) as *const c_char;
@@ -12609,6 +21310,11 @@ mod test {
                 20,
                 10800,
                 false,
+                true,
+                true,
+                0,
+                0,
+                0,
                 error_ptr,
             );
             let passphrase: *const c_char = CString::into_raw(CString::new("niao").unwrap()) as *const c_char;
@@ -19,6 +19,7 @@ enum TariTypeTag {
   Commitment = 2,
   U64 = 3,
   I64 = 4,
+  Kernel = 5,
 };
 
 enum TariUtxoSort {
@@ -26,6 +27,8 @@ enum TariUtxoSort {
   ValueDesc = 1,
   MinedHeightAsc = 2,
   MinedHeightDesc = 3,
+  MinedTimestampAsc = 4,
+  MinedTimestampDesc = 5,
 };
 
 /**
@@ -358,6 +361,7 @@ struct TariUtxo {
   uint8_t status;
   const char *coinbase_extra;
   const char *payment_id;
+  bool is_coinbase;
 };
 
 #ifdef __cplusplus
@@ -378,6 +382,41 @@ extern "C" {
  */
 struct TariVector *create_tari_vector(enum TariTypeTag tag);
 
+/**
+ * Gets the number of elements in a `TariVector`.
+ *
+ * ## Arguments
+ * `tv` - The pointer to `TariVector`
+ * `error_ptr` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `usize` - Returns the number of elements, or 0 if the vector is null.
+ *
+ * # Safety
+ * None
+ */
+uintptr_t tari_vector_get_length(const struct TariVector *tv,
+                                 int32_t *error_ptr);
+
+/**
+ * Gets the type-tag of the payload of a `TariVector`.
+ *
+ * ## Arguments
+ * `tv` - The pointer to `TariVector`
+ * `error_ptr` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `TariTypeTag` - Returns the type-tag of the vector. Note that it returns `Text` if the vector is null, the
+ * error_ptr must be checked to tell the two apart.
+ *
+ * # Safety
+ * None
+ */
+enum TariTypeTag tari_vector_get_tag(const struct TariVector *tv,
+                                     int32_t *error_ptr);
+
 /**
  * Appending a given value to the back of the vector.
  *
@@ -392,6 +431,65 @@ struct TariVector *create_tari_vector(enum TariTypeTag tag);
  */
 void tari_vector_push_string(struct TariVector *tv, const char *s, int32_t *error_ptr);
 
+/**
+ * Appending a given u64 value to the back of a `TariVector` tagged as `U64`.
+ *
+ * ## Arguments
+ * `tv` - The pointer to `TariVector`
+ * `value` - An item to push.
+ * `error_ptr` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `()` - Does not return a value, equivalent to void in C
+ *
+ * # Safety
+ * `destroy_tari_vector()` must be called to free the allocated memory.
+ */
+void tari_vector_push_u64(struct TariVector *tv,
+                          unsigned long long value,
+                          int32_t *error_ptr);
+
+/**
+ * Gets the U64 element at the given position of a `TariVector` tagged as `U64`.
+ *
+ * ## Arguments
+ * `tv` - The pointer to `TariVector`
+ * `position` - The zero based index of the element
+ * `error_ptr` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `c_ulonglong` - Returns the element, or 0 if the vector is null, is not tagged as `U64` or the position is out of
+ * range.
+ *
+ * # Safety
+ * None
+ */
+unsigned long long tari_vector_get_u64_at(struct TariVector *tv,
+                                          uintptr_t position,
+                                          int32_t *error_ptr);
+
+/**
+ * Gets the I64 element at the given position of a `TariVector` tagged as `I64`.
+ *
+ * ## Arguments
+ * `tv` - The pointer to `TariVector`
+ * `position` - The zero based index of the element
+ * `error_ptr` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `c_longlong` - Returns the element, or 0 if the vector is null, is not tagged as `I64` or the position is out of
+ * range.
+ *
+ * # Safety
+ * None
+ */
+long long tari_vector_get_i64_at(struct TariVector *tv,
+                                 uintptr_t position,
+                                 int32_t *error_ptr);
+
 /**
  * Frees memory allocated for `TariVector`.
  *
@@ -420,6 +518,37 @@ void destroy_tari_vector(struct TariVector *v);
  */
 void destroy_tari_coin_preview(struct TariCoinPreview *p);
 
+/**
+ * Frees memory allocated for `TariUtxo`.
+ *
+ * ## Arguments
+ * `utxo` - The pointer to `TariUtxo`
+ *
+ * ## Returns
+ * `()` - Does not return a value, equivalent to void in C
+ *
+ * # Safety
+ * None
+ */
+void destroy_tari_utxo(struct TariUtxo *utxo);
+
+/**
+ * Gets the number of blocks until a `TariUtxo` matures and can be spent.
+ *
+ * ## Arguments
+ * `utxo` - The pointer to `TariUtxo`
+ * `current_height` - The current tip height of the chain
+ *
+ * ## Returns
+ * `c_ulonglong` - Returns the number of blocks until the output's `lock_height` is reached, 0 if it is already
+ * mature or if `utxo` is null.
+ *
+ * # Safety
+ * None
+ */
+unsigned long long tari_utxo_blocks_until_mature(const struct TariUtxo *utxo,
+                                                 unsigned long long current_height);
+
 /**
  * -------------------------------- Strings ------------------------------------------------ ///
  * Frees memory for a char array
@@ -574,6 +703,47 @@ unsigned char byte_vector_get_at(struct ByteVector *ptr,
 unsigned int byte_vector_get_length(const struct ByteVector *vec,
                                     int *error_out);
 
+/**
+ * Creates a new ByteVector containing the bytes of `a` followed by the bytes of `b`
+ *
+ * ## Arguments
+ * `a` - The pointer to the first ByteVector
+ * `b` - The pointer to the second ByteVector
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `*mut ByteVector` - Pointer to the created ByteVector. Note that it will be ptr::null_mut() if either a or b is null
+ *
+ * # Safety
+ * The ```byte_vector_destroy``` function must be called when finished with a ByteVector to prevent a memory leak
+ */
+struct ByteVector *byte_vector_concat(const struct ByteVector *a,
+                                      const struct ByteVector *b,
+                                      int *error_out);
+
+/**
+ * Creates a new ByteVector containing `len` bytes of a ByteVector starting at `start`
+ *
+ * ## Arguments
+ * `vec` - The pointer to a ByteVector
+ * `start` - The position of the first byte of the slice
+ * `len` - The number of bytes in the slice
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `*mut ByteVector` - Pointer to the created ByteVector. Note that it will be ptr::null_mut() if vec is null or if
+ * the slice does not lie within the ByteVector
+ *
+ * # Safety
+ * The ```byte_vector_destroy``` function must be called when finished with a ByteVector to prevent a memory leak
+ */
+struct ByteVector *byte_vector_slice(const struct ByteVector *vec,
+                                     unsigned int start,
+                                     unsigned int len,
+                                     int *error_out);
+
 /**
  * -------------------------------------------------------------------------------------------- ///
  * -------------------------------- Public Key ------------------------------------------------ ///
@@ -692,6 +862,44 @@ TariPublicKey *public_key_from_private_key(TariPrivateKey *secret_key,
 TariPublicKey *public_key_from_hex(const char *key,
                                    int *error_out);
 
+/**
+ * Converts a TariPublicKey to its hex encoded representation
+ *
+ * ## Arguments
+ * `pk` - The pointer to a TariPublicKey
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `*mut c_char` - Returns a pointer to a char array containing the hex encoded public key. Note that it returns
+ * ptr::null_mut() if pk is null
+ *
+ * # Safety
+ * The ```string_destroy``` method must be called when finished with a string from rust to prevent a memory leak
+ */
+char *public_key_to_hex(TariPublicKey *pk,
+                        int *error_out);
+
+/**
+ * Compares two TariPublicKeys for equality
+ *
+ * ## Arguments
+ * `a` - The pointer to the first TariPublicKey
+ * `b` - The pointer to the second TariPublicKey
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `bool` - Returns true if the public keys are equal, false otherwise. Note that it returns false if either
+ * pointer is null
+ *
+ * # Safety
+ * None
+ */
+bool public_key_equals(TariPublicKey *a,
+                       TariPublicKey *b,
+                       int *error_out);
+
 /**
  * -------------------------------------------------------------------------------------------- ///
  * -------------------------------- Tari Address ---------------------------------------------- ///
@@ -712,6 +920,32 @@ TariPublicKey *public_key_from_hex(const char *key,
 TariWalletAddress *tari_address_create(struct ByteVector *bytes,
                                        int *error_out);
 
+/**
+ * Creates a dual TariWalletAddress from a spend key and a view key
+ *
+ * ## Arguments
+ * `spend_key` - The pointer to the TariPublicKey used as the spend key
+ * `view_key` - The pointer to the TariPublicKey used as the view key
+ * `network` - The network byte of the address, which must be a known network
+ * `features` - The feature flags of the address, where bit 0 is one-sided and bit 1 is interactive. At least one
+ * must be set
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `*mut TariWalletAddress` - Returns the address. Note that it will be ptr::null_mut() if either key is null, or if
+ * the network or features are invalid
+ *
+ * # Safety
+ * The ```tari_address_destroy``` function must be called when finished with a TariWalletAddress to prevent a memory
+ * leak
+ */
+TariWalletAddress *tari_address_create_dual(TariPublicKey *spend_key,
+                                            TariPublicKey *view_key,
+                                            unsigned int network,
+                                            int features,
+                                            int *error_out);
+
 /**
  * Frees memory for a TariWalletAddress
  *
@@ -814,6 +1048,26 @@ char *tari_address_network(TariWalletAddress *address,
 uint8_t tari_address_network_u8(TariWalletAddress *address,
                                 int *error_out);
 
+/**
+ * Checks whether a TariWalletAddress belongs to the given network
+ *
+ * ## Arguments
+ * `address` - The pointer to a TariWalletAddress
+ * `network` - The network id, as returned by `tari_address_network_u8`
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `bool` - Returns true if the address network matches. Returns false if it does not match, if address is null or if
+ * network is not a valid network id
+ *
+ * # Safety
+ * None
+ */
+bool tari_address_matches_network(TariWalletAddress *address,
+                                  unsigned int network,
+                                  int *error_out);
+
 /**
  * Returns the u8 representation of a TariWalletAddress's checksum
  *
@@ -866,6 +1120,23 @@ char *tari_address_features(TariWalletAddress *address,
 uint8_t tari_address_features_u8(TariWalletAddress *address,
                                  int *error_out);
 
+/**
+ * Checks whether a TariWalletAddress can receive one-sided payments
+ *
+ * ## Arguments
+ * `address` - The pointer to a TariWalletAddress
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `bool` - Returns true if the address has the one-sided feature set, false if not or on failure
+ *
+ * # Safety
+ * None
+ */
+bool tari_address_supports_one_sided(TariWalletAddress *address,
+                                     int *error_out);
+
 /**
  * Creates a public key from a TariWalletAddress's view key
  *
@@ -875,10 +1146,11 @@ uint8_t tari_address_features_u8(TariWalletAddress *address,
  * as an out parameter.
  *
  * ## Returns
- * `*mut TariPublicKey` - Returns a pointer to a TariPublicKey. Note that it returns null if there is no key present
+ * `*mut TariPublicKey` - Returns a pointer to a TariPublicKey. Note that it returns null and sets an InvalidArgument
+ * error if the address is a single address, which has no view key
  *
  * # Safety
- * The ```string_destroy``` method must be called when finished with a string from rust to prevent a memory leak
+ * The ```public_key_destroy``` method must be called when finished with a TariPublicKey to prevent a memory leak
  */
 TariPublicKey *tari_address_view_key(TariWalletAddress *address,
                                      int *error_out);
@@ -895,11 +1167,32 @@ TariPublicKey *tari_address_view_key(TariWalletAddress *address,
  * `*mut TariPublicKey` - Returns a pointer to a TariPublicKey. Note that it returns null
  *
  * # Safety
- * The ```string_destroy``` method must be called when finished with a string from rust to prevent a memory leak
+ * The ```public_key_destroy``` method must be called when finished with a TariPublicKey to prevent a memory leak
  */
 TariPublicKey *tari_address_spend_key(TariWalletAddress *address,
                                       int *error_out);
 
+/**
+ * Compares two TariWalletAddresses for equality. The full address is compared, so addresses with the same keys on
+ * different networks, or with different features, are not equal.
+ *
+ * ## Arguments
+ * `a` - The pointer to the first TariWalletAddress
+ * `b` - The pointer to the second TariWalletAddress
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `bool` - Returns true if the addresses are equal, false otherwise. Note that it returns false if either
+ * pointer is null
+ *
+ * # Safety
+ * None
+ */
+bool tari_address_equals(TariWalletAddress *a,
+                         TariWalletAddress *b,
+                         int *error_out);
+
 /**
  * Creates a TariWalletAddress from a char array in emoji format
  *
@@ -909,7 +1202,10 @@ TariPublicKey *tari_address_spend_key(TariWalletAddress *address,
  * as an out parameter.
  *
  * ## Returns
- * `*mut c_char` - Returns a pointer to a TariWalletAddress. Note that it returns null on error.
+ * `*mut c_char` - Returns a pointer to a TariWalletAddress. Note that it returns null on error, with `error_out` set
+ * to 701 if the emoji id is well formed but belongs to a different network than the one currently in use, 704 if it
+ * contains characters outside of the emoji set, 706 if its checksum does not match, or another `TariAddressError`
+ * code if it could not be decoded.
  *
  * # Safety
  * The ```public_key_destroy``` method must be called when finished with a TariWalletAddress to prevent a memory leak
@@ -1051,6 +1347,24 @@ void tari_unblinded_output_destroy(TariUnblindedOutput *output);
 char *tari_unblinded_output_to_json(TariUnblindedOutput *output,
                                     int *error_out);
 
+/**
+ * returns the TariUnblindedOutput as compact CBOR encoded bytes, e.g. to fit an output into a single QR code
+ *
+ * ## Arguments
+ * `output` - The pointer to a TariUnblindedOutput
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `*mut ByteVector` - Returns a ByteVector with the CBOR encoding. Note that it returns ptr::null_mut() if
+ * TariUnblindedOutput is null or the output could not be encoded, in which case `error_out` is set to 7
+ *
+ * # Safety
+ * The ```byte_vector_destroy``` function must be called when finished with the ByteVector to prevent a memory leak
+ */
+struct ByteVector *tari_unblinded_output_to_cbor(TariUnblindedOutput *output,
+                                                 int *error_out);
+
 /**
  * Creates a TariUnblindedOutput from a char array
  *
@@ -1069,6 +1383,25 @@ char *tari_unblinded_output_to_json(TariUnblindedOutput *output,
 TariUnblindedOutput *create_tari_unblinded_output_from_json(const char *output_json,
                                                             int *error_out);
 
+/**
+ * Creates a TariUnblindedOutput from CBOR encoded bytes as produced by `tari_unblinded_output_to_cbor`
+ *
+ * ## Arguments
+ * `bytes` - The pointer to a ByteVector holding the CBOR encoding of the TariUnblindedOutput
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `*mut TariUnblindedOutput` - Returns a pointer to a TariUnblindedOutput. Note that it returns ptr::null_mut()
+ * if bytes is null or if the bytes could not be decoded, in which case `error_out` is set to 7
+ *
+ * # Safety
+ * The ```tari_unblinded_output_destroy``` function must be called when finished with a TariUnblindedOutput to
+ * prevent a memory leak
+ */
+TariUnblindedOutput *create_tari_unblinded_output_from_cbor(const struct ByteVector *bytes,
+                                                            int *error_out);
+
 /**
  * -------------------------------------------------------------------------------------------- ///
  * ----------------------------------- TariUnblindedOutputs ------------------------------------///
@@ -1141,6 +1474,31 @@ void unblinded_outputs_destroy(struct TariUnblindedOutputs *outputs);
 struct TariUnblindedOutputs *wallet_get_unspent_outputs(struct TariWallet *wallet,
                                                         int *error_out);
 
+/**
+ * Get a page of the TariUnblindedOutputs from a TariWallet. Unlike `wallet_get_unspent_outputs`, only the outputs in
+ * the requested page are converted, which avoids deriving the keys of every unspent output on a large wallet. The
+ * outputs are ordered by the order in which they were added to the wallet, so consecutive pages do not overlap.
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer
+ * `offset` - The number of unspent outputs to skip
+ * `limit` - The maximum number of unspent outputs to return
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `*mut TariUnblindedOutputs` - returns at most `limit` unspent unblinded outputs, note that it returns ptr::null_mut()
+ * if wallet is null
+ *
+ * # Safety
+ * The ```unblinded_outputs_destroy``` method must be called when finished with a TariUnblindedOutput to prevent a
+ * memory leak
+ */
+struct TariUnblindedOutputs *wallet_get_unspent_outputs_paged(struct TariWallet *wallet,
+                                                              uintptr_t offset,
+                                                              uintptr_t limit,
+                                                              int *error_out);
+
 /**
  * Import an external UTXO into the wallet as a non-rewindable (i.e. non-recoverable) output. This will add a spendable
  * UTXO (as EncumberedToBeReceived) and create a faux completed transaction to record the event.
@@ -1169,41 +1527,133 @@ unsigned long long wallet_import_external_utxo_as_non_rewindable(struct TariWall
                                                                  int *error_out);
 
 /**
- * -------------------------------------------------------------------------------------------- ///
- * -------------------------------- Private Key ----------------------------------------------- ///
- * Creates a TariPrivateKey from a ByteVector
+ * Import an external UTXO into the wallet as a non-rewindable (i.e. non-recoverable) output, as
+ * `wallet_import_external_utxo_as_non_rewindable` does, but with caller supplied metadata on the faux completed
+ * transaction so that it can be tied back to an external ledger entry.
  *
  * ## Arguments
- * `bytes` - The pointer to a ByteVector
+ * `wallet` - The TariWallet pointer
+ * `output` - The pointer to a TariUnblindedOutput
+ * `source_address` - The tari address of the source of the transaction
+ * `message` - The message that the transaction will have, "Imported UTXO" is used if it is null
+ * `payment_id` - The pointer to a ByteVector containing the payment id to attach to the transaction and output, can be
+ * null if no payment id should be attached
+ * `import_status` - The status of the faux transaction, either 3 (Imported) or 8 (OneSidedUnconfirmed). A negative
+ * value uses the default of 3 (Imported). Any other value, including 9 (OneSidedConfirmed) which needs the block the
+ * output was mined in, fails with an `InvalidArgument` error (code 7).
  * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
  * as an out parameter.
  *
  * ## Returns
- * `*mut TariPrivateKey` - Returns a pointer to a TariPrivateKey. Note that it returns ptr::null_mut()
- * if bytes is null or if there was an error creating the TariPrivateKey from bytes
+ * `c_ulonglong` -  Returns the TransactionID of the generated transaction, note that it will be zero if the import
+ * failed
  *
  * # Safety
- * The ```private_key_destroy``` method must be called when finished with a TariPrivateKey to prevent a memory leak
+ * None
  */
-TariPrivateKey *private_key_create(struct ByteVector *bytes,
-                                   int *error_out);
+unsigned long long wallet_import_external_utxo_as_non_rewindable_with_metadata(struct TariWallet *wallet,
+                                                                               TariUnblindedOutput *output,
+                                                                               TariWalletAddress *source_address,
+                                                                               const char *message,
+                                                                               const struct ByteVector *payment_id,
+                                                                               int import_status,
+                                                                               int *error_out);
 
 /**
- * Frees memory for a TariPrivateKey
+ * Import an external UTXO into the wallet as a rewindable (i.e. recoverable) output. In contrast to
+ * `wallet_import_external_utxo_as_non_rewindable`, the output is run through the wallet's recovery path and is only
+ * accepted if its encrypted data can be decrypted with this wallet's keys. Such an output is tied to the wallet's key
+ * chain and will be found again when the wallet is recovered from its seed words, whereas a non-rewindable import is
+ * lost on recovery. A faux completed transaction is created to record the event.
  *
  * ## Arguments
- * `pk` - The pointer to a TariPrivateKey
+ * `wallet` - The TariWallet pointer
+ * `output` - The pointer to a TariUnblindedOutput
+ * `source_address` - The tari address of the source of the transaction
+ * `message` - The message that the transaction will have
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
  *
  * ## Returns
- * `()` - Does not return a value, equivalent to void in C
+ * `c_ulonglong` -  Returns the TransactionID of the generated transaction, note that it will be zero if the output
+ * could not be rewound with this wallet's keys or was already imported
  *
  * # Safety
  * None
  */
-void private_key_destroy(TariPrivateKey *pk);
+unsigned long long wallet_import_unblinded_output_as_rewindable(struct TariWallet *wallet,
+                                                                TariUnblindedOutput *output,
+                                                                TariWalletAddress *source_address,
+                                                                const char *message,
+                                                                int *error_out);
 
 /**
- * Gets a ByteVector from a TariPrivateKey
+ * Mark the faux transaction created by `wallet_import_external_utxo_as_non_rewindable` as mined and confirmed once
+ * the caller has independently verified that the imported output is mined. The imported output becomes spendable.
+ * The block hash must be that of the real header at `mined_height`, as TXO validation checks it against the chain
+ * and reverts the output if it does not match.
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer
+ * `transaction_id` - The TransactionId of the imported transaction
+ * `mined_height` - The height of the block in which the imported output was mined
+ * `block_hash` - The pointer to a char array containing the hex encoded hash of the block in which the imported
+ * output was mined
+ * `mined_timestamp` - The timestamp, in seconds since the Unix epoch, of the block in which the imported output was
+ * mined
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `bool` - Returns true if the transaction was marked as confirmed, false if the wallet or block hash is null, the
+ * block hash is invalid, the transaction is not found or the transaction is not in the `Imported` status
+ *
+ * # Safety
+ * None
+ */
+bool wallet_mark_imported_transaction_confirmed(struct TariWallet *wallet,
+                                                unsigned long long transaction_id,
+                                                unsigned long long mined_height,
+                                                const char *block_hash,
+                                                unsigned long long mined_timestamp,
+                                                int *error_out);
+
+/**
+ * -------------------------------------------------------------------------------------------- ///
+ * -------------------------------- Private Key ----------------------------------------------- ///
+ * Creates a TariPrivateKey from a ByteVector
+ *
+ * ## Arguments
+ * `bytes` - The pointer to a ByteVector
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `*mut TariPrivateKey` - Returns a pointer to a TariPrivateKey. Note that it returns ptr::null_mut()
+ * if bytes is null or if there was an error creating the TariPrivateKey from bytes
+ *
+ * # Safety
+ * The ```private_key_destroy``` method must be called when finished with a TariPrivateKey to prevent a memory leak
+ */
+TariPrivateKey *private_key_create(struct ByteVector *bytes,
+                                   int *error_out);
+
+/**
+ * Frees memory for a TariPrivateKey
+ *
+ * ## Arguments
+ * `pk` - The pointer to a TariPrivateKey
+ *
+ * ## Returns
+ * `()` - Does not return a value, equivalent to void in C
+ *
+ * # Safety
+ * None
+ */
+void private_key_destroy(TariPrivateKey *pk);
+
+/**
+ * Gets a ByteVector from a TariPrivateKey
  *
  * ## Arguments
  * `pk` - The pointer to a TariPrivateKey
@@ -1252,6 +1702,50 @@ TariPrivateKey *private_key_generate(void);
 TariPrivateKey *private_key_from_hex(const char *key,
                                      int *error_out);
 
+/**
+ * -------------------------------------------------------------------------------------------- ///
+ * -------------------------------- Commitment ------------------------------------------------ ///
+ * Creates the Pedersen commitment `C = v·H + k·G` to a value and blinding factor
+ *
+ * ## Arguments
+ * `value` - The value, in MicroMinotari, being committed to
+ * `blinding_key` - The pointer to a TariPrivateKey used as the blinding factor
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `*mut c_char` - Returns a pointer to a char array containing the hex encoded commitment. Note that it returns
+ * ptr::null_mut() if blinding_key is null
+ *
+ * # Safety
+ * The ```string_destroy``` method must be called when finished with a string from rust to prevent a memory leak
+ */
+char *commitment_create(unsigned long long value,
+                        TariPrivateKey *blinding_key,
+                        int *error_out);
+
+/**
+ * Checks that a hex encoded Pedersen commitment opens to the given value and blinding factor
+ *
+ * ## Arguments
+ * `commitment_hex` - The pointer to a char array containing the hex encoded commitment
+ * `value` - The value, in MicroMinotari, the commitment is expected to commit to
+ * `blinding_key` - The pointer to a TariPrivateKey expected to be the blinding factor
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `bool` - Returns true if the commitment opens to the value and blinding factor, false otherwise. Note that it
+ * returns false if either pointer is null or commitment_hex is not a valid commitment
+ *
+ * # Safety
+ * None
+ */
+bool commitment_verify(const char *commitment_hex,
+                       unsigned long long value,
+                       TariPrivateKey *blinding_key,
+                       int *error_out);
+
 /**
  * -------------------------------------------------------------------------------------------- ///
  * -------------------------------- Range Proof ----------------------------------------------- ///
@@ -1423,6 +1917,29 @@ TariEncryptedOpenings *encrypted_data_create_from_bytes(const struct ByteVector
 struct ByteVector *encrypted_data_as_bytes(const TariEncryptedOpenings *encrypted_data,
                                            int *error_out);
 
+/**
+ * Decrypts a TariEncryptedOpenings to recover the value and mask of the output it belongs to
+ *
+ * ## Arguments
+ * `encrypted_data` - The pointer to a TariEncryptedOpenings
+ * `encryption_key` - The pointer to a TariPrivateKey the data was encrypted with
+ * `commitment` - The pointer to a char array containing the hex encoded commitment of the output
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `*mut TariVector` - Returns a `U64` vector whose first element is the value and whose remaining 32 elements are the
+ * bytes of the mask, one byte per element. Note that it returns ptr::null_mut() if any argument is null or invalid, and
+ * fails with a `DecryptionFailed` error (code 16) if the key or commitment does not match the encrypted data
+ *
+ * # Safety
+ * `destroy_tari_vector()` must be called after use.
+ */
+struct TariVector *encrypted_data_decrypt(const TariEncryptedOpenings *encrypted_data,
+                                          TariPrivateKey *encryption_key,
+                                          const char *commitment,
+                                          int *error_out);
+
 /**
  * Frees memory for a TariEncryptedOpenings
  *
@@ -1466,6 +1983,28 @@ TariOutputFeatures *output_features_create_from_bytes(unsigned char version,
                                                       unsigned short range_proof_type,
                                                       int *error_out);
 
+/**
+ * Creates the TariOutputFeatures of a coinbase output, with the coinbase output type and a bullet proof plus range
+ * proof
+ *
+ * ## Arguments
+ * `maturity` - The block height from which the output can be spent
+ * `extra` - The coinbase extra bytes as a ByteVector. It can be null to model an empty coinbase extra.
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `TariOutputFeatures` - Returns an output features object. Note that it will be ptr::null_mut() if the coinbase
+ * extra is too long
+ *
+ * # Safety
+ * The ```output_features_destroy``` function must be called when finished with a TariOutputFeatures to
+ * prevent a memory leak
+ */
+TariOutputFeatures *output_features_create_coinbase(unsigned long long maturity,
+                                                    const struct ByteVector *extra,
+                                                    int *error_out);
+
 /**
  * Frees memory for a TariOutputFeatures
  *
@@ -1515,6 +2054,26 @@ struct TariSeedWords *seed_words_create_from_cipher(const char *cipher_bytes,
                                                     const char *passphrase,
                                                     int *error_out);
 
+/**
+ * Create an instance of TariSeedWords from a complete, whitespace separated seed phrase
+ *
+ * ## Arguments
+ * `phrase` - The seed phrase as a string, with the words separated by whitespace
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `TariSeedWords` - Returns the populated TariSeedWords instance, or null if the phrase is not a valid seed phrase. The
+ * error code distinguishes between an incorrect number of words (912), a word that is not in any mnemonic word list
+ * (911), words that do not share a language (910) and a phrase that fails the checksum (432).
+ *
+ * # Safety
+ * The `seed_words_destroy` method must be called when finished with a TariSeedWords instance from rust to prevent a
+ * memory leak
+ */
+struct TariSeedWords *seed_words_create_from_string(const char *phrase,
+                                                    int *error_out);
+
 /**
  * Create a TariSeedWords instance containing the entire mnemonic wordlist for the requested language
  *
@@ -1598,6 +2157,45 @@ unsigned char seed_words_push_word(struct TariSeedWords *seed_words,
                                    const char *passphrase,
                                    int *error_out);
 
+/**
+ * Validates a complete TariSeedWords phrase against an optional passphrase
+ *
+ * ## Arguments
+ * `seed_words` - The pointer to a TariSeedWords
+ * `passphrase` - Optional passphrase that was used when the seed phrase was created, a null pointer validates the
+ * phrase as one that was created without a passphrase
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `bool` - Returns true if the seed words can be deciphered with the given passphrase, false otherwise. A mismatched
+ * passphrase fails the MAC check of the cipher seed and `error_out` is set to the decryption failure code (431), the
+ * same phrase with the correct passphrase is required to restore the wallet via `wallet_create`.
+ *
+ * # Safety
+ * None
+ */
+bool seed_words_validate_with_passphrase(struct TariSeedWords *seed_words,
+                                         const char *passphrase,
+                                         int *error_out);
+
+/**
+ * Removes all words from a TariSeedWords so the same instance can be reused, the cleared words are zeroized
+ *
+ * ## Arguments
+ * `seed_words` - The pointer to a TariSeedWords
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `()` - Does not return a value, equivalent to void in C
+ *
+ * # Safety
+ * None
+ */
+void seed_words_clear(struct TariSeedWords *seed_words,
+                      int *error_out);
+
 /**
  * Frees memory for a TariSeedWords
  *
@@ -1689,6 +2287,46 @@ bool contact_get_favourite(TariContact *contact,
 TariWalletAddress *contact_get_tari_address(TariContact *contact,
                                             int *error_out);
 
+/**
+ * Sets the alias of the TariContact in place, so a fetched contact can be modified and passed back to
+ * `wallet_upsert_contact`
+ *
+ * ## Arguments
+ * `contact` - The pointer to a TariContact
+ * `alias` - The pointer to a char array containing the new alias
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `bool` - Returns true if the alias was updated, false if contact or alias is null or alias is not valid UTF-8
+ *
+ * # Safety
+ * None
+ */
+bool contact_set_alias(TariContact *contact,
+                       const char *alias,
+                       int *error_out);
+
+/**
+ * Sets the favourite flag of the TariContact in place, so a fetched contact can be modified and passed back to
+ * `wallet_upsert_contact`
+ *
+ * ## Arguments
+ * `contact` - The pointer to a TariContact
+ * `favourite` - The new favourite flag
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `bool` - Returns true if the flag was updated, false if contact is null
+ *
+ * # Safety
+ * None
+ */
+bool contact_set_favourite(TariContact *contact,
+                           bool favourite,
+                           int *error_out);
+
 /**
  * Frees memory for a TariContact
  *
@@ -2100,6 +2738,62 @@ TariWalletAddress *completed_transaction_get_destination_tari_address(TariComple
 TariTransactionKernel *completed_transaction_get_transaction_kernel(TariCompletedTransaction *transaction,
                                                                     int *error_out);
 
+/**
+ * Gets all of the TariTransactionKernels of a TariCompletedTransaction, including transactions with more than one
+ * kernel
+ *
+ * ## Arguments
+ * `transaction` - The pointer to a TariCompletedTransaction
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `*mut TariVector` - Returns a `Kernel` vector holding a `TariTransactionKernel` pointer per kernel, note that it
+ * will be ptr::null_mut() if transaction is null or if the transaction status is Pending
+ *
+ * # Safety
+ * The ```transaction_kernel_destroy``` method must be called on each TariTransactionKernel and
+ * ```destroy_tari_vector``` on the vector when finished with them to prevent a memory leak
+ */
+struct TariVector *completed_transaction_get_kernels(TariCompletedTransaction *transaction,
+                                                     int *error_out);
+
+/**
+ * Gets the input commitments of the body of a TariCompletedTransaction
+ *
+ * ## Arguments
+ * `transaction` - The pointer to a TariCompletedTransaction
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `*mut TariVector` - Returns a `Commitment` vector of hex strings, note that it will be ptr::null_mut() if
+ * transaction is null, if the transaction status is Pending or Imported, or if an input commitment is not available
+ *
+ * # Safety
+ * The ```destroy_tari_vector``` method must be called when finished with the TariVector to prevent a memory leak
+ */
+struct TariVector *completed_transaction_get_input_commitments(TariCompletedTransaction *transaction,
+                                                               int *error_out);
+
+/**
+ * Gets the output commitments of the body of a TariCompletedTransaction
+ *
+ * ## Arguments
+ * `transaction` - The pointer to a TariCompletedTransaction
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `*mut TariVector` - Returns a `Commitment` vector of hex strings, note that it will be ptr::null_mut() if
+ * transaction is null or if the transaction status is Pending or Imported
+ *
+ * # Safety
+ * The ```destroy_tari_vector``` method must be called when finished with the TariVector to prevent a memory leak
+ */
+struct TariVector *completed_transaction_get_output_commitments(TariCompletedTransaction *transaction,
+                                                                int *error_out);
+
 /**
  * Gets the source TariWalletAddress of a TariCompletedTransaction
  *
@@ -2145,6 +2839,24 @@ TariWalletAddress *completed_transaction_get_source_tari_address(TariCompletedTr
 int completed_transaction_get_status(TariCompletedTransaction *transaction,
                                      int *error_out);
 
+/**
+ * Converts a transaction status code, as returned by `completed_transaction_get_status`, to its display string
+ *
+ * ## Arguments
+ * `status` - The transaction status code
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `*mut c_char` - Returns a pointer to a char array. Note that it returns ptr::null_mut() if status is not a valid
+ * transaction status code
+ *
+ * # Safety
+ * The ```string_destroy``` method must be called when finished with a string from rust to prevent a memory leak
+ */
+char *transaction_status_to_string(int status,
+                                   int *error_out);
+
 /**
  * Gets the amount of a TariCompletedTransaction
  *
@@ -2180,7 +2892,8 @@ unsigned long long completed_transaction_get_fee(TariCompletedTransaction *trans
                                                  int *error_out);
 
 /**
- * Gets the timestamp of a TariCompletedTransaction
+ * Gets the net change a TariCompletedTransaction makes to the wallet's balance. Inbound transactions add their amount,
+ * outbound transactions remove their amount plus the fee the wallet paid.
  *
  * ## Arguments
  * `transaction` - The pointer to a TariCompletedTransaction
@@ -2188,16 +2901,18 @@ unsigned long long completed_transaction_get_fee(TariCompletedTransaction *trans
  * as an out parameter.
  *
  * ## Returns
- * `c_ulonglong` - Returns the timestamp, note that it will be zero if transaction is null
+ * `c_longlong` - Returns the signed net amount, positive for received and negative for sent. Note that it will be
+ * zero if transaction is null or its direction is unknown
  *
  * # Safety
  * None
  */
-unsigned long long completed_transaction_get_timestamp(TariCompletedTransaction *transaction,
-                                                       int *error_out);
+long long completed_transaction_get_net_amount(TariCompletedTransaction *transaction,
+                                               int *error_out);
 
 /**
- * Gets the message of a TariCompletedTransaction
+ * Gets the effective fee per gram of a TariCompletedTransaction, i.e. the kernel fee divided by the weight of the
+ * transaction body, weighed as `completed_transaction_get_weight` does
  *
  * ## Arguments
  * `transaction` - The pointer to a TariCompletedTransaction
@@ -2205,17 +2920,18 @@ unsigned long long completed_transaction_get_timestamp(TariCompletedTransaction
  * as an out parameter.
  *
  * ## Returns
- * `*const c_char` - Returns the pointer to the char array, note that it will return a pointer
- * to an empty char array if transaction is null
+ * `c_ulonglong` - Returns the fee per gram rounded down, note that it will be zero if transaction is null or if the
+ * transaction status is Pending or Imported, as those transactions do not have a body
  *
  * # Safety
- * The ```string_destroy``` method must be called when finished with string coming from rust to prevent a memory leak
+ * None
  */
-const char *completed_transaction_get_message(TariCompletedTransaction *transaction,
-                                              int *error_out);
+unsigned long long completed_transaction_get_fee_per_gram(TariCompletedTransaction *transaction,
+                                                          int *error_out);
 
 /**
- * Gets the payment id of a TariCompletedTransaction
+ * Gets the weight, in grams, of the body of a TariCompletedTransaction, using the consensus weight parameters of the
+ * current network that apply at the height the transaction was mined at, or the latest ones if it is not yet mined
  *
  * ## Arguments
  * `transaction` - The pointer to a TariCompletedTransaction
@@ -2223,34 +2939,124 @@ const char *completed_transaction_get_message(TariCompletedTransaction *transact
  * as an out parameter.
  *
  * ## Returns
- * `*const c_char` - Returns the pointer to the char array, note that it will return a pointer
- * to an empty char array if transaction is null
+ * `c_ulonglong` - Returns the weight of the transaction body, note that it will be zero if transaction is null or if
+ * the transaction does not have a body, i.e. it has no kernels, as is the case when its status is Pending or Imported
  *
  * # Safety
- * The ```string_destroy``` method must be called when finished with string coming from rust to prevent a memory leak
+ * None
  */
-const char *completed_transaction_get_payment_id(TariCompletedTransaction *transaction,
-                                                 int *error_out);
+unsigned long long completed_transaction_get_weight(TariCompletedTransaction *transaction,
+                                                   int *error_out);
 
 /**
- * This function checks to determine if a TariCompletedTransaction was originally a TariPendingOutboundTransaction
+ * Gets the timestamp of a TariCompletedTransaction
  *
  * ## Arguments
- * `tx` - The TariCompletedTransaction
+ * `transaction` - The pointer to a TariCompletedTransaction
  * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
  * as an out parameter.
  *
  * ## Returns
- * `bool` - Returns if the transaction was originally sent from the wallet
+ * `c_ulonglong` - Returns the timestamp, note that it will be zero if transaction is null
  *
  * # Safety
  * None
  */
-bool completed_transaction_is_outbound(TariCompletedTransaction *tx,
-                                       int *error_out);
+unsigned long long completed_transaction_get_timestamp(TariCompletedTransaction *transaction,
+                                                       int *error_out);
 
 /**
- * Gets the number of confirmations of a TariCompletedTransaction
+ * Gets the timestamp of the block in which a TariCompletedTransaction was mined
+ *
+ * ## Arguments
+ * `transaction` - The pointer to a TariCompletedTransaction
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `c_ulonglong` - Returns the mined timestamp in milliseconds since the unix epoch, note that it will be zero if the
+ * transaction has not been mined yet or if transaction is null
+ *
+ * # Safety
+ * None
+ */
+unsigned long long completed_transaction_get_mined_timestamp(TariCompletedTransaction *transaction,
+                                                             int *error_out);
+
+/**
+ * Gets the message of a TariCompletedTransaction
+ *
+ * ## Arguments
+ * `transaction` - The pointer to a TariCompletedTransaction
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `*const c_char` - Returns the pointer to the char array, note that it will return a pointer
+ * to an empty char array if transaction is null
+ *
+ * # Safety
+ * The ```string_destroy``` method must be called when finished with string coming from rust to prevent a memory leak
+ */
+const char *completed_transaction_get_message(TariCompletedTransaction *transaction,
+                                              int *error_out);
+
+/**
+ * Gets the payment id of a TariCompletedTransaction
+ *
+ * ## Arguments
+ * `transaction` - The pointer to a TariCompletedTransaction
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `*const c_char` - Returns the pointer to the char array, note that it will return a pointer
+ * to an empty char array if transaction is null
+ *
+ * # Safety
+ * The ```string_destroy``` method must be called when finished with string coming from rust to prevent a memory leak
+ */
+const char *completed_transaction_get_payment_id(TariCompletedTransaction *transaction,
+                                                 int *error_out);
+
+/**
+ * This function checks to determine if a TariCompletedTransaction was originally a TariPendingOutboundTransaction
+ *
+ * ## Arguments
+ * `tx` - The TariCompletedTransaction
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `bool` - Returns if the transaction was originally sent from the wallet
+ *
+ * # Safety
+ * None
+ */
+bool completed_transaction_is_outbound(TariCompletedTransaction *tx,
+                                       int *error_out);
+
+/**
+ * This function checks to determine if a TariCompletedTransaction was received as a one-sided payment, i.e. it was
+ * found by scanning the blockchain rather than negotiated with the sender, so no reply was sent for it. This is
+ * recorded when the payment is found and is kept once it is mined; imported outputs are never one-sided payments
+ *
+ * ## Arguments
+ * `tx` - The TariCompletedTransaction
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `bool` - Returns if the transaction was received as a one-sided payment, note that it returns false if tx is null
+ *
+ * # Safety
+ * None
+ */
+bool completed_transaction_is_one_sided(TariCompletedTransaction *tx,
+                                        int *error_out);
+
+/**
+ * Gets the number of confirmations of a TariCompletedTransaction
  *
  * ## Arguments
  * `tx` - The TariCompletedTransaction
@@ -2679,6 +3485,9 @@ TariTransportConfig *transport_memory_create(void);
  *
  * ## Arguments
  * `listener_address` - The pointer to a char array
+ * `dial_timeout_secs` - The maximum time in seconds to wait for an outbound connection to be established, 0 to use
+ * the OS default
+ * `bypass_proxy` - When true, DNS addresses are resolved directly instead of through a configured SOCKS proxy
  * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
  * as an out parameter.
  *
@@ -2690,8 +3499,34 @@ TariTransportConfig *transport_memory_create(void);
  * leak
  */
 TariTransportConfig *transport_tcp_create(const char *listener_address,
+                                          unsigned long long dial_timeout_secs,
+                                          bool bypass_proxy,
                                           int *error_out);
 
+/**
+ * Creates a tcp transport type that dials all peers through a SOCKS5 proxy
+ *
+ * ## Arguments
+ * `listener_address` - The pointer to a char array
+ * `socks_proxy_address` - The pointer to a char array containing the multiaddr of the SOCKS5 proxy
+ * `socks_username` - The pointer to a char array containing the socks username, can be null
+ * `socks_password` - The pointer to a char array containing the socks password, can be null
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `*mut TariTransportConfig` - Returns a pointer to a SOCKS5 TariTransportConfig, null on error.
+ *
+ * # Safety
+ * The ```transport_config_destroy``` method must be called when finished with a TariTransportConfig to prevent a
+ * memory leak
+ */
+TariTransportConfig *transport_tcp_create_with_proxy(const char *listener_address,
+                                                     const char *socks_proxy_address,
+                                                     const char *socks_username,
+                                                     const char *socks_password,
+                                                     int *error_out);
+
 /**
  * Creates a tor transport type
  *
@@ -2720,6 +3555,38 @@ TariTransportConfig *transport_tor_create(const char *control_server_address,
                                           const char *socks_password,
                                           int *error_out);
 
+/**
+ * Creates a tor transport type that reuses a previously exported tor identity, so that the onion address of the
+ * wallet is stable across restarts
+ *
+ * ## Arguments
+ * `control_server_address` - The pointer to a char array
+ * `tor_cookie` - The pointer to a ByteVector containing the contents of the tor cookie file, can be null
+ * `tor_port` - The tor port
+ * `tor_proxy_bypass_for_outbound` - Whether tor will use a direct tcp connection for a given bypass address instead of
+ * the tor proxy if tcp is available, if not it has no effect
+ * `socks_password` - The pointer to a char array containing the socks password, can be null
+ * `tor_identity_json` - The pointer to a char array containing the tor identity JSON obtained from
+ * `wallet_export_tor_identity`
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `*mut TariTransportConfig` - Returns a pointer to a tor TariTransportConfig, null on error.
+ *
+ * # Safety
+ * The ```transport_config_destroy``` method must be called when finished with a TariTransportConfig to prevent a
+ * memory leak
+ */
+TariTransportConfig *transport_tor_create_with_identity(const char *control_server_address,
+                                                        const struct ByteVector *tor_cookie,
+                                                        unsigned short tor_port,
+                                                        bool tor_proxy_bypass_for_outbound,
+                                                        const char *socks_username,
+                                                        const char *socks_password,
+                                                        const char *tor_identity_json,
+                                                        int *error_out);
+
 /**
  * Gets the address for a memory transport type
  *
@@ -2764,8 +3631,6 @@ void transport_type_destroy(TariTransportConfig *transport);
 void transport_config_destroy(TariTransportConfig *transport);
 
 /**
- * ---------------------------------------------------------------------------------------------///
- * ----------------------------------- CommsConfig ---------------------------------------------///
  * Creates a TariCommsConfig. The result from this function is required when initializing a TariWallet.
  *
  * ## Arguments
@@ -2780,6 +3645,13 @@ void transport_config_destroy(TariTransportConfig *transport);
  * database files will be created and the application has write access to
  * `discovery_timeout_in_secs`: specify how long the Discovery Timeout for the wallet is.
  * `exclude_dial_test_addresses`: exclude dialing of test addresses; this should be 'true' for production wallets
+ * `saf_auto_request`: automatically request store-and-forward messages from peers; 'true' unless traffic needs to be
+ * kept to a minimum, e.g. on metered connections
+ * `dht_auto_join`: automatically send a join message to the network on startup; 'true' unless traffic needs to be
+ * kept to a minimum
+ * `num_neighbouring_nodes`: the number of neighbouring nodes the DHT maintains connections to, 0 for the default of 5
+ * `num_random_nodes`: the number of random nodes the DHT maintains connections to, 0 for the default of 1
+ * `min_desired_peers`: the minimum number of peers network discovery aims for, 0 for the default of 16
  * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
  * as an out parameter.
  *
@@ -2797,6 +3669,11 @@ TariCommsConfig *comms_config_create(const char *public_address,
                                      unsigned long long discovery_timeout_in_secs,
                                      unsigned long long saf_message_duration_in_secs,
                                      bool exclude_dial_test_addresses,
+                                     bool saf_auto_request,
+                                     bool dht_auto_join,
+                                     unsigned int num_neighbouring_nodes,
+                                     unsigned int num_random_nodes,
+                                     unsigned int min_desired_peers,
                                      int *error_out);
 
 /**
@@ -2830,6 +3707,46 @@ void comms_config_destroy(TariCommsConfig *wc);
 struct TariPublicKeys *comms_list_connected_public_keys(struct TariWallet *wallet,
                                                         int *error_out);
 
+/**
+ * This function lists all connected peers with their public key, node id and the address they are connected on
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `*mut TariVector` - Returns a `Text` vector with one JSON object per connected peer, in the form
+ * `{"public_key": "<hex>", "node_id": "<hex>", "address": "<multiaddr>"}`. Note the result will be null if there was
+ * an error
+ *
+ * # Safety
+ * The caller is responsible for null checking and deallocating the returned object using `destroy_tari_vector()`.
+ */
+struct TariVector *comms_list_connected_peers(struct TariWallet *wallet,
+                                              int *error_out);
+
+/**
+ * This function attempts to connect to the peer with the given public key, waiting at most 30 seconds for the
+ * connection to be established
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer
+ * `public_key` - The TariPublicKey pointer of the peer to dial
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `bool` - Returns true if the peer is connected, false otherwise. If the peer could not be dialed or the dial timed
+ * out the error code 17 is set.
+ *
+ * # Safety
+ * This function blocks the calling thread until the dial completes or times out
+ */
+bool wallet_dial_peer(struct TariWallet *wallet,
+                      TariPublicKey *public_key,
+                      int *error_out);
+
 /**
  * Gets the length of the public keys vector
  *
@@ -2886,7 +3803,9 @@ TariPublicKey *public_keys_get_at(const struct TariPublicKeys *public_keys,
  * `passphrase` - An optional string that represents the passphrase used to
  * encrypt/decrypt the databases for this wallet. If it is left Null no encryption is used. If the databases have been
  * encrypted then the correct passphrase is required or this function will fail.
- * `seed_passphrase` - an optional string, if present this will derypt the seed words
+ * `seed_passphrase` - an optional string, if present this will derypt the seed words. It must match the passphrase
+ * the seed words were created with, a mismatched passphrase fails to decipher the seed words and this function will
+ * fail. Use `seed_words_validate_with_passphrase` to check a phrase and passphrase before creating the wallet.
  * `seed_words` - An optional instance of TariSeedWords, used to create a wallet for recovery purposes.
  * If this is null, then a new master key is created for the wallet.
  * `dns_seed_name_servers_str` - An optional list of DNS servers to query to get hold of the seed peer list.
@@ -2958,6 +3877,9 @@ TariPublicKey *public_keys_get_at(const struct TariPublicKeys *public_keys,
  *     Online,         // 1
  *     Offline,        // 2
  * }
+ * `callback_base_node_state` - This callback is called when the state of the connected base node changes. The
+ * `TariBaseNodeState` can be read with the `basenode_state_get_*` functions and must be freed with
+ * `basenode_state_destroy`.
  * `recovery_in_progress` - Pointer to an bool which will be modified to indicate if there is an outstanding recovery
  * that should be completed or not to an error code should one occur, may not be null. Functions as an out parameter.
  * `error_out` - Pointer to an int which will be modified
@@ -3024,275 +3946,1503 @@ struct TariWallet *wallet_create(void *context,
                                  int *error_out);
 
 /**
- * Retrieves the version of an app that last accessed the wallet database
+ * Creates a watch-only TariWallet from a private view key and a public spend key. The wallet scans for and reports
+ * funds sent to the address made from these keys, but cannot spend them: sending, coin splits and coin joins fail
+ * with the watch-only error code (15).
  *
  * ## Arguments
- * `config` - The TariCommsConfig pointer
- * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
- * as an out parameter.
+ * `view_key` - The TariPrivateKey pointer of the private view key, may not be null
+ * `spend_public_key` - The TariPublicKey pointer of the public spend key, may not be null
+ * All other arguments are as for `wallet_create`. A wallet database that was created by another kind of wallet cannot
+ * be opened as watch-only.
+ *
  * ## Returns
- * `*mut c_char` - Returns the pointer to the hexadecimal representation of the signature and
+ * `*mut TariWallet` - Returns a pointer to a TariWallet, note that it returns ptr::null_mut()
+ * if config, view_key or spend_public_key is null, a wallet error was encountered or if the runtime could not be
+ * created
  *
  * # Safety
- * The ```string_destroy``` method must be called when finished with a string coming from rust to prevent a memory leak
+ * The ```wallet_destroy``` method must be called when finished with a TariWallet to prevent a memory leak
  */
-char *wallet_get_last_version(TariCommsConfig *config,
-                              int *error_out);
+struct TariWallet *wallet_create_watch_only(void *context,
+                                            TariCommsConfig *config,
+                                            const char *log_path,
+                                            int log_verbosity,
+                                            unsigned int num_rolling_log_files,
+                                            unsigned int size_per_log_file_bytes,
+                                            const char *passphrase,
+                                            TariPrivateKey *view_key,
+                                            TariPublicKey *spend_public_key,
+                                            const char *network_str,
+                                            const char *dns_seeds_str,
+                                            const char *dns_seed_name_servers_str,
+                                            bool use_dns_sec,
+                                            void (*callback_received_transaction)(void *context,
+                                                                                  TariPendingInboundTransaction*),
+                                            void (*callback_received_transaction_reply)(void *context,
+                                                                                        TariCompletedTransaction*),
+                                            void (*callback_received_finalized_transaction)(void *context,
+                                                                                            TariCompletedTransaction*),
+                                            void (*callback_transaction_broadcast)(void *context,
+                                                                                   TariCompletedTransaction*),
+                                            void (*callback_transaction_mined)(void *context,
+                                                                               TariCompletedTransaction*),
+                                            void (*callback_transaction_mined_unconfirmed)(void *context,
+                                                                                           TariCompletedTransaction*,
+                                                                                           uint64_t),
+                                            void (*callback_faux_transaction_confirmed)(void *context,
+                                                                                        TariCompletedTransaction*),
+                                            void (*callback_faux_transaction_unconfirmed)(void *context,
+                                                                                          TariCompletedTransaction*,
+                                                                                          uint64_t),
+                                            void (*callback_transaction_send_result)(void *context,
+                                                                                     unsigned long long,
+                                                                                     TariTransactionSendStatus*),
+                                            void (*callback_transaction_cancellation)(void *context,
+                                                                                      TariCompletedTransaction*,
+                                                                                      uint64_t),
+                                            void (*callback_txo_validation_complete)(void *context,
+                                                                                     uint64_t,
+                                                                                     uint64_t),
+                                            void (*callback_contacts_liveness_data_updated)(void *context,
+                                                                                            TariContactsLivenessData*),
+                                            void (*callback_balance_updated)(void *context, TariBalance*),
+                                            void (*callback_transaction_validation_complete)(void *context,
+                                                                                             uint64_t,
+                                                                                             uint64_t),
+                                            void (*callback_saf_messages_received)(void *context),
+                                            void (*callback_connectivity_status)(void *context, uint64_t),
+                                            void (*callback_wallet_scanned_height)(void *context, uint64_t),
+                                            void (*callback_base_node_state)(void *context,
+                                                                             struct TariBaseNodeState*),
+                                            bool *recovery_in_progress,
+                                            int *error_out);
 
 /**
- * Retrieves the network of an app that last accessed the wallet database
+ * Gets the type of a TariWallet
  *
  * ## Arguments
- * `config` - The TariCommsConfig pointer
+ * `wallet` - The TariWallet pointer
  * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
  * as an out parameter.
+ *
  * ## Returns
- * `*mut c_char` - Returns the pointer to the hexadecimal representation of the signature and
+ * `c_int` - Returns the wallet type, or -1 if wallet is null:
+ *     DerivedKeys,    // 0 - keys are derived from the wallet seed
+ *     Ledger,         // 1 - spending is signed on a Ledger device
+ *     ProvidedKeys,   // 2 - keys were provided when the wallet was created
+ *     WatchOnly,      // 3 - provided keys without a private spend key, see `wallet_create_watch_only`
  *
  * # Safety
- * The ```string_destroy``` method must be called when finished with a string coming from rust to prevent a memory leak
+ * None
  */
-char *wallet_get_last_network(TariCommsConfig *config,
-                              int *error_out);
+int wallet_get_wallet_type(struct TariWallet *wallet,
+                           int *error_out);
 
 /**
- * Retrieves the balance from a wallet
+ * Replaces the balance updated callback of a running wallet. The new callback is used from the next event onwards and
+ * is called with the same context that was passed to `wallet_create`.
  *
  * ## Arguments
- * `wallet` - The TariWallet pointer.
+ * `wallet` - The TariWallet pointer
+ * `callback` - The callback function pointer matching the function signature. This is called when the balance of the
+ * wallet changes, with the same semantics as the `callback_balance_updated` argument of `wallet_create`
  * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
  * as an out parameter.
+ *
  * ## Returns
- * `*mut Balance` - Returns the pointer to the TariBalance or null if error occurs
+ * `bool` - Returns true if the callback was replaced, false if the wallet is null
  *
  * # Safety
- * The ```balance_destroy``` method must be called when finished with a TariBalance to prevent a memory leak
+ * None
  */
-TariBalance *wallet_get_balance(struct TariWallet *wallet,
-                                int *error_out);
+bool wallet_set_balance_updated_callback(struct TariWallet *wallet,
+                                         void (*callback)(void *context, TariBalance*),
+                                         int *error_out);
 
 /**
- * This function returns a list of unspent UTXO values and commitments.
+ * Replaces the connectivity status callback of a running wallet. The new callback is used from the next event onwards
+ * and is called with the same context that was passed to `wallet_create`.
  *
  * ## Arguments
- * * `wallet` - The TariWallet pointer,
- * * `page` - Page offset,
- * * `page_size` - A number of items per page,
- * * `sorting` - An enum representing desired sorting,
- * * `dust_threshold` - A value filtering threshold. Outputs whose values are <= `dust_threshold` are not listed in the
- *   result.
- * * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
- *   Functions as an out parameter.
+ * `wallet` - The TariWallet pointer
+ * `callback` - The callback function pointer matching the function signature. This is called when the connectivity
+ * status to the base node changes, with the same semantics as the `callback_connectivity_status` argument of
+ * `wallet_create`
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
  *
  * ## Returns
- * `*mut TariVector` - Returns a struct with an array pointer, length and capacity (needed for proper destruction
- * after use).
+ * `bool` - Returns true if the callback was replaced, false if the wallet is null
  *
  * # Safety
- * `destroy_tari_vector()` must be called after use.
- * Items that fail to produce `.as_transaction_output()` are omitted from the list and a `warn!()` message is logged to
- * LOG_TARGET.
+ * None
  */
-struct TariVector *wallet_get_utxos(struct TariWallet *wallet,
-                                    uintptr_t page,
-                                    uintptr_t page_size,
-                                    enum TariUtxoSort sorting,
-                                    struct TariVector *states,
-                                    uint64_t dust_threshold,
-                                    int32_t *error_ptr);
+bool wallet_set_connectivity_status_callback(struct TariWallet *wallet,
+                                             void (*callback)(void *context, uint64_t),
+                                             int *error_out);
 
 /**
- * This function returns a list of all UTXO values, commitment's hex values and states.
+ * Replaces the base node state callback of a running wallet. The new callback is used from the next event onwards and
+ * is called with the same context that was passed to `wallet_create`.
  *
  * ## Arguments
- * * `wallet` - The TariWallet pointer,
- * * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
- *   Functions as an out parameter.
+ * `wallet` - The TariWallet pointer
+ * `callback` - The callback function pointer matching the function signature. This is called when the state of the
+ * base node changes, with the same semantics as the `callback_base_node_state` argument of `wallet_create`
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
  *
  * ## Returns
- * `*mut TariVector` - Returns a struct with an array pointer, length and capacity (needed for proper destruction
- *     after use).
- *
- * ## States
- * 0 - Unspent
- * 1 - Spent
- * 2 - EncumberedToBeReceived
- * 3 - EncumberedToBeSpent
- * 4 - Invalid
- * 5 - CancelledInbound
- * 6 - UnspentMinedUnconfirmed
- * 7 - ShortTermEncumberedToBeReceived
- * 8 - ShortTermEncumberedToBeSpent
- * 9 - SpentMinedUnconfirmed
- * 10 - AbandonedCoinbase
- * 11 - NotStored
+ * `bool` - Returns true if the callback was replaced, false if the wallet is null
  *
  * # Safety
- * `destroy_tari_vector()` must be called after use.
- * Items that fail to produce `.as_transaction_output()` are omitted from the list and a `warn!()` message is logged to
- * LOG_TARGET.
+ * None
  */
-struct TariVector *wallet_get_all_utxos(struct TariWallet *wallet,
-                                        int32_t *error_ptr);
+bool wallet_set_base_node_state_callback(struct TariWallet *wallet,
+                                         void (*callback)(void *context, struct TariBaseNodeState*),
+                                         int *error_out);
 
 /**
- * This function will tell the wallet to do a coin split.
+ * Retrieves the version of this library build, including the git commit and build profile
  *
  * ## Arguments
- * * `wallet` - The TariWallet pointer
- * * `commitments` - A `TariVector` of "strings", tagged as `TariTypeTag::String`, containing commitment's hex values
- *   (see `Commitment::to_hex()`)
- * * `number_of_splits` - The number of times to split the amount
- * * `fee_per_gram` - The transaction fee
- * * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
- *   Functions as an out parameter.
+ * None
  *
  * ## Returns
- * `c_ulonglong` - Returns the transaction id.
+ * `*mut c_char` - Returns the pointer to the version string, e.g. `1.0.0-fc435c-release`
  *
  * # Safety
- * `TariVector` must be freed after use with `destroy_tari_vector()`
+ * The ```string_destroy``` method must be called when finished with a string coming from rust to prevent a memory leak
  */
-uint64_t wallet_coin_split(struct TariWallet *wallet,
-                           struct TariVector *commitments,
-                           uintptr_t number_of_splits,
-                           uint64_t fee_per_gram,
-                           int32_t *error_ptr);
+char *wallet_get_version(void);
 
 /**
- * This function will tell the wallet to do a coin join, resulting in a new coin worth a sum of the joined coins minus
- * the fee.
+ * Retrieves the git commit hash this library was built from
  *
  * ## Arguments
- * * `wallet` - The TariWallet pointer
- * * `commitments` - A `TariVector` of "strings", tagged as `TariTypeTag::String`, containing commitment's hex values
- *   (see `Commitment::to_hex()`)
- * * `fee_per_gram` - The transaction fee
- * * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
+ * None
+ *
+ * ## Returns
+ * `*mut c_char` - Returns the pointer to the commit hash string, or `NoGitRepository` if the build had no git
+ * information available
+ *
+ * # Safety
+ * The ```string_destroy``` method must be called when finished with a string coming from rust to prevent a memory leak
+ */
+char *wallet_get_commit_hash(void);
+
+/**
+ * Retrieves the version of an app that last accessed the wallet database
+ *
+ * ## Arguments
+ * `config` - The TariCommsConfig pointer
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ * ## Returns
+ * `*mut c_char` - Returns the pointer to the hexadecimal representation of the signature and
+ *
+ * # Safety
+ * The ```string_destroy``` method must be called when finished with a string coming from rust to prevent a memory leak
+ */
+char *wallet_get_last_version(TariCommsConfig *config,
+                              int *error_out);
+
+/**
+ * Retrieves the network of an app that last accessed the wallet database
+ *
+ * ## Arguments
+ * `config` - The TariCommsConfig pointer
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ * ## Returns
+ * `*mut c_char` - Returns the pointer to the hexadecimal representation of the signature and
+ *
+ * # Safety
+ * The ```string_destroy``` method must be called when finished with a string coming from rust to prevent a memory leak
+ */
+char *wallet_get_last_network(TariCommsConfig *config,
+                              int *error_out);
+
+/**
+ * Retrieves the version of an app that last accessed the wallet database at the given path. Unlike
+ * `wallet_get_last_version` this does not require a TariCommsConfig, so it can be used to inspect a wallet file before
+ * deciding whether to open it.
+ *
+ * ## Arguments
+ * `db_path` - The full path to the wallet sqlite database file, e.g. `<datastore_path>/<database_name>.sqlite3`
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ * ## Returns
+ * `*mut c_char` - Returns the pointer to the version string, or null if no version has been recorded
+ *
+ * # Safety
+ * The ```string_destroy``` method must be called when finished with a string coming from rust to prevent a memory leak
+ */
+char *wallet_db_get_last_version(const char *db_path,
+                                 int *error_out);
+
+/**
+ * Retrieves the network of an app that last accessed the wallet database at the given path. Unlike
+ * `wallet_get_last_network` this does not require a TariCommsConfig, so it can be used to inspect a wallet file before
+ * deciding whether to open it.
+ *
+ * ## Arguments
+ * `db_path` - The full path to the wallet sqlite database file, e.g. `<datastore_path>/<database_name>.sqlite3`
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ * ## Returns
+ * `*mut c_char` - Returns the pointer to the network string, or null if no network has been recorded
+ *
+ * # Safety
+ * The ```string_destroy``` method must be called when finished with a string coming from rust to prevent a memory leak
+ */
+char *wallet_db_get_last_network(const char *db_path,
+                                 int *error_out);
+
+/**
+ * Protects a wallet database that has no passphrase with the given passphrase. This is a one-shot database
+ * operation that is used before `wallet_create`, which must then be given the same passphrase.
+ *
+ * ## Arguments
+ * `config` - The TariCommsConfig pointer, used to derive the path of the wallet database
+ * `passphrase` - The passphrase to encrypt the wallet databases with, may not be null or empty
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `bool` - Returns true if the passphrase was applied, false otherwise. If the database is already protected by a
+ * passphrase the error code 422 is set, an empty passphrase sets the error code 426, and if there is no wallet
+ * database at the path none is created and the error code 436 is set.
+ *
+ * # Safety
+ * This must not be called while a TariWallet is open on the same database. All the sqlite backends share a main key
+ * that is encrypted with a key derived from the passphrase, so only the encrypted main key is re-keyed.
+ */
+bool wallet_apply_encryption(TariCommsConfig *config,
+                             const char *passphrase,
+                             int *error_out);
+
+/**
+ * Removes the passphrase protecting a wallet database, after which `wallet_create` must be given an empty
+ * passphrase to open it. This is a one-shot database operation that is used before `wallet_create`.
+ *
+ * ## Arguments
+ * `config` - The TariCommsConfig pointer, used to derive the path of the wallet database
+ * `passphrase` - The passphrase currently used to encrypt the wallet databases, may not be null
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `bool` - Returns true if the passphrase was removed, false otherwise. If the database is not protected by a
+ * passphrase the error code 435 is set, if `passphrase` does not match the error code for an invalid passphrase
+ * (428) is set, and if there is no wallet database at the path none is created and the error code 436 is set.
+ *
+ * # Safety
+ * This must not be called while a TariWallet is open on the same database. All the sqlite backends share a main key
+ * that is encrypted with a key derived from the passphrase, so only the encrypted main key is re-keyed.
+ */
+bool wallet_remove_encryption(TariCommsConfig *config,
+                              const char *passphrase,
+                              int *error_out);
+
+/**
+ * Changes the passphrase used to encrypt the wallet databases
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer
+ * `existing` - The passphrase currently used to encrypt the wallet databases, may not be null
+ * `new` - The new passphrase to encrypt the wallet databases with, may not be null
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `bool` - Returns true if the passphrase was changed, false otherwise. If `existing` does not match the current
+ * passphrase the error code for an invalid passphrase (428) is set and nothing is changed.
+ *
+ * # Safety
+ * All the sqlite backends (wallet, transaction, output manager, contacts and key manager) are encrypted with a shared
+ * main key that is itself encrypted with a key derived from the passphrase, so only the encrypted main key is replaced
+ * and the backends are re-keyed in a single write. The passphrases and derived key material are zeroized on drop.
+ */
+bool wallet_change_passphrase(struct TariWallet *wallet,
+                              const char *existing,
+                              const char *new,
+                              int *error_out);
+
+/**
+ * Retrieves the balance from a wallet
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer.
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ * ## Returns
+ * `*mut Balance` - Returns the pointer to the TariBalance or null if error occurs
+ *
+ * # Safety
+ * The ```balance_destroy``` method must be called when finished with a TariBalance to prevent a memory leak
+ */
+TariBalance *wallet_get_balance(struct TariWallet *wallet,
+                                int *error_out);
+
+/**
+ * This function returns a list of unspent UTXO values and commitments.
+ *
+ * ## Arguments
+ * * `wallet` - The TariWallet pointer,
+ * * `page` - Page offset,
+ * * `page_size` - A number of items per page,
+ * * `sorting` - An enum representing desired sorting,
+ * * `dust_threshold` - A value filtering threshold. Outputs whose values are <= `dust_threshold` are not listed in the
+ *   result.
+ * * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
  *   Functions as an out parameter.
  *
  * ## Returns
- * `TariVector` - Returns the transaction id.
+ * `*mut TariVector` - Returns a struct with an array pointer, length and capacity (needed for proper destruction
+ * after use).
+ *
+ * # Safety
+ * `destroy_tari_vector()` must be called after use.
+ * Items that fail to produce `.as_transaction_output()` are omitted from the list and a `warn!()` message is logged to
+ * LOG_TARGET.
+ */
+struct TariVector *wallet_get_utxos(struct TariWallet *wallet,
+                                    uintptr_t page,
+                                    uintptr_t page_size,
+                                    enum TariUtxoSort sorting,
+                                    struct TariVector *states,
+                                    uint64_t dust_threshold,
+                                    int32_t *error_ptr);
+
+/**
+ * This function returns aggregate totals of the full set of UTXOs matched by the `wallet_get_utxos` filters, so that
+ * a paged listing can show the totals of all the matching outputs.
+ *
+ * ## Arguments
+ * * `wallet` - The TariWallet pointer,
+ * * `states` - An optional `U64` vector of `TariUtxo` status values to filter by, null matches all statuses,
+ * * `dust_threshold` - A value filtering threshold. Outputs whose values are <= `dust_threshold` are not counted.
+ * * `error_ptr` - Pointer to an int which will be modified to an error code should one occur, may not be null.
+ *   Functions as an out parameter.
+ *
+ * ## Returns
+ * `*mut TariVector` - Returns a `U64` vector holding `[count, total_value, min_value, max_value]`, the values are
+ * zero if no outputs match.
+ *
+ * # Safety
+ * `destroy_tari_vector()` must be called after use.
+ */
+struct TariVector *wallet_get_utxos_summary(struct TariWallet *wallet,
+                                            struct TariVector *states,
+                                            uint64_t dust_threshold,
+                                            int32_t *error_ptr);
+
+/**
+ * Counts the spendable outputs of the wallet, i.e. the unspent outputs that are mature at the given height, without
+ * fetching the outputs themselves.
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer
+ * `at_height` - The block height at which the outputs must be spendable, 0 uses the current chain tip height as last
+ * reported by the wallet's base node
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `c_ulonglong` - Returns the number of spendable outputs, or 0 if an error occurred, which includes `at_height` being
+ * 0 while no chain metadata has been received from a base node yet
+ *
+ * # Safety
+ * None
+ */
+unsigned long long wallet_get_spendable_outputs_count(struct TariWallet *wallet,
+                                                      unsigned long long at_height,
+                                                      int *error_out);
+
+/**
+ * This function returns a list of all UTXO values, commitment's hex values and states.
+ *
+ * ## Arguments
+ * * `wallet` - The TariWallet pointer,
+ * * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
+ *   Functions as an out parameter.
+ *
+ * ## Returns
+ * `*mut TariVector` - Returns a struct with an array pointer, length and capacity (needed for proper destruction
+ *     after use).
+ *
+ * ## States
+ * 0 - Unspent
+ * 1 - Spent
+ * 2 - EncumberedToBeReceived
+ * 3 - EncumberedToBeSpent
+ * 4 - Invalid
+ * 5 - CancelledInbound
+ * 6 - UnspentMinedUnconfirmed
+ * 7 - ShortTermEncumberedToBeReceived
+ * 8 - ShortTermEncumberedToBeSpent
+ * 9 - SpentMinedUnconfirmed
+ * 10 - AbandonedCoinbase
+ * 11 - NotStored
+ *
+ * # Safety
+ * `destroy_tari_vector()` must be called after use.
+ * Items that fail to produce `.as_transaction_output()` are omitted from the list and a `warn!()` message is logged to
+ * LOG_TARGET.
+ */
+struct TariVector *wallet_get_all_utxos(struct TariWallet *wallet,
+                                        int32_t *error_ptr);
+
+/**
+ * This function returns how many outputs the wallet holds in each state, without fetching the outputs themselves.
+ *
+ * ## Arguments
+ * * `wallet` - The TariWallet pointer,
+ * * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
+ *   Functions as an out parameter.
+ *
+ * ## Returns
+ * `*mut TariVector` - Returns a `U64` vector with one count per state, indexed by the state values below. Note that
+ * it returns ptr::null_mut() if wallet is null or an error is encountered.
+ *
+ * ## States
+ * 0 - Unspent
+ * 1 - Spent
+ * 2 - EncumberedToBeReceived
+ * 3 - EncumberedToBeSpent
+ * 4 - Invalid
+ * 5 - CancelledInbound
+ * 6 - UnspentMinedUnconfirmed
+ * 7 - ShortTermEncumberedToBeReceived
+ * 8 - ShortTermEncumberedToBeSpent
+ * 9 - SpentMinedUnconfirmed
+ * 10 - NotStored
+ *
+ * # Safety
+ * `destroy_tari_vector()` must be called after use.
+ */
+struct TariVector *wallet_get_output_status_counts(struct TariWallet *wallet,
+                                                   int32_t *error_ptr);
+
+/**
+ * This function returns the UTXOs of the wallet whose maturity height lies in the given inclusive range, regardless
+ * of their state. This allows listing e.g. the coinbase outputs that mature within the next few blocks.
+ *
+ * ## Arguments
+ * * `wallet` - The TariWallet pointer,
+ * * `min_height` - The lowest maturity height to include
+ * * `max_height` - The highest maturity height to include
+ * * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
+ *   Functions as an out parameter.
+ *
+ * ## Returns
+ * `*mut TariVector` - Returns a struct with an array pointer, length and capacity (needed for proper destruction
+ *     after use).
+ *
+ * # Safety
+ * `destroy_tari_vector()` must be called after use.
+ */
+struct TariVector *wallet_get_utxos_by_maturity(struct TariWallet *wallet,
+                                                unsigned long long min_height,
+                                                unsigned long long max_height,
+                                                int32_t *error_ptr);
+
+/**
+ * This function returns the current derivation index of each of the wallet's key manager branches. The names of the
+ * branches are returned in the same order by `wallet_get_key_manager_branch_names`. This is intended as read-only
+ * diagnostic information, e.g. to detect a mismatch between derived keys and recovered outputs.
+ *
+ * ## Arguments
+ * * `wallet` - The TariWallet pointer
+ * * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
+ *   Functions as an out parameter.
+ *
+ * ## Returns
+ * `*mut TariVector` - Returns a vector of `unsigned long long` tagged as `TariTypeTag::U64`, note that it returns
+ * ptr::null_mut() if the wallet is null or the indices could not be read
+ *
+ * # Safety
+ * `destroy_tari_vector()` must be called after use.
+ */
+struct TariVector *wallet_get_key_manager_branch_indices(struct TariWallet *wallet,
+                                                         int *error_out);
+
+/**
+ * This function returns the names of the wallet's key manager branches, in the same order as the indices returned by
+ * `wallet_get_key_manager_branch_indices`.
+ *
+ * ## Arguments
+ * * `wallet` - The TariWallet pointer
+ * * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
+ *   Functions as an out parameter.
+ *
+ * ## Returns
+ * `*mut TariVector` - Returns a vector of strings tagged as `TariTypeTag::Text`, note that it returns
+ * ptr::null_mut() if the wallet is null or the branches could not be read
+ *
+ * # Safety
+ * `destroy_tari_vector()` must be called after use.
+ */
+struct TariVector *wallet_get_key_manager_branch_names(struct TariWallet *wallet,
+                                                       int *error_out);
+
+/**
+ * Derives the private key at the given index of a key manager branch from the wallet seed, e.g. to sign outside of
+ * the wallet. This does not advance the branch index.
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer
+ * `branch` - The pointer to a char array containing the name of the key manager branch, see
+ * `wallet_get_key_manager_branch_names`
+ * `index` - The index of the key in the branch
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `*mut TariPrivateKey` - Returns the derived private key, note that it returns ptr::null_mut() if the wallet or
+ * branch is null, if the wallet is a watch-only wallet or if the key could not be derived
+ *
+ * # Safety
+ * The ```private_key_destroy``` method must be called when finished with a TariPrivateKey to prevent a memory leak
+ */
+TariPrivateKey *wallet_derive_key(struct TariWallet *wallet,
+                                  const char *branch,
+                                  unsigned long long index,
+                                  int *error_out);
+
+/**
+ * This function returns the single output matching a commitment from the wallet's storage, without fetching the
+ * whole UTXO set.
+ *
+ * ## Arguments
+ * * `wallet` - The TariWallet pointer
+ * * `commitment` - The hex encoded commitment of the output (see `Commitment::to_hex()`)
+ * * `error_ptr` - Pointer to an int which will be modified to an error code should one occur, may not be null.
+ *   Functions as an out parameter.
+ *
+ * ## Returns
+ * `*mut TariUtxo` - Returns the matching output, note that it returns ptr::null_mut() if the wallet or commitment is
+ * null, the commitment is invalid or no output with the commitment is found
+ *
+ * # Safety
+ * `destroy_tari_utxo()` must be called after use.
+ */
+struct TariUtxo *wallet_get_utxo_by_commitment(struct TariWallet *wallet,
+                                               const char *commitment,
+                                               int32_t *error_ptr);
+
+/**
+ * This function will tell the wallet to do a coin split.
+ *
+ * ## Arguments
+ * * `wallet` - The TariWallet pointer
+ * * `commitments` - A `TariVector` of "strings", tagged as `TariTypeTag::String`, containing commitment's hex values
+ *   (see `Commitment::to_hex()`)
+ * * `number_of_splits` - The number of times to split the amount
+ * * `fee_per_gram` - The transaction fee
+ * * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
+ *   Functions as an out parameter.
+ *
+ * ## Returns
+ * `c_ulonglong` - Returns the transaction id.
+ *
+ * # Safety
+ * `TariVector` must be freed after use with `destroy_tari_vector()`
+ */
+uint64_t wallet_coin_split(struct TariWallet *wallet,
+                           struct TariVector *commitments,
+                           uintptr_t number_of_splits,
+                           uint64_t fee_per_gram,
+                           int32_t *error_ptr);
+
+/**
+ * This function will tell the wallet to do a coin join, resulting in a new coin worth a sum of the joined coins minus
+ * the fee.
+ *
+ * ## Arguments
+ * * `wallet` - The TariWallet pointer
+ * * `commitments` - A `TariVector` of "strings", tagged as `TariTypeTag::String`, containing commitment's hex values
+ *   (see `Commitment::to_hex()`)
+ * * `fee_per_gram` - The transaction fee
+ * * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
+ *   Functions as an out parameter.
+ *
+ * ## Returns
+ * `TariVector` - Returns the transaction id.
+ *
+ * # Safety
+ * `TariVector` must be freed after use with `destroy_tari_vector()`
+ */
+uint64_t wallet_coin_join(struct TariWallet *wallet,
+                          struct TariVector *commitments,
+                          uint64_t fee_per_gram,
+                          int32_t *error_ptr);
+
+/**
+ * This function will tell the wallet to join its smallest unspent outputs into a single new coin worth at least
+ * `target_amount` after the fee. Outputs are selected in ascending order of value until the target plus the fee is
+ * met.
+ *
+ * ## Arguments
+ * * `wallet` - The TariWallet pointer
+ * * `target_amount` - The minimum value of the joined output in MicroMinotari
+ * * `fee_per_gram` - The transaction fee
+ * * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
+ *   Functions as an out parameter.
+ *
+ * ## Returns
+ * `u64` - Returns the transaction id, or 0 if an error occurred. If the unspent outputs of the wallet cannot reach the
+ * target `error_out` is set to the insufficient funds error code.
+ *
+ * # Safety
+ * None
+ */
+uint64_t wallet_coin_join_to_amount(struct TariWallet *wallet,
+                                    unsigned long long target_amount,
+                                    uint64_t fee_per_gram,
+                                    int32_t *error_ptr);
+
+/**
+ * This function will tell what the outcome of a coin join would be.
+ *
+ * ## Arguments
+ * * `wallet` - The TariWallet pointer
+ * * `commitments` - A `TariVector` of "strings", tagged as `TariTypeTag::String`, containing commitment's hex values
+ *   (see `Commitment::to_hex()`)
+ * * `fee_per_gram` - The transaction fee
+ * * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
+ *   Functions as an out parameter.
+ *
+ * ## Returns
+ * `*mut TariCoinPreview` - A struct with expected output values and the fee.
+ *
+ * # Safety
+ * `TariVector` must be freed after use with `destroy_tari_vector()`
+ */
+struct TariCoinPreview *wallet_preview_coin_join(struct TariWallet *wallet,
+                                                 struct TariVector *commitments,
+                                                 uint64_t fee_per_gram,
+                                                 int32_t *error_ptr);
+
+/**
+ * This function will tell what the outcome of a coin split would be.
+ *
+ * ## Arguments
+ * * `wallet` - The TariWallet pointer
+ * * `commitments` - A `TariVector` of "strings", tagged as `TariTypeTag::String`, containing commitment's hex values
+ *   (see `Commitment::to_hex()`)
+ * * `number_of_splits` - The number of times to split the amount
+ * * `fee_per_gram` - The transaction fee
+ * * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
+ *   Functions as an out parameter.
+ *
+ * ## Returns
+ * `*mut TariCoinPreview` - A struct with expected output values and the fee.
+ *
+ * # Safety
+ * `TariVector` must be freed after use with `destroy_tari_vector()`
+ */
+struct TariCoinPreview *wallet_preview_coin_split(struct TariWallet *wallet,
+                                                  struct TariVector *commitments,
+                                                  uintptr_t number_of_splits,
+                                                  uint64_t fee_per_gram,
+                                                  int32_t *error_ptr);
+
+/**
+ * This function will tell what the outcome of joining the given commitments into a single output and then splitting
+ * that output evenly would be.
+ *
+ * ## Arguments
+ * * `wallet` - The TariWallet pointer
+ * * `commitments` - A `TariVector` of "strings", tagged as `TariTypeTag::String`, containing commitment's hex values
+ *   (see `Commitment::to_hex()`)
+ * * `number_of_splits` - The number of outputs the joined amount is split into
+ * * `fee_per_gram` - The transaction fee
+ * * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
+ *   Functions as an out parameter.
+ *
+ * ## Returns
+ * `*mut TariCoinPreview` - A struct with the expected split output values and the combined fee of the join and the
+ * split.
+ *
+ * # Safety
+ * `TariVector` must be freed after use with `destroy_tari_vector()`
+ */
+struct TariCoinPreview *wallet_preview_join_split(struct TariWallet *wallet,
+                                                  struct TariVector *commitments,
+                                                  uintptr_t number_of_splits,
+                                                  uint64_t fee_per_gram,
+                                                  int32_t *error_ptr);
+
+/**
+ * Signs a message using the public key of the TariWallet
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer.
+ * `msg` - The message pointer.
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ * ## Returns
+ * `*mut c_char` - Returns the pointer to the hexadecimal representation of the signature and
+ * public nonce, seperated by a pipe character. Empty if an error occured.
+ *
+ * # Safety
+ * The ```string_destroy``` method must be called when finished with a string coming from rust to prevent a memory leak
+ */
+char *wallet_sign_message(struct TariWallet *wallet,
+                          const char *msg,
+                          int *error_out);
+
+/**
+ * Signs a message with the given private key, using the same signing domain as `wallet_sign_message`
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer.
+ * `secret_key` - The pointer to the TariPrivateKey to sign the message with.
+ * `msg` - The message pointer.
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ * ## Returns
+ * `*mut c_char` - Returns the pointer to the hexadecimal representation of the signature and
+ * public nonce, seperated by a pipe character. Empty if an error occured. The result can be checked with
+ * `wallet_verify_message_signature` using the public key of `secret_key`.
+ *
+ * # Safety
+ * The ```string_destroy``` method must be called when finished with a string coming from rust to prevent a memory leak
+ */
+char *wallet_sign_message_with_key(struct TariWallet *wallet,
+                                   TariPrivateKey *secret_key,
+                                   const char *msg,
+                                   int *error_out);
+
+/**
+ * Verifies the signature of the message signed by a TariWallet
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer.
+ * `public_key` - The pointer to the TariPublicKey of the wallet which originally signed the message
+ * `hex_sig_nonce` - The pointer to the sting containing the hexadecimal representation of the
+ * signature and public nonce seperated by a pipe character.
+ * `msg` - The pointer to the msg the signature will be checked against.
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ * ## Returns
+ * `bool` - Returns if the signature is valid or not, will be false if an error occurs.
+ *
+ * # Safety
+ * None
+ */
+bool wallet_verify_message_signature(struct TariWallet *wallet,
+                                     TariPublicKey *public_key,
+                                     const char *hex_sig_nonce,
+                                     const char *msg,
+                                     int *error_out);
+
+/**
+ * Verifies the signature of the message signed by a TariWallet, distinguishing a signature that does not match from
+ * input that could not be parsed
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer.
+ * `public_key` - The pointer to the TariPublicKey of the wallet which originally signed the message
+ * `hex_sig_nonce` - The pointer to the sting containing the hexadecimal representation of the
+ * signature and public nonce seperated by a pipe character.
+ * `msg` - The pointer to the msg the signature will be checked against.
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ * ## Returns
+ * `c_int` - Returns an int version of the `SignatureVerificationResult` enum
+ *     '0' -> Valid, the signature is valid for the message and public key
+ *     '1' -> Invalid, the input is well formed but the signature does not match, `error_out` is not set
+ *     '2' -> MalformedInput, a pointer is null or the signature could not be parsed, `error_out` is set to the
+ *            corresponding error code
+ *
+ * # Safety
+ * None
+ */
+int wallet_verify_message_signature_detailed(struct TariWallet *wallet,
+                                             TariPublicKey *public_key,
+                                             const char *hex_sig_nonce,
+                                             const char *msg,
+                                             int *error_out);
+
+/**
+ * Adds a base node peer to the TariWallet
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer
+ * `public_key` - The TariPublicKey pointer
+ * `address` - The pointer to a char array
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `bool` - Returns if successful or not
+ *
+ * # Safety
+ * None
+ */
+bool wallet_set_base_node_peer(struct TariWallet *wallet,
+                               TariPublicKey *public_key,
+                               const char *address,
+                               int *error_out);
+
+/**
+ * Drops the connection to the wallet's current base node and re-establishes it
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `bool` - Returns true if a reconnection attempt was initiated, false if no base node peer has been set
+ *
+ * # Safety
+ * None
+ */
+bool wallet_reset_base_node_connection(struct TariWallet *wallet,
+                                       int *error_out);
+
+/**
+ * Gets all seed peers known by the wallet
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `TariPublicKeys` - Returns a list of all known public keys
+ *
+ * # Safety
+ * None
+ */
+struct TariPublicKeys *wallet_get_seed_peers(struct TariWallet *wallet,
+                                             int *error_out);
+
+/**
+ * Gets the base node peers the wallet's connectivity service is configured to use, in priority order. Unlike
+ * `comms_list_connected_public_keys`, this lists the configured peers whether or not they are currently connected.
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `TariPublicKeys` - Returns the public keys of the configured base node peers, which is empty if no base node has
+ * been set. Returns null if wallet is null.
+ *
+ * # Safety
+ * The ```public_keys_destroy``` method must be called when finished with a TariPublicKeys to prevent a memory leak
+ */
+struct TariPublicKeys *wallet_get_base_node_peers(struct TariWallet *wallet,
+                                                  int *error_out);
+
+/**
+ * Gets the current chain tip height as last reported by the wallet's base node
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `c_ulonglong` - Returns the height of the longest chain, or 0 if no chain metadata has been received from a base
+ * node yet, in which case `error_out` is set
+ *
+ * # Safety
+ * None
+ */
+unsigned long long wallet_get_network_tip_height(struct TariWallet *wallet,
+                                                 int *error_out);
+
+/**
+ * Gets the age of the chain tip, as the difference between the local time and the timestamp of the chain tip last
+ * reported by the wallet's base node. This is not the offset of the local clock from the network time: it includes
+ * the time since the tip was mined, which is expected to be up to a few block intervals, so only an age that is much
+ * larger than the block interval, or a negative age, suggests that the local clock is wrong.
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `i64` - Returns the age in seconds, negative if the tip timestamp is ahead of the local clock, or 0 if no chain
+ * metadata has been received from a base node yet, in which case `error_out` is set
+ *
+ * # Safety
+ * None
+ */
+int64_t wallet_get_tip_age(struct TariWallet *wallet,
+                           int *error_out);
+
+/**
+ * Waits until the wallet's base node reports that it is synced, or until the timeout elapses
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer
+ * `timeout_secs` - The maximum number of seconds to wait
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `bool` - Returns true if the base node is synced, false if the timeout elapsed before that happened
+ *
+ * # Safety
+ * This function blocks the calling thread until it returns, so it should not be called from a UI thread
+ */
+bool wallet_wait_until_initial_sync(struct TariWallet *wallet,
+                                    unsigned long long timeout_secs,
+                                    int *error_out);
+
+/**
+ * Gets the current status of the connection to the wallet's base node, the same value that is pushed to
+ * `callback_connectivity_status` when it changes
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `c_int` - Returns the status encoded as an integer as follows, or -1 if there was an error:
+ * pub enum OnlineStatus {
+ *     Connecting,     // 0
+ *     Online,         // 1
+ *     Offline,        // 2
+ * }
+ *
+ * # Safety
+ * None
+ */
+int wallet_get_connectivity_status(struct TariWallet *wallet,
+                                   int *error_out);
+
+/**
+ * Gets the round-trip latency of the most recent request to the wallet's base node, as measured by the base node
+ * monitor. This is the base node counterpart of `liveness_data_get_latency` for contacts.
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `c_int` - Returns the latency in milli-seconds (ms), or -1 if no latency has been measured yet or there was an error
+ *
+ * # Safety
+ * None
+ */
+int wallet_get_base_node_latency(struct TariWallet *wallet,
+                                 int *error_out);
+
+/**
+ * Upserts a TariContact to the TariWallet. If the contact does not exist it will be Inserted. If it does exist the
+ * Alias will be updated.
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer
+ * `contact` - The TariContact pointer
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `bool` - Returns if successful or not
+ *
+ * # Safety
+ * None
+ */
+bool wallet_upsert_contact(struct TariWallet *wallet,
+                           TariContact *contact,
+                           int *error_out);
+
+/**
+ * Removes a TariContact from the TariWallet
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer
+ * `tx` - The TariPendingInboundTransaction pointer
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `bool` - Returns if successful or not
+ *
+ * # Safety
+ * None
+ */
+bool wallet_remove_contact(struct TariWallet *wallet,
+                           TariContact *contact,
+                           int *error_out);
+
+/**
+ * Gets the available balance from a TariBalance. This is the balance the user can spend.
+ *
+ * ## Arguments
+ * `balance` - The TariBalance pointer
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `c_ulonglong` - The available balance, 0 if wallet is null
+ *
+ * # Safety
+ * None
+ */
+unsigned long long balance_get_available(TariBalance *balance,
+                                         int *error_out);
+
+/**
+ * Gets the time locked balance from a TariBalance. This is the balance the user can spend.
+ *
+ * ## Arguments
+ * `balance` - The TariBalance pointer
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `c_ulonglong` - The time locked balance, 0 if wallet is null
+ *
+ * # Safety
+ * None
+ */
+unsigned long long balance_get_time_locked(TariBalance *balance,
+                                           int *error_out);
+
+/**
+ * Gets the pending incoming balance from a TariBalance. This is the balance the user can spend.
+ *
+ * ## Arguments
+ * `balance` - The TariBalance pointer
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `c_ulonglong` - The pending incoming, 0 if wallet is null
+ *
+ * # Safety
+ * None
+ */
+unsigned long long balance_get_pending_incoming(TariBalance *balance,
+                                                int *error_out);
+
+/**
+ * Gets the pending outgoing balance from a TariBalance. This is the balance the user can spend.
+ *
+ * ## Arguments
+ * `balance` - The TariBalance pointer
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `c_ulonglong` - The pending outgoing balance, 0 if wallet is null
+ *
+ * # Safety
+ * None
+ */
+unsigned long long balance_get_pending_outgoing(TariBalance *balance,
+                                                int *error_out);
+
+/**
+ * Frees memory for a TariBalance
+ *
+ * ## Arguments
+ * `balance` - The pointer to a TariBalance
+ *
+ * ## Returns
+ * `()` - Does not return a value, equivalent to void in C
+ *
+ * # Safety
+ * None
+ */
+void balance_destroy(TariBalance *balance);
+
+/**
+ * Sends a TariPendingOutboundTransaction
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer
+ * `destination` - The TariWalletAddress pointer of the peer
+ * `amount` - The amount
+ * `commitments` - A `TariVector` of "strings", tagged as `TariTypeTag::String`, containing commitment's hex values
+ *   (see `Commitment::to_hex()`)
+ * `fee_per_gram` - The transaction fee
+ * `message` - The pointer to a char array, at most 256 bytes long, otherwise the send fails with a `MessageTooLong`
+ * error (code 11). If it is null or empty the message set with `wallet_set_default_message` is used instead
+ * `one_sided` - Whether to send a one-sided transaction to a stealth address of the destination, as
+ * `wallet_send_one_sided_transaction` does. The destination must then have the one-sided feature set, otherwise the
+ * send fails with an `InvalidArgument` error (code 7)
+ * `payment_id_string` - The pointer to a char array of the payment id of a one-sided transaction, can be null. A
+ * payment id longer than 189 bytes fails with an `InvalidArgument` error (code 7)
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `unsigned long long` - Returns 0 if unsuccessful or the TxId of the sent transaction if successful
+ *
+ * # Safety
+ * None
+ */
+unsigned long long wallet_send_transaction(struct TariWallet *wallet,
+                                           TariWalletAddress *destination,
+                                           unsigned long long amount,
+                                           struct TariVector *commitments,
+                                           unsigned long long fee_per_gram,
+                                           const char *message,
+                                           bool one_sided,
+                                           const char *payment_id_string,
+                                           int *error_out);
+
+/**
+ * Sends a one-sided transaction to a stealth address of the destination. The output is built entirely by this wallet,
+ * so the recipient does not need to be online or take part in the transaction.
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer
+ * `destination` - The TariWalletAddress pointer of the recipient, which must have the one-sided feature set
+ * `amount` - The amount
+ * `fee_per_gram` - The transaction fee
+ * `message` - The pointer to a char array, at most 256 bytes long, otherwise the send fails with a `MessageTooLong`
+ * error (code 11). If it is null or empty the message set with `wallet_set_default_message` is used instead
+ * `payment_id` - The pointer to a ByteVector containing the payment id to attach to the output, can be null if no
+ * payment id should be attached. A payment id longer than 189 bytes fails with an `InvalidArgument` error (code 7)
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `unsigned long long` - Returns 0 if unsuccessful or the TxId of the sent transaction if successful. If the destination
+ * does not support one-sided payments the send fails with an `InvalidArgument` error (code 7).
  *
  * # Safety
- * `TariVector` must be freed after use with `destroy_tari_vector()`
+ * None
  */
-uint64_t wallet_coin_join(struct TariWallet *wallet,
-                          struct TariVector *commitments,
-                          uint64_t fee_per_gram,
-                          int32_t *error_ptr);
+unsigned long long wallet_send_one_sided_transaction(struct TariWallet *wallet,
+                                                     TariWalletAddress *destination,
+                                                     unsigned long long amount,
+                                                     unsigned long long fee_per_gram,
+                                                     const char *message,
+                                                     const struct ByteVector *payment_id,
+                                                     int *error_out);
 
 /**
- * This function will tell what the outcome of a coin join would be.
+ * Sends a single one-sided transaction that pays each of the given recipients
  *
  * ## Arguments
- * * `wallet` - The TariWallet pointer
- * * `commitments` - A `TariVector` of "strings", tagged as `TariTypeTag::String`, containing commitment's hex values
- *   (see `Commitment::to_hex()`)
- * * `fee_per_gram` - The transaction fee
- * * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
- *   Functions as an out parameter.
+ * `wallet` - The TariWallet pointer
+ * `addresses` - A `TariVector` of "strings", tagged as `TariTypeTag::Text`, containing the recipient addresses as
+ * base58, emoji or hex strings
+ * `amounts` - A `TariVector` of `unsigned long long`, tagged as `TariTypeTag::U64`, containing the amount for each
+ * recipient in the same order as `addresses`
+ * `fee_per_gram` - The transaction fee
+ * `message` - The pointer to a char array, at most 256 bytes long, otherwise the send fails with a `MessageTooLong`
+ * error (code 11). If it is null or empty the message set with `wallet_set_default_message` is used instead
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
  *
  * ## Returns
- * `*mut TariCoinPreview` - A struct with expected output values and the fee.
+ * `unsigned long long` - Returns 0 if unsuccessful or the TxId of the sent transaction if successful. If `addresses`
+ * and `amounts` are empty or differ in length, or the amounts add up to more than fits in an `unsigned long long`,
+ * the send fails with an `InvalidArgument` error (code 7).
  *
  * # Safety
- * `TariVector` must be freed after use with `destroy_tari_vector()`
+ * None
  */
-struct TariCoinPreview *wallet_preview_coin_join(struct TariWallet *wallet,
-                                                 struct TariVector *commitments,
-                                                 uint64_t fee_per_gram,
-                                                 int32_t *error_ptr);
+unsigned long long wallet_send_transaction_to_many(struct TariWallet *wallet,
+                                                   struct TariVector *addresses,
+                                                   struct TariVector *amounts,
+                                                   unsigned long long fee_per_gram,
+                                                   const char *message,
+                                                   int *error_out);
 
 /**
- * This function will tell what the outcome of a coin split would be.
+ * Sends a TariPendingOutboundTransaction
  *
  * ## Arguments
- * * `wallet` - The TariWallet pointer
- * * `commitments` - A `TariVector` of "strings", tagged as `TariTypeTag::String`, containing commitment's hex values
+ * `wallet` - The TariWallet pointer
+ * `destination` - The TariWalletAddress pointer of the peer
+ * `fee_per_gram` - The transaction fee
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `unsigned long long` - Returns 0 if unsuccessful or the TxId of the sent transaction if successful
+ *
+ * # Safety
+ * None
+ */
+unsigned long long scrape_wallet(struct TariWallet *wallet,
+                                 TariWalletAddress *destination,
+                                 unsigned long long fee_per_gram,
+                                 int *error_out);
+
+/**
+ * Gets a fee estimate for an amount
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer
+ * `amount` - The amount
+ * `commitments` - A `TariVector` of "strings", tagged as `TariTypeTag::String`, containing commitment's hex values
  *   (see `Commitment::to_hex()`)
- * * `number_of_splits` - The number of times to split the amount
- * * `fee_per_gram` - The transaction fee
- * * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null.
- *   Functions as an out parameter.
+ * `fee_per_gram` - The fee per gram
+ * `num_kernels` - The number of transaction kernels
+ * `num_outputs` - The number of outputs
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
  *
  * ## Returns
- * `*mut TariCoinPreview` - A struct with expected output values and the fee.
+ * `unsigned long long` - Returns 0 if unsuccessful or the fee estimate in MicroMinotari if successful
  *
  * # Safety
- * `TariVector` must be freed after use with `destroy_tari_vector()`
+ * None
  */
-struct TariCoinPreview *wallet_preview_coin_split(struct TariWallet *wallet,
-                                                  struct TariVector *commitments,
-                                                  uintptr_t number_of_splits,
-                                                  uint64_t fee_per_gram,
-                                                  int32_t *error_ptr);
+unsigned long long wallet_get_fee_estimate(struct TariWallet *wallet,
+                                           unsigned long long amount,
+                                           struct TariVector *commitments,
+                                           unsigned long long fee_per_gram,
+                                           unsigned int num_kernels,
+                                           unsigned int num_outputs,
+                                           int *error_out);
 
 /**
- * Signs a message using the public key of the TariWallet
+ * Gets the lowest fee per gram the wallet should use for a transaction. UIs can use it as the floor for a
+ * user entered fee per gram, since a lower value gives a transaction without a fee. The network has no consensus
+ * minimum fee per gram, and base nodes may additionally refuse transactions below a locally configured total fee,
+ * which the wallet cannot see.
  *
  * ## Arguments
- * `wallet` - The TariWallet pointer.
- * `msg` - The message pointer.
+ * `wallet` - The TariWallet pointer
  * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
  * as an out parameter.
+ *
  * ## Returns
- * `*mut c_char` - Returns the pointer to the hexadecimal representation of the signature and
- * public nonce, seperated by a pipe character. Empty if an error occured.
+ * `c_ulonglong` - Returns the minimum fee per gram in MicroMinotari, or 0 if wallet is null
  *
  * # Safety
- * The ```string_destroy``` method must be called when finished with a string coming from rust to prevent a memory leak
+ * None
  */
-char *wallet_sign_message(struct TariWallet *wallet,
-                          const char *msg,
-                          int *error_out);
+unsigned long long wallet_get_minimum_fee_per_gram(struct TariWallet *wallet,
+                                                   int *error_out);
 
 /**
- * Verifies the signature of the message signed by a TariWallet
+ * Estimates the fee of a transaction of the given shape without selecting any of the wallet's outputs, so unlike
+ * `wallet_get_fee_estimate` the wallet does not need to hold the funds. The transaction is assumed to spend a single
+ * input into outputs with default features and scripts, which is what the wallet assumes when it cannot select
+ * outputs for an estimate, and is weighed with the latest consensus weighting of the current network.
  *
  * ## Arguments
- * `wallet` - The TariWallet pointer.
- * `public_key` - The pointer to the TariPublicKey of the wallet which originally signed the message
- * `hex_sig_nonce` - The pointer to the sting containing the hexadecimal representation of the
- * signature and public nonce seperated by a pipe character.
- * `msg` - The pointer to the msg the signature will be checked against.
+ * `wallet` - The TariWallet pointer
+ * `amount` - The amount to send, must be greater than zero
+ * `fee_per_gram` - The fee per gram
+ * `num_outputs` - The number of outputs, must be greater than zero
+ * `num_kernels` - The number of transaction kernels, must be greater than zero
  * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
  * as an out parameter.
+ *
  * ## Returns
- * `bool` - Returns if the signature is valid or not, will be false if an error occurs.
+ * `unsigned long long` - Returns 0 if unsuccessful or the fee estimate in MicroMinotari if successful
  *
  * # Safety
  * None
  */
-bool wallet_verify_message_signature(struct TariWallet *wallet,
-                                     TariPublicKey *public_key,
-                                     const char *hex_sig_nonce,
-                                     const char *msg,
-                                     int *error_out);
+unsigned long long wallet_estimate_fee(struct TariWallet *wallet,
+                                       unsigned long long amount,
+                                       unsigned long long fee_per_gram,
+                                       unsigned int num_outputs,
+                                       unsigned int num_kernels,
+                                       int *error_out);
+
+/**
+ * Gets the largest amount that can be sent in a single transaction, i.e. the value of the spendable outputs that
+ * would be selected for the transaction less the exact fee for spending all of them. Use this for "send all" instead
+ * of subtracting a fee estimate from the available balance.
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer
+ * `fee_per_gram` - The fee per gram
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `unsigned long long` - Returns the maximum spendable amount in MicroMinotari, 0 if there is nothing spendable or if
+ * unsuccessful
+ *
+ * # Safety
+ * None
+ */
+unsigned long long wallet_get_max_spendable_amount(struct TariWallet *wallet,
+                                                   unsigned long long fee_per_gram,
+                                                   int *error_out);
+
+/**
+ * Gets the number of mining confirmations required
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `unsigned long long` - Returns the number of confirmations required
+ *
+ * # Safety
+ * None
+ */
+unsigned long long wallet_get_num_confirmations_required(struct TariWallet *wallet,
+                                                         int *error_out);
+
+/**
+ * Sets the number of mining confirmations required
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer
+ * `num` - The number of confirmations to require
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `()` - Does not return a value, equivalent to void in C
+ *
+ * # Safety
+ * None
+ */
+void wallet_set_num_confirmations_required(struct TariWallet *wallet,
+                                           unsigned long long num,
+                                           int *error_out);
+
+/**
+ * Get the TariContacts from a TariWallet
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `*mut TariContacts` - returns the contacts, note that it returns ptr::null_mut() if
+ * wallet is null
+ *
+ * # Safety
+ * The ```contacts_destroy``` method must be called when finished with a TariContacts to prevent a memory leak
+ */
+struct TariContacts *wallet_get_contacts(struct TariWallet *wallet,
+                                         int *error_out);
+
+/**
+ * Search the TariContacts of a TariWallet by alias
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer
+ * `query` - The pointer to a char array. Contacts whose alias contains the query are returned; ASCII characters are
+ * matched case-insensitively. An empty query returns all contacts.
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `*mut TariContacts` - returns the matching contacts, note that it returns ptr::null_mut() if
+ * wallet or query is null or an error is encountered
+ *
+ * # Safety
+ * The ```contacts_destroy``` method must be called when finished with a TariContacts to prevent a memory leak
+ */
+struct TariContacts *wallet_search_contacts(struct TariWallet *wallet,
+                                            const char *query,
+                                            int *error_out);
+
+/**
+ * Get the TariCompletedTransactions from a TariWallet
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `*mut TariCompletedTransactions` - returns the transactions, note that it returns ptr::null_mut() if
+ * wallet is null or an error is encountered
+ *
+ * # Safety
+ * The ```completed_transactions_destroy``` method must be called when finished with a TariCompletedTransactions to
+ * prevent a memory leak
+ */
+struct TariCompletedTransactions *wallet_get_completed_transactions(struct TariWallet *wallet,
+                                                                    int *error_out);
+
+/**
+ * Get the TariCompletedTransactions from a TariWallet, optionally leaving out coinbase transactions
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer
+ * `include_coinbase` - Whether coinbase transactions should be included, when false they are filtered out by the
+ * database query rather than after loading
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `*mut TariCompletedTransactions` - returns the transactions, applying the same status filtering as
+ * `wallet_get_completed_transactions`, note that it returns ptr::null_mut() if wallet is null or an error is
+ * encountered
+ *
+ * # Safety
+ * The ```completed_transactions_destroy``` method must be called when finished with a TariCompletedTransactions to
+ * prevent a memory leak
+ */
+struct TariCompletedTransactions *wallet_get_completed_transactions_ex(struct TariWallet *wallet,
+                                                                       bool include_coinbase,
+                                                                       int *error_out);
+
+/**
+ * Get the non-cancelled TariCompletedTransactions from a TariWallet that were created or mined at or after the given
+ * time, in any status, so that a transaction created before the checkpoint but mined after it is still returned
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer
+ * `since_timestamp` - The checkpoint as seconds since the Unix epoch. Transactions with a timestamp in the same second
+ * are included so that none are missed between polls
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `*mut TariCompletedTransactions` - returns the transactions ordered by the later of their creation and mined
+ * timestamps, note that it returns ptr::null_mut() if wallet is null or an error is encountered
+ *
+ * # Safety
+ * The ```completed_transactions_destroy``` method must be called when finished with a TariCompletedTransactions to
+ * prevent a memory leak
+ */
+struct TariCompletedTransactions *wallet_get_completed_transactions_since(struct TariWallet *wallet,
+                                                                          unsigned long long since_timestamp,
+                                                                          int *error_out);
 
 /**
- * Adds a base node peer to the TariWallet
+ * Get the number of non-cancelled completed transactions of a TariWallet, optionally only those with a given status
  *
  * ## Arguments
  * `wallet` - The TariWallet pointer
- * `public_key` - The TariPublicKey pointer
- * `address` - The pointer to a char array
+ * `status` - The transaction status code, as returned by `completed_transaction_get_status`, or -1 to count all
+ * statuses
  * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
  * as an out parameter.
  *
  * ## Returns
- * `bool` - Returns if successful or not
+ * `c_ulonglong` - Returns the number of transactions, note that it returns 0 if wallet is null, if status is not a
+ * valid transaction status code or if an error is encountered
  *
  * # Safety
  * None
  */
-bool wallet_set_base_node_peer(struct TariWallet *wallet,
-                               TariPublicKey *public_key,
-                               const char *address,
-                               int *error_out);
+unsigned long long wallet_get_transaction_count(struct TariWallet *wallet,
+                                                int status,
+                                                int *error_out);
 
 /**
- * Gets all seed peers known by the wallet
+ * Get the TariPendingInboundTransactions from a TariWallet
+ *
+ * Currently a CompletedTransaction with the Status of Completed and Broadcast is considered Pending by the frontend
  *
  * ## Arguments
  * `wallet` - The TariWallet pointer
@@ -3300,252 +5450,276 @@ bool wallet_set_base_node_peer(struct TariWallet *wallet,
  * as an out parameter.
  *
  * ## Returns
- * `TariPublicKeys` - Returns a list of all known public keys
+ * `*mut TariPendingInboundTransactions` - returns the transactions, note that it returns ptr::null_mut() if
+ * wallet is null or and error is encountered
  *
  * # Safety
- * None
+ * The ```pending_inbound_transactions_destroy``` method must be called when finished with a
+ * TariPendingInboundTransactions to prevent a memory leak
  */
-struct TariPublicKeys *wallet_get_seed_peers(struct TariWallet *wallet,
-                                             int *error_out);
+struct TariPendingInboundTransactions *wallet_get_pending_inbound_transactions(struct TariWallet *wallet,
+                                                                               int *error_out);
 
 /**
- * Upserts a TariContact to the TariWallet. If the contact does not exist it will be Inserted. If it does exist the
- * Alias will be updated.
+ * Get the TariPendingOutboundTransactions from a TariWallet
+ *
+ * Currently a CompletedTransaction with the Status of Completed and Broadcast is considered Pending by the frontend
  *
  * ## Arguments
  * `wallet` - The TariWallet pointer
- * `contact` - The TariContact pointer
  * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
  * as an out parameter.
  *
  * ## Returns
- * `bool` - Returns if successful or not
+ * `*mut TariPendingOutboundTransactions` - returns the transactions, note that it returns ptr::null_mut() if
+ * wallet is null or and error is encountered
  *
  * # Safety
- * None
+ * The ```pending_outbound_transactions_destroy``` method must be called when finished with a
+ * TariPendingOutboundTransactions to prevent a memory leak
  */
-bool wallet_upsert_contact(struct TariWallet *wallet,
-                           TariContact *contact,
-                           int *error_out);
+struct TariPendingOutboundTransactions *wallet_get_pending_outbound_transactions(struct TariWallet *wallet,
+                                                                                 int *error_out);
 
 /**
- * Removes a TariContact from the TariWallet
+ * Get the summed amount of all pending inbound transactions of a TariWallet, read directly from the pending
+ * inbound transactions without fetching them
  *
  * ## Arguments
  * `wallet` - The TariWallet pointer
- * `tx` - The TariPendingInboundTransaction pointer
  * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
  * as an out parameter.
  *
  * ## Returns
- * `bool` - Returns if successful or not
+ * `c_ulonglong` - Returns the total amount in MicroMinotari, note that it returns 0 if wallet is null or an error is
+ * encountered
  *
  * # Safety
  * None
  */
-bool wallet_remove_contact(struct TariWallet *wallet,
-                           TariContact *contact,
-                           int *error_out);
+unsigned long long wallet_get_pending_incoming_total(struct TariWallet *wallet,
+                                                     int *error_out);
 
 /**
- * Gets the available balance from a TariBalance. This is the balance the user can spend.
+ * Get the summed amount of all pending outbound transactions of a TariWallet, read directly from the pending
+ * outbound transactions without fetching them. Fees are not included
  *
  * ## Arguments
- * `balance` - The TariBalance pointer
+ * `wallet` - The TariWallet pointer
  * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
  * as an out parameter.
  *
  * ## Returns
- * `c_ulonglong` - The available balance, 0 if wallet is null
+ * `c_ulonglong` - Returns the total amount in MicroMinotari, note that it returns 0 if wallet is null or an error is
+ * encountered
  *
  * # Safety
  * None
  */
-unsigned long long balance_get_available(TariBalance *balance,
-                                         int *error_out);
+unsigned long long wallet_get_pending_outgoing_total(struct TariWallet *wallet,
+                                                     int *error_out);
 
 /**
- * Gets the time locked balance from a TariBalance. This is the balance the user can spend.
+ * Get the all Cancelled Transactions from a TariWallet. This function will also get cancelled pending inbound and
+ * outbound transaction and include them in this list by converting them to CompletedTransactions
  *
  * ## Arguments
- * `balance` - The TariBalance pointer
+ * `wallet` - The TariWallet pointer
  * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
  * as an out parameter.
  *
  * ## Returns
- * `c_ulonglong` - The time locked balance, 0 if wallet is null
+ * `*mut TariCompletedTransactions` - returns the transactions, note that it returns ptr::null_mut() if
+ * wallet is null or an error is encountered
  *
  * # Safety
- * None
+ * The ```completed_transactions_destroy``` method must be called when finished with a TariCompletedTransactions to
+ * prevent a memory leak
  */
-unsigned long long balance_get_time_locked(TariBalance *balance,
-                                           int *error_out);
+struct TariCompletedTransactions *wallet_get_cancelled_transactions(struct TariWallet *wallet,
+                                                                    int *error_out);
 
 /**
- * Gets the pending incoming balance from a TariBalance. This is the balance the user can spend.
+ * Exports the full transaction history of the wallet as a single JSON document. The document contains a `version`
+ * header followed by `completed`, `cancelled`, `pending_inbound`, `pending_outbound`, `cancelled_pending_inbound` and
+ * `cancelled_pending_outbound` arrays, each sorted by timestamp. Completed and cancelled entries use the same
+ * serialization as `tari_completed_transaction_to_json`.
  *
  * ## Arguments
- * `balance` - The TariBalance pointer
+ * `wallet` - The TariWallet pointer
  * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
  * as an out parameter.
  *
  * ## Returns
- * `c_ulonglong` - The pending incoming, 0 if wallet is null
+ * `*mut c_char` - Returns a pointer to the JSON document. Note that it returns ptr::null_mut() if wallet is null or
+ * an error is encountered
  *
  * # Safety
- * None
+ * The ```string_destroy``` method must be called when finished with a string coming from rust to prevent a memory
+ * leak. The whole history is held in memory while the document is built, so this may allocate a large string for
+ * wallets with a long history.
  */
-unsigned long long balance_get_pending_incoming(TariBalance *balance,
-                                                int *error_out);
+char *wallet_export_transactions_json(struct TariWallet *wallet,
+                                      int *error_out);
 
 /**
- * Gets the pending outgoing balance from a TariBalance. This is the balance the user can spend.
+ * Get the TariCompletedTransaction from a TariWallet by its' TransactionId
  *
  * ## Arguments
- * `balance` - The TariBalance pointer
+ * `wallet` - The TariWallet pointer
+ * `transaction_id` - The TransactionId
  * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
  * as an out parameter.
  *
  * ## Returns
- * `c_ulonglong` - The pending outgoing balance, 0 if wallet is null
+ * `*mut TariCompletedTransaction` - returns the transaction, note that it returns ptr::null_mut() if
+ * wallet is null, an error is encountered or if the transaction is not found
  *
  * # Safety
- * None
+ * The ```completed_transaction_destroy``` method must be called when finished with a TariCompletedTransaction to
+ * prevent a memory leak
  */
-unsigned long long balance_get_pending_outgoing(TariBalance *balance,
-                                                int *error_out);
+TariCompletedTransaction *wallet_get_completed_transaction_by_id(struct TariWallet *wallet,
+                                                                 unsigned long long transaction_id,
+                                                                 int *error_out);
 
 /**
- * Frees memory for a TariBalance
+ * Get the number of confirmations of a completed transaction from a TariWallet by its TransactionId, without
+ * fetching all of the completed transactions
  *
  * ## Arguments
- * `balance` - The pointer to a TariBalance
+ * `wallet` - The TariWallet pointer
+ * `transaction_id` - The TransactionId
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
  *
  * ## Returns
- * `()` - Does not return a value, equivalent to void in C
+ * `c_ulonglong` - Returns the number of confirmations of the transaction, note that it returns 0 and sets
+ * `error_out` if the wallet is null or the transaction is not found
  *
  * # Safety
  * None
  */
-void balance_destroy(TariBalance *balance);
+unsigned long long wallet_get_transaction_confirmations(struct TariWallet *wallet,
+                                                        unsigned long long transaction_id,
+                                                        int *error_out);
 
 /**
- * Sends a TariPendingOutboundTransaction
+ * Get the direction of a transaction from a TariWallet by its TransactionId. Pending and completed transactions are
+ * both looked up, but only the direction is returned so the caller does not need to fetch, inspect and destroy a
+ * transaction pointer.
  *
  * ## Arguments
  * `wallet` - The TariWallet pointer
- * `destination` - The TariWalletAddress pointer of the peer
- * `amount` - The amount
- * `commitments` - A `TariVector` of "strings", tagged as `TariTypeTag::String`, containing commitment's hex values
- *   (see `Commitment::to_hex()`)
- * `fee_per_gram` - The transaction fee
- * `message` - The pointer to a char array
+ * `transaction_id` - The TransactionId
  * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
  * as an out parameter.
  *
  * ## Returns
- * `unsigned long long` - Returns 0 if unsuccessful or the TxId of the sent transaction if successful
+ * `c_int` - Returns the direction of the transaction encoded as an integer as follows:
+ * 0 - Inbound
+ * 1 - Outbound
+ * -1 - The transaction was not found or its direction is unknown. `error_out` is only set if the wallet is null or
+ * there was an error looking up the transaction
  *
  * # Safety
  * None
  */
-unsigned long long wallet_send_transaction(struct TariWallet *wallet,
-                                           TariWalletAddress *destination,
-                                           unsigned long long amount,
-                                           struct TariVector *commitments,
-                                           unsigned long long fee_per_gram,
-                                           const char *message,
-                                           bool one_sided,
-                                           const char *payment_id_string,
-                                           int *error_out);
+int wallet_get_transaction_direction(struct TariWallet *wallet,
+                                     unsigned long long transaction_id,
+                                     int *error_out);
 
 /**
- * Sends a TariPendingOutboundTransaction
+ * Gets the reason a transaction is cancelled from a TariWallet by its TransactionId. Pending and completed
+ * transactions are both looked up, but only the reason is returned so the caller does not need to fetch, inspect
+ * and destroy a transaction pointer.
  *
  * ## Arguments
  * `wallet` - The TariWallet pointer
- * `destination` - The TariWalletAddress pointer of the peer
- * `fee_per_gram` - The transaction fee
+ * `transaction_id` - The TransactionId
  * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
  * as an out parameter.
  *
  * ## Returns
- * `unsigned long long` - Returns 0 if unsuccessful or the TxId of the sent transaction if successful
+ * `c_int` - Returns the same reason codes as `completed_transaction_get_cancellation_reason`, with -1 meaning the
+ * transaction is not cancelled. Cancelled pending transactions are reported as UserCancelled. If the transaction is
+ * not found -1 is returned and `error_out` is set to 204.
  *
  * # Safety
  * None
  */
-unsigned long long scrape_wallet(struct TariWallet *wallet,
-                                 TariWalletAddress *destination,
-                                 unsigned long long fee_per_gram,
-                                 int *error_out);
+int wallet_get_transaction_cancellation_reason(struct TariWallet *wallet,
+                                               unsigned long long transaction_id,
+                                               int *error_out);
 
 /**
- * Gets a fee estimate for an amount
+ * Get the TariPendingInboundTransaction from a TariWallet by its' TransactionId, without fetching all of the pending
+ * inbound transactions. Completed inbound transactions that are not yet mined are returned as pending.
  *
  * ## Arguments
  * `wallet` - The TariWallet pointer
- * `amount` - The amount
- * `commitments` - A `TariVector` of "strings", tagged as `TariTypeTag::String`, containing commitment's hex values
- *   (see `Commitment::to_hex()`)
- * `fee_per_gram` - The fee per gram
- * `num_kernels` - The number of transaction kernels
- * `num_outputs` - The number of outputs
+ * `transaction_id` - The TransactionId
  * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
  * as an out parameter.
  *
  * ## Returns
- * `unsigned long long` - Returns 0 if unsuccessful or the fee estimate in MicroMinotari if successful
+ * `*mut TariPendingInboundTransaction` - returns the transaction, note that it returns ptr::null_mut() if
+ * wallet is null, an error is encountered or if the transaction is not found. A transaction that is not found sets
+ * `error_out` to 108, which is distinct from the null wallet error
  *
  * # Safety
- * None
+ * The ```pending_inbound_transaction_destroy``` method must be called when finished with a
+ * TariPendingInboundTransaction to prevent a memory leak
  */
-unsigned long long wallet_get_fee_estimate(struct TariWallet *wallet,
-                                           unsigned long long amount,
-                                           struct TariVector *commitments,
-                                           unsigned long long fee_per_gram,
-                                           unsigned int num_kernels,
-                                           unsigned int num_outputs,
-                                           int *error_out);
+TariPendingInboundTransaction *wallet_get_pending_inbound_transaction_by_id(struct TariWallet *wallet,
+                                                                            unsigned long long transaction_id,
+                                                                            int *error_out);
 
 /**
- * Gets the number of mining confirmations required
+ * Get the TariPendingOutboundTransaction from a TariWallet by its' TransactionId
  *
  * ## Arguments
  * `wallet` - The TariWallet pointer
+ * `transaction_id` - The TransactionId
  * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
  * as an out parameter.
  *
  * ## Returns
- * `unsigned long long` - Returns the number of confirmations required
+ * `*mut TariPendingOutboundTransaction` - returns the transaction, note that it returns ptr::null_mut() if
+ * wallet is null, an error is encountered or if the transaction is not found
  *
  * # Safety
- * None
+ * The ```pending_outbound_transaction_destroy``` method must be called when finished with a
+ * TariPendingOutboundtransaction to prevent a memory leak
  */
-unsigned long long wallet_get_num_confirmations_required(struct TariWallet *wallet,
-                                                         int *error_out);
+TariPendingOutboundTransaction *wallet_get_pending_outbound_transaction_by_id(struct TariWallet *wallet,
+                                                                              unsigned long long transaction_id,
+                                                                              int *error_out);
 
 /**
- * Sets the number of mining confirmations required
+ * Get a Cancelled transaction from a TariWallet by its TransactionId. Pending Inbound or Outbound transaction will be
+ * converted to a CompletedTransaction
  *
  * ## Arguments
  * `wallet` - The TariWallet pointer
- * `num` - The number of confirmations to require
+ * `transaction_id` - The TransactionId
  * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
  * as an out parameter.
  *
  * ## Returns
- * `()` - Does not return a value, equivalent to void in C
+ * `*mut TariCompletedTransaction` - returns the transaction, note that it returns ptr::null_mut() if
+ * wallet is null, an error is encountered or if the transaction is not found
  *
  * # Safety
- * None
+ * The ```completed_transaction_destroy``` method must be called when finished with a TariCompletedTransaction to
+ * prevent a memory leak
  */
-void wallet_set_num_confirmations_required(struct TariWallet *wallet,
-                                           unsigned long long num,
-                                           int *error_out);
+TariCompletedTransaction *wallet_get_cancelled_transaction_by_id(struct TariWallet *wallet,
+                                                                 unsigned long long transaction_id,
+                                                                 int *error_out);
 
 /**
- * Get the TariContacts from a TariWallet
+ * Get the interactive TariWalletAddress from a TariWallet
  *
  * ## Arguments
  * `wallet` - The TariWallet pointer
@@ -3553,17 +5727,17 @@ void wallet_set_num_confirmations_required(struct TariWallet *wallet,
  * as an out parameter.
  *
  * ## Returns
- * `*mut TariContacts` - returns the contacts, note that it returns ptr::null_mut() if
- * wallet is null
+ * `*mut TariWalletAddress` - returns the address, note that ptr::null_mut() is returned
+ * if wc is null
  *
  * # Safety
- * The ```contacts_destroy``` method must be called when finished with a TariContacts to prevent a memory leak
+ * The ```tari_address_destroy``` method must be called when finished with a TariWalletAddress to prevent a memory leak
  */
-struct TariContacts *wallet_get_contacts(struct TariWallet *wallet,
-                                         int *error_out);
+TariWalletAddress *wallet_get_tari_interactive_address(struct TariWallet *wallet,
+                                                       int *error_out);
 
 /**
- * Get the TariCompletedTransactions from a TariWallet
+ * Get the one_sided only TariWalletAddress from a TariWallet
  *
  * ## Arguments
  * `wallet` - The TariWallet pointer
@@ -3571,41 +5745,37 @@ struct TariContacts *wallet_get_contacts(struct TariWallet *wallet,
  * as an out parameter.
  *
  * ## Returns
- * `*mut TariCompletedTransactions` - returns the transactions, note that it returns ptr::null_mut() if
- * wallet is null or an error is encountered
+ * `*mut TariWalletAddress` - returns the address, note that ptr::null_mut() is returned
+ * if wc is null
  *
  * # Safety
- * The ```completed_transactions_destroy``` method must be called when finished with a TariCompletedTransactions to
- * prevent a memory leak
+ * The ```tari_address_destroy``` method must be called when finished with a TariWalletAddress to prevent a memory leak
  */
-struct TariCompletedTransactions *wallet_get_completed_transactions(struct TariWallet *wallet,
-                                                                    int *error_out);
+TariWalletAddress *wallet_get_tari_one_sided_address(struct TariWallet *wallet,
+                                                     int *error_out);
 
 /**
- * Get the TariPendingInboundTransactions from a TariWallet
- *
- * Currently a CompletedTransaction with the Status of Completed and Broadcast is considered Pending by the frontend
+ * Checks whether a TariWalletAddress belongs to the same network as the TariWallet, so that sends to an address for
+ * the wrong network can be rejected up front
  *
  * ## Arguments
  * `wallet` - The TariWallet pointer
+ * `address` - The pointer to a TariWalletAddress
  * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
  * as an out parameter.
  *
  * ## Returns
- * `*mut TariPendingInboundTransactions` - returns the transactions, note that it returns ptr::null_mut() if
- * wallet is null or and error is encountered
+ * `bool` - Returns true if the address is on the wallet's network, false if it is not or if wallet or address is null
  *
  * # Safety
- * The ```pending_inbound_transactions_destroy``` method must be called when finished with a
- * TariPendingInboundTransactions to prevent a memory leak
+ * None
  */
-struct TariPendingInboundTransactions *wallet_get_pending_inbound_transactions(struct TariWallet *wallet,
-                                                                               int *error_out);
+bool wallet_can_send_to_address(struct TariWallet *wallet,
+                                TariWalletAddress *address,
+                                int *error_out);
 
 /**
- * Get the TariPendingOutboundTransactions from a TariWallet
- *
- * Currently a CompletedTransaction with the Status of Completed and Broadcast is considered Pending by the frontend
+ * Get the comms public key of the node identity of a TariWallet
  *
  * ## Arguments
  * `wallet` - The TariWallet pointer
@@ -3613,19 +5783,17 @@ struct TariPendingInboundTransactions *wallet_get_pending_inbound_transactions(s
  * as an out parameter.
  *
  * ## Returns
- * `*mut TariPendingOutboundTransactions` - returns the transactions, note that it returns ptr::null_mut() if
- * wallet is null or and error is encountered
+ * `*mut TariPublicKey` - returns the comms public key, note that ptr::null_mut() is returned
+ * if wallet is null
  *
  * # Safety
- * The ```pending_outbound_transactions_destroy``` method must be called when finished with a
- * TariPendingOutboundTransactions to prevent a memory leak
+ * The ```public_key_destroy``` method must be called when finished with a TariPublicKey to prevent a memory leak
  */
-struct TariPendingOutboundTransactions *wallet_get_pending_outbound_transactions(struct TariWallet *wallet,
-                                                                                 int *error_out);
+TariPublicKey *wallet_get_comms_public_key(struct TariWallet *wallet,
+                                           int *error_out);
 
 /**
- * Get the all Cancelled Transactions from a TariWallet. This function will also get cancelled pending inbound and
- * outbound transaction and include them in this list by converting them to CompletedTransactions
+ * Get the hex encoded comms node id of the node identity of a TariWallet
  *
  * ## Arguments
  * `wallet` - The TariWallet pointer
@@ -3633,60 +5801,54 @@ struct TariPendingOutboundTransactions *wallet_get_pending_outbound_transactions
  * as an out parameter.
  *
  * ## Returns
- * `*mut TariCompletedTransactions` - returns the transactions, note that it returns ptr::null_mut() if
- * wallet is null or an error is encountered
+ * `*mut c_char` - returns the hex encoded node id, note that ptr::null_mut() is returned
+ * if wallet is null
  *
  * # Safety
- * The ```completed_transactions_destroy``` method must be called when finished with a TariCompletedTransactions to
- * prevent a memory leak
+ * The ```string_destroy``` method must be called when finished with a string coming from rust to prevent a memory leak
  */
-struct TariCompletedTransactions *wallet_get_cancelled_transactions(struct TariWallet *wallet,
-                                                                    int *error_out);
+char *wallet_get_node_id(struct TariWallet *wallet,
+                         int *error_out);
 
 /**
- * Get the TariCompletedTransaction from a TariWallet by its' TransactionId
+ * Export the tor identity of a TariWallet as JSON, to be provided to `transport_tor_create_with_identity` so that
+ * the onion address of the wallet is stable across restarts
  *
  * ## Arguments
  * `wallet` - The TariWallet pointer
- * `transaction_id` - The TransactionId
  * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
  * as an out parameter.
  *
  * ## Returns
- * `*mut TariCompletedTransaction` - returns the transaction, note that it returns ptr::null_mut() if
- * wallet is null, an error is encountered or if the transaction is not found
+ * `*mut c_char` - returns the tor identity JSON, note that ptr::null_mut() is returned if the wallet is null or has
+ * no tor identity
  *
  * # Safety
- * The ```completed_transaction_destroy``` method must be called when finished with a TariCompletedTransaction to
- * prevent a memory leak
+ * The ```string_destroy``` method must be called when finished with a string coming from rust to prevent a memory leak
  */
-TariCompletedTransaction *wallet_get_completed_transaction_by_id(struct TariWallet *wallet,
-                                                                 unsigned long long transaction_id,
-                                                                 int *error_out);
+char *wallet_export_tor_identity(struct TariWallet *wallet,
+                                 int *error_out);
 
 /**
- * Get the TariPendingInboundTransaction from a TariWallet by its' TransactionId
+ * Get the onion address a TariWallet using the Tor transport is reachable at, as published in its node identity
  *
  * ## Arguments
  * `wallet` - The TariWallet pointer
- * `transaction_id` - The TransactionId
  * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
  * as an out parameter.
  *
  * ## Returns
- * `*mut TariPendingInboundTransaction` - returns the transaction, note that it returns ptr::null_mut() if
- * wallet is null, an error is encountered or if the transaction is not found
+ * `*mut c_char` - returns the onion address as a multiaddr string, note that an empty string is returned and
+ * `error_out` is set if the wallet is null or is not using the Tor transport
  *
  * # Safety
- * The ```pending_inbound_transaction_destroy``` method must be called when finished with a
- * TariPendingInboundTransaction to prevent a memory leak
+ * The ```string_destroy``` method must be called when finished with a string coming from rust to prevent a memory leak
  */
-TariPendingInboundTransaction *wallet_get_pending_inbound_transaction_by_id(struct TariWallet *wallet,
-                                                                            unsigned long long transaction_id,
-                                                                            int *error_out);
+char *wallet_get_onion_address(struct TariWallet *wallet,
+                               int *error_out);
 
 /**
- * Get the TariPendingOutboundTransaction from a TariWallet by its' TransactionId
+ * Cancel a Pending Transaction
  *
  * ## Arguments
  * `wallet` - The TariWallet pointer
@@ -3695,59 +5857,63 @@ TariPendingInboundTransaction *wallet_get_pending_inbound_transaction_by_id(stru
  * as an out parameter.
  *
  * ## Returns
- * `*mut TariPendingOutboundTransaction` - returns the transaction, note that it returns ptr::null_mut() if
- * wallet is null, an error is encountered or if the transaction is not found
+ * `bool` - returns whether the transaction could be cancelled
  *
  * # Safety
- * The ```pending_outbound_transaction_destroy``` method must be called when finished with a
- * TariPendingOutboundtransaction to prevent a memory leak
+ * None
  */
-TariPendingOutboundTransaction *wallet_get_pending_outbound_transaction_by_id(struct TariWallet *wallet,
-                                                                              unsigned long long transaction_id,
-                                                                              int *error_out);
+bool wallet_cancel_pending_transaction(struct TariWallet *wallet,
+                                       unsigned long long transaction_id,
+                                       int *error_out);
 
 /**
- * Get a Cancelled transaction from a TariWallet by its TransactionId. Pending Inbound or Outbound transaction will be
- * converted to a CompletedTransaction
+ * Cancel every pending outbound transaction that is older than the given age, releasing the outputs it encumbered
  *
  * ## Arguments
  * `wallet` - The TariWallet pointer
- * `transaction_id` - The TransactionId
+ * `older_than_secs` - The age in seconds a pending outbound transaction must exceed to be cancelled
  * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
  * as an out parameter.
  *
  * ## Returns
- * `*mut TariCompletedTransaction` - returns the transaction, note that it returns ptr::null_mut() if
- * wallet is null, an error is encountered or if the transaction is not found
+ * `c_uint` - Returns the number of transactions that were cancelled, note that it returns 0 if wallet is null or an
+ * error is encountered. A transaction that fails to cancel is left pending and not counted, the others are still
+ * cancelled
  *
  * # Safety
- * The ```completed_transaction_destroy``` method must be called when finished with a TariCompletedTransaction to
- * prevent a memory leak
+ * None
  */
-TariCompletedTransaction *wallet_get_cancelled_transaction_by_id(struct TariWallet *wallet,
-                                                                 unsigned long long transaction_id,
-                                                                 int *error_out);
+unsigned int wallet_cancel_expired_pending_transactions(struct TariWallet *wallet,
+                                                        unsigned long long older_than_secs,
+                                                        int *error_out);
 
+#if defined(TARI_FFI_TEST)
 /**
- * Get the interactive TariWalletAddress from a TariWallet
+ * Advance the clock the wallet's transaction service reads the current time from. This is only available when the
+ * library is built with the `ffi-test` feature, so that integration tests can exercise time-based behaviour such as
+ * pending transaction timeouts deterministically. The service observes the new time the next time it measures the
+ * age of a transaction.
  *
  * ## Arguments
  * `wallet` - The TariWallet pointer
+ * `seconds` - The number of seconds to move the clock forward by
  * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
  * as an out parameter.
  *
  * ## Returns
- * `*mut TariWalletAddress` - returns the address, note that ptr::null_mut() is returned
- * if wc is null
+ * `bool` - Returns true if the clock was advanced, false if wallet is null
  *
  * # Safety
- * The ```tari_address_destroy``` method must be called when finished with a TariWalletAddress to prevent a memory leak
+ * None
  */
-TariWalletAddress *wallet_get_tari_interactive_address(struct TariWallet *wallet,
-                                                       int *error_out);
+bool wallet_test_advance_time(struct TariWallet *wallet,
+                              unsigned long long seconds,
+                              int *error_out);
 
 /**
- * Get the one_sided only TariWalletAddress from a TariWallet
+ * Simulate a counterparty sending this wallet a transaction. This is only available when the library is built with
+ * the `ffi-test` feature. The transaction is accepted as if it had arrived over the network, so it is stored as a
+ * pending inbound transaction and the `callback_received_transaction` callback is called.
  *
  * ## Arguments
  * `wallet` - The TariWallet pointer
@@ -3755,33 +5921,36 @@ TariWalletAddress *wallet_get_tari_interactive_address(struct TariWallet *wallet
  * as an out parameter.
  *
  * ## Returns
- * `*mut TariWalletAddress` - returns the address, note that ptr::null_mut() is returned
- * if wc is null
+ * `bool` - Returns true if the transaction was received, false if wallet is null or an error occurred
  *
  * # Safety
- * The ```tari_address_destroy``` method must be called when finished with a TariWalletAddress to prevent a memory leak
+ * None
  */
-TariWalletAddress *wallet_get_tari_one_sided_address(struct TariWallet *wallet,
-                                                     int *error_out);
+bool wallet_test_receive_transaction(struct TariWallet *wallet,
+                                     int *error_out);
 
 /**
- * Cancel a Pending Transaction
+ * Simulate the recipient of a pending outbound transaction replying to it. This is only available when the library
+ * is built with the `ffi-test` feature. The transaction is finalized with a recipient output built from this wallet's
+ * keys, moved to the completed transactions and broadcast like any other. The `callback_received_transaction_reply`
+ * callback is called once it has been completed, which may happen after this function returns.
  *
  * ## Arguments
  * `wallet` - The TariWallet pointer
- * `transaction_id` - The TransactionId
+ * `tx` - The pending outbound transaction to complete as a TariCompletedTransaction, only its transaction id is used
  * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
  * as an out parameter.
  *
  * ## Returns
- * `bool` - returns whether the transaction could be cancelled
+ * `bool` - Returns true if the transaction was completed, false if wallet or tx is null or an error occurred
  *
  * # Safety
  * None
  */
-bool wallet_cancel_pending_transaction(struct TariWallet *wallet,
-                                       unsigned long long transaction_id,
-                                       int *error_out);
+bool wallet_test_complete_sent_transaction(struct TariWallet *wallet,
+                                           TariCompletedTransaction *tx,
+                                           int *error_out);
+#endif
 
 /**
  * This function will tell the wallet to query the set base node to confirm the status of transaction outputs
@@ -3802,6 +5971,26 @@ bool wallet_cancel_pending_transaction(struct TariWallet *wallet,
 unsigned long long wallet_start_txo_validation(struct TariWallet *wallet,
                                                int *error_out);
 
+/**
+ * This function will tell the wallet to reset the validation state of all its transaction outputs (TXOs) and
+ * revalidate every one of them against the set base node. Unlike `wallet_start_txo_validation`, outputs that were
+ * already validated are checked again, which makes this the fix for a wallet whose balance has gone out of sync.
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter. It is set to 116 if a TXO validation is already running, in which case nothing is reset.
+ *
+ * ## Returns
+ * `c_ulonglong` -  Returns a unique Request Key that is used to identify which `callback_txo_validation_complete`
+ * call refers to this revalidation. Note the result will be 0 if there was an error
+ *
+ * # Safety
+ * None
+ */
+unsigned long long wallet_revalidate_all_outputs(struct TariWallet *wallet,
+                                                 int *error_out);
+
 /**
  * This function will tell the wallet to query the set base node to confirm the status of mined transactions.
  *
@@ -3856,6 +6045,28 @@ bool wallet_restart_transaction_broadcast(struct TariWallet *wallet,
 struct TariSeedWords *wallet_get_seed_words(struct TariWallet *wallet,
                                             int *error_out);
 
+/**
+ * Gets the seed words representing the seed private key of the provided `TariWallet` in the requested mnemonic
+ * language.
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer
+ * `language` - The required language as a string, as accepted by `seed_words_get_mnemonic_word_list_for_language`
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `*mut TariSeedWords` - A collection of the seed words. Note that it returns ptr::null_mut() if wallet or language is
+ * null or if the language is not supported
+ *
+ * # Safety
+ * The ```tari_seed_words_destroy``` method must be called when finished with a
+ * TariSeedWords to prevent a memory leak
+ */
+struct TariSeedWords *wallet_get_seed_words_in_language(struct TariWallet *wallet,
+                                                        const char *language,
+                                                        int *error_out);
+
 /**
  * Set the power mode of the wallet to Low Power mode which will reduce the amount of network operations the wallet
  * performs to conserve power
@@ -3888,7 +6099,8 @@ void wallet_set_normal_power_mode(struct TariWallet *wallet,
  *
  * ## Arguments
  * `wallet` - The TariWallet pointer.
- * `key` - The pointer to a Utf8 string representing the Key
+ * `key` - The pointer to a Utf8 string representing the Key. Keys reserved by this library, such as the one holding
+ * the default message, fail with an `InvalidArgument` error (code 7)
  * `value` - The pointer to a Utf8 string representing the Value ot be stored
  * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
  * as an out parameter.
@@ -3910,7 +6122,8 @@ bool wallet_set_key_value(struct TariWallet *wallet,
  *
  * ## Arguments
  * `wallet` - The TariWallet pointer.
- * `key` - The pointer to a Utf8 string representing the Key
+ * `key` - The pointer to a Utf8 string representing the Key. Keys reserved by this library, such as the one holding
+ * the default message, fail with an `InvalidArgument` error (code 7)
  * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
  * as an out parameter.
  *
@@ -3925,12 +6138,35 @@ char *wallet_get_value(struct TariWallet *wallet,
                        const char *key,
                        int *error_out);
 
+/**
+ * get a stored Value that was previously stored in the Wallet storage used for Client Key Value store. Unlike
+ * `wallet_get_value` a missing Key is not treated as an error.
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer.
+ * `key` - The pointer to a Utf8 string representing the Key. Keys reserved by this library, such as the one holding
+ * the default message, fail with an `InvalidArgument` error (code 7)
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `*mut c_char` - Returns a pointer to a char array of the Value string. Note that it returns a null pointer if the
+ * Key is not found, in which case the error_ptr is not set, or if an error occured.
+ *
+ * # Safety
+ * The ```string_destroy``` method must be called when finished with a string from rust to prevent a memory leak
+ */
+char *wallet_get_key_value(struct TariWallet *wallet,
+                           const char *key,
+                           int *error_out);
+
 /**
  * Clears a Value for the provided Key Value in the Wallet storage used for Client Key Value store
  *
  * ## Arguments
  * `wallet` - The TariWallet pointer.
- * `key` - The pointer to a Utf8 string representing the Key
+ * `key` - The pointer to a Utf8 string representing the Key. Keys reserved by this library, such as the one holding
+ * the default message, fail with an `InvalidArgument` error (code 7)
  * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
  * as an out parameter.
  *
@@ -3945,6 +6181,49 @@ bool wallet_clear_value(struct TariWallet *wallet,
                         const char *key,
                         int *error_out);
 
+/**
+ * Sets the message that `wallet_send_transaction`, `wallet_send_one_sided_transaction` and
+ * `wallet_send_transaction_to_many` attach to a transaction when they are called without a message. The message is
+ * kept under a reserved key of the Wallet storage used for Client Key Value store, so it persists across
+ * restarts but can not be changed through `wallet_set_key_value` or `wallet_clear_value`.
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer.
+ * `message` - The pointer to a char array, at most 256 bytes long, otherwise it fails with a `MessageTooLong` error
+ * (code 11). If it is null or empty the default message is cleared
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `bool` - Return a boolean value indicating the operation's success or failure. The error_ptr will hold the error
+ * code if there was a failure
+ *
+ * # Safety
+ * None
+ */
+bool wallet_set_default_message(struct TariWallet *wallet,
+                                const char *message,
+                                int *error_out);
+
+/**
+ * Gets the message that the send functions attach to a transaction when they are called without a message, as set
+ * with `wallet_set_default_message`
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer.
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `*mut c_char` - Returns a pointer to a char array of the default message, which is empty if no default message is
+ * set. Note that it returns a null pointer if an error occured.
+ *
+ * # Safety
+ * The ```string_destroy``` method must be called when finished with a string from rust to prevent a memory leak
+ */
+char *wallet_get_default_message(struct TariWallet *wallet,
+                                 int *error_out);
+
 /**
  * Check if a Wallet has the data of an In Progress Recovery in its database.
  *
@@ -3955,7 +6234,8 @@ bool wallet_clear_value(struct TariWallet *wallet,
  *
  * ## Returns
  * `bool` - Return a boolean value indicating whether there is an in progress recovery or not. An error will also
- * result in a false result.
+ * result in a false result. Unlike the `recovery_in_progress` out parameter of `wallet_create`, which is only written
+ * once at startup, this reads the recovery marker from the database on every call so it can be polled.
  *
  * # Safety
  * None
@@ -3963,6 +6243,48 @@ bool wallet_clear_value(struct TariWallet *wallet,
 bool wallet_is_recovery_in_progress(struct TariWallet *wallet,
                                     int *error_out);
 
+/**
+ * Gets the output counts from the most recent UTXO scan, which is either the last recovery or the last background
+ * scan that checked outputs. This helps tell an empty chain history apart from outputs that were found with this
+ * wallet's keys but could not be recovered.
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer.
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `*mut TariVector` - Returns a `U64` vector of `[outputs_scanned, outputs_recovered, outputs_failed_decode]`, where
+ * `outputs_failed_decode` counts outputs whose spending key was recovered but whose script key could not be derived.
+ * All counts are zero if no scan has completed a round since the wallet started. Note that it returns ptr::null_mut()
+ * if wallet is null.
+ *
+ * # Safety
+ * `destroy_tari_vector()` must be called after use.
+ */
+struct TariVector *wallet_get_recovery_stats(struct TariWallet *wallet,
+                                             int *error_out);
+
+/**
+ * Abandons an in progress recovery by clearing the recovery marker and the UTXO scanner progress from the wallet
+ * database, so that the next scan starts from the wallet birthday. Outputs that have already been recovered are not
+ * removed.
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer.
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `bool` - Return a boolean value indicating whether any recovery state was cleared. An error will also result in a
+ * false result.
+ *
+ * # Safety
+ * None
+ */
+bool wallet_clear_recovery_state(struct TariWallet *wallet,
+                                 int *error_out);
+
 /**
  * Starts the Wallet recovery process.
  *
@@ -4029,6 +6351,35 @@ bool wallet_start_recovery(struct TariWallet *wallet,
                            const char *recovered_output_message,
                            int *error_out);
 
+/**
+ * Rescans the blockchain for the outputs of this wallet from the given height. The progress of the UTXO scanner is
+ * cleared, the scan start height is set as with `wallet_set_scan_start_height` and the scanner is started against
+ * the seed peers, as when `wallet_start_recovery` is called without base node public keys. Outputs that have already
+ * been found are kept.
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer.
+ * `height` - The block height to start scanning from
+ * `recovery_progress_callback` - The callback function pointer that will be used to asynchronously communicate
+ * progress to the client, with the same events as the `recovery_progress_callback` of `wallet_start_recovery`
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `bool` - Return a boolean value indicating whether the rescan started successfully or not. If a recovery or rescan
+ * is already in progress no new rescan is started and `error_out` is set to a "busy" error.
+ *
+ * # Safety
+ * None
+ */
+bool wallet_rescan_from_height(struct TariWallet *wallet,
+                               unsigned long long height,
+                               void (*recovery_progress_callback)(void *context,
+                                                                  uint8_t,
+                                                                  uint64_t,
+                                                                  uint64_t),
+                               int *error_out);
+
 /**
  * Set the text message that is applied to a detected One-Side payment transaction when it is scanned from the
  * blockchain
@@ -4050,6 +6401,46 @@ bool wallet_set_one_sided_payment_message(struct TariWallet *wallet,
                                           const char *message,
                                           int *error_out);
 
+/**
+ * Gets the birthday of the wallet, as stored from its `CipherSeed` when the wallet was created. Recovery and scanning
+ * start from shortly before this date unless a start height is set with `wallet_set_scan_start_height`.
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer.
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `c_ulonglong` - Returns the birthday as the number of days since 2022-01-01 UTC, or 0 if there was an error
+ *
+ * # Safety
+ * None
+ */
+unsigned long long wallet_get_birthday(struct TariWallet *wallet,
+                                       int *error_out);
+
+/**
+ * Sets the block height from which the UTXO scanner starts when it has no previously scanned blocks to continue from,
+ * i.e. for a recovery started with `wallet_start_recovery`. This replaces the start height derived from the wallet
+ * birthday and allows a targeted rescan from a known height. A height beyond the chain tip starts the scan at the tip.
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer.
+ * `height` - The block height to start scanning from
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `bool` - Return a boolean value indicating the operation's success or failure. The error_ptr will hold the error
+ * code if there was a failure
+ *
+ * # Safety
+ * None
+ */
+bool wallet_set_scan_start_height(struct TariWallet *wallet,
+                                  unsigned long long height,
+                                  int *error_out);
+
 /**
  * Gets the current emoji set
  *
@@ -4112,6 +6503,41 @@ struct ByteVector *emoji_set_get_at(const struct EmojiSet *emoji_set,
  */
 void emoji_set_destroy(struct EmojiSet *emoji_set);
 
+/**
+ * Stops the wallet services and the network activity of a TariWallet without freeing it. Once stopped, functions that
+ * need the wallet services fail with a "wallet stopped" error. The handle must still be freed with `wallet_destroy`.
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `bool` - Returns true once the wallet services have shut down, false if the wallet is null or was already stopped
+ *
+ * # Safety
+ * None
+ */
+bool wallet_stop(struct TariWallet *wallet,
+                 int *error_out);
+
+/**
+ * Checks whether the wallet services of a TariWallet are running, i.e. `wallet_stop` has not been called on it
+ *
+ * ## Arguments
+ * `wallet` - The TariWallet pointer
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `bool` - Returns true if the wallet is running, false if it has been stopped or the wallet is null
+ *
+ * # Safety
+ * None
+ */
+bool wallet_is_running(struct TariWallet *wallet,
+                       int *error_out);
+
 /**
  * Frees memory for a TariWallet
  *
@@ -4382,6 +6808,24 @@ unsigned long long basenode_state_get_height_of_the_longest_chain(struct TariBas
 struct ByteVector *basenode_state_get_best_block(struct TariBaseNodeState *ptr,
                                                  int *error_out);
 
+/**
+ * Extracts the best block hash as a hex string
+ *
+ * ## Arguments
+ * `ptr` - The pointer to a `TariBaseNodeState`
+ * `error_out` - Pointer to an int which will be modified to an error code should one occur, may not be null. Functions
+ * as an out parameter.
+ *
+ * ## Returns
+ * `*mut c_char` - The hex encoded block hash of the current tip of the longest valid chain. Note that it returns an
+ * empty string if there was an error
+ *
+ * # Safety
+ * The ```string_destroy``` method must be called when finished with a string from rust to prevent a memory leak
+ */
+char *basenode_state_get_best_block_hash(struct TariBaseNodeState *ptr,
+                                         int *error_out);
+
 /**
  * Extracts a timestamp of the best block
  *
@@ -4489,6 +6933,20 @@ unsigned long long basenode_state_get_node_updated_at(struct TariBaseNodeState *
 unsigned long long basenode_state_get_latency(struct TariBaseNodeState *ptr,
                                               int *error_out);
 
+/**
+ * Frees memory for a `TariBaseNodeState` received through the base node state callback
+ *
+ * ## Arguments
+ * `ptr` - The pointer to a `TariBaseNodeState`
+ *
+ * ## Returns
+ * `()` - Does not return a value, equivalent to void in C
+ *
+ * # Safety
+ * None
+ */
+void basenode_state_destroy(struct TariBaseNodeState *ptr);
+
 #ifdef __cplusplus
 } // extern "C"
 #endif // __cplusplus
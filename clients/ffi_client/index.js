@@ -40,6 +40,11 @@ try {
     30,
     600,
     false,
+    true,
+    true,
+    0,
+    0,
+    0,
     err
   );
 
@@ -66,6 +66,11 @@ impl StaticApplicationInfo {
             r#"#[allow(dead_code)] pub const APP_AUTHOR: &str = "{}";"#,
             self.manifest.package.authors.join(","),
         )?;
+        writeln!(
+            file,
+            r#"#[allow(dead_code)] pub const APP_COMMIT: &str = "{}";"#,
+            self.commit
+        )?;
         Ok(out_path)
     }
 
@@ -26,11 +26,15 @@ use std::{
     pin::Pin,
     sync::Arc,
     task::{Context, Poll},
+    time::Duration,
 };
 
 use futures::{ready, FutureExt};
 use multiaddr::Multiaddr;
-use tokio::net::{TcpListener, TcpStream};
+use tokio::{
+    net::{TcpListener, TcpStream},
+    time,
+};
 use tokio_stream::Stream;
 
 use super::{dns::DnsResolver, Transport};
@@ -48,6 +52,7 @@ pub struct TcpTransport {
     // #[allow(clippy::option_option)]
     // keepalive: Option<Option<Duration>>,
     nodelay: Option<bool>,
+    connect_timeout: Option<Duration>,
     dns_resolver: DnsResolverRef,
 }
 
@@ -67,6 +72,9 @@ impl TcpTransport {
     // #[doc("Sets `TCP_NODELAY` i.e disable Nagle's algorithm if set to true.")]
     setter_mut!(set_nodelay, nodelay, Option<bool>);
 
+    // #[doc("Sets the maximum time to wait for an outbound connection to be established.")]
+    setter_mut!(set_connect_timeout, connect_timeout, Option<Duration>);
+
     /// Create a new TcpTransport
     pub fn new() -> Self {
         Default::default()
@@ -103,6 +111,7 @@ impl Default for TcpTransport {
         Self {
             ttl: None,
             nodelay: None,
+            connect_timeout: None,
             dns_resolver: Arc::new(SystemDnsResolver),
         }
     }
@@ -132,7 +141,20 @@ impl Transport for TcpTransport {
             .await
             .map_err(|err| io::Error::new(io::ErrorKind::Other, format!("Address resolution failed: {}", err)))?;
 
-        let socket = TcpOutbound::new(TcpStream::connect(socket_addr).boxed(), self.clone()).await?;
+        let connect = TcpStream::connect(socket_addr);
+        let connect = match self.connect_timeout {
+            Some(connect_timeout) => async move {
+                time::timeout(connect_timeout, connect).await.map_err(|_| {
+                    io::Error::new(
+                        io::ErrorKind::TimedOut,
+                        format!("Connection timed out after {:.2?}", connect_timeout),
+                    )
+                })?
+            }
+            .boxed(),
+            None => connect.boxed(),
+        };
+        let socket = TcpOutbound::new(connect, self.clone()).await?;
         Ok(socket)
     }
 }
@@ -44,6 +44,13 @@ impl TcpWithTorTransport {
         self
     }
 
+    /// Sets the SOCKS address to the Tor proxy to use for onion addresses only. DNS addresses are resolved directly,
+    /// bypassing the proxy.
+    pub fn set_tor_socks_proxy_for_onion_only(&mut self, socks_config: SocksConfig) -> &mut Self {
+        self.socks_transport = Some(SocksTransport::new(socks_config));
+        self
+    }
+
     /// Create a new TcpTransport with the Tor socks proxy enabled
     pub fn with_tor_socks_proxy(socks_config: SocksConfig) -> Self {
         let mut transport = Self::default();
@@ -50,6 +50,11 @@ impl CommsConfig {
                 30,
                 600,
                 false, // This needs to be 'false' for the tests to pass
+                true,
+                true,
+                0,
+                0,
+                0,
                 &mut error,
             );
             if error > 0 {
@@ -347,7 +347,12 @@ extern "C" {
     pub fn transaction_send_status_decode(status: *const TariTransactionSendStatus, error_out: *mut c_int) -> c_uint;
     pub fn transaction_send_status_destroy(status: *mut TariTransactionSendStatus);
     pub fn transport_memory_create() -> *mut TariTransportConfig;
-    pub fn transport_tcp_create(listener_address: *const c_char, error_out: *mut c_int) -> *mut TariTransportConfig;
+    pub fn transport_tcp_create(
+        listener_address: *const c_char,
+        dial_timeout_secs: c_ulonglong,
+        bypass_proxy: bool,
+        error_out: *mut c_int,
+    ) -> *mut TariTransportConfig;
     pub fn transport_tor_create(
         control_server_address: *const c_char,
         tor_cookie: *const ByteVector,
@@ -368,6 +373,11 @@ extern "C" {
         discovery_timeout_in_secs: c_ulonglong,
         saf_message_duration_in_secs: c_ulonglong,
         exclude_dial_test_addresses: bool,
+        saf_auto_request: bool,
+        dht_auto_join: bool,
+        num_neighbouring_nodes: c_uint,
+        num_random_nodes: c_uint,
+        min_desired_peers: c_uint,
         error_out: *mut c_int,
     ) -> *mut TariCommsConfig;
     pub fn comms_config_destroy(wc: *mut TariCommsConfig);
@@ -43,7 +43,7 @@ impl TransportConfig {
         let ptr;
         let mut error: c_int = 0;
         unsafe {
-            ptr = ffi_import::transport_tcp_create(listener_address, &mut error);
+            ptr = ffi_import::transport_tcp_create(listener_address, 0, false, &mut error);
             if error > 0 {
                 println!("transport_tcp_create error {}", error);
                 panic!("transport_tcp_create error");